@@ -0,0 +1,189 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+//! Benchmarks the spatial structures against testing every object.
+//!
+//! Run with `cargo bench`. The scene is a grid of boxes, viewed by a camera that sees
+//! only part of it.
+
+#![feature (test)]
+
+extern crate ion_core;
+extern crate test;
+
+use ion_core::util::math::{Aabb, Frustum, Mat4, Ray, Util, Vec3};
+use ion_core::util::spatial::{Bvh, Octree};
+
+use test::Bencher;
+
+// Static variables
+static GRID_SIZE: usize = 40;
+
+/*===============================================================================================*/
+/*------SCENE SETUP------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Returns a grid of boxes, GRID_SIZE along each axis.
+fn get_objects () -> Vec<(Aabb, usize)> {
+
+    let mut objects = Vec::new ();
+    let extents = Vec3 {x: 0.5, y: 0.5, z: 0.5};
+
+    for x in 0..GRID_SIZE {
+
+        for y in 0..GRID_SIZE {
+
+            for z in 0..GRID_SIZE {
+
+                let centre = Vec3 {x: x as f32 * 4.0 - 80.0, y: y as f32 * 4.0 - 80.0, z: z as f32 * 4.0 - 80.0};
+                objects.push ((Aabb::from_centre_extents (&centre, &extents), objects.len ()));
+            }
+        }
+    }
+
+    objects
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Returns the frustum of a camera in the grid.
+fn get_frustum () -> Frustum {
+
+    let view = Mat4::view (&Vec3::new (), &Vec3 {x: 1.0, y: 0.2, z: 1.0}, &Vec3::up ());
+    let projection = Mat4::perspective (Util::deg2rad (60.0), 16.0 / 9.0, 0.1, 60.0);
+
+    Frustum::from_matrix (&(projection * view))
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Returns an octree containing the objects.
+fn get_octree (objects: &[(Aabb, usize)]) -> Octree<usize> {
+
+    let mut octree = Octree::new (&Vec3::new (), 82.0, 4);
+
+    for &(bounds, index) in objects {
+        octree.insert (&bounds, index);
+    }
+
+    octree
+}
+
+/*===============================================================================================*/
+/*------FRUSTUM QUERIES--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+#[bench]
+fn frustum_brute_force (b: &mut Bencher) {
+
+    let objects = get_objects ();
+    let frustum = get_frustum ();
+
+    b.iter (|| objects.iter ().filter (|&&(ref bounds, _)| frustum.intersects_aabb (bounds)).count ());
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+#[bench]
+fn frustum_bvh (b: &mut Bencher) {
+
+    let bvh = Bvh::build (get_objects ());
+    let frustum = get_frustum ();
+
+    b.iter (|| bvh.query_frustum (&frustum).len ());
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+#[bench]
+fn frustum_octree (b: &mut Bencher) {
+
+    let octree = get_octree (&get_objects ());
+    let frustum = get_frustum ();
+
+    b.iter (|| octree.query_frustum (&frustum).len ());
+}
+
+/*===============================================================================================*/
+/*------RAY QUERIES------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+#[bench]
+fn ray_brute_force (b: &mut Bencher) {
+
+    let objects = get_objects ();
+    let ray = Ray::new (&Vec3 {x: -90.0, y: 0.1, z: 0.1}, &Vec3 {x: 1.0, y: 0.05, z: 0.02});
+
+    b.iter (|| objects.iter ().filter (|&&(ref bounds, _)| ray.intersect_aabb (bounds).is_some ()).count ());
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+#[bench]
+fn ray_bvh (b: &mut Bencher) {
+
+    let bvh = Bvh::build (get_objects ());
+    let ray = Ray::new (&Vec3 {x: -90.0, y: 0.1, z: 0.1}, &Vec3 {x: 1.0, y: 0.05, z: 0.02});
+
+    b.iter (|| bvh.query_ray (&ray, 1000.0).len ());
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+#[bench]
+fn ray_octree (b: &mut Bencher) {
+
+    let octree = get_octree (&get_objects ());
+    let ray = Ray::new (&Vec3 {x: -90.0, y: 0.1, z: 0.1}, &Vec3 {x: 1.0, y: 0.05, z: 0.02});
+
+    b.iter (|| octree.query_ray (&ray, 1000.0).len ());
+}
+
+/*===============================================================================================*/
+/*------CONSTRUCTION-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+#[bench]
+fn build_bvh (b: &mut Bencher) {
+
+    let objects = get_objects ();
+    b.iter (|| Bvh::build (objects.clone ()).get_node_count ());
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+#[bench]
+fn update_octree (b: &mut Bencher) {
+
+    let objects = get_objects ();
+    let mut octree = Octree::new (&Vec3::new (), 82.0, 4);
+    let handles: Vec<_> = objects.iter ().map (|&(bounds, index)| octree.insert (&bounds, index)).collect ();
+    let offset = Vec3 {x: 0.25, y: 0.0, z: 0.0};
+    let mut step = 0;
+
+    // Moves every object back and forth
+    b.iter (|| {
+
+        step += 1;
+
+        for (handle, &(bounds, _)) in handles.iter ().zip (objects.iter ()) {
+
+            let shift = if step % 2 == 0 {Vec3::new ()} else {offset};
+            octree.update (*handle, &Aabb {min: bounds.min + shift, max: bounds.max + shift});
+        }
+    });
+}
@@ -0,0 +1,86 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::Util;
+
+/*===============================================================================================*/
+/*------ATTENUATION MODEL ENUM-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Defines how the volume of an emitter falls off with distance.
+///
+/// Every model plays at full volume up to the minimum distance, and stops falling off
+/// at the maximum distance. The rolloff factor scales how quickly the volume falls.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum AttenuationModel {
+
+    /// The volume falls in a straight line, to silence at the maximum distance.
+    Linear,
+    /// The volume falls in inverse proportion to the distance, like sound in open air.
+    Inverse,
+    /// The volume falls exponentially with the distance.
+    Exponential,
+}
+
+/*===============================================================================================*/
+/*------ATTENUATION MODEL PUBLIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AttenuationModel {
+
+    /// Returns the volume at a distance, from 0 to 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::AttenuationModel;
+    /// #
+    /// assert_eq! (AttenuationModel::Linear.get_gain (6.0, 1.0, 11.0, 1.0), 0.5);
+    /// assert_eq! (AttenuationModel::Inverse.get_gain (2.0, 1.0, 100.0, 1.0), 0.5);
+    /// assert! ((AttenuationModel::Exponential.get_gain (4.0, 1.0, 100.0, 1.0) - 0.25).abs () < 0.0001);
+    ///
+    /// // Within the minimum distance, the volume is full
+    /// assert_eq! (AttenuationModel::Inverse.get_gain (0.5, 1.0, 100.0, 1.0), 1.0);
+    ///
+    /// // Past the maximum distance, the volume stops falling
+    /// assert_eq! (AttenuationModel::Inverse.get_gain (500.0, 1.0, 100.0, 1.0), 0.01);
+    /// ```
+    pub fn get_gain (&self, distance: f32, min_distance: f32, max_distance: f32, rolloff: f32) -> f32 {
+
+        let min_distance = Util::max (min_distance, 0.0001);
+        let max_distance = Util::max (max_distance, min_distance);
+        let distance = Util::clamp (distance, min_distance, max_distance);
+
+        let gain = match *self {
+
+            AttenuationModel::Linear => {
+
+                if max_distance > min_distance {
+                    1.0 - rolloff * (distance - min_distance) / (max_distance - min_distance)
+                }
+
+                else {
+                    1.0
+                }
+            },
+
+            AttenuationModel::Inverse     => min_distance / (min_distance + rolloff * (distance - min_distance)),
+            AttenuationModel::Exponential => (distance / min_distance).powf (-rolloff)
+        };
+
+        Util::clamp (gain, 0.0, 1.0)
+    }
+}
@@ -0,0 +1,55 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioConfig, AudioMixer};
+use ::util::traits::AsAny;
+
+use std::sync::{Arc, Mutex};
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND TRAIT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Used for device agnostic audio output.
+///
+/// Backends pull mixed audio from the mixer whenever their output needs more, either from
+/// a device callback on the audio thread, or from `update` on the main thread.
+/// The mixer is shared behind a mutex, so it should only be locked briefly.
+pub trait AudioBackend: AsAny {
+
+    /// Opens the output, and starts pulling audio from the mixer.
+    ///
+    /// The config holds the requested format. The backend sets the format of the mixer
+    /// to the one it actually gets.  
+    /// An error means the backend can't play audio at all. A backend which can recover,
+    /// such as one waiting for a device to be connected, should keep the mixer and return Ok.
+    fn open (&mut self, mixer: Arc<Mutex<AudioMixer>>, config: &AudioConfig) -> Result<(), ()>;
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Updates the output, with the time in seconds since the last update.
+    fn update (&mut self, _delta_time: f32) {
+
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Closes the output.
+    fn close (&mut self) {
+
+    }
+}
@@ -0,0 +1,256 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioBackend, AudioConfig, AudioMixer};
+use ::audio::wav_codec::WavCodec;
+use ::util::traits::AsAny;
+
+use std::any::Any;
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::sync::{Arc, Mutex};
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND FILE STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An audio backend which writes its output to a WAV file.
+///
+/// Samples are written as 32-bit floats, so the file holds exactly what the mixer produced.
+/// The backend can follow real time through `update`, or be driven offline with `render`,
+/// which makes it useful for testing mixes without a sound card.
+pub struct AudioBackendFile {
+
+    // Private
+    _file_path: String,
+    _sample_rate: u32,
+    _channels: u16,
+    _mixer: Option<Arc<Mutex<AudioMixer>>>,
+    _writer: Option<BufWriter<File>>,
+    _frames_written: usize,
+    _pending_frames: f64,
+    _buffer: Vec<f32>,
+}
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND FILE PUBLIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioBackendFile {
+
+    /// Pulls a number of frames from the mixer, and writes them to the file.
+    ///
+    /// Fails if the backend isn't open, or the file can't be written.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioBackend, AudioBackendFile, AudioClip, AudioConfig, AudioMixer, Voice};
+    /// # use std::env;
+    /// # use std::sync::{Arc, Mutex};
+    /// #
+    /// let path = env::temp_dir ().join ("ion_audio_backend_example.wav");
+    /// let path = path.to_str ().unwrap ();
+    ///
+    /// let samples = (0..64).map (|i| i as f32 / 64.0).collect::<Vec<f32>> ();
+    /// let clip = Arc::new (AudioClip::from_samples (48000, 2, samples).unwrap ());
+    ///
+    /// // Write a mix to the file
+    /// let mixer = Arc::new (Mutex::new (AudioMixer::default ()));
+    /// mixer.lock ().unwrap ().play (Voice::new (clip.clone ())).unwrap ();
+    ///
+    /// let mut config = AudioConfig::new ();
+    /// config.audio_sample_rate = 48000;
+    ///
+    /// let mut backend = AudioBackendFile::new (path);
+    ///
+    /// backend.open (mixer, &config).unwrap ();
+    /// backend.render (40).unwrap ();
+    /// backend.close ();
+    ///
+    /// // Mix the same voice offline
+    /// let mut offline = AudioMixer::new (48000, 2);
+    /// let mut expected = [0.0; 80];
+    ///
+    /// offline.play (Voice::new (clip.clone ())).unwrap ();
+    /// offline.mix (&mut expected);
+    ///
+    /// let written = AudioClip::load (path).unwrap ();
+    ///
+    /// assert_eq! (written.get_sample_rate (), 48000);
+    /// assert_eq! (written.get_samples (), &expected[..]);
+    /// assert_eq! (&written.get_samples ()[..64], clip.get_samples ());
+    /// ```
+    pub fn render (&mut self, frame_count: usize) -> Result<(), ()> {
+
+        let mixer = match self._mixer {
+
+            Some (ref mixer) => mixer,
+            None => {
+
+                error! ("Audio file backend \"{}\" is not open.", self._file_path);
+                return Err (());
+            }
+        };
+
+        self._buffer.resize (frame_count * self._channels as usize, 0.0);
+
+        if let Ok (mut mixer) = mixer.lock () {
+            mixer.mix (&mut self._buffer);
+        }
+
+        if let Some (ref mut writer) = self._writer {
+
+            if let Err (e) = WavCodec::write_samples (writer, &self._buffer) {
+
+                error! ("Could not write to audio file \"{}\".\n{}", self._file_path, e);
+                return Err (());
+            }
+        }
+
+        self._frames_written += frame_count;
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of frames written to the file.
+    pub fn get_frames_written (&self) -> usize {
+        self._frames_written
+    }
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND FILE PUBLIC STATIC METHODS-------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new file backend with an output path.
+    ///
+    /// The file isn't created until the backend is opened.
+    pub fn new (file_path: &str) -> AudioBackendFile {
+
+        AudioBackendFile {
+
+            _file_path:      file_path.to_string (),
+            _sample_rate:    0,
+            _channels:       0,
+            _mixer:          None,
+            _writer:         None,
+            _frames_written: 0,
+            _pending_frames: 0.0,
+            _buffer:         Vec::new (),
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AudioBackend for AudioBackendFile {
+
+    fn open (&mut self, mixer: Arc<Mutex<AudioMixer>>, config: &AudioConfig) -> Result<(), ()> {
+
+        self.close ();
+
+        if let Ok (mut mixer) = mixer.lock () {
+
+            mixer.set_format (config.audio_sample_rate, config.audio_channels);
+
+            self._sample_rate = mixer.get_sample_rate ();
+            self._channels    = mixer.get_channels ();
+        }
+
+        // The header is rewritten with the real length when the file is closed
+        let mut writer = match File::create (&self._file_path) {
+
+            Ok (file) => BufWriter::new (file),
+            Err (e) => {
+
+                error! ("Audio file \"{}\" could not be created.\n{}", self._file_path, e);
+                return Err (());
+            }
+        };
+
+        if let Err (e) = WavCodec::write_header (&mut writer, self._sample_rate, self._channels, 0) {
+
+            error! ("Could not write to audio file \"{}\".\n{}", self._file_path, e);
+            return Err (());
+        }
+
+        info! ("Writing audio to \"{}\".", self._file_path);
+
+        self._mixer = Some (mixer);
+        self._writer = Some (writer);
+        self._frames_written = 0;
+        self._pending_frames = 0.0;
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn update (&mut self, delta_time: f32) {
+
+        // Carry the fractional frame over, so the output keeps pace with real time
+        self._pending_frames += delta_time as f64 * self._sample_rate as f64;
+
+        let frame_count = self._pending_frames as usize;
+
+        self._pending_frames -= frame_count as f64;
+
+        if self._mixer.is_some () && self.render (frame_count).is_err () {
+            self.close ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn close (&mut self) {
+
+        self._mixer = None;
+
+        if let Some (mut writer) = self._writer.take () {
+
+            let (sample_rate, channels, frame_count) = (self._sample_rate, self._channels, self._frames_written);
+
+            let result = writer.seek (SeekFrom::Start (0)).and_then (|_| {
+
+                try! (WavCodec::write_header (&mut writer, sample_rate, channels, frame_count));
+                writer.flush ()
+            });
+
+            if let Err (e) = result {
+                error! ("Could not finish audio file \"{}\".\n{}", self._file_path, e);
+            }
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for AudioBackendFile {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Drop for AudioBackendFile {
+
+    fn drop (&mut self) {
+        self.close ();
+    }
+}
@@ -0,0 +1,137 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioBackend, AudioConfig, AudioMixer};
+use ::util::traits::AsAny;
+
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND NULL STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An audio backend which discards its output.
+///
+/// The mixer is still pulled in real time, so voices finish when they would on a device.
+/// It is used when no audio plugin is loaded, or the device fails to open.
+pub struct AudioBackendNull {
+
+    // Private
+    _sample_rate: u32,
+    _channels: u16,
+    _mixer: Option<Arc<Mutex<AudioMixer>>>,
+    _pending_frames: f64,
+    _buffer: Vec<f32>,
+}
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND NULL PUBLIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioBackendNull {
+
+    /// Pulls a number of frames from the mixer.
+    ///
+    /// Nothing happens if the backend isn't open.
+    pub fn render (&mut self, frame_count: usize) {
+
+        if let Some (ref mixer) = self._mixer {
+
+            self._buffer.resize (frame_count * self._channels as usize, 0.0);
+
+            if let Ok (mut mixer) = mixer.lock () {
+                mixer.mix (&mut self._buffer);
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND NULL PUBLIC STATIC METHODS-------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new null backend.
+    pub fn new () -> AudioBackendNull {
+
+        AudioBackendNull {
+
+            _sample_rate:    0,
+            _channels:       0,
+            _mixer:          None,
+            _pending_frames: 0.0,
+            _buffer:         Vec::new (),
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for AudioBackendNull {
+
+    fn default () -> AudioBackendNull {
+        AudioBackendNull::new ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AudioBackend for AudioBackendNull {
+
+    fn open (&mut self, mixer: Arc<Mutex<AudioMixer>>, config: &AudioConfig) -> Result<(), ()> {
+
+        if let Ok (mut mixer) = mixer.lock () {
+
+            mixer.set_format (config.audio_sample_rate, config.audio_channels);
+
+            self._sample_rate = mixer.get_sample_rate ();
+            self._channels    = mixer.get_channels ();
+        }
+
+        self._mixer = Some (mixer);
+        self._pending_frames = 0.0;
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn update (&mut self, delta_time: f32) {
+
+        // Carry the fractional frame over, so the output keeps pace with real time
+        self._pending_frames += delta_time as f64 * self._sample_rate as f64;
+
+        let frame_count = self._pending_frames as usize;
+
+        self._pending_frames -= frame_count as f64;
+        self.render (frame_count);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn close (&mut self) {
+        self._mixer = None;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for AudioBackendNull {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
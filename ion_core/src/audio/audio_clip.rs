@@ -0,0 +1,194 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::ogg_codec::OggCodec;
+use ::audio::wav_codec::WavCodec;
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+/*===============================================================================================*/
+/*------AUDIO CLIP STRUCT------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A fully decoded sound.
+///
+/// Samples are stored as interleaved floats in the -1 to 1 range.  
+/// Clips are shared between voices, so they are usually wrapped in an `Arc`.
+#[derive (Clone)]
+pub struct AudioClip {
+
+    // Private
+    _sample_rate: u32,
+    _channels: u16,
+    _samples: Vec<f32>,
+}
+
+/*===============================================================================================*/
+/*------AUDIO CLIP PUBLIC METHODS----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioClip {
+
+    /// Returns the sample rate in Hz.
+    pub fn get_sample_rate (&self) -> u32 {
+        self._sample_rate
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of interleaved channels.
+    pub fn get_channels (&self) -> u16 {
+        self._channels
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the interleaved samples.
+    pub fn get_samples (&self) -> &[f32] {
+        &self._samples
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of frames, where a frame holds one sample per channel.
+    pub fn get_frame_count (&self) -> usize {
+        self._samples.len () / self._channels as usize
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the length of the clip in seconds.
+    pub fn get_duration (&self) -> f32 {
+        self.get_frame_count () as f32 / self._sample_rate as f32
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Encodes the clip as a 32-bit float WAV file.
+    pub fn encode_wav (&self) -> Vec<u8> {
+        WavCodec::encode (self)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Saves the clip as a 32-bit float WAV file.
+    pub fn save_wav (&self, file_path: &str) -> Result<(), ()> {
+
+        info! ("Saving audio clip \"{}\".", file_path);
+
+        match File::create (file_path).and_then (|mut file| file.write_all (&self.encode_wav ())) {
+
+            Ok (_) => Ok (()),
+            Err (e) => {
+
+                error! ("Could not save audio clip \"{}\".\n{}", file_path, e);
+                Err (())
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------AUDIO CLIP PUBLIC STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new clip using existing samples.
+    ///
+    /// Fails if there are no channels, or the samples don't fill a whole number of frames.
+    pub fn from_samples (sample_rate: u32, channels: u16, samples: Vec<f32>) -> Result<AudioClip, ()> {
+
+        if channels == 0 || sample_rate == 0 || samples.len () % channels as usize != 0 {
+
+            error! ("{} samples do not make a {} channel clip at {}Hz.", samples.len (), channels, sample_rate);
+            return Err (());
+        }
+
+        Ok (AudioClip {_sample_rate: sample_rate, _channels: channels, _samples: samples})
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Decodes a clip from the contents of a WAV or Ogg Vorbis file.
+    ///
+    /// The format is detected from the data.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::AudioClip;
+    /// #
+    /// let clip = AudioClip::from_samples (22050, 2, vec! [0.5, -0.25, 0.125, 1.0]).unwrap ();
+    /// let decoded = AudioClip::decode (&clip.encode_wav ()).unwrap ();
+    ///
+    /// assert_eq! (decoded.get_sample_rate (), 22050);
+    /// assert_eq! (decoded.get_channels (), 2);
+    /// assert_eq! (decoded.get_frame_count (), 2);
+    /// assert_eq! (decoded.get_samples (), clip.get_samples ());
+    ///
+    /// // A format chunk larger than any WAV format is rejected
+    /// let mut corrupt = clip.encode_wav ();
+    /// corrupt[16..20].copy_from_slice (&[0xF0, 0xFF, 0xFF, 0xFF]);
+    ///
+    /// assert! (AudioClip::decode (&corrupt).is_err ());
+    /// ```
+    pub fn decode (data: &[u8]) -> Result<AudioClip, ()> {
+
+        if data.starts_with (b"RIFF") {
+            WavCodec::decode (data)
+        }
+
+        else if data.starts_with (b"OggS") {
+            OggCodec::decode (data)
+        }
+
+        else {
+
+            error! ("Audio format is not supported.");
+            Err (())
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads an audio file.
+    pub fn load (file_path: &str) -> Result<AudioClip, ()> {
+
+        info! ("Loading audio clip \"{}\".", file_path);
+
+        let mut data = Vec::new ();
+
+        match File::open (Path::new (file_path)).and_then (|mut file| file.read_to_end (&mut data)) {
+
+            Ok (_) => {},
+            Err (e) => {
+
+                error! ("Audio clip \"{}\" could not be read.\n{}", file_path, e);
+                return Err (());
+            }
+        }
+
+        match AudioClip::decode (&data) {
+
+            Ok (clip) => Ok (clip),
+            Err (_) => {
+
+                error! ("Audio clip \"{}\" could not be decoded.", file_path);
+                Err (())
+            }
+        }
+    }
+}
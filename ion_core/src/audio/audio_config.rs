@@ -0,0 +1,91 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------AUDIO CONFIG STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Stores the config for the audio manager, loaded from the `audio` config file.
+#[derive (Copy, Clone, Deserialize, Serialize)]
+pub struct AudioConfig {
+
+    // Public
+    /// The output sample rate in Hz.
+    pub audio_sample_rate: u32,
+    /// The number of output channels.
+    pub audio_channels: u16,
+    /// The number of frames the device asks the mixer for at a time.
+    ///
+    /// Smaller buffers lower the latency, but are more likely to run dry.
+    pub audio_buffer_size: u16,
+}
+
+/*===============================================================================================*/
+/*------AUDIO CONFIG PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioConfig {
+
+    /// Returns whether the config can be used to open an audio device.
+    ///
+    /// There must be between 1 and 8 channels, the sample rate must be between 8kHz and 192kHz,
+    /// and the buffer size must be a power of two.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::AudioConfig;
+    /// #
+    /// let mut config = AudioConfig::new ();
+    /// assert! (config.is_valid ());
+    ///
+    /// config.audio_channels = 256;
+    /// assert! (!config.is_valid ());
+    ///
+    /// config = AudioConfig::new ();
+    /// config.audio_buffer_size = 0;
+    /// assert! (!config.is_valid ());
+    /// ```
+    pub fn is_valid (&self) -> bool {
+
+        self.audio_channels >= 1 && self.audio_channels <= 8 &&
+        self.audio_sample_rate >= 8000 && self.audio_sample_rate <= 192000 &&
+        self.audio_buffer_size.is_power_of_two ()
+    }
+
+/*===============================================================================================*/
+/*------AUDIO CONFIG PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new Audio Config instance.
+    ///
+    /// It uses 44.1kHz stereo output, with a buffer of 1024 frames.
+    pub fn new () -> AudioConfig {
+
+        AudioConfig {audio_sample_rate: 44100,
+                     audio_channels: 2,
+                     audio_buffer_size: 1024}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for AudioConfig {
+
+    fn default () -> AudioConfig {
+        AudioConfig::new ()
+    }
+}
@@ -0,0 +1,58 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::BusHandle;
+use ::util::traits::AsAny;
+
+use std::any::Any;
+
+/*===============================================================================================*/
+/*------AUDIO EFFECT TRAIT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An effect which processes the audio of a mixer bus.
+///
+/// Effects are run on the mixer's thread, in the order they were added to the bus,
+/// before the bus is mixed into its parent.
+pub trait AudioEffect: AsAny + Send {
+
+    /// Processes a block of interleaved stereo audio in place.
+    ///
+    /// `sidechain` is the audio of the effect's sidechain bus, if it has one.
+    /// It is the same length as the buffer.
+    fn process (&mut self, buffer: &mut [f32], sample_rate: u32, sidechain: Option<&[f32]>);
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the bus whose audio is passed to `process` as the sidechain.
+    ///
+    /// Buses are mixed from the last added to the first, so the sidechain bus should be
+    /// added after the bus the effect is on. Otherwise it has not been fully mixed yet.
+    fn get_sidechain (&self) -> Option<BusHandle> {
+        None
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Clears any audio held by the effect, such as a reverb or delay tail.
+    fn reset (&mut self) {}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns an instance of `&mut Any`, so parameters can be changed on the concrete effect.
+    fn as_any_mut (&mut self) -> &mut Any;
+}
@@ -0,0 +1,166 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AttenuationModel, VoiceHandle};
+use ::physics::physics_3d::Transform3D;
+use ::util::math::{Util, Vec3};
+
+// The time in seconds taken to fade in or out of occlusion.
+static OCCLUSION_FADE_TIME: f32 = 0.1;
+
+/*===============================================================================================*/
+/*------AUDIO EMITTER STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Places a playing voice in the world.
+///
+/// The emitter is attached to a transform, such as the transform of a rigid body,
+/// and sits at an offset from it. Each update, the spatial audio sets the volume, pan
+/// and pitch of the voice from where the emitter is relative to the listener.
+#[derive (Copy, Clone)]
+pub struct AudioEmitter {
+
+    // Public
+    /// The transform the emitter is attached to.
+    pub transform: Transform3D,
+    /// The position of the emitter, relative to the transform.
+    pub offset: Vec3,
+    /// The volume before attenuation.
+    pub volume: f32,
+    /// The pitch before the doppler shift.
+    pub pitch: f32,
+    /// How the volume falls off with distance.
+    pub attenuation: AttenuationModel,
+    /// The distance up to which the emitter plays at full volume.
+    pub min_distance: f32,
+    /// The distance past which the volume stops falling.
+    pub max_distance: f32,
+    /// How quickly the volume falls with distance.
+    pub rolloff: f32,
+    /// Scales the doppler shift. Zero disables it.
+    pub doppler_factor: f32,
+    /// The volume multiplier when the emitter is occluded.
+    pub occluded_volume: f32,
+
+    // Private
+    _voice: VoiceHandle,
+    _velocity: Vec3,
+    _last_position: Option<Vec3>,
+    _occlusion: f32,
+}
+
+/*===============================================================================================*/
+/*------AUDIO EMITTER PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioEmitter {
+
+    /// Returns the voice the emitter controls.
+    pub fn get_voice (&self) -> VoiceHandle {
+        self._voice
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the world space position.
+    pub fn get_position (&self) -> Vec3 {
+        self.transform.apply (&self.offset)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the velocity, in units per second.
+    pub fn get_velocity (&self) -> Vec3 {
+        self._velocity
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns how occluded the emitter is, from 0 to 1.
+    ///
+    /// It fades towards the result of the occlusion callback, so blocked sounds don't cut out.
+    pub fn get_occlusion (&self) -> f32 {
+        self._occlusion
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the volume multiplier from occlusion.
+    pub fn get_occlusion_gain (&self) -> f32 {
+        Util::lerp (1.0, self.occluded_volume, self._occlusion)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Updates the velocity from the distance moved since the last update,
+    /// and fades the occlusion towards whether the emitter is occluded.
+    ///
+    /// On the first update, the occlusion is set straight away.
+    pub fn update (&mut self, is_occluded: bool, delta_time: f32) {
+
+        let position = self.get_position ();
+        let target = if is_occluded {1.0} else {0.0};
+
+        match self._last_position {
+
+            Some (last_position) => {
+
+                if delta_time > 0.0 {
+                    self._velocity = (position - last_position) / delta_time;
+                }
+
+                let step = delta_time / OCCLUSION_FADE_TIME;
+
+                self._occlusion = if self._occlusion < target {Util::min (self._occlusion + step, target)}
+                                  else {Util::max (self._occlusion - step, target)};
+            },
+
+            None => self._occlusion = target
+        }
+
+        self._last_position = Some (position);
+    }
+
+/*===============================================================================================*/
+/*------AUDIO EMITTER PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new emitter for a voice, at the origin.
+    ///
+    /// It uses inverse distance attenuation, with a minimum distance of 1 and a maximum of 100.
+    pub fn new (voice: VoiceHandle) -> AudioEmitter {
+
+        AudioEmitter {
+
+            transform:       Transform3D::default (),
+            offset:          Vec3::new (),
+            volume:          1.0,
+            pitch:           1.0,
+            attenuation:     AttenuationModel::Inverse,
+            min_distance:    1.0,
+            max_distance:    100.0,
+            rolloff:         1.0,
+            doppler_factor:  1.0,
+            occluded_volume: 0.25,
+
+            _voice:          voice,
+            _velocity:       Vec3::new (),
+            _last_position:  None,
+            _occlusion:      0.0,
+        }
+    }
+}
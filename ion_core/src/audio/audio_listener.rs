@@ -0,0 +1,124 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::Camera;
+use ::util::math::{Quat, Vec3};
+
+/*===============================================================================================*/
+/*------AUDIO LISTENER STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The position and orientation sounds are heard from.
+///
+/// The listener faces `rotation * Vec3::forward ()`, with `rotation * Vec3::up ()` as up.
+/// Its velocity is worked out from how far it moves between updates.
+#[derive (Copy, Clone)]
+pub struct AudioListener {
+
+    // Public
+    /// The position.
+    pub position: Vec3,
+    /// The orientation. It should be normalized.
+    pub rotation: Quat,
+
+    // Private
+    _velocity: Vec3,
+    _last_position: Option<Vec3>,
+}
+
+/*===============================================================================================*/
+/*------AUDIO LISTENER PUBLIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioListener {
+
+    /// Moves the listener to a camera, facing the same way.
+    pub fn follow_camera (&mut self, camera: &Camera) {
+
+        self.position = camera.position;
+        self.rotation = Quat::look_rotation (&(camera.target - camera.position), &camera.up);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the direction the listener faces.
+    pub fn get_forward (&self) -> Vec3 {
+        self.rotation * Vec3::forward ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the up direction of the listener.
+    pub fn get_up (&self) -> Vec3 {
+        self.rotation * Vec3::up ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the direction of the listener's right ear.
+    pub fn get_right (&self) -> Vec3 {
+        Vec3::cross (&self.get_forward (), &self.get_up ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the velocity, in units per second.
+    pub fn get_velocity (&self) -> Vec3 {
+        self._velocity
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Updates the velocity from the distance moved since the last update.
+    pub fn update (&mut self, delta_time: f32) {
+
+        if let Some (last_position) = self._last_position {
+
+            if delta_time > 0.0 {
+                self._velocity = (self.position - last_position) / delta_time;
+            }
+        }
+
+        self._last_position = Some (self.position);
+    }
+
+/*===============================================================================================*/
+/*------AUDIO LISTENER PUBLIC STATIC METHODS-----------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new listener at the origin.
+    pub fn new () -> AudioListener {
+
+        AudioListener {
+
+            position:       Vec3::new (),
+            rotation:       Quat::identity (),
+
+            _velocity:      Vec3::new (),
+            _last_position: None,
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for AudioListener {
+
+    fn default () -> AudioListener {
+        AudioListener::new ()
+    }
+}
@@ -0,0 +1,258 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioBackend, AudioBackendNull, AudioConfig, AudioMixer, SpatialAudio};
+use ::engine::App;
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Instant;
+
+/*===============================================================================================*/
+/*------AUDIO MANAGER STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The audio manager.
+///
+/// It owns the mixer, the backend which plays it, and the spatial audio which positions
+/// voices around the listener. Until an audio plugin provides a backend, the mixer is
+/// played through a null backend.
+pub struct AudioManager {
+
+    // Public
+    /// The spatial audio, updated with the mixer each frame.
+    pub spatial: SpatialAudio,
+
+    // Private
+    _config: AudioConfig,
+    _mixer: Arc<Mutex<AudioMixer>>,
+    _backend: Box<AudioBackend>,
+    _is_open: bool,
+    _last_update: Option<Instant>,
+}
+
+/*===============================================================================================*/
+/*------AUDIO MANAGER PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioManager {
+
+    /// Initializes the audio manager, and opens the backend.
+    ///
+    /// The audio config is loaded through the resource manager when an app is running.
+    pub fn init (&mut self) {
+
+        info! ("Initializing the Audio Manager.");
+
+        if let Ok (app) = App::get_instance () {
+
+            let resource_mgr = app.resource_mgr.clone ();
+            let config_result = resource_mgr.borrow ().load_config::<AudioConfig> ("audio");
+
+            match config_result {
+
+                Ok (config) => {
+
+                    if config.is_valid () {
+                        self._config = config;
+                    }
+
+                    else {
+
+                        warn! ("The audio config asks for {} channels at {}Hz with a {} frame buffer, \
+                                which can't be used. The default config is used instead.",
+                               config.audio_channels, config.audio_sample_rate, config.audio_buffer_size);
+
+                        self._config = AudioConfig::new ();
+                    }
+                },
+
+                Err (_) => {
+
+                    match resource_mgr.borrow ().new_config::<AudioConfig> ("audio") {
+                        Ok (_) | Err (_) => {}
+                    }
+                }
+            }
+        }
+
+        self._open_backend ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Replaces the audio backend.
+    ///
+    /// The new backend is opened straight away if the manager has been initialized,
+    /// or by `init` otherwise. The null backend is used instead if it fails to open.
+    pub fn set_backend (&mut self, backend: Box<AudioBackend>) {
+
+        self._backend.close ();
+        self._backend = backend;
+
+        if self._is_open {
+            self._open_backend ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the active audio backend.
+    pub fn get_backend (&self) -> &AudioBackend {
+        &*self._backend
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the audio config.
+    pub fn get_config (&self) -> AudioConfig {
+        self._config
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the shared mixer.
+    pub fn get_mixer (&self) -> Arc<Mutex<AudioMixer>> {
+        self._mixer.clone ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Locks the mixer for playing and changing voices.
+    ///
+    /// The backend can't mix while the lock is held, so it should be released quickly.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioManager, Voice};
+    /// # use std::sync::Arc;
+    /// #
+    /// let mut audio_mgr = AudioManager::new ();
+    /// audio_mgr.init ();
+    ///
+    /// let clip = Arc::new (AudioClip::from_samples (44100, 1, vec! [0.0; 441]).unwrap ());
+    /// let handle = audio_mgr.lock_mixer ().play (Voice::new (clip)).unwrap ();
+    ///
+    /// assert! (audio_mgr.lock_mixer ().is_playing (handle));
+    /// ```
+    pub fn lock_mixer (&self) -> MutexGuard<AudioMixer> {
+
+        // A panic while mixing doesn't leave the mixer in an unusable state
+        match self._mixer.lock () {
+
+            Ok (mixer) => mixer,
+            Err (poisoned) => poisoned.into_inner ()
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Updates the spatial audio and the backend with the time since the last update.
+    pub fn update (&mut self) {
+
+        let now = Instant::now ();
+
+        let delta_time = match self._last_update {
+
+            Some (last) => {
+
+                let elapsed = now.duration_since (last);
+                elapsed.as_secs () as f32 + elapsed.subsec_nanos () as f32 / 1000000000.0
+            },
+
+            None => 0.0
+        };
+
+        self._last_update = Some (now);
+
+        {
+            let mut mixer = match self._mixer.lock () {
+
+                Ok (mixer) => mixer,
+                Err (poisoned) => poisoned.into_inner ()
+            };
+
+            self.spatial.update (&mut mixer, delta_time);
+        }
+
+        self._backend.update (delta_time);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Stops every voice, and closes the backend.
+    pub fn release (&mut self) {
+
+        self.lock_mixer ().stop_all ();
+        self._backend.close ();
+        self._is_open = false;
+    }
+
+/*===============================================================================================*/
+/*------AUDIO MANAGER PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new audio manager.
+    pub fn new () -> AudioManager {
+
+        AudioManager {
+
+            spatial:      SpatialAudio::new (),
+
+            _config:      AudioConfig::new (),
+            _mixer:       Arc::new (Mutex::new (AudioMixer::default ())),
+            _backend:     Box::new (AudioBackendNull::new ()),
+            _is_open:     false,
+            _last_update: None,
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for AudioManager {
+
+    fn default () -> AudioManager {
+        AudioManager::new ()
+    }
+}
+
+/*===============================================================================================*/
+/*------AUDIO MANAGER PRIVATE METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioManager {
+
+    // Opens the backend, falling back to the null backend if it fails.
+    fn _open_backend (&mut self) {
+
+        if self._backend.open (self._mixer.clone (), &self._config).is_err () {
+
+            warn! ("The audio backend could not be started, so audio will not be heard.");
+
+            let mut backend = AudioBackendNull::new ();
+
+            match backend.open (self._mixer.clone (), &self._config) {
+                Ok (_) | Err (_) => {}
+            }
+
+            self._backend = Box::new (backend);
+        }
+
+        self._is_open = true;
+        self._last_update = None;
+    }
+}
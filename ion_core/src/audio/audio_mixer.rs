@@ -0,0 +1,412 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{Bus, BusHandle, Voice, VoiceHandle};
+use ::util::math::Util;
+
+use std::cmp;
+use std::mem;
+
+/*===============================================================================================*/
+/*------AUDIO MIXER STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Mixes the playing voices into an output buffer.
+///
+/// Each voice is mixed into its bus, and each bus is run through its effects and mixed into
+/// its parent, until everything reaches the master bus. The mixer doesn't depend on any device or clock, so the same voices
+/// always produce the same samples. Audio backends call `mix` whenever the device needs more.
+pub struct AudioMixer {
+
+    // Public
+    /// The maximum number of voices which can play at once.
+    pub max_voices: usize,
+
+    // Private
+    _sample_rate: u32,
+    _channels: u16,
+    _voices: Vec<VoiceSlot>,
+    _buses: Vec<Bus>,
+    _bus_buffers: Vec<Vec<f32>>,
+}
+
+/*===============================================================================================*/
+/*------AUDIO MIXER PUBLIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioMixer {
+
+    /// Starts playing a voice.
+    ///
+    /// Fails if the maximum number of voices are already playing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioMixer, Voice};
+    /// # use std::sync::Arc;
+    /// #
+    /// let clip = Arc::new (AudioClip::from_samples (44100, 1, vec! [0.5, 0.25, -0.5, 1.0]).unwrap ());
+    /// let mut mixer = AudioMixer::new (44100, 1);
+    ///
+    /// // At half speed, every other sample is interpolated
+    /// let mut voice = Voice::new (clip);
+    /// voice.pitch = 0.5;
+    /// voice.looping = true;
+    ///
+    /// mixer.play (voice).unwrap ();
+    ///
+    /// let mut output = [0.0; 9];
+    /// mixer.mix (&mut output);
+    ///
+    /// assert_eq! (output, [0.5, 0.375, 0.25, -0.125, -0.5, 0.25, 1.0, 0.75, 0.5]);
+    /// ```
+    pub fn play (&mut self, voice: Voice) -> Result<VoiceHandle, ()> {
+
+        if self.get_voice_count () >= self.max_voices {
+
+            warn! ("Could not play voice, as {} voices are already playing.", self.max_voices);
+            return Err (());
+        }
+
+        // Reuse the first free slot
+        for (index, slot) in self._voices.iter_mut ().enumerate () {
+
+            if slot.voice.is_none () {
+
+                slot.voice = Some (voice);
+                return Ok (VoiceHandle::new (index, slot.generation));
+            }
+        }
+
+        self._voices.push (VoiceSlot {generation: 0, voice: Some (voice)});
+        Ok (VoiceHandle::new (self._voices.len () - 1, 0))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Stops a voice.
+    pub fn stop (&mut self, handle: VoiceHandle) {
+
+        if self.get_voice (handle).is_some () {
+            self._free_slot (handle.get_index ());
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Stops every voice.
+    pub fn stop_all (&mut self) {
+
+        for index in 0..self._voices.len () {
+
+            if self._voices[index].voice.is_some () {
+                self._free_slot (index);
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a voice, or `None` if it has finished or been stopped.
+    pub fn get_voice (&self, handle: VoiceHandle) -> Option<&Voice> {
+
+        match self._voices.get (handle.get_index ()) {
+
+            Some (slot) if slot.generation == handle.get_generation () => slot.voice.as_ref (),
+            _ => None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable voice, or `None` if it has finished or been stopped.
+    pub fn get_voice_mut (&mut self, handle: VoiceHandle) -> Option<&mut Voice> {
+
+        match self._voices.get_mut (handle.get_index ()) {
+
+            Some (slot) if slot.generation == handle.get_generation () => slot.voice.as_mut (),
+            _ => None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether a voice is still playing.
+    ///
+    /// Paused voices count as playing.
+    pub fn is_playing (&self, handle: VoiceHandle) -> bool {
+        self.get_voice (handle).is_some ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of playing voices.
+    pub fn get_voice_count (&self) -> usize {
+        self._voices.iter ().filter (|slot| slot.voice.is_some ()).count ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a bus which outputs to a parent bus.
+    ///
+    /// The master bus is used if the parent doesn't exist.
+    pub fn add_bus (&mut self, parent: BusHandle) -> BusHandle {
+
+        let parent = if parent.get_index () < self._buses.len () {
+            parent
+        }
+
+        else {
+
+            warn! ("Bus {} does not exist, so the master bus is used instead.", parent.get_index ());
+            BusHandle::master ()
+        };
+
+        self._buses.push (Bus::new (Some (parent)));
+        self._bus_buffers.push (Vec::new ());
+
+        BusHandle::new (self._buses.len () - 1)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a bus.
+    pub fn get_bus (&self, handle: BusHandle) -> Option<&Bus> {
+        self._buses.get (handle.get_index ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable bus.
+    pub fn get_bus_mut (&mut self, handle: BusHandle) -> Option<&mut Bus> {
+        self._buses.get_mut (handle.get_index ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of buses, including the master bus.
+    pub fn get_bus_count (&self) -> usize {
+        self._buses.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the output sample rate in Hz.
+    pub fn get_sample_rate (&self) -> u32 {
+        self._sample_rate
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of interleaved output channels.
+    pub fn get_channels (&self) -> u16 {
+        self._channels
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the output sample rate and channel count.
+    ///
+    /// Backends call this once they know the format of the device.
+    pub fn set_format (&mut self, sample_rate: u32, channels: u16) {
+
+        self._sample_rate = cmp::max (sample_rate, 1);
+        self._channels = cmp::max (channels, 1);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Mixes the next block of audio, and advances the voices.
+    ///
+    /// The output is interleaved with the mixer's channel count. Mono output is the average of
+    /// the left and right channels, and any channels past the first two are silent.
+    /// Samples are clamped to the -1 to 1 range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioMixer, BusHandle, Voice};
+    /// # use std::sync::Arc;
+    /// #
+    /// let clip = Arc::new (AudioClip::from_samples (44100, 1, vec! [0.5, 0.25, -0.5, 1.0]).unwrap ());
+    ///
+    /// let mut mixer = AudioMixer::new (44100, 2);
+    /// let sfx = mixer.add_bus (BusHandle::master ());
+    /// mixer.get_bus_mut (sfx).unwrap ().volume = 0.5;
+    ///
+    /// let mut voice = Voice::new (clip);
+    /// voice.pan = -1.0;
+    /// voice.bus = sfx;
+    ///
+    /// let handle = mixer.play (voice).unwrap ();
+    ///
+    /// let mut output = [0.0; 12];
+    /// mixer.mix (&mut output);
+    ///
+    /// // The voice is panned hard left, and finishes after four frames
+    /// assert_eq! (output, [0.25, 0.0, 0.125, 0.0, -0.25, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    /// assert! (!mixer.is_playing (handle));
+    /// ```
+    pub fn mix (&mut self, output: &mut [f32]) {
+
+        let channels = self._channels as usize;
+        let frame_count = output.len () / channels;
+
+        for buffer in &mut self._bus_buffers {
+
+            buffer.clear ();
+            buffer.resize (frame_count * 2, 0.0);
+        }
+
+        // Mix the voices into their buses
+        for index in 0..self._voices.len () {
+
+            let is_finished = match self._voices[index].voice {
+
+                Some (ref mut voice) => {
+
+                    let bus = if voice.bus.get_index () < self._bus_buffers.len () {voice.bus.get_index ()} else {0};
+
+                    voice.render (&mut self._bus_buffers[bus], self._sample_rate);
+                    voice.is_finished ()
+                },
+
+                None => false
+            };
+
+            if is_finished {
+                self._free_slot (index);
+            }
+        }
+
+        // Run each bus through its effects, and mix it into its parent, which always comes before it
+        for index in (1..self._buses.len ()).rev () {
+
+            self._apply_effects (index);
+
+            let parent = self._buses[index].get_parent ().map_or (0, |parent| parent.get_index ());
+            let gain = self._buses[index].get_gain ();
+            let (parents, children) = self._bus_buffers.split_at_mut (index);
+
+            for (sample, child_sample) in parents[parent].iter_mut ().zip (children[0].iter ()) {
+                *sample += *child_sample * gain;
+            }
+        }
+
+        // Write the master bus to the output
+        self._apply_effects (0);
+
+        let gain = self._buses[0].get_gain ();
+
+        for sample in output.iter_mut () {
+            *sample = 0.0;
+        }
+
+        for (frame, master) in output.chunks_mut (channels).zip (self._bus_buffers[0].chunks (2)) {
+
+            if frame.len () < channels {
+                break;
+            }
+
+            let left  = master[0] * gain;
+            let right = master[1] * gain;
+
+            if channels == 1 {
+                frame[0] = Util::clamp ((left + right) * 0.5, -1.0, 1.0);
+            }
+
+            else {
+
+                frame[0] = Util::clamp (left,  -1.0, 1.0);
+                frame[1] = Util::clamp (right, -1.0, 1.0);
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------AUDIO MIXER PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new mixer with only a master bus.
+    pub fn new (sample_rate: u32, channels: u16) -> AudioMixer {
+
+        let mut mixer = AudioMixer {
+
+            max_voices:   64,
+
+            _sample_rate: 0,
+            _channels:    0,
+            _voices:      Vec::new (),
+            _buses:       vec! [Bus::new (None)],
+            _bus_buffers: vec! [Vec::new ()],
+        };
+
+        mixer.set_format (sample_rate, channels);
+        mixer
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for AudioMixer {
+
+    fn default () -> AudioMixer {
+        AudioMixer::new (44100, 2)
+    }
+}
+
+/*===============================================================================================*/
+/*------AUDIO MIXER PRIVATE METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioMixer {
+
+    // Frees a voice slot, so the handles to its voice are no longer valid.
+    fn _free_slot (&mut self, index: usize) {
+
+        let slot = &mut self._voices[index];
+
+        slot.voice = None;
+        slot.generation = slot.generation.wrapping_add (1);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Runs the audio of a bus through its effects.
+    fn _apply_effects (&mut self, index: usize) {
+
+        if self._buses[index].get_effect_count () == 0 {
+            return;
+        }
+
+        // Take the buffer out, so the other buses can be read as sidechains while it is changed
+        let mut buffer = mem::replace (&mut self._bus_buffers[index], Vec::new ());
+
+        self._buses[index].process_effects (&mut buffer, self._sample_rate, &self._bus_buffers);
+        self._bus_buffers[index] = buffer;
+    }
+}
+
+/*===============================================================================================*/
+/*------VOICE SLOT STRUCT------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// A voice, and the number of times its slot has been reused.
+struct VoiceSlot {
+
+    generation: u32,
+    voice: Option<Voice>,
+}
@@ -0,0 +1,167 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::Util;
+
+/*===============================================================================================*/
+/*------AUDIO PARAM STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An effect parameter which can be automated over time.
+///
+/// The value is kept within a range, and can be set straight away or ramped linearly
+/// to a target. Effects advance their parameters once per frame, so ramps are smooth
+/// regardless of the mixer's block size.
+#[derive (Copy, Clone, Debug)]
+pub struct AudioParam {
+
+    // Private
+    _value: f32,
+    _target: f32,
+    _remaining_time: f32,
+    _min: f32,
+    _max: f32,
+}
+
+/*===============================================================================================*/
+/*------AUDIO PARAM PUBLIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioParam {
+
+    /// Returns the current value.
+    pub fn get_value (&self) -> f32 {
+        self._value
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the value being ramped to, or the current value if it isn't ramping.
+    pub fn get_target (&self) -> f32 {
+        self._target
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the lowest value allowed.
+    pub fn get_min (&self) -> f32 {
+        self._min
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the highest value allowed.
+    pub fn get_max (&self) -> f32 {
+        self._max
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether the value is ramping to a target.
+    pub fn is_ramping (&self) -> bool {
+        self._remaining_time > 0.0
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the value straight away, stopping any ramp.
+    pub fn set_value (&mut self, value: f32) {
+
+        self._value = Util::clamp (value, self._min, self._max);
+        self._target = self._value;
+        self._remaining_time = 0.0;
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Ramps the value linearly from where it is now to a target, over a duration in seconds.
+    ///
+    /// Any ramp already in progress is replaced.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::AudioParam;
+    /// #
+    /// let mut param = AudioParam::new (0.0, 0.0, 1.0);
+    /// param.ramp_to (1.0, 1.0);
+    ///
+    /// // At four frames a second, the ramp takes four frames
+    /// let values: Vec<f32> = (0..6).map (|_| param.advance (4)).collect ();
+    ///
+    /// assert_eq! (values, [0.0, 0.25, 0.5, 0.75, 1.0, 1.0]);
+    /// assert! (!param.is_ramping ());
+    /// ```
+    pub fn ramp_to (&mut self, target: f32, duration: f32) {
+
+        if duration <= 0.0 {
+            self.set_value (target);
+        }
+
+        else {
+
+            self._target = Util::clamp (target, self._min, self._max);
+            self._remaining_time = duration;
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the value for the next frame, and moves any ramp on by one frame.
+    pub fn advance (&mut self, sample_rate: u32) -> f32 {
+
+        let value = self._value;
+
+        if self._remaining_time > 0.0 {
+
+            let sample_rate = sample_rate as f32;
+            let remaining_frames = self._remaining_time * sample_rate;
+
+            if remaining_frames <= 1.0 {
+
+                self._value = self._target;
+                self._remaining_time = 0.0;
+            }
+
+            else {
+
+                self._value += (self._target - value) / remaining_frames;
+                self._remaining_time -= 1.0 / sample_rate;
+            }
+        }
+
+        value
+    }
+
+/*===============================================================================================*/
+/*------AUDIO PARAM PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new parameter, with a value clamped to a range.
+    pub fn new (value: f32, min: f32, max: f32) -> AudioParam {
+
+        let value = Util::clamp (value, min, max);
+
+        AudioParam {
+
+            _value:          value,
+            _target:         value,
+            _remaining_time: 0.0,
+            _min:            min,
+            _max:            max,
+        }
+    }
+}
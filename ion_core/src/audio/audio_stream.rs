@@ -0,0 +1,255 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate lewton;
+
+use ::audio::ogg_codec::OggCodec;
+use ::audio::wav_codec::{WavCodec, WavHeader};
+
+use self::lewton::inside_ogg::OggStreamReader;
+
+use std::cmp;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+// The number of frames read from a WAV file at a time.
+static WAV_CHUNK_FRAMES: usize = 4096;
+
+/*===============================================================================================*/
+/*------AUDIO STREAM STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A sound which is decoded from its file a piece at a time.
+///
+/// Streams are used for music and other long sounds, where decoding the whole file
+/// up front would use too much memory. WAV and Ogg Vorbis files are supported.
+pub struct AudioStream {
+
+    // Private
+    _file_path: String,
+    _sample_rate: u32,
+    _channels: u16,
+    _decoder: StreamDecoder,
+}
+
+/*===============================================================================================*/
+/*------AUDIO STREAM PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioStream {
+
+    /// Returns the sample rate in Hz.
+    pub fn get_sample_rate (&self) -> u32 {
+        self._sample_rate
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of interleaved channels.
+    pub fn get_channels (&self) -> u16 {
+        self._channels
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the path of the file being streamed.
+    pub fn get_file_path (&self) -> &str {
+        &self._file_path
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Decodes the next piece of the stream, and appends its samples.
+    ///
+    /// Returns the number of frames decoded, which is zero at the end of the stream.
+    pub fn read (&mut self, samples: &mut Vec<f32>) -> Result<usize, ()> {
+
+        match self._decoder {
+
+            StreamDecoder::Wav {ref mut reader, ref header, ref mut remaining} => {
+
+                let frame_size = header.get_frame_size ();
+                let length = cmp::min (*remaining, (WAV_CHUNK_FRAMES * frame_size) as u64);
+                let mut data = Vec::with_capacity (length as usize);
+
+                if let Err (e) = reader.by_ref ().take (length).read_to_end (&mut data) {
+
+                    error! ("Could not read audio stream \"{}\".\n{}", self._file_path, e);
+                    return Err (());
+                }
+
+                // A short read means the file was truncated
+                *remaining = if data.len () as u64 == length {*remaining - length} else {0};
+
+                WavCodec::decode_samples (header, &data, samples);
+                Ok (data.len () / frame_size)
+            },
+
+            StreamDecoder::Ogg (ref mut reader) => {
+
+                // Skip over any packets without samples
+                loop {
+
+                    match try! (OggCodec::read_packet (reader, samples)) {
+
+                        Some (0) => continue,
+                        Some (frames) => return Ok (frames),
+                        None => return Ok (0)
+                    }
+                }
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves back to the start of the stream.
+    pub fn rewind (&mut self) -> Result<(), ()> {
+
+        match self._decoder {
+
+            StreamDecoder::Wav {ref mut reader, ref header, ref mut remaining} => {
+
+                if let Err (e) = reader.seek (SeekFrom::Start (header.data_offset)) {
+
+                    error! ("Could not rewind audio stream \"{}\".\n{}", self._file_path, e);
+                    return Err (());
+                }
+
+                *remaining = header.data_length;
+                return Ok (());
+            },
+
+            StreamDecoder::Ogg (_) => {}
+        }
+
+        // Vorbis streams are reopened, as seeking needs the granule positions of every page
+        let (decoder, _, _) = try! (AudioStream::_open_decoder (&self._file_path));
+        self._decoder = decoder;
+
+        Ok (())
+    }
+
+/*===============================================================================================*/
+/*------AUDIO STREAM PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Opens an audio file for streaming.
+    ///
+    /// The format is detected from the file contents.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioStream};
+    /// # use std::env;
+    /// #
+    /// let samples = (0..10000).map (|i| (i % 100) as f32 / 100.0).collect::<Vec<f32>> ();
+    /// let clip = AudioClip::from_samples (44100, 1, samples).unwrap ();
+    ///
+    /// let path = env::temp_dir ().join ("ion_audio_stream_example.wav");
+    /// clip.save_wav (path.to_str ().unwrap ()).unwrap ();
+    ///
+    /// let mut stream = AudioStream::open (path.to_str ().unwrap ()).unwrap ();
+    /// let mut streamed = Vec::new ();
+    ///
+    /// // The file is read a piece at a time
+    /// assert! (stream.read (&mut streamed).unwrap () < 10000);
+    /// while stream.read (&mut streamed).unwrap () > 0 {}
+    ///
+    /// assert_eq! (&streamed[..], clip.get_samples ());
+    /// ```
+    pub fn open (file_path: &str) -> Result<AudioStream, ()> {
+
+        info! ("Opening audio stream \"{}\".", file_path);
+
+        let (decoder, sample_rate, channels) = try! (AudioStream::_open_decoder (file_path));
+
+        Ok (AudioStream {
+
+            _file_path:   file_path.to_string (),
+            _sample_rate: sample_rate,
+            _channels:    channels,
+            _decoder:     decoder,
+        })
+    }
+}
+
+/*===============================================================================================*/
+/*------AUDIO STREAM PRIVATE STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioStream {
+
+    // Opens the file, and reads the headers of its format.
+    fn _open_decoder (file_path: &str) -> Result<(StreamDecoder, u32, u16), ()> {
+
+        let mut reader = match File::open (file_path) {
+
+            Ok (file) => BufReader::new (file),
+            Err (e) => {
+
+                error! ("Audio stream \"{}\" could not be opened.\n{}", file_path, e);
+                return Err (());
+            }
+        };
+
+        let mut magic = [0; 4];
+
+        if reader.read_exact (&mut magic).and_then (|_| reader.seek (SeekFrom::Start (0))).is_err () {
+
+            error! ("Audio stream \"{}\" could not be read.", file_path);
+            return Err (());
+        }
+
+        if &magic == b"RIFF" {
+
+            let header = try! (WavCodec::read_header (&mut reader));
+            let (sample_rate, channels) = (header.sample_rate, header.channels);
+
+            Ok ((StreamDecoder::Wav {reader: reader, header: header, remaining: header.data_length},
+                 sample_rate, channels))
+        }
+
+        else if &magic == b"OggS" {
+
+            let reader = try! (OggCodec::open (reader));
+            let (sample_rate, channels) = (reader.ident_hdr.audio_sample_rate, reader.ident_hdr.audio_channels as u16);
+
+            Ok ((StreamDecoder::Ogg (reader), sample_rate, channels))
+        }
+
+        else {
+
+            error! ("Audio stream \"{}\" is not a WAV or Ogg Vorbis file.", file_path);
+            Err (())
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------STREAM DECODER ENUM----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The decoder for each supported format.
+enum StreamDecoder {
+
+    // A WAV file, with the number of data bytes left to read.
+    Wav {reader: BufReader<File>, header: WavHeader, remaining: u64},
+
+    // An Ogg Vorbis file.
+    Ogg (OggStreamReader<BufReader<File>>),
+}
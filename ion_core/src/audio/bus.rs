@@ -0,0 +1,180 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioEffect, BusHandle};
+
+/*===============================================================================================*/
+/*------BUS STRUCT-------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A group of voices which are mixed together, and share a volume.
+///
+/// Every bus except the master bus outputs to a parent bus, so buses form a tree
+/// with the master bus at the root. A voice is heard at the product of its own volume,
+/// and the volume of each bus on the way to the master bus.
+///
+/// Effects inserted on a bus process its audio before it is mixed into its parent.
+pub struct Bus {
+
+    // Public
+    /// The volume of the bus.
+    pub volume: f32,
+    /// Whether the bus is silenced.
+    pub muted: bool,
+
+    // Private
+    _parent: Option<BusHandle>,
+    _effects: Vec<Box<AudioEffect>>,
+}
+
+/*===============================================================================================*/
+/*------BUS PUBLIC METHODS-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Bus {
+
+    /// Returns the bus this bus outputs to, or `None` for the master bus.
+    pub fn get_parent (&self) -> Option<BusHandle> {
+        self._parent
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the volume the bus is mixed into its parent at.
+    pub fn get_gain (&self) -> f32 {
+
+        if self.muted {
+            0.0
+        }
+
+        else {
+            self.volume
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds an effect after the bus's other effects, returning its index.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioMixer, BusHandle};
+    /// # use ion_core::audio::effects::{BiquadFilter, FilterType};
+    /// #
+    /// let mut mixer = AudioMixer::new (44100, 2);
+    /// let music = mixer.add_bus (BusHandle::master ());
+    ///
+    /// let bus = mixer.get_bus_mut (music).unwrap ();
+    /// let filter = bus.add_effect (Box::new (BiquadFilter::new (FilterType::LowPass, 20000.0, 0.7071)));
+    ///
+    /// // Sweep the cutoff down over two seconds
+    /// bus.get_effect_mut::<BiquadFilter> (filter).unwrap ().cutoff.ramp_to (500.0, 2.0);
+    /// ```
+    pub fn add_effect (&mut self, effect: Box<AudioEffect>) -> usize {
+
+        self._effects.push (effect);
+        self._effects.len () - 1
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes an effect, and returns it. The effects after it move down an index.
+    pub fn remove_effect (&mut self, index: usize) -> Option<Box<AudioEffect>> {
+
+        if index < self._effects.len () {
+            Some (self._effects.remove (index))
+        }
+
+        else {
+            None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes every effect.
+    pub fn clear_effects (&mut self) {
+        self._effects.clear ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns an effect, or `None` if it doesn't exist or isn't of the given type.
+    pub fn get_effect<T: AudioEffect + 'static> (&self, index: usize) -> Option<&T> {
+        self._effects.get (index).and_then (|effect| effect.as_any ().downcast_ref::<T> ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable effect, or `None` if it doesn't exist or isn't of the given type.
+    pub fn get_effect_mut<T: AudioEffect + 'static> (&mut self, index: usize) -> Option<&mut T> {
+        self._effects.get_mut (index).and_then (|effect| effect.as_any_mut ().downcast_mut::<T> ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of effects.
+    pub fn get_effect_count (&self) -> usize {
+        self._effects.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Runs a block of interleaved stereo audio through the effects, in order.
+    ///
+    /// This is called by the mixer, with the audio of every bus indexed by handle,
+    /// which the effects' sidechains are read from.
+    pub fn process_effects (&mut self, buffer: &mut [f32], sample_rate: u32, bus_buffers: &[Vec<f32>]) {
+
+        for effect in &mut self._effects {
+
+            let sidechain = match effect.get_sidechain () {
+
+                Some (handle) => match bus_buffers.get (handle.get_index ()) {
+
+                    Some (sidechain) if sidechain.len () == buffer.len () => Some (&sidechain[..]),
+                    _ => None
+                },
+
+                None => None
+            };
+
+            effect.process (buffer, sample_rate, sidechain);
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Clears any audio held by the effects, such as reverb and delay tails.
+    pub fn reset_effects (&mut self) {
+
+        for effect in &mut self._effects {
+            effect.reset ();
+        }
+    }
+
+/*===============================================================================================*/
+/*------BUS PUBLIC STATIC METHODS----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new bus at full volume, with no effects.
+    ///
+    /// Buses are normally created with `AudioMixer::add_bus`.
+    pub fn new (parent: Option<BusHandle>) -> Bus {
+        Bus {volume: 1.0, muted: false, _parent: parent, _effects: Vec::new ()}
+    }
+}
@@ -0,0 +1,59 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------BUS HANDLE STRUCT------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Identifies a bus in a mixer.
+///
+/// Buses are never removed, so a handle stays valid for the life of its mixer.
+/// The master bus always has index zero.
+#[derive (Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BusHandle {
+
+    // Private
+    _index: usize,
+}
+
+/*===============================================================================================*/
+/*------BUS HANDLE PUBLIC METHODS----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BusHandle {
+
+    /// Returns the index of the bus in the mixer.
+    pub fn get_index (&self) -> usize {
+        self._index
+    }
+
+/*===============================================================================================*/
+/*------BUS HANDLE PUBLIC STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new handle to a bus.
+    pub fn new (index: usize) -> BusHandle {
+        BusHandle {_index: index}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a handle to the master bus.
+    pub fn master () -> BusHandle {
+        BusHandle {_index: 0}
+    }
+}
@@ -0,0 +1,236 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioEffect, AudioParam};
+use ::audio::effects::FilterType;
+use ::util::math::Util;
+use ::util::traits::AsAny;
+
+use std::any::Any;
+use std::f32::consts;
+
+/*===============================================================================================*/
+/*------BIQUAD FILTER STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A second order low-pass, high-pass or band-pass filter.
+///
+/// The coefficients are from Robert Bristow-Johnson's audio EQ cookbook,
+/// and are worked out again whenever the cutoff or Q change.
+#[derive (Copy, Clone)]
+pub struct BiquadFilter {
+
+    // Public
+    /// The frequencies the filter lets through.
+    pub filter_type: FilterType,
+    /// The cutoff, or centre frequency of a band-pass filter, in Hz.
+    pub cutoff: AudioParam,
+    /// The resonance. 0.7071 gives a flat pass band.
+    pub q: AudioParam,
+
+    // Private
+    _coefficients: BiquadCoefficients,
+    _coefficient_inputs: Option<(FilterType, f32, f32, u32)>,
+    _channels: [BiquadState; 2],
+}
+
+/*===============================================================================================*/
+/*------BIQUAD FILTER PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BiquadFilter {
+
+    /// Returns a new filter.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioMixer, BusHandle, Voice};
+    /// # use ion_core::audio::effects::{BiquadFilter, FilterType};
+    /// # use std::f32::consts;
+    /// # use std::sync::Arc;
+    /// #
+    /// // Returns the loudest sample in the second half of a sine wave played through a low-pass filter
+    /// let get_peak = |frequency: f32| {
+    ///
+    ///     let samples = (0..4410).map (|i| (i as f32 * frequency * 2.0 * consts::PI / 44100.0).sin ()).collect ();
+    ///     let clip = Arc::new (AudioClip::from_samples (44100, 1, samples).unwrap ());
+    ///
+    ///     let mut mixer = AudioMixer::new (44100, 1);
+    ///     mixer.get_bus_mut (BusHandle::master ()).unwrap ()
+    ///          .add_effect (Box::new (BiquadFilter::new (FilterType::LowPass, 500.0, 0.7071)));
+    ///
+    ///     mixer.play (Voice::new (clip)).unwrap ();
+    ///
+    ///     let mut output = vec! [0.0; 4410];
+    ///     mixer.mix (&mut output);
+    ///
+    ///     output[2205..].iter ().fold (0.0f32, |peak, sample| peak.max (sample.abs ()))
+    /// };
+    ///
+    /// // Low frequencies pass through, and high frequencies are cut
+    /// assert! (get_peak (100.0) > 0.95);
+    /// assert! (get_peak (8000.0) < 0.01);
+    /// ```
+    pub fn new (filter_type: FilterType, cutoff: f32, q: f32) -> BiquadFilter {
+
+        BiquadFilter {
+
+            filter_type:         filter_type,
+            cutoff:              AudioParam::new (cutoff, 10.0, 22000.0),
+            q:                   AudioParam::new (q, 0.1, 20.0),
+
+            _coefficients:       BiquadCoefficients::default (),
+            _coefficient_inputs: None,
+            _channels:           [BiquadState::default (); 2],
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AudioEffect for BiquadFilter {
+
+    fn process (&mut self, buffer: &mut [f32], sample_rate: u32, _sidechain: Option<&[f32]>) {
+
+        for frame in buffer.chunks_mut (2) {
+
+            let cutoff = self.cutoff.advance (sample_rate);
+            let q = self.q.advance (sample_rate);
+
+            self._update_coefficients (cutoff, q, sample_rate);
+
+            for (sample, state) in frame.iter_mut ().zip (self._channels.iter_mut ()) {
+                *sample = state.process (&self._coefficients, *sample);
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn reset (&mut self) {
+        self._channels = [BiquadState::default (); 2];
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn as_any_mut (&mut self) -> &mut Any {
+        self
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for BiquadFilter {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
+
+/*===============================================================================================*/
+/*------BIQUAD FILTER PRIVATE METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BiquadFilter {
+
+    // Works out the coefficients again if the inputs to them have changed.
+    fn _update_coefficients (&mut self, cutoff: f32, q: f32, sample_rate: u32) {
+
+        let inputs = (self.filter_type, cutoff, q, sample_rate);
+
+        if self._coefficient_inputs == Some (inputs) {
+            return;
+        }
+
+        // Keep the cutoff below the Nyquist frequency
+        let cutoff = Util::min (cutoff, sample_rate as f32 * 0.49);
+
+        let omega = 2.0 * consts::PI * cutoff / sample_rate as f32;
+        let cos = omega.cos ();
+        let alpha = omega.sin () / (2.0 * q);
+
+        let (b0, b1, b2) = match self.filter_type {
+
+            FilterType::LowPass  => ((1.0 - cos) * 0.5, 1.0 - cos, (1.0 - cos) * 0.5),
+            FilterType::HighPass => ((1.0 + cos) * 0.5, -(1.0 + cos), (1.0 + cos) * 0.5),
+            FilterType::BandPass => (alpha, 0.0, -alpha),
+        };
+
+        let a0 = 1.0 + alpha;
+
+        self._coefficients = BiquadCoefficients {
+
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: -2.0 * cos / a0,
+            a2: (1.0 - alpha) / a0,
+        };
+
+        self._coefficient_inputs = Some (inputs);
+    }
+}
+
+/*===============================================================================================*/
+/*------BIQUAD COEFFICIENTS STRUCT---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The coefficients of a biquad filter, normalized so a0 is 1.
+#[derive (Copy, Clone, Default)]
+struct BiquadCoefficients {
+
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+}
+
+/*===============================================================================================*/
+/*------BIQUAD STATE STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The last two inputs and outputs of one channel of a biquad filter.
+#[derive (Copy, Clone, Default)]
+struct BiquadState {
+
+    x1: f32,
+    x2: f32,
+    y1: f32,
+    y2: f32,
+}
+
+/*===============================================================================================*/
+/*------BIQUAD STATE PRIVATE METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BiquadState {
+
+    // Filters one sample, in direct form 1.
+    fn process (&mut self, coefficients: &BiquadCoefficients, input: f32) -> f32 {
+
+        let output = coefficients.b0 * input + coefficients.b1 * self.x1 + coefficients.b2 * self.x2 -
+                     coefficients.a1 * self.y1 - coefficients.a2 * self.y2;
+
+        self.x2 = self.x1;
+        self.x1 = input;
+        self.y2 = self.y1;
+        self.y1 = output;
+
+        output
+    }
+}
@@ -0,0 +1,236 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioEffect, AudioParam, BusHandle};
+use ::util::math::Util;
+use ::util::traits::AsAny;
+
+use std::any::Any;
+
+// The quietest level the compressor measures, in decibels.
+static MIN_LEVEL: f32 = -120.0;
+
+/*===============================================================================================*/
+/*------COMPRESSOR STRUCT------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Turns the audio down when it gets louder than a threshold.
+///
+/// The level is measured from the peak of each frame, and the gain reduction
+/// follows it at the attack and release speeds. With a sidechain bus, the level
+/// is measured from that bus instead, so one bus can duck under another.
+#[derive (Copy, Clone)]
+pub struct Compressor {
+
+    // Public
+    /// The level in decibels above which the audio is turned down.
+    pub threshold: AudioParam,
+    /// How many decibels over the threshold the input has to rise to raise the output by one.
+    pub ratio: AudioParam,
+    /// The time in seconds the gain reduction takes to react to a louder level.
+    pub attack: AudioParam,
+    /// The time in seconds the gain reduction takes to recover from a quieter level.
+    pub release: AudioParam,
+    /// The most the audio can be turned down by, in decibels.
+    pub range: AudioParam,
+    /// The gain in decibels applied after compressing.
+    pub makeup_gain: AudioParam,
+    /// The bus the level is measured from, or `None` to measure the audio being compressed.
+    pub sidechain: Option<BusHandle>,
+
+    // Private
+    _gain_reduction: f32,
+}
+
+/*===============================================================================================*/
+/*------COMPRESSOR PUBLIC METHODS----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Compressor {
+
+    /// Returns how many decibels the audio is currently turned down by.
+    pub fn get_gain_reduction (&self) -> f32 {
+        self._gain_reduction
+    }
+
+/*===============================================================================================*/
+/*------COMPRESSOR PUBLIC STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new compressor.
+    ///
+    /// It has a 10ms attack and a 200ms release, with no limit on the gain reduction,
+    /// and no makeup gain.
+    pub fn new (threshold: f32, ratio: f32) -> Compressor {
+
+        Compressor {
+
+            threshold:       AudioParam::new (threshold, MIN_LEVEL, 0.0),
+            ratio:           AudioParam::new (ratio, 1.0, 100.0),
+            attack:          AudioParam::new (0.01, 0.0, 1.0),
+            release:         AudioParam::new (0.2, 0.0, 5.0),
+            range:           AudioParam::new (-MIN_LEVEL, 0.0, -MIN_LEVEL),
+            makeup_gain:     AudioParam::new (0.0, 0.0, 24.0),
+            sidechain:       None,
+
+            _gain_reduction: 0.0,
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a compressor which ducks a bus under the audio of another bus.
+    ///
+    /// The ducked bus is turned down by up to `depth` decibels while the sidechain bus plays.
+    /// The sidechain bus should be added to the mixer after the ducked bus.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioMixer, BusHandle, Voice};
+    /// # use ion_core::audio::effects::Compressor;
+    /// # use std::sync::Arc;
+    /// #
+    /// let mut mixer = AudioMixer::new (1000, 2);
+    /// let music = mixer.add_bus (BusHandle::master ());
+    /// let dialogue = mixer.add_bus (BusHandle::master ());
+    ///
+    /// mixer.get_bus_mut (music).unwrap ().add_effect (Box::new (Compressor::ducker (dialogue, 12.0)));
+    ///
+    /// // The music is panned left and the dialogue right, so they can be told apart
+    /// let mut voice = Voice::new (Arc::new (AudioClip::from_samples (1000, 1, vec! [0.5; 1000]).unwrap ()));
+    /// voice.pan = -1.0;
+    /// voice.bus = music;
+    /// voice.looping = true;
+    ///
+    /// mixer.play (voice).unwrap ();
+    ///
+    /// let mut voice = Voice::new (Arc::new (AudioClip::from_samples (1000, 1, vec! [0.5; 500]).unwrap ()));
+    /// voice.pan = 1.0;
+    /// voice.bus = dialogue;
+    ///
+    /// mixer.play (voice).unwrap ();
+    ///
+    /// // While the dialogue plays, the music is turned down by 12dB
+    /// let mut output = vec! [0.0; 1000];
+    /// mixer.mix (&mut output);
+    ///
+    /// assert! ((output[998] - 0.5 * 0.251).abs () < 0.001);
+    /// assert_eq! (output[999], 0.5);
+    ///
+    /// // Once the dialogue finishes, the music comes back up
+    /// mixer.mix (&mut output);
+    ///
+    /// assert! (output[998] > 0.49);
+    /// assert_eq! (output[999], 0.0);
+    /// ```
+    pub fn ducker (sidechain: BusHandle, depth: f32) -> Compressor {
+
+        // Anything heard on the sidechain is far enough over the threshold
+        // to turn the bus down by the whole range
+        let mut compressor = Compressor::new (-40.0, 100.0);
+
+        compressor.range.set_value (depth);
+        compressor.release.set_value (0.1);
+        compressor.sidechain = Some (sidechain);
+
+        compressor
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AudioEffect for Compressor {
+
+    fn process (&mut self, buffer: &mut [f32], sample_rate: u32, sidechain: Option<&[f32]>) {
+
+        for (index, frame) in buffer.chunks_mut (2).enumerate () {
+
+            let threshold = self.threshold.advance (sample_rate);
+            let ratio = self.ratio.advance (sample_rate);
+            let attack = self.attack.advance (sample_rate);
+            let release = self.release.advance (sample_rate);
+            let range = self.range.advance (sample_rate);
+            let makeup_gain = self.makeup_gain.advance (sample_rate);
+
+            // Measure the peak of the sidechain, or of the frame itself
+            let peak = match sidechain {
+
+                Some (sidechain) => sidechain[index * 2..].iter ().take (frame.len ())
+                                                          .fold (0.0, |peak, sample| Util::max (peak, sample.abs ())),
+                None => frame.iter ().fold (0.0, |peak, sample| Util::max (peak, sample.abs ()))
+            };
+
+            let level = if peak > 0.0 {Util::max (20.0 * peak.log10 (), MIN_LEVEL)} else {MIN_LEVEL};
+            let over = level - threshold;
+
+            let target = if over > 0.0 {Util::min (over * (1.0 - 1.0 / ratio), range)} else {0.0};
+
+            // Move the gain reduction towards the target at the attack or release speed
+            let time = if target > self._gain_reduction {attack} else {release};
+
+            let coefficient = if time > 0.0 {(-1.0 / (time * sample_rate as f32)).exp ()}
+                              else {0.0};
+
+            self._gain_reduction = target + (self._gain_reduction - target) * coefficient;
+
+            let gain = Compressor::_decibels_to_gain (makeup_gain - self._gain_reduction);
+
+            for sample in frame.iter_mut () {
+                *sample *= gain;
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_sidechain (&self) -> Option<BusHandle> {
+        self.sidechain
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn reset (&mut self) {
+        self._gain_reduction = 0.0;
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn as_any_mut (&mut self) -> &mut Any {
+        self
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for Compressor {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
+
+/*===============================================================================================*/
+/*------COMPRESSOR PRIVATE STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Compressor {
+
+    // Converts a level in decibels to a linear gain.
+    fn _decibels_to_gain (decibels: f32) -> f32 {
+        10.0f32.powf (decibels / 20.0)
+    }
+}
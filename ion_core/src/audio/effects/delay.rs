@@ -0,0 +1,169 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioEffect, AudioParam};
+use ::util::math::Util;
+use ::util::traits::AsAny;
+
+use std::any::Any;
+use std::cmp;
+
+// The longest delay time in seconds, which sets the size of the delay line.
+static MAX_DELAY_TIME: f32 = 2.0;
+
+/*===============================================================================================*/
+/*------DELAY STRUCT-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An echo, which repeats the audio after a delay and feeds the repeats back into itself.
+///
+/// The delay time can be changed while playing. Fractional delays are interpolated,
+/// so sweeping the time bends the pitch of the echoes smoothly.
+pub struct Delay {
+
+    // Public
+    /// The delay time in seconds, up to 2 seconds.
+    pub time: AudioParam,
+    /// How much of each echo is fed back to repeat again.
+    pub feedback: AudioParam,
+    /// The level of the echoes.
+    pub wet: AudioParam,
+    /// The level of the original audio.
+    pub dry: AudioParam,
+
+    // Private
+    _sample_rate: u32,
+    _buffer: Vec<f32>,
+    _position: usize,
+}
+
+/*===============================================================================================*/
+/*------DELAY PUBLIC STATIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Delay {
+
+    /// Returns a new delay, with the echoes at half volume.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioMixer, BusHandle, Voice};
+    /// # use ion_core::audio::effects::Delay;
+    /// # use std::sync::Arc;
+    /// #
+    /// let clip = Arc::new (AudioClip::from_samples (8, 1, vec! [1.0]).unwrap ());
+    /// let mut mixer = AudioMixer::new (8, 1);
+    ///
+    /// // At eight frames a second, half a second is four frames
+    /// let mut delay = Delay::new (0.5, 0.5);
+    /// delay.wet.set_value (1.0);
+    ///
+    /// mixer.get_bus_mut (BusHandle::master ()).unwrap ().add_effect (Box::new (delay));
+    /// mixer.play (Voice::new (clip)).unwrap ();
+    ///
+    /// let mut output = [0.0; 13];
+    /// mixer.mix (&mut output);
+    ///
+    /// // Each echo is half as loud as the last
+    /// assert_eq! (output, [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.25]);
+    /// ```
+    pub fn new (time: f32, feedback: f32) -> Delay {
+
+        Delay {
+
+            time:         AudioParam::new (time, 0.0, MAX_DELAY_TIME),
+            feedback:     AudioParam::new (feedback, 0.0, 0.99),
+            wet:          AudioParam::new (0.5, 0.0, 1.0),
+            dry:          AudioParam::new (1.0, 0.0, 1.0),
+
+            _sample_rate: 0,
+            _buffer:      Vec::new (),
+            _position:    0,
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AudioEffect for Delay {
+
+    fn process (&mut self, buffer: &mut [f32], sample_rate: u32, _sidechain: Option<&[f32]>) {
+
+        // The delay line holds the longest delay, and one frame more to interpolate with
+        if self._sample_rate != sample_rate {
+
+            let frame_count = (MAX_DELAY_TIME * sample_rate as f32) as usize + 2;
+
+            self._sample_rate = sample_rate;
+            self._buffer = vec! [0.0; frame_count * 2];
+            self._position = 0;
+        }
+
+        let frame_count = self._buffer.len () / 2;
+
+        for frame in buffer.chunks_mut (2) {
+
+            let time = self.time.advance (sample_rate);
+            let feedback = self.feedback.advance (sample_rate);
+            let wet = self.wet.advance (sample_rate);
+            let dry = self.dry.advance (sample_rate);
+
+            // Read between the two frames either side of the delay time
+            let delay = Util::clamp (time * sample_rate as f32, 1.0, (frame_count - 2) as f32);
+            let whole_frames = delay.floor ();
+            let fraction = delay - whole_frames;
+
+            let near = (self._position + frame_count - whole_frames as usize) % frame_count;
+            let far = (near + frame_count - 1) % frame_count;
+
+            for channel in 0..cmp::min (frame.len (), 2) {
+
+                let delayed = Util::lerp_unclamped (self._buffer[near * 2 + channel],
+                                                    self._buffer[far * 2 + channel], fraction);
+
+                self._buffer[self._position * 2 + channel] = frame[channel] + delayed * feedback;
+                frame[channel] = frame[channel] * dry + delayed * wet;
+            }
+
+            self._position = (self._position + 1) % frame_count;
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn reset (&mut self) {
+
+        for sample in &mut self._buffer {
+            *sample = 0.0;
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn as_any_mut (&mut self) -> &mut Any {
+        self
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for Delay {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
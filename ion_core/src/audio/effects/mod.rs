@@ -0,0 +1,35 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The audio effects module.
+//!
+//! Contains the filters, reverb, delay and compressor which can be inserted on mixer buses.
+/*===============================================================================================*/
+
+// Modules
+mod biquad_filter;
+mod compressor;
+mod delay;
+mod filter_type;
+mod reverb;
+
+pub use self::biquad_filter::BiquadFilter;
+pub use self::compressor::Compressor;
+pub use self::delay::Delay;
+pub use self::filter_type::FilterType;
+pub use self::reverb::Reverb;
@@ -0,0 +1,316 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioEffect, AudioParam};
+use ::util::traits::AsAny;
+
+use std::any::Any;
+
+// The comb and allpass filter lengths in frames at 44.1kHz, from Freeverb.
+static COMB_TUNINGS: [usize; 8] = [1116, 1188, 1277, 1356, 1422, 1491, 1557, 1617];
+static ALLPASS_TUNINGS: [usize; 4] = [556, 441, 341, 225];
+
+// How many frames longer the right channel's filters are, to decorrelate the channels.
+static STEREO_SPREAD: usize = 23;
+
+// The gains Freeverb scales its input and parameters by.
+static INPUT_GAIN: f32 = 0.015;
+static WET_SCALE: f32 = 3.0;
+static DAMPING_SCALE: f32 = 0.4;
+static ROOM_SCALE: f32 = 0.28;
+static ROOM_OFFSET: f32 = 0.7;
+static ALLPASS_FEEDBACK: f32 = 0.5;
+
+/*===============================================================================================*/
+/*------REVERB STRUCT----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A room reverb, based on Jezar's Freeverb.
+///
+/// Each channel runs the input through eight parallel comb filters, which make the
+/// decaying echoes of a room, then through four allpass filters in series, which diffuse them.
+pub struct Reverb {
+
+    // Public
+    /// The size of the room, from 0 to 1. Larger rooms decay more slowly.
+    pub room_size: AudioParam,
+    /// How quickly high frequencies decay, from 0 to 1.
+    pub damping: AudioParam,
+    /// The level of the reverb.
+    pub wet: AudioParam,
+    /// The level of the original audio.
+    pub dry: AudioParam,
+    /// The stereo width of the reverb, from 0 for mono to 1.
+    pub width: AudioParam,
+
+    // Private
+    _sample_rate: u32,
+    _channels: Vec<ReverbChannel>,
+}
+
+/*===============================================================================================*/
+/*------REVERB PUBLIC STATIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Reverb {
+
+    /// Returns a new reverb, for a medium sized room.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioMixer, BusHandle, Voice};
+    /// # use ion_core::audio::effects::Reverb;
+    /// # use std::sync::Arc;
+    /// #
+    /// // Returns the energy of the tail of an impulse played through a reverb
+    /// let get_tail_energy = |room_size: f32| {
+    ///
+    ///     let clip = Arc::new (AudioClip::from_samples (44100, 1, vec! [1.0]).unwrap ());
+    ///     let mut mixer = AudioMixer::new (44100, 2);
+    ///
+    ///     let mut reverb = Reverb::new ();
+    ///     reverb.room_size.set_value (room_size);
+    ///     reverb.dry.set_value (0.0);
+    ///
+    ///     mixer.get_bus_mut (BusHandle::master ()).unwrap ().add_effect (Box::new (reverb));
+    ///     mixer.play (Voice::new (clip)).unwrap ();
+    ///
+    ///     let mut output = vec! [0.0; 88200];
+    ///     mixer.mix (&mut output);
+    ///
+    ///     // Nothing is heard until the shortest comb filter has delayed the impulse
+    ///     assert! (output[..2000].iter ().all (|sample| *sample == 0.0));
+    ///
+    ///     output[44100..].iter ().map (|sample| sample * sample).sum::<f32> ()
+    /// };
+    ///
+    /// // Larger rooms ring on for longer
+    /// assert! (get_tail_energy (0.9) > get_tail_energy (0.2) * 10.0);
+    /// ```
+    pub fn new () -> Reverb {
+
+        Reverb {
+
+            room_size:    AudioParam::new (0.5, 0.0, 1.0),
+            damping:      AudioParam::new (0.5, 0.0, 1.0),
+            wet:          AudioParam::new (1.0 / WET_SCALE, 0.0, 1.0),
+            dry:          AudioParam::new (1.0, 0.0, 1.0),
+            width:        AudioParam::new (1.0, 0.0, 1.0),
+
+            _sample_rate: 0,
+            _channels:    Vec::new (),
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for Reverb {
+
+    fn default () -> Reverb {
+        Reverb::new ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AudioEffect for Reverb {
+
+    fn process (&mut self, buffer: &mut [f32], sample_rate: u32, _sidechain: Option<&[f32]>) {
+
+        // The filter lengths are tuned for 44.1kHz, so they are scaled to the sample rate
+        if self._sample_rate != sample_rate {
+
+            self._sample_rate = sample_rate;
+            self._channels = vec! [ReverbChannel::new (0, sample_rate), ReverbChannel::new (STEREO_SPREAD, sample_rate)];
+        }
+
+        for frame in buffer.chunks_mut (2) {
+
+            if frame.len () < 2 {
+                break;
+            }
+
+            let feedback = self.room_size.advance (sample_rate) * ROOM_SCALE + ROOM_OFFSET;
+            let damping = self.damping.advance (sample_rate) * DAMPING_SCALE;
+            let wet = self.wet.advance (sample_rate) * WET_SCALE;
+            let dry = self.dry.advance (sample_rate);
+            let width = self.width.advance (sample_rate);
+
+            // Both channels reverberate the same mono input
+            let input = (frame[0] + frame[1]) * INPUT_GAIN;
+
+            let left  = self._channels[0].process (input, feedback, damping);
+            let right = self._channels[1].process (input, feedback, damping);
+
+            // Mix some of each channel into the other to narrow the width
+            let wet_direct = wet * (width * 0.5 + 0.5);
+            let wet_cross = wet * (1.0 - width) * 0.5;
+
+            frame[0] = frame[0] * dry + left * wet_direct + right * wet_cross;
+            frame[1] = frame[1] * dry + right * wet_direct + left * wet_cross;
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn reset (&mut self) {
+
+        // The filters are made again on the next block
+        self._sample_rate = 0;
+        self._channels.clear ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn as_any_mut (&mut self) -> &mut Any {
+        self
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for Reverb {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
+
+/*===============================================================================================*/
+/*------REVERB CHANNEL STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The comb and allpass filters for one channel of the reverb.
+struct ReverbChannel {
+
+    combs: Vec<CombFilter>,
+    allpasses: Vec<AllpassFilter>,
+}
+
+/*===============================================================================================*/
+/*------REVERB CHANNEL PRIVATE METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ReverbChannel {
+
+    // Reverberates one sample.
+    fn process (&mut self, input: f32, feedback: f32, damping: f32) -> f32 {
+
+        let mut output = 0.0;
+
+        for comb in &mut self.combs {
+            output += comb.process (input, feedback, damping);
+        }
+
+        for allpass in &mut self.allpasses {
+            output = allpass.process (output);
+        }
+
+        output
+    }
+
+/*===============================================================================================*/
+/*------REVERB CHANNEL PRIVATE STATIC METHODS----------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Returns the filters for a channel, lengthened by a number of frames at 44.1kHz.
+    fn new (spread: usize, sample_rate: u32) -> ReverbChannel {
+
+        let get_length = |tuning: usize| {
+            ((tuning + spread) as f32 * sample_rate as f32 / 44100.0) as usize + 1
+        };
+
+        ReverbChannel {
+
+            combs: COMB_TUNINGS.iter ().map (|tuning| CombFilter {
+
+                buffer:       vec! [0.0; get_length (*tuning)],
+                position:     0,
+                filter_store: 0.0,
+
+            }).collect (),
+
+            allpasses: ALLPASS_TUNINGS.iter ().map (|tuning| AllpassFilter {
+
+                buffer:   vec! [0.0; get_length (*tuning)],
+                position: 0,
+
+            }).collect (),
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------COMB FILTER STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// A feedback comb filter, with a low-pass filter in the feedback path to damp high frequencies.
+struct CombFilter {
+
+    buffer: Vec<f32>,
+    position: usize,
+    filter_store: f32,
+}
+
+/*===============================================================================================*/
+/*------COMB FILTER PRIVATE METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl CombFilter {
+
+    // Filters one sample.
+    fn process (&mut self, input: f32, feedback: f32, damping: f32) -> f32 {
+
+        let output = self.buffer[self.position];
+
+        self.filter_store = output * (1.0 - damping) + self.filter_store * damping;
+        self.buffer[self.position] = input + self.filter_store * feedback;
+        self.position = (self.position + 1) % self.buffer.len ();
+
+        output
+    }
+}
+
+/*===============================================================================================*/
+/*------ALLPASS FILTER STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// A Schroeder allpass filter, which smears echoes out without colouring their frequencies.
+struct AllpassFilter {
+
+    buffer: Vec<f32>,
+    position: usize,
+}
+
+/*===============================================================================================*/
+/*------ALLPASS FILTER PRIVATE METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AllpassFilter {
+
+    // Filters one sample.
+    fn process (&mut self, input: f32) -> f32 {
+
+        let buffered = self.buffer[self.position];
+
+        self.buffer[self.position] = input + buffered * ALLPASS_FEEDBACK;
+        self.position = (self.position + 1) % self.buffer.len ();
+
+        buffered - input
+    }
+}
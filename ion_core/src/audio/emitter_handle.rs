@@ -0,0 +1,60 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------EMITTER HANDLE STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Identifies an emitter in the spatial audio.
+///
+/// Emitter slots are reused once an emitter is removed, so each handle also stores the generation
+/// of its slot. A handle to a removed emitter never refers to an emitter added later.
+#[derive (Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EmitterHandle {
+
+    // Private
+    _index: usize,
+    _generation: u32,
+}
+
+/*===============================================================================================*/
+/*------EMITTER HANDLE PUBLIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl EmitterHandle {
+
+    /// Returns the index of the emitter slot.
+    pub fn get_index (&self) -> usize {
+        self._index
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the generation of the emitter slot.
+    pub fn get_generation (&self) -> u32 {
+        self._generation
+    }
+
+/*===============================================================================================*/
+/*------EMITTER HANDLE PUBLIC STATIC METHODS-----------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new handle to an emitter slot.
+    pub fn new (index: usize, generation: u32) -> EmitterHandle {
+        EmitterHandle {_index: index, _generation: generation}
+    }
+}
@@ -0,0 +1,68 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The audio module.
+//!
+//! Contains the software mixer and its bus effects, the WAV and Ogg Vorbis decoders,
+//! the backends which play the mix, and the spatial audio which positions voices in 3D.
+/*===============================================================================================*/
+
+// Modules
+pub mod effects;
+mod attenuation_model;
+mod audio_backend;
+mod audio_backend_file;
+mod audio_backend_null;
+mod audio_clip;
+mod audio_config;
+mod audio_effect;
+mod audio_emitter;
+mod audio_listener;
+mod audio_manager;
+mod audio_mixer;
+mod audio_param;
+mod audio_stream;
+mod bus;
+mod bus_handle;
+mod emitter_handle;
+mod ogg_codec;
+mod spatial_audio;
+mod stream_buffer;
+mod voice;
+mod voice_handle;
+mod wav_codec;
+
+pub use self::attenuation_model::AttenuationModel;
+pub use self::audio_backend::AudioBackend;
+pub use self::audio_backend_file::AudioBackendFile;
+pub use self::audio_backend_null::AudioBackendNull;
+pub use self::audio_clip::AudioClip;
+pub use self::audio_config::AudioConfig;
+pub use self::audio_effect::AudioEffect;
+pub use self::audio_emitter::AudioEmitter;
+pub use self::audio_listener::AudioListener;
+pub use self::audio_manager::AudioManager;
+pub use self::audio_mixer::AudioMixer;
+pub use self::audio_param::AudioParam;
+pub use self::audio_stream::AudioStream;
+pub use self::bus::Bus;
+pub use self::bus_handle::BusHandle;
+pub use self::emitter_handle::EmitterHandle;
+pub use self::spatial_audio::SpatialAudio;
+pub use self::voice::Voice;
+pub use self::voice_handle::VoiceHandle;
@@ -0,0 +1,102 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate lewton;
+
+use ::audio::AudioClip;
+
+use self::lewton::inside_ogg::OggStreamReader;
+
+use std::io::{Cursor, Read, Seek};
+
+/*===============================================================================================*/
+/*------OGG CODEC STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Decodes Ogg Vorbis files.
+#[derive (Copy, Clone)]
+pub struct OggCodec;
+
+/*===============================================================================================*/
+/*------OGG CODEC PUBLIC STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl OggCodec {
+
+    /// Decodes an Ogg Vorbis file.
+    pub fn decode (data: &[u8]) -> Result<AudioClip, ()> {
+
+        let mut reader = try! (OggCodec::open (Cursor::new (data)));
+        let mut samples = Vec::new ();
+
+        while try! (OggCodec::read_packet (&mut reader, &mut samples)).is_some () {}
+
+        AudioClip::from_samples (reader.ident_hdr.audio_sample_rate,
+                                 reader.ident_hdr.audio_channels as u16,
+                                 samples)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Opens an Ogg Vorbis stream, and reads its headers.
+    pub fn open<R: Read + Seek> (reader: R) -> Result<OggStreamReader<R>, ()> {
+
+        match OggStreamReader::new (reader) {
+
+            Ok (reader) => {
+
+                if reader.ident_hdr.audio_channels == 0 {
+
+                    error! ("Ogg Vorbis stream has no channels.");
+                    return Err (());
+                }
+
+                Ok (reader)
+            },
+
+            Err (e) => {
+
+                error! ("Could not read Ogg Vorbis headers.\n{}", e);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Decodes the next packet of a stream, and appends its samples.
+    ///
+    /// Returns the number of frames decoded, or `None` at the end of the stream.
+    pub fn read_packet<R: Read + Seek> (reader: &mut OggStreamReader<R>, samples: &mut Vec<f32>) -> Result<Option<usize>, ()> {
+
+        match reader.read_dec_packet_itl () {
+
+            Ok (Some (packet)) => {
+
+                samples.extend (packet.iter ().map (|sample| *sample as f32 / 32768.0));
+                Ok (Some (packet.len () / reader.ident_hdr.audio_channels as usize))
+            },
+
+            Ok (None) => Ok (None),
+            Err (e) => {
+
+                error! ("Could not decode Ogg Vorbis packet.\n{}", e);
+                Err (())
+            }
+        }
+    }
+}
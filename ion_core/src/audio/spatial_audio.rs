@@ -0,0 +1,368 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioEmitter, AudioListener, AudioMixer, EmitterHandle};
+use ::renderer::Camera;
+use ::util::math::{Util, Vec3};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// The fraction of the speed of sound that emitters and listeners are limited to,
+// so the doppler shift stays finite.
+static MAX_DOPPLER_SPEED: f32 = 0.95;
+
+/*===============================================================================================*/
+/*------SPATIAL AUDIO STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Positions voices in 3D space around a listener.
+///
+/// Each update sets the volume, pan and pitch of every emitter's voice, from its
+/// distance and direction to the listener, their velocities, and whether it is occluded.
+/// Emitters are removed once their voice has finished or been stopped.
+pub struct SpatialAudio {
+
+    // Public
+    /// The listener. It follows the camera, if one is set.
+    pub listener: AudioListener,
+    /// The speed of sound in units per second, used for the doppler shift.
+    pub speed_of_sound: f32,
+    /// Scales the doppler shift of every emitter. Zero disables it.
+    pub doppler_factor: f32,
+
+    // Private
+    _emitters: Vec<EmitterSlot>,
+    _camera: Option<Rc<RefCell<Camera>>>,
+    _occlusion_callback: Option<Box<Fn (&Vec3, &Vec3) -> bool>>,
+}
+
+/*===============================================================================================*/
+/*------SPATIAL AUDIO PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl SpatialAudio {
+
+    /// Adds an emitter, returning its handle.
+    pub fn add_emitter (&mut self, emitter: AudioEmitter) -> EmitterHandle {
+
+        // Reuse the first free slot
+        for (index, slot) in self._emitters.iter_mut ().enumerate () {
+
+            if slot.emitter.is_none () {
+
+                slot.emitter = Some (emitter);
+                return EmitterHandle::new (index, slot.generation);
+            }
+        }
+
+        self._emitters.push (EmitterSlot {generation: 0, emitter: Some (emitter)});
+        EmitterHandle::new (self._emitters.len () - 1, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes an emitter. Its voice keeps playing, with the volume, pan and pitch it was left at.
+    pub fn remove_emitter (&mut self, handle: EmitterHandle) {
+
+        if self.get_emitter (handle).is_some () {
+            self._free_slot (handle.get_index ());
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns an emitter, or `None` if it has been removed.
+    pub fn get_emitter (&self, handle: EmitterHandle) -> Option<&AudioEmitter> {
+
+        match self._emitters.get (handle.get_index ()) {
+
+            Some (slot) if slot.generation == handle.get_generation () => slot.emitter.as_ref (),
+            _ => None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable emitter, or `None` if it has been removed.
+    pub fn get_emitter_mut (&mut self, handle: EmitterHandle) -> Option<&mut AudioEmitter> {
+
+        match self._emitters.get_mut (handle.get_index ()) {
+
+            Some (slot) if slot.generation == handle.get_generation () => slot.emitter.as_mut (),
+            _ => None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of emitters.
+    pub fn get_emitter_count (&self) -> usize {
+        self._emitters.iter ().filter (|slot| slot.emitter.is_some ()).count ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the camera the listener follows, or `None` to position the listener by hand.
+    pub fn set_camera (&mut self, camera: Option<Rc<RefCell<Camera>>>) {
+        self._camera = camera;
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the camera the listener follows.
+    pub fn get_camera (&self) -> Option<Rc<RefCell<Camera>>> {
+        self._camera.clone ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the callback which tests whether an emitter is occluded.
+    ///
+    /// It is given the listener position and the emitter position, and returns whether
+    /// something blocks the path between them. Occluded emitters are quietened by their
+    /// occluded volume.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioEmitter, AudioMixer, SpatialAudio, Voice};
+    /// # use ion_core::physics::BodyType;
+    /// # use ion_core::physics::physics_3d::{PhysicsWorld3D, RigidBody3D, Shape3D};
+    /// # use ion_core::util::math::{Ray, Vec3};
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use std::sync::Arc;
+    /// #
+    /// // A wall between the listener and the emitter
+    /// let world = Rc::new (RefCell::new (PhysicsWorld3D::new ()));
+    /// let mut wall = RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 0.1, y: 5.0, z: 5.0}});
+    /// wall.position = Vec3 {x: 5.0, y: 0.0, z: 0.0};
+    ///
+    /// world.borrow_mut ().add_body (wall);
+    /// world.borrow_mut ().step (1.0 / 60.0);
+    ///
+    /// let mut spatial = SpatialAudio::new ();
+    /// let physics = world.clone ();
+    ///
+    /// spatial.set_occlusion_callback (Box::new (move |listener, emitter| {
+    ///
+    ///     let offset = *emitter - *listener;
+    ///     let ray = Ray::new (listener, &offset);
+    ///
+    ///     physics.borrow ().raycast (&ray, Vec3::length (&offset), 0xFFFFFFFF).is_some ()
+    /// }));
+    ///
+    /// let clip = Arc::new (AudioClip::from_samples (44100, 1, vec! [0.5; 44100]).unwrap ());
+    /// let mut mixer = AudioMixer::new (44100, 2);
+    /// let voice = mixer.play (Voice::new (clip)).unwrap ();
+    ///
+    /// let mut emitter = AudioEmitter::new (voice);
+    /// emitter.transform.position = Vec3 {x: 10.0, y: 0.0, z: 0.0};
+    /// emitter.min_distance = 10.0;
+    /// emitter.occluded_volume = 0.25;
+    ///
+    /// spatial.add_emitter (emitter);
+    /// spatial.update (&mut mixer, 1.0 / 60.0);
+    ///
+    /// assert_eq! (mixer.get_voice (voice).unwrap ().volume, 0.25);
+    /// ```
+    pub fn set_occlusion_callback (&mut self, callback: Box<Fn (&Vec3, &Vec3) -> bool>) {
+        self._occlusion_callback = Some (callback);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes the occlusion callback, so no emitter is occluded.
+    pub fn clear_occlusion_callback (&mut self) {
+        self._occlusion_callback = None;
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves the listener to the camera, and updates the voice of every emitter.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioEmitter, AudioMixer, SpatialAudio, Voice};
+    /// # use ion_core::renderer::Camera;
+    /// # use ion_core::util::math::Vec3;
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use std::sync::Arc;
+    /// #
+    /// let clip = Arc::new (AudioClip::from_samples (44100, 1, vec! [0.5; 44100]).unwrap ());
+    /// let mut mixer = AudioMixer::new (44100, 2);
+    /// let voice = mixer.play (Voice::new (clip)).unwrap ();
+    ///
+    /// // The camera looks down -Z, so +X is on its right
+    /// let camera = Rc::new (RefCell::new (Camera::new ()));
+    /// let mut spatial = SpatialAudio::new ();
+    /// spatial.set_camera (Some (camera.clone ()));
+    ///
+    /// let mut emitter = AudioEmitter::new (voice);
+    /// emitter.transform.position = Vec3 {x: 2.0, y: 0.0, z: 0.0};
+    ///
+    /// let emitter = spatial.add_emitter (emitter);
+    /// spatial.update (&mut mixer, 1.0 / 60.0);
+    ///
+    /// assert_eq! (mixer.get_voice (voice).unwrap ().volume, 0.5);
+    /// assert! (mixer.get_voice (voice).unwrap ().pan > 0.99);
+    ///
+    /// // Turning the camera around moves the emitter to the left
+    /// camera.borrow_mut ().target = Vec3::forward ();
+    /// spatial.update (&mut mixer, 1.0 / 60.0);
+    ///
+    /// assert! (mixer.get_voice (voice).unwrap ().pan < -0.99);
+    ///
+    /// // Moving towards the listener raises the pitch
+    /// spatial.get_emitter_mut (emitter).unwrap ().transform.position.x = 1.5;
+    /// spatial.update (&mut mixer, 1.0 / 60.0);
+    ///
+    /// assert! (mixer.get_voice (voice).unwrap ().pitch > 1.05);
+    /// ```
+    pub fn update (&mut self, mixer: &mut AudioMixer, delta_time: f32) {
+
+        if let Some (ref camera) = self._camera {
+            self.listener.follow_camera (&camera.borrow ());
+        }
+
+        self.listener.update (delta_time);
+
+        for index in 0..self._emitters.len () {
+
+            let voice_handle = match self._emitters[index].emitter {
+
+                Some (ref emitter) => emitter.get_voice (),
+                None => continue
+            };
+
+            let voice = match mixer.get_voice_mut (voice_handle) {
+
+                Some (voice) => voice,
+                None => {
+
+                    self._free_slot (index);
+                    continue;
+                }
+            };
+
+            let listener = &self.listener;
+            let emitter = self._emitters[index].emitter.as_mut ().unwrap ();
+
+            let is_occluded = match self._occlusion_callback {
+
+                Some (ref callback) => callback (&listener.position, &emitter.get_position ()),
+                None => false
+            };
+
+            emitter.update (is_occluded, delta_time);
+
+            let offset = listener.position - emitter.get_position ();
+            let distance = Vec3::length (&offset);
+
+            let gain = emitter.attenuation.get_gain (distance, emitter.min_distance,
+                                                     emitter.max_distance, emitter.rolloff);
+
+            voice.volume = emitter.volume * gain * emitter.get_occlusion_gain ();
+            voice.pan = 0.0;
+            voice.pitch = emitter.pitch;
+
+            if distance > 0.0 {
+
+                // From the emitter to the listener
+                let direction = offset / distance;
+
+                // Emitters inside the minimum distance blend towards the centre,
+                // so the pan doesn't flip as they pass through the listener
+                let spread = if emitter.min_distance > 0.0 {Util::clamp (distance / emitter.min_distance, 0.0, 1.0)}
+                             else {1.0};
+
+                voice.pan = -Vec3::dot (&direction, &listener.get_right ()) * spread;
+
+                let doppler_factor = self.doppler_factor * emitter.doppler_factor;
+
+                if doppler_factor > 0.0 && self.speed_of_sound > 0.0 {
+
+                    let max_speed = self.speed_of_sound / doppler_factor * MAX_DOPPLER_SPEED;
+
+                    let listener_speed = Util::clamp (Vec3::dot (&listener.get_velocity (), &direction),
+                                                      -max_speed, max_speed);
+                    let emitter_speed  = Util::clamp (Vec3::dot (&emitter.get_velocity (), &direction),
+                                                      -max_speed, max_speed);
+
+                    voice.pitch *= (self.speed_of_sound - doppler_factor * listener_speed) /
+                                   (self.speed_of_sound - doppler_factor * emitter_speed);
+                }
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------SPATIAL AUDIO PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns new spatial audio, with the listener at the origin.
+    pub fn new () -> SpatialAudio {
+
+        SpatialAudio {
+
+            listener:            AudioListener::new (),
+            speed_of_sound:      343.3,
+            doppler_factor:      1.0,
+
+            _emitters:           Vec::new (),
+            _camera:             None,
+            _occlusion_callback: None,
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for SpatialAudio {
+
+    fn default () -> SpatialAudio {
+        SpatialAudio::new ()
+    }
+}
+
+/*===============================================================================================*/
+/*------SPATIAL AUDIO PRIVATE METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl SpatialAudio {
+
+    // Frees an emitter slot, so the handles to its emitter are no longer valid.
+    fn _free_slot (&mut self, index: usize) {
+
+        let slot = &mut self._emitters[index];
+
+        slot.emitter = None;
+        slot.generation = slot.generation.wrapping_add (1);
+    }
+}
+
+/*===============================================================================================*/
+/*------EMITTER SLOT STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// An emitter, and the number of times its slot has been reused.
+struct EmitterSlot {
+
+    generation: u32,
+    emitter: Option<AudioEmitter>,
+}
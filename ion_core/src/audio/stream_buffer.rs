@@ -0,0 +1,275 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+
+use ::audio::AudioStream;
+
+use std::cell::UnsafeCell;
+use std::cmp;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+// The number of frames decoded ahead of the mixer.
+static STREAM_BUFFER_FRAMES: usize = 16384;
+
+// The time in milliseconds the decoder thread waits before topping up the buffer.
+static DECODE_INTERVAL: u64 = 5;
+
+/*===============================================================================================*/
+/*------STREAM BUFFER STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The samples of a stream, decoded ahead on their own thread.
+///
+/// The decoder thread writes into a lock-free ring buffer, which the mixer reads from,
+/// so no file reading or decoding happens on the audio thread. The buffer is filled before
+/// the thread starts, so streams shorter than the buffer are decoded up front.  
+/// The thread stops once the buffer is dropped.
+pub struct StreamBuffer {
+
+    // Private
+    _shared: Arc<SharedRing>,
+    _sample_rate: u32,
+    _channels: u16,
+}
+
+/*===============================================================================================*/
+/*------STREAM BUFFER PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl StreamBuffer {
+
+    /// Returns the sample rate in Hz.
+    pub fn get_sample_rate (&self) -> u32 {
+        self._sample_rate
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of interleaved channels.
+    pub fn get_channels (&self) -> u16 {
+        self._channels
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets whether the decoder starts over when it reaches the end of the stream.
+    pub fn set_looping (&self, looping: bool) {
+        self._shared.is_looping.store (looping, Ordering::Relaxed);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Appends up to `max_frames` decoded frames to `samples`, without blocking.
+    ///
+    /// Returns the number of frames appended.
+    pub fn read (&self, samples: &mut Vec<f32>, max_frames: usize) -> usize {
+
+        let shared   = &self._shared;
+        let channels = self._channels as usize;
+
+        let write = shared.write.load (Ordering::Acquire);
+        let read  = shared.read.load (Ordering::Relaxed);
+        let count = cmp::min (write.wrapping_sub (read), max_frames * channels);
+
+        for i in 0..count {
+            samples.push (unsafe {*shared.samples[read.wrapping_add (i) % shared.samples.len ()].get ()});
+        }
+
+        shared.read.store (read.wrapping_add (count), Ordering::Release);
+        count / channels
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether every frame of the stream has been read.
+    ///
+    /// A looping stream only ends if it can't be rewound.
+    pub fn is_at_end (&self) -> bool {
+
+        let shared = &self._shared;
+
+        // The end flags are set after the last frames are written
+        let is_at_end = shared.is_at_end.load (Ordering::Acquire) &&
+                        (shared.is_exhausted.load (Ordering::Acquire) || !shared.is_looping.load (Ordering::Relaxed));
+
+        is_at_end && shared.write.load (Ordering::Acquire) == shared.read.load (Ordering::Relaxed)
+    }
+
+/*===============================================================================================*/
+/*------STREAM BUFFER PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Fills a new buffer from a stream, and starts its decoder thread.
+    pub fn new (stream: AudioStream) -> StreamBuffer {
+
+        let sample_rate = stream.get_sample_rate ();
+        let channels = cmp::max (stream.get_channels (), 1);
+
+        let shared = Arc::new (SharedRing {
+
+            samples:      (0..STREAM_BUFFER_FRAMES * channels as usize).map (|_| UnsafeCell::new (0.0)).collect (),
+            read:         AtomicUsize::new (0),
+            write:        AtomicUsize::new (0),
+            is_at_end:    AtomicBool::new (false),
+            is_exhausted: AtomicBool::new (false),
+            is_looping:   AtomicBool::new (false),
+            is_closed:    AtomicBool::new (false),
+        });
+
+        let mut decoder = StreamDecoder {stream: stream, pending: Vec::new (), pending_start: 0, has_rewound: false};
+        decoder.fill (&shared);
+
+        let thread_shared = shared.clone ();
+        let thread = thread::Builder::new ().name ("ion_audio_stream".to_string ()).spawn (move || {
+
+            while !thread_shared.is_closed.load (Ordering::Relaxed) && !thread_shared.is_exhausted.load (Ordering::Relaxed) {
+
+                decoder.fill (&thread_shared);
+                thread::sleep (Duration::from_millis (DECODE_INTERVAL));
+            }
+        });
+
+        if let Err (e) = thread {
+
+            error! ("Could not start the decoder thread of an audio stream.\n{}", e);
+            StreamDecoder::_set_exhausted (&shared);
+        }
+
+        StreamBuffer {_shared: shared, _sample_rate: sample_rate, _channels: channels}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Drop for StreamBuffer {
+
+    fn drop (&mut self) {
+        self._shared.is_closed.store (true, Ordering::Relaxed);
+    }
+}
+
+/*===============================================================================================*/
+/*------SHARED RING STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// A single producer, single consumer ring of samples.
+// The read and write positions only ever increase, wrapping around the length of the ring.
+struct SharedRing {
+
+    samples: Vec<UnsafeCell<f32>>,
+    read: AtomicUsize,
+    write: AtomicUsize,
+    is_at_end: AtomicBool,
+    is_exhausted: AtomicBool,
+    is_looping: AtomicBool,
+    is_closed: AtomicBool,
+}
+
+// Each sample is only written by the decoder before the write position passes it,
+// and only read by the mixer before the read position passes it.
+unsafe impl Sync for SharedRing {}
+
+/*===============================================================================================*/
+/*------STREAM DECODER STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Decodes a stream into a ring, keeping the samples which didn't fit yet.
+struct StreamDecoder {
+
+    stream: AudioStream,
+    pending: Vec<f32>,
+    pending_start: usize,
+    has_rewound: bool,
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl StreamDecoder {
+
+    // Decodes until the ring is full, or the end of the stream is reached.
+    fn fill (&mut self, ring: &SharedRing) {
+
+        loop {
+
+            // Write what was left over from the last fill
+            self.pending_start += StreamDecoder::_write (ring, &self.pending[self.pending_start..]);
+
+            if self.pending_start < self.pending.len () {
+                return;
+            }
+
+            self.pending.clear ();
+            self.pending_start = 0;
+
+            // The voice may be set to loop after the stream has reached the end
+            if ring.is_at_end.load (Ordering::Relaxed) {
+
+                if ring.is_exhausted.load (Ordering::Relaxed) || !ring.is_looping.load (Ordering::Relaxed) {
+                    return;
+                }
+
+                if self.stream.rewind ().is_err () {
+
+                    StreamDecoder::_set_exhausted (ring);
+                    return;
+                }
+
+                self.has_rewound = true;
+                ring.is_at_end.store (false, Ordering::Release);
+            }
+
+            match self.stream.read (&mut self.pending) {
+
+                // An empty stream stops instead of rewinding forever
+                Ok (0) if !self.has_rewound => ring.is_at_end.store (true, Ordering::Release),
+                Ok (0) | Err (_) => StreamDecoder::_set_exhausted (ring),
+                Ok (_) => self.has_rewound = false
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Marks the stream as ended for good.
+    fn _set_exhausted (ring: &SharedRing) {
+
+        ring.is_exhausted.store (true, Ordering::Release);
+        ring.is_at_end.store (true, Ordering::Release);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Writes as many samples as fit into the ring, and returns the number written.
+    //
+    // Both ends always move by whole frames, as the ring holds a whole number of frames.
+    fn _write (ring: &SharedRing, samples: &[f32]) -> usize {
+
+        let read  = ring.read.load (Ordering::Acquire);
+        let write = ring.write.load (Ordering::Relaxed);
+        let count = cmp::min (ring.samples.len () - write.wrapping_sub (read), samples.len ());
+
+        for (i, sample) in samples[..count].iter ().enumerate () {
+            unsafe {*ring.samples[write.wrapping_add (i) % ring.samples.len ()].get () = *sample};
+        }
+
+        ring.write.store (write.wrapping_add (count), Ordering::Release);
+        count
+    }
+}
@@ -0,0 +1,363 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::{AudioClip, AudioStream, BusHandle};
+use ::audio::stream_buffer::StreamBuffer;
+use ::util::math::Util;
+
+use std::cmp;
+use std::sync::Arc;
+
+// The most frames taken from a stream buffer at a time.
+static STREAM_READ_FRAMES: usize = 1024;
+
+/*===============================================================================================*/
+/*------VOICE STRUCT-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A single playing sound.
+///
+/// Voices play either a decoded clip, or a stream. Streams are decoded ahead on their own thread,
+/// and a voice which catches up with its decoder waits for it. The sound is resampled to the rate of
+/// the mixer with linear interpolation, so at a pitch of one with matching rates the samples
+/// are copied unchanged. Mono sounds are played on both channels, and any channels past
+/// the first two are ignored.
+pub struct Voice {
+
+    // Public
+    /// The volume of the voice.
+    pub volume: f32,
+    /// The stereo position, from -1 for left to 1 for right.
+    ///
+    /// Panning attenuates the opposite channel, so a centred voice plays at full volume on both.
+    pub pan: f32,
+    /// The playback speed, which also shifts the pitch.
+    pub pitch: f32,
+    /// Whether the voice starts over when it reaches the end.
+    pub looping: bool,
+    /// Whether the voice is paused.
+    pub paused: bool,
+    /// The bus the voice is mixed into.
+    pub bus: BusHandle,
+
+    // Private
+    _source: VoiceSource,
+    _position: f64,
+    _is_finished: bool,
+}
+
+/*===============================================================================================*/
+/*------VOICE PUBLIC METHODS---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Voice {
+
+    /// Returns the sample rate of the sound in Hz.
+    pub fn get_sample_rate (&self) -> u32 {
+
+        match self._source {
+
+            VoiceSource::Clip (ref clip) => clip.get_sample_rate (),
+            VoiceSource::Stream {ref stream, ..} => stream.get_sample_rate ()
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether the voice plays a stream.
+    pub fn is_streaming (&self) -> bool {
+
+        match self._source {
+
+            VoiceSource::Clip (_) => false,
+            VoiceSource::Stream {..} => true
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether the voice has reached the end of its sound.
+    pub fn is_finished (&self) -> bool {
+        self._is_finished
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Mixes the voice into an interleaved stereo buffer, and advances it.
+    ///
+    /// Nothing is mixed if the voice is paused or finished.
+    pub fn render (&mut self, output: &mut [f32], sample_rate: u32) {
+
+        if self.paused || self._is_finished {
+            return;
+        }
+
+        let step = Util::max (self.pitch, 0.0) as f64 * self.get_sample_rate () as f64 / sample_rate as f64;
+        let pan = Util::clamp (self.pan, -1.0, 1.0);
+
+        let left_gain  = self.volume * Util::min (1.0 - pan, 1.0);
+        let right_gain = self.volume * Util::min (1.0 + pan, 1.0);
+
+        if let VoiceSource::Stream {ref stream, ..} = self._source {
+            stream.set_looping (self.looping);
+        }
+
+        for frame in output.chunks_mut (2) {
+
+            let (left, right) = match self._sample () {
+
+                VoiceSample::Frame (left, right) => (left, right),
+
+                // Wait for the decoder, rather than skipping ahead
+                VoiceSample::Starved => break,
+                VoiceSample::End => {
+
+                    self._is_finished = true;
+                    break;
+                }
+            };
+
+            frame[0] += left  * left_gain;
+            frame[1] += right * right_gain;
+
+            self._position += step;
+        }
+
+        // Release the frames which have been played
+        let position = self._position as u64;
+
+        if let VoiceSource::Stream {ref stream, ref mut buffer, ref mut buffer_start, ..} = self._source {
+
+            let channels = stream.get_channels () as usize;
+            let played = cmp::min ((position - *buffer_start) as usize, buffer.len () / channels);
+
+            buffer.drain (..played * channels);
+            *buffer_start += played as u64;
+        }
+    }
+
+/*===============================================================================================*/
+/*------VOICE PUBLIC STATIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new voice which plays a clip.
+    ///
+    /// The voice plays once at full volume on the master bus.
+    pub fn new (clip: Arc<AudioClip>) -> Voice {
+        Voice::_new (VoiceSource::Clip (clip))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new voice which plays a stream.
+    ///
+    /// The voice plays once at full volume on the master bus.  
+    /// The start of the stream is decoded before this returns, and the rest on its own thread.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::audio::{AudioClip, AudioMixer, AudioStream, Voice};
+    /// # use std::env;
+    /// # use std::sync::Arc;
+    /// #
+    /// let samples = (0..1000).map (|i| (i % 50) as f32 / 50.0).collect::<Vec<f32>> ();
+    /// let clip = AudioClip::from_samples (44100, 1, samples).unwrap ();
+    ///
+    /// let path = env::temp_dir ().join ("ion_voice_stream_example.wav");
+    /// clip.save_wav (path.to_str ().unwrap ()).unwrap ();
+    ///
+    /// // A stream plays the same samples as its decoded clip
+    /// let mut streamed = [0.0; 1200];
+    /// let mut mixer = AudioMixer::new (44100, 1);
+    /// mixer.play (Voice::from_stream (AudioStream::open (path.to_str ().unwrap ()).unwrap ())).unwrap ();
+    /// mixer.mix (&mut streamed);
+    ///
+    /// let mut decoded = [0.0; 1200];
+    /// let mut mixer = AudioMixer::new (44100, 1);
+    /// mixer.play (Voice::new (Arc::new (clip))).unwrap ();
+    /// mixer.mix (&mut decoded);
+    ///
+    /// assert_eq! (&streamed[..], &decoded[..]);
+    /// assert_eq! (mixer.get_voice_count (), 0);
+    /// ```
+    pub fn from_stream (stream: AudioStream) -> Voice {
+        Voice::_new (VoiceSource::Stream {stream: StreamBuffer::new (stream), buffer: Vec::new (), buffer_start: 0})
+    }
+}
+
+/*===============================================================================================*/
+/*------VOICE PRIVATE METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Voice {
+
+    // Returns the interpolated frame at the current position.
+    fn _sample (&mut self) -> VoiceSample {
+
+        let looping = self.looping;
+
+        let (first, second, fraction) = match self._source {
+
+            VoiceSource::Clip (ref clip) => {
+
+                let frame_count = clip.get_frame_count ();
+
+                if frame_count == 0 {
+                    return VoiceSample::End;
+                }
+
+                if self._position >= frame_count as f64 {
+
+                    if !looping {
+                        return VoiceSample::End;
+                    }
+
+                    self._position %= frame_count as f64;
+                }
+
+                let index = self._position as usize;
+                let channels = clip.get_channels () as usize;
+                let samples = clip.get_samples ();
+
+                // Looping clips interpolate back to the first frame
+                let second = if index + 1 < frame_count {
+                    Voice::_read_frame (samples, channels, index + 1)
+                }
+
+                else if looping {
+                    Voice::_read_frame (samples, channels, 0)
+                }
+
+                else {
+                    (0.0, 0.0)
+                };
+
+                (Voice::_read_frame (samples, channels, index), second, self._position - index as f64)
+            },
+
+            VoiceSource::Stream {ref stream, ref mut buffer, buffer_start} => {
+
+                let index = self._position as u64;
+                let channels = stream.get_channels () as usize;
+
+                // Take decoded frames until both frames used for interpolation are available
+                while index + 2 > buffer_start + (buffer.len () / channels) as u64 {
+
+                    if stream.read (buffer, STREAM_READ_FRAMES) == 0 {
+                        break;
+                    }
+                }
+
+                let buffer_end = buffer_start + (buffer.len () / channels) as u64;
+
+                if index + 1 >= buffer_end && !stream.is_at_end () {
+                    return VoiceSample::Starved;
+                }
+
+                if index >= buffer_end {
+                    return VoiceSample::End;
+                }
+
+                let offset = (index - buffer_start) as usize;
+
+                let second = if index + 1 < buffer_end {
+                    Voice::_read_frame (buffer, channels, offset + 1)
+                }
+
+                else {
+                    (0.0, 0.0)
+                };
+
+                (Voice::_read_frame (buffer, channels, offset), second, self._position - index as f64)
+            }
+        };
+
+        let fraction = fraction as f32;
+
+        VoiceSample::Frame (first.0 + (second.0 - first.0) * fraction,
+                            first.1 + (second.1 - first.1) * fraction)
+    }
+
+/*===============================================================================================*/
+/*------VOICE PRIVATE STATIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Returns a new voice with the default settings.
+    fn _new (source: VoiceSource) -> Voice {
+
+        Voice {
+
+            volume:       1.0,
+            pan:          0.0,
+            pitch:        1.0,
+            looping:      false,
+            paused:       false,
+            bus:          BusHandle::master (),
+
+            _source:      source,
+            _position:    0.0,
+            _is_finished: false,
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a frame as a left and right sample.
+    fn _read_frame (samples: &[f32], channels: usize, frame: usize) -> (f32, f32) {
+
+        let offset = frame * channels;
+
+        if channels == 1 {
+            (samples[offset], samples[offset])
+        }
+
+        else {
+            (samples[offset], samples[offset + 1])
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------VOICE SOURCE ENUM------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The sound a voice plays.
+enum VoiceSource {
+
+    // A decoded clip.
+    Clip (Arc<AudioClip>),
+
+    // A stream, with the decoded frames which haven't been played yet.
+    Stream {stream: StreamBuffer, buffer: Vec<f32>, buffer_start: u64},
+}
+
+/*===============================================================================================*/
+/*------VOICE SAMPLE ENUM------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The result of sampling a voice.
+enum VoiceSample {
+
+    // The left and right samples of a frame.
+    Frame (f32, f32),
+
+    // The stream decoder hasn't caught up yet.
+    Starved,
+
+    // The sound has ended.
+    End,
+}
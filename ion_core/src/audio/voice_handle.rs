@@ -0,0 +1,60 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------VOICE HANDLE STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Identifies a playing voice in a mixer.
+///
+/// Voice slots are reused once a voice finishes, so each handle also stores the generation
+/// of its slot. A handle to a finished voice never refers to a voice started later.
+#[derive (Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VoiceHandle {
+
+    // Private
+    _index: usize,
+    _generation: u32,
+}
+
+/*===============================================================================================*/
+/*------VOICE HANDLE PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl VoiceHandle {
+
+    /// Returns the index of the voice slot in the mixer.
+    pub fn get_index (&self) -> usize {
+        self._index
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the generation of the voice slot.
+    pub fn get_generation (&self) -> u32 {
+        self._generation
+    }
+
+/*===============================================================================================*/
+/*------VOICE HANDLE PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new handle to a voice slot.
+    pub fn new (index: usize, generation: u32) -> VoiceHandle {
+        VoiceHandle {_index: index, _generation: generation}
+    }
+}
@@ -0,0 +1,417 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::audio::AudioClip;
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::mem;
+
+// Format tags.
+const WAV_FORMAT_PCM:        u16 = 0x0001;
+const WAV_FORMAT_FLOAT:      u16 = 0x0003;
+const WAV_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+// The size of the header written by the encoder.
+const WAV_HEADER_SIZE: usize = 44;
+
+// The largest format chunk, which is the size of WAVE_FORMAT_EXTENSIBLE.
+const WAV_MAX_FORMAT_SIZE: usize = 40;
+
+/*===============================================================================================*/
+/*------WAV CODEC STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Decodes and encodes WAV files.
+///
+/// 8, 16, 24 and 32-bit PCM, and 32 and 64-bit float files are supported, including the
+/// extensible format. Files are always encoded as 32-bit float, so samples survive unchanged.
+#[derive (Copy, Clone)]
+pub struct WavCodec;
+
+/*===============================================================================================*/
+/*------WAV CODEC PUBLIC STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl WavCodec {
+
+    /// Decodes a WAV file.
+    pub fn decode (data: &[u8]) -> Result<AudioClip, ()> {
+
+        let mut reader = io::Cursor::new (data);
+        let header = try! (WavCodec::read_header (&mut reader));
+
+        let start = header.data_offset as usize;
+        let end = if header.data_length as usize > data.len () - start {
+            data.len ()
+        }
+
+        else {
+            start + header.data_length as usize
+        };
+
+        let mut samples = Vec::new ();
+        WavCodec::decode_samples (&header, &data[start..end], &mut samples);
+
+        AudioClip::from_samples (header.sample_rate, header.channels, samples)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Encodes a clip as a 32-bit float WAV file.
+    pub fn encode (clip: &AudioClip) -> Vec<u8> {
+
+        let mut data = Vec::with_capacity (WAV_HEADER_SIZE + clip.get_samples ().len () * 4);
+
+        WavCodec::write_header (&mut data, clip.get_sample_rate (), clip.get_channels (), clip.get_frame_count ()).unwrap ();
+        WavCodec::write_samples (&mut data, clip.get_samples ()).unwrap ();
+
+        data
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Reads the header of a WAV file.
+    ///
+    /// The reader is left at the start of the sample data.
+    pub fn read_header<R: Read + Seek> (reader: &mut R) -> Result<WavHeader, ()> {
+
+        let mut riff = [0; 12];
+
+        if reader.read_exact (&mut riff).is_err () || &riff[0..4] != b"RIFF" || &riff[8..12] != b"WAVE" {
+
+            error! ("File is not a WAV file.");
+            return Err (());
+        }
+
+        let mut format: Option<(WavSampleFormat, u16, u32)> = None;
+
+        // Walk the chunks until the sample data is found
+        loop {
+
+            let mut chunk = [0; 8];
+
+            if reader.read_exact (&mut chunk).is_err () {
+
+                error! ("WAV file has no data chunk.");
+                return Err (());
+            }
+
+            let chunk_size = WavCodec::_read_u32 (&chunk, 4);
+
+            if &chunk[0..4] == b"fmt " {
+
+                let mut fmt = [0; WAV_MAX_FORMAT_SIZE];
+                let fmt_size = chunk_size as usize;
+
+                // The size is checked before reading, so a corrupt size can't allocate
+                if fmt_size < 16 || fmt_size > WAV_MAX_FORMAT_SIZE || reader.read_exact (&mut fmt[..fmt_size]).is_err () {
+
+                    error! ("WAV format chunk is invalid.");
+                    return Err (());
+                }
+
+                format = Some (try! (WavCodec::_parse_format (&fmt[..fmt_size])));
+
+                if chunk_size & 1 != 0 {
+                    try! (WavCodec::_skip (reader, 1));
+                }
+            }
+
+            else if &chunk[0..4] == b"data" {
+
+                let (sample_format, channels, sample_rate) = match format {
+
+                    Some (format) => format,
+                    None => {
+
+                        error! ("WAV data chunk comes before the format chunk.");
+                        return Err (());
+                    }
+                };
+
+                let data_offset = match reader.seek (SeekFrom::Current (0)) {
+
+                    Ok (offset) => offset,
+                    Err (e) => {
+
+                        error! ("Could not read WAV data.\n{}", e);
+                        return Err (());
+                    }
+                };
+
+                return Ok (WavHeader {
+
+                    sample_rate:   sample_rate,
+                    channels:      channels,
+                    sample_format: sample_format,
+                    data_offset:   data_offset,
+                    data_length:   chunk_size as u64,
+                });
+            }
+
+            else {
+                try! (WavCodec::_skip (reader, chunk_size as i64 + (chunk_size & 1) as i64));
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Converts raw sample data to floats, and appends them to the samples.
+    ///
+    /// Any incomplete frame at the end of the data is ignored.
+    pub fn decode_samples (header: &WavHeader, data: &[u8], samples: &mut Vec<f32>) {
+
+        let sample_size = header.sample_format.get_size ();
+        let frame_size = sample_size * header.channels as usize;
+        let data = &data[..data.len () - data.len () % frame_size];
+
+        samples.reserve (data.len () / sample_size);
+
+        for sample in data.chunks (sample_size) {
+
+            let value = match header.sample_format {
+
+                WavSampleFormat::Int8  => (sample[0] as f32 - 128.0) / 128.0,
+                WavSampleFormat::Int16 => (sample[0] as u16 | (sample[1] as u16) << 8) as i16 as f32 / 32768.0,
+                WavSampleFormat::Int24 => {
+
+                    let value = (sample[0] as u32) << 8 | (sample[1] as u32) << 16 | (sample[2] as u32) << 24;
+                    (value as i32 >> 8) as f32 / 8388608.0
+                },
+
+                WavSampleFormat::Int32   => WavCodec::_read_u32 (sample, 0) as i32 as f32 / 2147483648.0,
+                WavSampleFormat::Float32 => unsafe {mem::transmute::<u32, f32> (WavCodec::_read_u32 (sample, 0))},
+                WavSampleFormat::Float64 => {
+
+                    let bits = WavCodec::_read_u32 (sample, 0) as u64 | (WavCodec::_read_u32 (sample, 4) as u64) << 32;
+                    let value: f64 = unsafe {mem::transmute (bits)};
+
+                    value as f32
+                }
+            };
+
+            samples.push (value);
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Writes the header of a 32-bit float WAV file.
+    pub fn write_header<W: Write> (writer: &mut W, sample_rate: u32, channels: u16, frame_count: usize) -> io::Result<()> {
+
+        let data_length = (frame_count * channels as usize * 4) as u32;
+        let mut header = Vec::with_capacity (WAV_HEADER_SIZE);
+
+        header.extend_from_slice (b"RIFF");
+        WavCodec::_push_u32 (&mut header, data_length + WAV_HEADER_SIZE as u32 - 8);
+        header.extend_from_slice (b"WAVEfmt ");
+        WavCodec::_push_u32 (&mut header, 16);
+        WavCodec::_push_u16 (&mut header, WAV_FORMAT_FLOAT);
+        WavCodec::_push_u16 (&mut header, channels);
+        WavCodec::_push_u32 (&mut header, sample_rate);
+        WavCodec::_push_u32 (&mut header, sample_rate * channels as u32 * 4);
+        WavCodec::_push_u16 (&mut header, channels * 4);
+        WavCodec::_push_u16 (&mut header, 32);
+        header.extend_from_slice (b"data");
+        WavCodec::_push_u32 (&mut header, data_length);
+
+        writer.write_all (&header)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Writes samples as 32-bit floats.
+    pub fn write_samples<W: Write> (writer: &mut W, samples: &[f32]) -> io::Result<()> {
+
+        let mut data = Vec::with_capacity (samples.len () * 4);
+
+        for sample in samples {
+            WavCodec::_push_u32 (&mut data, unsafe {mem::transmute::<f32, u32> (*sample)});
+        }
+
+        writer.write_all (&data)
+    }
+}
+
+/*===============================================================================================*/
+/*------WAV CODEC PRIVATE STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl WavCodec {
+
+    // Parses the format chunk into the sample format, channel count and sample rate.
+    fn _parse_format (fmt: &[u8]) -> Result<(WavSampleFormat, u16, u32), ()> {
+
+        let mut format_tag = WavCodec::_read_u16 (fmt, 0);
+        let channels       = WavCodec::_read_u16 (fmt, 2);
+        let sample_rate    = WavCodec::_read_u32 (fmt, 4);
+        let bits           = WavCodec::_read_u16 (fmt, 14);
+
+        // The extensible format stores the real format tag at the start of the sub format
+        if format_tag == WAV_FORMAT_EXTENSIBLE && fmt.len () >= 26 {
+            format_tag = WavCodec::_read_u16 (fmt, 24);
+        }
+
+        let sample_format = match (format_tag, bits) {
+
+            (WAV_FORMAT_PCM, 8)    => WavSampleFormat::Int8,
+            (WAV_FORMAT_PCM, 16)   => WavSampleFormat::Int16,
+            (WAV_FORMAT_PCM, 24)   => WavSampleFormat::Int24,
+            (WAV_FORMAT_PCM, 32)   => WavSampleFormat::Int32,
+            (WAV_FORMAT_FLOAT, 32) => WavSampleFormat::Float32,
+            (WAV_FORMAT_FLOAT, 64) => WavSampleFormat::Float64,
+            _ => {
+
+                error! ("WAV format {} with {} bits per sample is not supported.", format_tag, bits);
+                return Err (());
+            }
+        };
+
+        if channels == 0 || sample_rate == 0 {
+
+            error! ("WAV file has {} channels at {}Hz.", channels, sample_rate);
+            return Err (());
+        }
+
+        Ok ((sample_format, channels, sample_rate))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Skips over bytes in the reader.
+    fn _skip<R: Seek> (reader: &mut R, count: i64) -> Result<(), ()> {
+
+        match reader.seek (SeekFrom::Current (count)) {
+
+            Ok (_) => Ok (()),
+            Err (e) => {
+
+                error! ("Could not read WAV file.\n{}", e);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Appends a little endian u16.
+    fn _push_u16 (data: &mut Vec<u8>, value: u16) {
+
+        data.push (value as u8);
+        data.push ((value >> 8) as u8);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Appends a little endian u32.
+    fn _push_u32 (data: &mut Vec<u8>, value: u32) {
+
+        WavCodec::_push_u16 (data, value as u16);
+        WavCodec::_push_u16 (data, (value >> 16) as u16);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a little endian u16.
+    fn _read_u16 (data: &[u8], offset: usize) -> u16 {
+        data[offset] as u16 | (data[offset + 1] as u16) << 8
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a little endian u32.
+    fn _read_u32 (data: &[u8], offset: usize) -> u32 {
+        WavCodec::_read_u16 (data, offset) as u32 | (WavCodec::_read_u16 (data, offset + 2) as u32) << 16
+    }
+}
+
+/*===============================================================================================*/
+/*------WAV HEADER STRUCT------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The layout of the sample data in a WAV file.
+#[derive (Copy, Clone)]
+pub struct WavHeader {
+
+    // Public
+    /// The sample rate in Hz.
+    pub sample_rate: u32,
+    /// The number of interleaved channels.
+    pub channels: u16,
+    /// The format of each sample.
+    pub sample_format: WavSampleFormat,
+    /// The offset of the sample data in the file.
+    pub data_offset: u64,
+    /// The length of the sample data in bytes.
+    pub data_length: u64,
+}
+
+/*===============================================================================================*/
+/*------WAV HEADER PUBLIC METHODS----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl WavHeader {
+
+    /// Returns the size of a frame in bytes.
+    pub fn get_frame_size (&self) -> usize {
+        self.sample_format.get_size () * self.channels as usize
+    }
+}
+
+/*===============================================================================================*/
+/*------WAV SAMPLE FORMAT ENUM-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The sample formats a WAV file can be decoded from.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum WavSampleFormat {
+
+    /// Unsigned 8-bit integers.
+    Int8,
+    /// Signed 16-bit integers.
+    Int16,
+    /// Signed 24-bit integers.
+    Int24,
+    /// Signed 32-bit integers.
+    Int32,
+    /// 32-bit floats.
+    Float32,
+    /// 64-bit floats.
+    Float64,
+}
+
+/*===============================================================================================*/
+/*------WAV SAMPLE FORMAT PUBLIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl WavSampleFormat {
+
+    /// Returns the size of a sample in bytes.
+    pub fn get_size (&self) -> usize {
+
+        match *self {
+
+            WavSampleFormat::Int8    => 1,
+            WavSampleFormat::Int16   => 2,
+            WavSampleFormat::Int24   => 3,
+            WavSampleFormat::Int32   => 4,
+            WavSampleFormat::Float32 => 4,
+            WavSampleFormat::Float64 => 8,
+        }
+    }
+}
@@ -14,6 +14,9 @@
 // limitations under the License.
 /*===============================================================================================*/
 
+use ::audio::AudioManager;
+use ::engine::FixedTimestep;
+use ::physics::PhysicsManager;
 use ::resource::ResourceManager;
 use ::renderer::RenderManager;
 use ::util::{Directory, Version, Logger};
@@ -47,6 +50,10 @@ pub struct App {
     pub resource_mgr: Rc<RefCell<ResourceManager>>,
     /// The render manager.
     pub render_mgr: Rc<RefCell<RenderManager>>,
+    /// The physics manager.
+    pub physics_mgr: Rc<RefCell<PhysicsManager>>,
+    /// The audio manager.
+    pub audio_mgr: Rc<RefCell<AudioManager>>,
 
     /// The project name.
     pub project_name: String,
@@ -57,6 +64,7 @@ pub struct App {
 
     // Private
     _is_in_main_loop: bool,
+    _fixed_timestep: FixedTimestep,
 }
 
 /*===============================================================================================*/
@@ -87,7 +95,16 @@ impl App {
         loop {
 
             if self._is_in_main_loop {
+
                 self.render_mgr.borrow_mut ().process_window_events ();
+
+                // Step the simulation at a fixed rate
+                for _ in 0..self._fixed_timestep.update () {
+                    self.physics_mgr.borrow_mut ().fixed_update (self._fixed_timestep.get_timestep ());
+                }
+
+                self.audio_mgr.borrow_mut ().update ();
+                self.render_mgr.borrow_mut ().present ();
             }
 
             else {
@@ -178,6 +195,7 @@ impl App {
 
         self.resource_mgr.borrow_mut ().init ();
         self.render_mgr.borrow_mut   ().init ();
+        self.audio_mgr.borrow_mut    ().init ();
     }
 
 /*-----------------------------------------------------------------------------------------------*/
@@ -233,6 +251,8 @@ impl App {
 
     // Releases the managers
     fn _release_managers (&self) {
+
+        self.audio_mgr.borrow_mut  ().release ();
         self.render_mgr.borrow_mut ().release ();
     }
 
@@ -268,6 +288,7 @@ pub struct AppBuilder {
     _project_name: String,
     _project_developer: String,
     _project_version: Version,
+    _fixed_timestep: f32,
 }
 
 /*===============================================================================================*/
@@ -301,6 +322,17 @@ impl AppBuilder {
         self
     }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the length of a fixed update step in seconds.
+    ///
+    /// The default is 60 steps per second. It is also used if the length isn't positive and finite.
+    pub fn fixed_timestep (&mut self, timestep: f32) -> &mut Self {
+
+        self._fixed_timestep = timestep;
+        self
+    }
+
 /*-----------------------------------------------------------------------------------------------*/
 
     /// Builds the app.
@@ -313,12 +345,15 @@ impl AppBuilder {
 
                 resource_mgr:      Rc::new (RefCell::new (ResourceManager::new ())),
                 render_mgr:        Rc::new (RefCell::new (RenderManager::new ())),
+                physics_mgr:       Rc::new (RefCell::new (PhysicsManager::new ())),
+                audio_mgr:         Rc::new (RefCell::new (AudioManager::new ())),
 
                 project_name:      self._project_name.clone (),
                 project_developer: self._project_developer.clone (),
                 project_version:   self._project_version,
 
                 _is_in_main_loop:  false,
+                _fixed_timestep:   FixedTimestep::new (self._fixed_timestep, 8),
             });
 
             unsafe {APP_POINTER = Some (Box::into_raw (ab))};
@@ -339,6 +374,7 @@ impl AppBuilder {
             _project_name:      "Untitled".to_string (),
             _project_developer: "Unknown".to_string (),
             _project_version:   Version::default (),
+            _fixed_timestep:    1.0 / 60.0,
         }
     }
 }
@@ -0,0 +1,200 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use std::time::Instant;
+
+/*===============================================================================================*/
+/*------STATIC VARIABLES-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The step length used when an invalid one is given.
+static DEFAULT_TIMESTEP: f32 = 1.0 / 60.0;
+
+/*===============================================================================================*/
+/*------FIXED TIMESTEP STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Converts variable frame times into a whole number of fixed length steps.
+///
+/// Time that does not fill a step is carried over to the next frame. The number of steps
+/// per frame is capped, so a long stall does not make the simulation fall further behind.
+///
+/// # Examples
+/// ```
+/// # use ion_core::engine::FixedTimestep;
+/// #
+/// let mut timestep = FixedTimestep::new (0.25, 5);
+///
+/// assert_eq! (timestep.advance (0.625), 2);
+/// assert_eq! (timestep.get_alpha (), 0.5);
+/// assert_eq! (timestep.advance (0.125), 1);
+///
+/// // Steps past the cap are dropped
+/// assert_eq! (timestep.advance (10.0), 5);
+/// assert_eq! (timestep.get_alpha (), 0.0);
+/// ```
+#[derive (Copy, Clone)]
+pub struct FixedTimestep {
+
+    // Private
+    _timestep: f32,
+    _max_steps: u32,
+    _accumulator: f32,
+    _last_update: Option<Instant>,
+}
+
+/*===============================================================================================*/
+/*------FIXED TIMESTEP PUBLIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl FixedTimestep {
+
+    /// Adds the time since the last call, and returns the number of steps to run.
+    ///
+    /// The first call only starts the clock, and returns zero.
+    pub fn update (&mut self) -> u32 {
+
+        let now = Instant::now ();
+        let steps = match self._last_update {
+
+            Some (last) => {
+
+                let elapsed = now.duration_since (last);
+                self.advance (elapsed.as_secs () as f32 + elapsed.subsec_nanos () as f32 / 1_000_000_000.0)
+            },
+
+            None => 0
+        };
+
+        self._last_update = Some (now);
+        steps
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a length of time in seconds, and returns the number of steps to run.
+    pub fn advance (&mut self, delta_time: f32) -> u32 {
+
+        self._accumulator += delta_time;
+
+        let mut steps = 0;
+
+        while self._accumulator >= self._timestep {
+
+            self._accumulator -= self._timestep;
+            steps += 1;
+
+            if steps == self._max_steps {
+
+                self._accumulator = 0.0;
+                break;
+            }
+        }
+
+        steps
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns how far the time is into the next step, from 0 to 1.
+    ///
+    /// It is used to interpolate the rendered state between the last two steps.
+    pub fn get_alpha (&self) -> f32 {
+        self._accumulator / self._timestep
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the length of a step in seconds.
+    pub fn get_timestep (&self) -> f32 {
+        self._timestep
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the length of a step in seconds.
+    ///
+    /// The length must be positive and finite, otherwise it is ignored.
+    pub fn set_timestep (&mut self, timestep: f32) {
+
+        if FixedTimestep::is_valid_timestep (timestep) {
+            self._timestep = timestep;
+        }
+
+        else {
+            warn! ("Fixed timestep of {} seconds is invalid, so it was not changed.", timestep);
+        }
+    }
+
+/*===============================================================================================*/
+/*------FIXED TIMESTEP PUBLIC STATIC METHODS-----------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new fixed timestep.
+    ///
+    /// The default is 60 steps per second, with at most 8 steps per update.  
+    /// A timestep which isn't positive and finite is replaced with the default.
+    ///
+    /// # Arguments
+    /// * `timestep` - The length of a step in seconds.
+    /// * `max_steps` - The most steps run for a single update.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::engine::FixedTimestep;
+    /// #
+    /// let mut timestep = FixedTimestep::new (0.0, 5);
+    ///
+    /// assert_eq! (timestep.get_timestep (), 1.0 / 60.0);
+    /// assert_eq! (timestep.advance (0.0), 0);
+    /// assert! (timestep.get_alpha ().is_finite ());
+    /// ```
+    pub fn new (timestep: f32, max_steps: u32) -> FixedTimestep {
+
+        let is_valid = FixedTimestep::is_valid_timestep (timestep);
+
+        if !is_valid {
+            warn! ("Fixed timestep of {} seconds is invalid, so the default is used instead.", timestep);
+        }
+
+        FixedTimestep {
+
+            _timestep: if is_valid {timestep} else {DEFAULT_TIMESTEP},
+            _max_steps: max_steps,
+            _accumulator: 0.0,
+            _last_update: None,
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether a length of time can be used as a step.
+    ///
+    /// Steps must be positive and finite.
+    pub fn is_valid_timestep (timestep: f32) -> bool {
+        timestep > 0.0 && timestep.is_finite ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for FixedTimestep {
+
+    fn default () -> FixedTimestep {
+        FixedTimestep::new (DEFAULT_TIMESTEP, 8)
+    }
+}
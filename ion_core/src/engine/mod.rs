@@ -23,7 +23,9 @@
 
 // Modules
 mod app;
+mod fixed_timestep;
 mod project_config;
 
 pub use self::app::App;
+pub use self::fixed_timestep::FixedTimestep;
 pub use self::project_config::ProjectConfig;
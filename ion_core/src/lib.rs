@@ -33,7 +33,9 @@
 extern crate log;
 
 // Modules
+pub mod audio;
 pub mod engine;
+pub mod physics;
 pub mod resource;
 pub mod util;
 //pub mod window;
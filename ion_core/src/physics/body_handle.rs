@@ -0,0 +1,52 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------BODY HANDLE STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Identifies a rigid body in a physics world.
+///
+/// Handles are ordered by the order the bodies were added in, which keeps iteration
+/// deterministic. Handles of removed bodies may be reused by bodies added later.
+#[derive (Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BodyHandle {
+
+    // Private
+    _index: usize,
+}
+
+/*===============================================================================================*/
+/*------BODY HANDLE PUBLIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BodyHandle {
+
+    /// Returns the index of the body slot in the world.
+    pub fn get_index (&self) -> usize {
+        self._index
+    }
+
+/*===============================================================================================*/
+/*------BODY HANDLE PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new handle to a body slot.
+    pub fn new (index: usize) -> BodyHandle {
+        BodyHandle {_index: index}
+    }
+}
@@ -0,0 +1,32 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------BODY TYPE ENUM---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// How a rigid body is moved by the simulation.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum BodyType {
+
+    /// Never moves, and has infinite mass.
+    Static,
+    /// Moves by its velocity only, and pushes dynamic bodies without being pushed back.
+    Kinematic,
+    /// Moved by gravity, forces and collisions.
+    Dynamic,
+}
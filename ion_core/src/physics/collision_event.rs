@@ -0,0 +1,37 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::{BodyHandle, CollisionEventType};
+
+/*===============================================================================================*/
+/*------COLLISION EVENT STRUCT-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Reports that two bodies started or stopped touching.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub struct CollisionEvent {
+
+    // Public
+    /// Whether the bodies started or stopped touching.
+    pub event_type: CollisionEventType,
+    /// The first body. Its handle is lower than the handle of the second body.
+    pub body_a: BodyHandle,
+    /// The second body.
+    pub body_b: BodyHandle,
+    /// Is either of the bodies a trigger? Triggers report overlaps, but are not pushed apart.
+    pub is_trigger: bool,
+}
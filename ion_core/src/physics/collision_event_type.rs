@@ -0,0 +1,30 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------COLLISION EVENT TYPE ENUM----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The kind of change reported by a collision event.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum CollisionEventType {
+
+    /// The bodies started touching this step.
+    Began,
+    /// The bodies stopped touching this step, or one of them was removed.
+    Ended,
+}
@@ -0,0 +1,76 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------COLLISION FILTER STRUCT------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Decides which bodies can collide with each other.
+///
+/// Two bodies collide only if the layer of each is in the mask of the other.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::CollisionFilter;
+/// #
+/// let player = CollisionFilter::new (0b001, 0b110);
+/// let enemy  = CollisionFilter::new (0b010, 0b101);
+/// let pickup = CollisionFilter::new (0b100, 0b001);
+///
+/// assert! (player.can_collide (&enemy));
+/// assert! (player.can_collide (&pickup));
+/// assert! (!enemy.can_collide (&pickup));
+/// ```
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CollisionFilter {
+
+    // Public
+    /// The layers the body is on.
+    pub layer: u32,
+    /// The layers the body collides with.
+    pub mask: u32,
+}
+
+/*===============================================================================================*/
+/*------COLLISION FILTER PUBLIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl CollisionFilter {
+
+    /// Returns true if bodies with the two filters collide.
+    pub fn can_collide (&self, other: &CollisionFilter) -> bool {
+        (self.layer & other.mask) != 0 && (other.layer & self.mask) != 0
+    }
+
+/*===============================================================================================*/
+/*------COLLISION FILTER PUBLIC STATIC METHODS---------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new filter.
+    pub fn new (layer: u32, mask: u32) -> CollisionFilter {
+        CollisionFilter {layer: layer, mask: mask}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for CollisionFilter {
+
+    fn default () -> CollisionFilter {
+        CollisionFilter::new (1, 0xFFFF_FFFF)
+    }
+}
@@ -0,0 +1,39 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The physics module.
+//!
+//! Contains the rigid body simulation, and the physics manager which steps it.
+/*===============================================================================================*/
+
+// Modules
+pub mod physics_2d;
+pub mod physics_3d;
+mod body_handle;
+mod body_type;
+mod collision_event;
+mod collision_event_type;
+mod collision_filter;
+mod physics_manager;
+
+pub use self::body_handle::BodyHandle;
+pub use self::body_type::BodyType;
+pub use self::collision_event::CollisionEvent;
+pub use self::collision_event_type::CollisionEventType;
+pub use self::collision_filter::CollisionFilter;
+pub use self::physics_manager::PhysicsManager;
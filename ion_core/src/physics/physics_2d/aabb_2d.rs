@@ -0,0 +1,111 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Util, Vec2};
+
+/*===============================================================================================*/
+/*------AABB 2D STRUCT---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A 2D axis aligned bounding box.
+#[derive (Copy, Clone, Default, Serialize, Deserialize)]
+pub struct Aabb2D {
+
+    // Public
+    /// The minimum corner of the box.
+    pub min: Vec2,
+    /// The maximum corner of the box.
+    pub max: Vec2,
+}
+
+/*===============================================================================================*/
+/*------AABB 2D PUBLIC METHODS-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Aabb2D {
+
+    /// Returns true if the box contains a point.
+    pub fn contains_point (&self, point: &Vec2) -> bool {
+
+        point.x >= self.min.x && point.x <= self.max.x &&
+        point.y >= self.min.y && point.y <= self.max.y
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if two boxes overlap.
+    ///
+    /// Boxes that only touch along an edge are treated as overlapping.
+    pub fn intersects (&self, other: &Aabb2D) -> bool {
+
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+        self.min.y <= other.max.y && self.max.y >= other.min.y
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the smallest box containing both boxes.
+    pub fn merge (&self, other: &Aabb2D) -> Aabb2D {
+
+        Aabb2D {min: Vec2 {x: Util::min (self.min.x, other.min.x), y: Util::min (self.min.y, other.min.y)},
+                max: Vec2 {x: Util::max (self.max.x, other.max.x), y: Util::max (self.max.y, other.max.y)}}
+    }
+
+/*===============================================================================================*/
+/*------AABB 2D PUBLIC STATIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new box from its corners.
+    pub fn new (min: Vec2, max: Vec2) -> Aabb2D {
+        Aabb2D {min: min, max: max}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the smallest box containing a set of points.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::physics_2d::Aabb2D;
+    /// # use ion_core::util::math::Vec2;
+    /// #
+    /// let aabb = Aabb2D::from_points (&[Vec2 {x: 1.0, y: -2.0}, Vec2 {x: -3.0, y: 4.0}]);
+    ///
+    /// assert! (aabb.min == Vec2 {x: -3.0, y: -2.0});
+    /// assert! (aabb.max == Vec2 {x: 1.0, y: 4.0});
+    /// assert! (aabb.intersects (&Aabb2D::new (Vec2 {x: 1.0, y: 4.0}, Vec2 {x: 2.0, y: 5.0})));
+    /// ```
+    pub fn from_points (points: &[Vec2]) -> Aabb2D {
+
+        let mut aabb = Aabb2D::default ();
+
+        for (index, point) in points.iter ().enumerate () {
+
+            if index == 0 {
+                aabb = Aabb2D::new (*point, *point);
+            }
+
+            else {
+
+                aabb.min = Vec2 {x: Util::min (aabb.min.x, point.x), y: Util::min (aabb.min.y, point.y)};
+                aabb.max = Vec2 {x: Util::max (aabb.max.x, point.x), y: Util::max (aabb.max.y, point.y)};
+            }
+        }
+
+        aabb
+    }
+}
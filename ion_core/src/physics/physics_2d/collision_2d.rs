@@ -0,0 +1,331 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::physics_2d::{ContactPoint2D, Manifold2D, Shape2D, Transform2D};
+use ::util::math::{Util, Vec2, EPSILON};
+
+use std::f32;
+
+// Static variables
+// How much more the second polygon must separate before it is used as the reference face
+static REFERENCE_FACE_TOLERANCE: f32 = 0.0005;
+
+/*===============================================================================================*/
+/*------COLLISION 2D STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Finds the contact points between 2D shapes.
+///
+/// Polygons are tested with the separating axis theorem, and their contact points are found
+/// by clipping the incident edge against the reference edge.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::physics_2d::{Collision2D, Shape2D, Transform2D};
+/// # use ion_core::util::math::Vec2;
+/// #
+/// let ground = Shape2D::Box {half_extents: Vec2 {x: 5.0, y: 1.0}};
+/// let block = Shape2D::Box {half_extents: Vec2 {x: 1.0, y: 1.0}};
+///
+/// let manifold = Collision2D::collide (&ground, &Transform2D::default (),
+///                                      &block, &Transform2D::new (Vec2 {x: 0.0, y: 1.75}, 0.0)).unwrap ();
+///
+/// assert! (manifold.normal == Vec2::up ());
+/// assert_eq! (manifold.contacts.len (), 2);
+/// assert_eq! (manifold.contacts[0].penetration, 0.25);
+///
+/// let ball = Shape2D::Circle {radius: 0.5};
+/// assert! (Collision2D::collide (&ground, &Transform2D::default (),
+///                                &ball, &Transform2D::new (Vec2 {x: 5.5, y: 1.5}, 0.0)).is_none ());
+/// ```
+#[derive (Copy, Clone)]
+pub struct Collision2D;
+
+/*===============================================================================================*/
+/*------COLLISION 2D PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Collision2D {
+
+    /// Returns the contacts between two shapes, or none if they do not overlap.
+    ///
+    /// The normal of the manifold points from the first shape to the second.
+    pub fn collide (shape_a: &Shape2D,
+                    transform_a: &Transform2D,
+                    shape_b: &Shape2D,
+                    transform_b: &Transform2D) -> Option<Manifold2D> {
+
+        match (shape_a, shape_b) {
+
+            (&Shape2D::Circle {radius: radius_a}, &Shape2D::Circle {radius: radius_b}) => {
+                Collision2D::_collide_circles (&transform_a.position, radius_a, &transform_b.position, radius_b)
+            },
+
+            (_, &Shape2D::Circle {radius}) => {
+                Collision2D::_collide_polygon_circle (&shape_a.get_vertices (), transform_a, &transform_b.position, radius)
+            },
+
+            (&Shape2D::Circle {radius}, _) => {
+
+                Collision2D::_collide_polygon_circle (&shape_b.get_vertices (), transform_b, &transform_a.position, radius).map (|mut manifold| {
+
+                    manifold.normal = -manifold.normal;
+                    manifold
+                })
+            },
+
+            _ => Collision2D::_collide_polygons (&shape_a.get_vertices (), transform_a, &shape_b.get_vertices (), transform_b)
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------COLLISION 2D PRIVATE STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Collision2D {
+
+    // Collides two circles.
+    fn _collide_circles (centre_a: &Vec2, radius_a: f32, centre_b: &Vec2, radius_b: f32) -> Option<Manifold2D> {
+
+        let offset = *centre_b - *centre_a;
+        let distance = Vec2::length (&offset);
+        let radius = radius_a + radius_b;
+
+        if distance > radius {
+            return None;
+        }
+
+        // Concentric circles are pushed apart vertically
+        let normal = if distance > EPSILON {offset / distance} else {Vec2::up ()};
+        let penetration = radius - distance;
+
+        Some (Manifold2D {normal: normal,
+                          contacts: vec! [ContactPoint2D {position: *centre_a + normal * (radius_a - penetration * 0.5),
+                                                          penetration: penetration}]})
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Collides a polygon with a circle.
+    fn _collide_polygon_circle (vertices: &[Vec2], transform: &Transform2D, centre: &Vec2, radius: f32) -> Option<Manifold2D> {
+
+        // Work in the local space of the polygon
+        let centre = transform.inverse_apply (centre);
+        let count = vertices.len ();
+
+        let mut edge = 0;
+        let mut separation = f32::MIN;
+
+        for i in 0..count {
+
+            let edge_separation = Vec2::dot (&Collision2D::_get_edge_normal (vertices, i), &(centre - vertices[i]));
+
+            if edge_separation > radius {
+                return None;
+            }
+
+            if edge_separation > separation {
+
+                edge = i;
+                separation = edge_separation;
+            }
+        }
+
+        let (normal, closest) = if separation < EPSILON {
+
+            // The centre is inside the polygon, so push it out of the nearest edge
+            let normal = Collision2D::_get_edge_normal (vertices, edge);
+            (normal, centre - normal * separation)
+        }
+
+        else {
+
+            let start = vertices[edge];
+            let end = vertices[(edge + 1) % count];
+            let direction = end - start;
+
+            let t = Util::clamp (Vec2::dot (&(centre - start), &direction) / Vec2::dot (&direction, &direction), 0.0, 1.0);
+            let closest = start + direction * t;
+            let distance = Vec2::distance (&centre, &closest);
+
+            if distance > radius {
+                return None;
+            }
+
+            ((centre - closest) / distance, closest)
+        };
+
+        let penetration = radius - Vec2::dot (&normal, &(centre - closest));
+
+        Some (Manifold2D {normal: transform.rotate (&normal),
+                          contacts: vec! [ContactPoint2D {position: transform.apply (&closest), penetration: penetration}]})
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Collides two convex polygons.
+    fn _collide_polygons (vertices_a: &[Vec2],
+                          transform_a: &Transform2D,
+                          vertices_b: &[Vec2],
+                          transform_b: &Transform2D) -> Option<Manifold2D> {
+
+        let world_a: Vec<Vec2> = vertices_a.iter ().map (|v| transform_a.apply (v)).collect ();
+        let world_b: Vec<Vec2> = vertices_b.iter ().map (|v| transform_b.apply (v)).collect ();
+
+        // Look for a separating axis among the edge normals of both polygons
+        let (edge_a, separation_a) = Collision2D::_find_max_separation (&world_a, &world_b);
+
+        if separation_a > 0.0 {
+            return None;
+        }
+
+        let (edge_b, separation_b) = Collision2D::_find_max_separation (&world_b, &world_a);
+
+        if separation_b > 0.0 {
+            return None;
+        }
+
+        // Prefer the first polygon as the reference, so the choice does not flicker between steps
+        let (reference, incident, edge, flip) = if separation_b > separation_a + REFERENCE_FACE_TOLERANCE {
+            (&world_b, &world_a, edge_b, true)
+        }
+
+        else {
+            (&world_a, &world_b, edge_a, false)
+        };
+
+        let start = reference[edge];
+        let end = reference[(edge + 1) % reference.len ()];
+        let normal = Collision2D::_get_edge_normal (reference, edge);
+        let tangent = Vec2::normalize (&(end - start));
+
+        // The incident edge faces most against the reference normal
+        let mut incident_edge = 0;
+        let mut min_dot = f32::MAX;
+
+        for i in 0..incident.len () {
+
+            let dot = Vec2::dot (&normal, &Collision2D::_get_edge_normal (incident, i));
+
+            if dot < min_dot {
+
+                incident_edge = i;
+                min_dot = dot;
+            }
+        }
+
+        let points = [incident[incident_edge], incident[(incident_edge + 1) % incident.len ()]];
+
+        // Clip the incident edge to the sides of the reference edge
+        let points = match Collision2D::_clip_segment (&points, &(-tangent), -Vec2::dot (&tangent, &start)) {
+
+            Some (points) => points,
+            None => return None
+        };
+
+        let points = match Collision2D::_clip_segment (&points, &tangent, Vec2::dot (&tangent, &end)) {
+
+            Some (points) => points,
+            None => return None
+        };
+
+        // Keep the points below the reference edge
+        let offset = Vec2::dot (&normal, &start);
+        let mut contacts = Vec::with_capacity (2);
+
+        for point in points.iter () {
+
+            let separation = Vec2::dot (&normal, point) - offset;
+
+            if separation <= 0.0 {
+                contacts.push (ContactPoint2D {position: *point, penetration: -separation});
+            }
+        }
+
+        if contacts.is_empty () {
+            return None;
+        }
+
+        Some (Manifold2D {normal: if flip {-normal} else {normal}, contacts: contacts})
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the edge of the first polygon along whose normal the polygons are furthest apart.
+    // A negative separation is the overlap along that normal.
+    fn _find_max_separation (vertices_a: &[Vec2], vertices_b: &[Vec2]) -> (usize, f32) {
+
+        let mut best_edge = 0;
+        let mut best_separation = f32::MIN;
+
+        for i in 0..vertices_a.len () {
+
+            let normal = Collision2D::_get_edge_normal (vertices_a, i);
+            let mut separation = f32::MAX;
+
+            for vertex in vertices_b.iter () {
+                separation = Util::min (separation, Vec2::dot (&normal, &(*vertex - vertices_a[i])));
+            }
+
+            if separation > best_separation {
+
+                best_edge = i;
+                best_separation = separation;
+            }
+        }
+
+        (best_edge, best_separation)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the outward normal of an edge of a counter-clockwise polygon.
+    fn _get_edge_normal (vertices: &[Vec2], edge: usize) -> Vec2 {
+
+        let direction = vertices[(edge + 1) % vertices.len ()] - vertices[edge];
+        Vec2::normalize (&Vec2 {x: direction.y, y: -direction.x})
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Clips a segment to the side of a line where dot (normal, point) <= offset.
+    fn _clip_segment (points: &[Vec2; 2], normal: &Vec2, offset: f32) -> Option<[Vec2; 2]> {
+
+        let distance_0 = Vec2::dot (normal, &points[0]) - offset;
+        let distance_1 = Vec2::dot (normal, &points[1]) - offset;
+
+        if distance_0 <= 0.0 && distance_1 <= 0.0 {
+            return Some (*points);
+        }
+
+        if distance_0 > 0.0 && distance_1 > 0.0 {
+            return None;
+        }
+
+        let t = distance_0 / (distance_0 - distance_1);
+        let clipped = points[0] + (points[1] - points[0]) * t;
+
+        if distance_0 > 0.0 {
+            Some ([clipped, points[1]])
+        }
+
+        else {
+            Some ([points[0], clipped])
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::Vec2;
+
+/*===============================================================================================*/
+/*------CONTACT POINT 2D STRUCT------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A point where two 2D shapes touch.
+#[derive (Copy, Clone, Default)]
+pub struct ContactPoint2D {
+
+    // Public
+    /// The world space position of the contact.
+    pub position: Vec2,
+    /// How far the shapes overlap along the contact normal.
+    pub penetration: f32,
+}
@@ -0,0 +1,34 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::physics_2d::ContactPoint2D;
+use ::util::math::Vec2;
+
+/*===============================================================================================*/
+/*------MANIFOLD 2D STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The contact points between two overlapping 2D shapes.
+#[derive (Clone, Default)]
+pub struct Manifold2D {
+
+    // Public
+    /// The world space contact normal, pointing from the first shape to the second.
+    pub normal: Vec2,
+    /// The contact points. There are one or two of them.
+    pub contacts: Vec<ContactPoint2D>,
+}
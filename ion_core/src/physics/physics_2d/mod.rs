@@ -0,0 +1,44 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The 2D physics module.
+//!
+//! Contains rigid bodies with circle, box and convex polygon shapes,
+//! and the world that simulates them.
+/*===============================================================================================*/
+
+// Modules
+mod aabb_2d;
+mod collision_2d;
+mod contact_point_2d;
+mod manifold_2d;
+mod physics_world_2d;
+mod rigid_body_2d;
+mod shape_2d;
+mod sweep_and_prune_2d;
+mod transform_2d;
+
+pub use self::aabb_2d::Aabb2D;
+pub use self::collision_2d::Collision2D;
+pub use self::contact_point_2d::ContactPoint2D;
+pub use self::manifold_2d::Manifold2D;
+pub use self::physics_world_2d::PhysicsWorld2D;
+pub use self::rigid_body_2d::RigidBody2D;
+pub use self::shape_2d::Shape2D;
+pub use self::sweep_and_prune_2d::SweepAndPrune2D;
+pub use self::transform_2d::Transform2D;
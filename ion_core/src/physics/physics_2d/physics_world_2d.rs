@@ -0,0 +1,832 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::{BodyHandle, BodyType, CollisionEvent, CollisionEventType};
+use ::physics::physics_2d::{Aabb2D, Collision2D, Manifold2D, RigidBody2D, SweepAndPrune2D};
+use ::util::math::{Util, Vec2};
+
+use std::collections::BTreeMap;
+use std::f32;
+
+// Static variables
+// The fraction of the overlap removed each step
+static BAUMGARTE: f32 = 0.2;
+// The overlap allowed between resting bodies, which stops contacts from jittering
+static LINEAR_SLOP: f32 = 0.01;
+// The closing speed below which bodies do not bounce
+static RESTITUTION_THRESHOLD: f32 = 1.0;
+// The speeds below which a body is resting
+static LINEAR_SLEEP_TOLERANCE: f32 = 0.05;
+static ANGULAR_SLEEP_TOLERANCE: f32 = 0.05;
+// How long an island must rest before it falls asleep
+static TIME_TO_SLEEP: f32 = 0.5;
+// How far a contact can move between steps and still reuse its impulses
+static CONTACT_MATCH_DISTANCE: f32 = 0.05;
+
+/*===============================================================================================*/
+/*------PHYSICS WORLD 2D STRUCT------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Simulates a set of 2D rigid bodies.
+///
+/// Each step integrates the bodies, finds the overlapping pairs with a sweep and prune
+/// broadphase, and pushes them apart with sequential impulses. The impulses of each contact
+/// are kept, and used as the starting guess on the next step, which keeps stacks stable.
+/// Groups of touching bodies that stop moving fall asleep together.
+///
+/// The simulation is deterministic. Bodies and contacts are always processed in handle order,
+/// so the same bodies and steps give exactly the same results.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::{BodyType, CollisionEventType};
+/// # use ion_core::physics::physics_2d::{PhysicsWorld2D, RigidBody2D, Shape2D};
+/// # use ion_core::util::math::Vec2;
+/// #
+/// let mut world = PhysicsWorld2D::new ();
+///
+/// let ground = world.add_body (RigidBody2D::new (BodyType::Static, Shape2D::Box {half_extents: Vec2 {x: 10.0, y: 0.5}}));
+///
+/// let mut ball = RigidBody2D::new (BodyType::Dynamic, Shape2D::Circle {radius: 0.5});
+/// ball.position = Vec2 {x: 0.0, y: 5.0};
+///
+/// let ball = world.add_body (ball);
+///
+/// for _ in 0..300 {
+///     world.step (1.0 / 60.0);
+/// }
+///
+/// // The ball lands, and falls asleep on the ground
+/// let events = world.drain_events ();
+///
+/// assert_eq! (events.len (), 1);
+/// assert! (events[0].event_type == CollisionEventType::Began);
+/// assert! (events[0].body_a == ground && events[0].body_b == ball);
+///
+/// let ball = world.get_body (ball).unwrap ();
+///
+/// assert! (ball.is_sleeping ());
+/// assert! ((ball.position.y - 1.0).abs () < 0.02);
+/// ```
+///
+/// Running the same scene twice gives bit-identical bodies.
+///
+/// ```
+/// # use ion_core::physics::BodyType;
+/// # use ion_core::physics::physics_2d::{PhysicsWorld2D, RigidBody2D, Shape2D};
+/// # use ion_core::util::math::Vec2;
+/// # use std::mem;
+/// #
+/// fn run_scene () -> Vec<u32> {
+///
+///     let mut world = PhysicsWorld2D::new ();
+///     world.add_body (RigidBody2D::new (BodyType::Static, Shape2D::Box {half_extents: Vec2 {x: 10.0, y: 0.5}}));
+///
+///     // Drop a pile of tilted boxes and circles onto each other
+///     let mut handles = Vec::new ();
+///
+///     for i in 0..12 {
+///
+///         let shape = if i % 2 == 0 {Shape2D::Box {half_extents: Vec2 {x: 0.5, y: 0.3}}}
+///                     else {Shape2D::Circle {radius: 0.4}};
+///
+///         let mut body = RigidBody2D::new (BodyType::Dynamic, shape);
+///         body.position = Vec2 {x: (i % 3) as f32 * 0.35 - 0.35, y: 1.5 + i as f32 * 0.9};
+///         body.rotation = i as f32 * 0.3;
+///
+///         handles.push (world.add_body (body));
+///     }
+///
+///     for _ in 0..240 {
+///         world.step (1.0 / 60.0);
+///     }
+///
+///     let mut state = Vec::new ();
+///
+///     for handle in handles {
+///
+///         let body = world.get_body (handle).unwrap ();
+///
+///         for value in &[body.position.x, body.position.y, body.rotation,
+///                        body.linear_velocity.x, body.linear_velocity.y, body.angular_velocity] {
+///             state.push (unsafe {mem::transmute::<f32, u32> (*value)});
+///         }
+///     }
+///
+///     state
+/// }
+///
+/// assert! (run_scene () == run_scene ());
+/// ```
+pub struct PhysicsWorld2D {
+
+    // Public
+    /// The acceleration applied to dynamic bodies.
+    pub gravity: Vec2,
+    /// The number of solver passes over the contacts each step.
+    pub velocity_iterations: u32,
+    /// Can bodies fall asleep?
+    pub allow_sleeping: bool,
+
+    // Private
+    _bodies: Vec<Option<RigidBody2D>>,
+    _free_slots: Vec<usize>,
+    _touching: BTreeMap<(BodyHandle, BodyHandle), bool>,
+    _impulses: BTreeMap<(usize, usize), Vec<(Vec2, f32, f32)>>,
+    _events: Vec<CollisionEvent>,
+}
+
+/*===============================================================================================*/
+/*------PHYSICS WORLD 2D PUBLIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PhysicsWorld2D {
+
+    /// Adds a body to the world, and returns its handle.
+    pub fn add_body (&mut self, body: RigidBody2D) -> BodyHandle {
+
+        match self._free_slots.pop () {
+
+            Some (index) => {
+
+                self._bodies[index] = Some (body);
+                BodyHandle::new (index)
+            },
+
+            None => {
+
+                self._bodies.push (Some (body));
+                BodyHandle::new (self._bodies.len () - 1)
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes a body from the world, and returns it.
+    ///
+    /// Contacts with the body end, and the bodies it was touching are woken up.
+    pub fn remove_body (&mut self, handle: BodyHandle) -> Option<RigidBody2D> {
+
+        let body = match self._bodies.get_mut (handle.get_index ()) {
+
+            Some (slot) => slot.take (),
+            None => return None
+        };
+
+        if body.is_some () {
+
+            let index = handle.get_index ();
+            let cached: Vec<(usize, usize)> = self._impulses.keys ()
+                                                            .filter (|pair| pair.0 == index || pair.1 == index)
+                                                            .cloned ()
+                                                            .collect ();
+
+            for pair in cached {
+                self._impulses.remove (&pair);
+            }
+
+            self._free_slots.push (index);
+
+            let pairs: Vec<(BodyHandle, BodyHandle)> = self._touching.keys ()
+                                                                     .filter (|pair| pair.0 == handle || pair.1 == handle)
+                                                                     .cloned ()
+                                                                     .collect ();
+
+            for pair in pairs {
+
+                let is_trigger = self._touching.remove (&pair).unwrap_or (false);
+                let other = if pair.0 == handle {pair.1} else {pair.0};
+
+                if let Some (body) = self.get_body_mut (other) {
+                    body.wake ();
+                }
+
+                self._events.push (CollisionEvent {event_type: CollisionEventType::Ended,
+                                                   body_a: pair.0,
+                                                   body_b: pair.1,
+                                                   is_trigger: is_trigger});
+            }
+        }
+
+        body
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the collision events since the last call, and clears them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::{BodyType, CollisionEventType};
+    /// # use ion_core::physics::physics_2d::{PhysicsWorld2D, RigidBody2D, Shape2D};
+    /// # use ion_core::util::math::Vec2;
+    /// #
+    /// let mut world = PhysicsWorld2D::new ();
+    ///
+    /// let mut zone = RigidBody2D::new (BodyType::Static, Shape2D::Box {half_extents: Vec2 {x: 1.0, y: 1.0}});
+    /// zone.is_trigger = true;
+    ///
+    /// let mut ball = RigidBody2D::new (BodyType::Dynamic, Shape2D::Circle {radius: 0.25});
+    /// ball.position = Vec2 {x: -2.0, y: 0.0};
+    /// ball.linear_velocity = Vec2 {x: 4.0, y: 0.0};
+    /// ball.gravity_scale = 0.0;
+    ///
+    /// world.add_body (zone);
+    /// world.add_body (ball);
+    ///
+    /// // The ball passes through the trigger
+    /// let mut events = Vec::new ();
+    ///
+    /// for _ in 0..60 {
+    ///
+    ///     world.step (1.0 / 60.0);
+    ///     events.extend (world.drain_events ());
+    /// }
+    ///
+    /// assert_eq! (events.len (), 2);
+    /// assert! (events[0].event_type == CollisionEventType::Began && events[0].is_trigger);
+    /// assert! (events[1].event_type == CollisionEventType::Ended && events[1].is_trigger);
+    /// assert! (world.drain_events ().is_empty ());
+    /// ```
+    pub fn drain_events (&mut self) -> Vec<CollisionEvent> {
+        self._events.drain (..).collect ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a body, or none if the handle has been removed.
+    pub fn get_body (&self, handle: BodyHandle) -> Option<&RigidBody2D> {
+        self._bodies.get (handle.get_index ()).and_then (|slot| slot.as_ref ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable body, or none if the handle has been removed.
+    pub fn get_body_mut (&mut self, handle: BodyHandle) -> Option<&mut RigidBody2D> {
+        self._bodies.get_mut (handle.get_index ()).and_then (|slot| slot.as_mut ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of bodies in the world.
+    pub fn get_body_count (&self) -> usize {
+        self._bodies.len () - self._free_slots.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the handles of the bodies in the world, in order.
+    pub fn get_handles (&self) -> Vec<BodyHandle> {
+
+        self._bodies.iter ()
+                    .enumerate ()
+                    .filter (|&(_, slot)| slot.is_some ())
+                    .map (|(index, _)| BodyHandle::new (index))
+                    .collect ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Advances the simulation.
+    ///
+    /// It should be called with a fixed time step, which keeps the simulation stable
+    /// and the results repeatable.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::BodyType;
+    /// # use ion_core::physics::physics_2d::{PhysicsWorld2D, RigidBody2D, Shape2D};
+    /// # use ion_core::util::math::Vec2;
+    /// #
+    /// fn build_world () -> PhysicsWorld2D {
+    ///
+    ///     let mut world = PhysicsWorld2D::new ();
+    ///     world.add_body (RigidBody2D::new (BodyType::Static, Shape2D::Box {half_extents: Vec2 {x: 10.0, y: 0.5}}));
+    ///
+    ///     for i in 0..10 {
+    ///
+    ///         let mut body = RigidBody2D::new (BodyType::Dynamic, Shape2D::Box {half_extents: Vec2 {x: 0.5, y: 0.5}});
+    ///         body.position = Vec2 {x: (i % 3) as f32 * 0.3, y: 1.0 + i as f32 * 1.1};
+    ///         body.rotation = i as f32 * 0.1;
+    ///
+    ///         world.add_body (body);
+    ///     }
+    ///
+    ///     world
+    /// }
+    ///
+    /// // The same world stepped the same way ends up in exactly the same state
+    /// let mut world_a = build_world ();
+    /// let mut world_b = build_world ();
+    ///
+    /// for _ in 0..240 {
+    ///
+    ///     world_a.step (1.0 / 60.0);
+    ///     world_b.step (1.0 / 60.0);
+    /// }
+    ///
+    /// for handle in world_a.get_handles () {
+    ///
+    ///     let a = world_a.get_body (handle).unwrap ();
+    ///     let b = world_b.get_body (handle).unwrap ();
+    ///
+    ///     assert! (a.position == b.position && a.rotation == b.rotation);
+    /// }
+    /// ```
+    pub fn step (&mut self, delta_time: f32) {
+
+        if delta_time <= 0.0 {
+            return;
+        }
+
+        let gravity = self.gravity;
+
+        for slot in self._bodies.iter_mut () {
+
+            if let Some (ref mut body) = *slot {
+                body.integrate_velocity (&gravity, delta_time);
+            }
+        }
+
+        let (touching, manifolds) = self._find_contacts ();
+
+        // Contact with a moving body wakes a sleeping one
+        for &(index_a, index_b, _) in manifolds.iter () {
+
+            for &index in [index_a, index_b].iter () {
+
+                if let Some (ref mut body) = self._bodies[index] {
+
+                    if body.is_sleeping () {
+                        body.wake ();
+                    }
+                }
+            }
+        }
+
+        self._solve_contacts (&manifolds, delta_time);
+
+        for slot in self._bodies.iter_mut () {
+
+            if let Some (ref mut body) = *slot {
+                body.integrate_position (delta_time);
+            }
+        }
+
+        if self.allow_sleeping {
+            self._update_sleeping (&manifolds, delta_time);
+        }
+
+        self._update_events (touching);
+    }
+
+/*===============================================================================================*/
+/*------PHYSICS WORLD 2D PUBLIC STATIC METHODS---------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty world with earth gravity.
+    pub fn new () -> PhysicsWorld2D {
+
+        PhysicsWorld2D {
+
+            gravity: Vec2 {x: 0.0, y: -9.81},
+            velocity_iterations: 8,
+            allow_sleeping: true,
+
+            _bodies: Vec::new (),
+            _free_slots: Vec::new (),
+            _touching: BTreeMap::new (),
+            _impulses: BTreeMap::new (),
+            _events: Vec::new (),
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for PhysicsWorld2D {
+
+    fn default () -> PhysicsWorld2D {
+        PhysicsWorld2D::new ()
+    }
+}
+
+/*===============================================================================================*/
+/*------PHYSICS WORLD 2D PRIVATE METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PhysicsWorld2D {
+
+    // Finds the touching pairs, and the contacts of the pairs that need solving.
+    fn _find_contacts (&self) -> (BTreeMap<(BodyHandle, BodyHandle), bool>, Vec<(usize, usize, Manifold2D)>) {
+
+        let boxes: Vec<(usize, Aabb2D)> = self._bodies.iter ()
+                                                      .enumerate ()
+                                                      .filter_map (|(index, slot)| slot.as_ref ().map (|body| (index, body.get_aabb ())))
+                                                      .collect ();
+
+        let mut touching = BTreeMap::new ();
+        let mut manifolds = Vec::new ();
+
+        for (index_a, index_b) in SweepAndPrune2D::find_pairs (&boxes) {
+
+            let body_a = self._bodies[index_a].as_ref ().unwrap ();
+            let body_b = self._bodies[index_b].as_ref ().unwrap ();
+            let pair = (BodyHandle::new (index_a), BodyHandle::new (index_b));
+            let is_trigger = body_a.is_trigger || body_b.is_trigger;
+
+            if !body_a.filter.can_collide (&body_b.filter) {
+                continue;
+            }
+
+            // Neither body has moved, so they are still touching if they were before
+            if !PhysicsWorld2D::_is_moving (body_a) && !PhysicsWorld2D::_is_moving (body_b) {
+
+                if let Some (&was_trigger) = self._touching.get (&pair) {
+                    touching.insert (pair, was_trigger);
+                }
+
+                continue;
+            }
+
+            // Only dynamic bodies are pushed apart
+            if !is_trigger &&
+               body_a.get_body_type () != BodyType::Dynamic &&
+               body_b.get_body_type () != BodyType::Dynamic {
+
+                continue;
+            }
+
+            if let Some (manifold) = Collision2D::collide (body_a.get_shape (), &body_a.get_transform (),
+                                                           body_b.get_shape (), &body_b.get_transform ()) {
+
+                touching.insert (pair, is_trigger);
+
+                if !is_trigger {
+                    manifolds.push ((index_a, index_b, manifold));
+                }
+            }
+        }
+
+        (touching, manifolds)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Pushes the touching bodies apart with sequential impulses.
+    fn _solve_contacts (&mut self, manifolds: &[(usize, usize, Manifold2D)], delta_time: f32) {
+
+        let mut velocities = vec! [(Vec2::new (), 0.0); self._bodies.len ()];
+        let mut masses = vec! [(0.0, 0.0); self._bodies.len ()];
+
+        for (index, slot) in self._bodies.iter ().enumerate () {
+
+            if let Some (ref body) = *slot {
+
+                velocities[index] = (body.linear_velocity, body.angular_velocity);
+                masses[index] = (body.get_inverse_mass (), body.get_inverse_inertia ());
+            }
+        }
+
+        let impulses = &self._impulses;
+        let mut constraints: Vec<ContactConstraint> = manifolds.iter ().map (|&(index_a, index_b, ref manifold)| {
+
+            let cached = impulses.get (&(index_a, index_b));
+            let body_a = self._bodies[index_a].as_ref ().unwrap ();
+            let body_b = self._bodies[index_b].as_ref ().unwrap ();
+
+            let (inverse_mass_a, inverse_inertia_a) = masses[index_a];
+            let (inverse_mass_b, inverse_inertia_b) = masses[index_b];
+
+            let normal = manifold.normal;
+            let tangent = Vec2 {x: normal.y, y: -normal.x};
+            let restitution = Util::max (body_a.restitution, body_b.restitution);
+
+            let points = manifold.contacts.iter ().map (|contact| {
+
+                let offset_a = contact.position - body_a.position;
+                let offset_b = contact.position - body_b.position;
+
+                let get_effective_mass = |direction: &Vec2| {
+
+                    let cross_a = Vec2::cross (&offset_a, direction);
+                    let cross_b = Vec2::cross (&offset_b, direction);
+                    let k = inverse_mass_a + inverse_mass_b +
+                            inverse_inertia_a * cross_a * cross_a +
+                            inverse_inertia_b * cross_b * cross_b;
+
+                    if k > 0.0 {1.0 / k} else {0.0}
+                };
+
+                // Remove part of the overlap, or bounce if the bodies are closing quickly
+                let closing_speed = Vec2::dot (&(body_b.get_velocity_at_point (&contact.position) -
+                                                 body_a.get_velocity_at_point (&contact.position)), &normal);
+
+                let mut bias = BAUMGARTE / delta_time * Util::max (contact.penetration - LINEAR_SLOP, 0.0);
+
+                if closing_speed < -RESTITUTION_THRESHOLD {
+                    bias = Util::max (bias, -restitution * closing_speed);
+                }
+
+                // Start from the impulses of the same contact last step
+                let (normal_impulse, tangent_impulse) = cached.and_then (|cached| {
+
+                    cached.iter ()
+                          .find (|&&(position, _, _)| Vec2::distance (&position, &contact.position) < CONTACT_MATCH_DISTANCE)
+                          .map (|&(_, normal_impulse, tangent_impulse)| (normal_impulse, tangent_impulse))
+
+                }).unwrap_or ((0.0, 0.0));
+
+                ConstraintPoint {
+
+                    offset_a: offset_a,
+                    offset_b: offset_b,
+                    normal_mass: get_effective_mass (&normal),
+                    tangent_mass: get_effective_mass (&tangent),
+                    bias: bias,
+                    position: contact.position,
+                    normal_impulse: normal_impulse,
+                    tangent_impulse: tangent_impulse,
+                }
+
+            }).collect ();
+
+            ContactConstraint {
+
+                body_a: index_a,
+                body_b: index_b,
+                normal: normal,
+                friction: (body_a.friction * body_b.friction).sqrt (),
+                points: points,
+            }
+
+        }).collect ();
+
+        for constraint in constraints.iter () {
+
+            let tangent = Vec2 {x: constraint.normal.y, y: -constraint.normal.x};
+
+            for point in constraint.points.iter () {
+
+                PhysicsWorld2D::_apply_impulse (&mut velocities, &masses, constraint.body_a, constraint.body_b, point,
+                                                &(constraint.normal * point.normal_impulse + tangent * point.tangent_impulse));
+            }
+        }
+
+        for _ in 0..self.velocity_iterations {
+
+            for constraint in constraints.iter_mut () {
+                PhysicsWorld2D::_solve_constraint (constraint, &mut velocities, &masses);
+            }
+        }
+
+        self._impulses = constraints.iter ().map (|constraint| {
+
+            ((constraint.body_a, constraint.body_b),
+             constraint.points.iter ().map (|point| (point.position, point.normal_impulse, point.tangent_impulse)).collect ())
+
+        }).collect ();
+
+        for (index, slot) in self._bodies.iter_mut ().enumerate () {
+
+            if let Some (ref mut body) = *slot {
+
+                if body.get_body_type () == BodyType::Dynamic {
+
+                    body.linear_velocity = velocities[index].0;
+                    body.angular_velocity = velocities[index].1;
+                }
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Puts groups of touching bodies to sleep once they have all been resting long enough.
+    fn _update_sleeping (&mut self, manifolds: &[(usize, usize, Manifold2D)], delta_time: f32) {
+
+        // Join the touching dynamic bodies into islands
+        let mut parents: Vec<usize> = (0..self._bodies.len ()).collect ();
+
+        for &(index_a, index_b, _) in manifolds.iter () {
+
+            let is_dynamic = |index: usize| {
+                self._bodies[index].as_ref ().map_or (false, |body| body.get_body_type () == BodyType::Dynamic)
+            };
+
+            if is_dynamic (index_a) && is_dynamic (index_b) {
+
+                let root_a = PhysicsWorld2D::_find_root (&mut parents, index_a);
+                let root_b = PhysicsWorld2D::_find_root (&mut parents, index_b);
+
+                parents[root_a] = root_b;
+            }
+        }
+
+        // An island sleeps once its most recently moving body has rested long enough
+        let mut island_times = vec! [f32::MAX; self._bodies.len ()];
+
+        for (index, slot) in self._bodies.iter_mut ().enumerate () {
+
+            if let Some (ref mut body) = *slot {
+
+                if body.get_body_type () == BodyType::Dynamic && !body.is_sleeping () {
+
+                    let root = PhysicsWorld2D::_find_root (&mut parents, index);
+                    let time = body.update_sleep_time (delta_time, LINEAR_SLEEP_TOLERANCE, ANGULAR_SLEEP_TOLERANCE);
+
+                    island_times[root] = Util::min (island_times[root], time);
+                }
+            }
+        }
+
+        for (index, slot) in self._bodies.iter_mut ().enumerate () {
+
+            if let Some (ref mut body) = *slot {
+
+                if body.get_body_type () == BodyType::Dynamic && !body.is_sleeping () &&
+                   island_times[PhysicsWorld2D::_find_root (&mut parents, index)] >= TIME_TO_SLEEP {
+
+                    body.sleep ();
+                }
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reports the pairs that started or stopped touching.
+    fn _update_events (&mut self, touching: BTreeMap<(BodyHandle, BodyHandle), bool>) {
+
+        for (pair, &is_trigger) in self._touching.iter () {
+
+            if !touching.contains_key (pair) {
+
+                self._events.push (CollisionEvent {event_type: CollisionEventType::Ended,
+                                                   body_a: pair.0,
+                                                   body_b: pair.1,
+                                                   is_trigger: is_trigger});
+            }
+        }
+
+        for (pair, &is_trigger) in touching.iter () {
+
+            if !self._touching.contains_key (pair) {
+
+                self._events.push (CollisionEvent {event_type: CollisionEventType::Began,
+                                                   body_a: pair.0,
+                                                   body_b: pair.1,
+                                                   is_trigger: is_trigger});
+            }
+        }
+
+        self._touching = touching;
+    }
+
+/*===============================================================================================*/
+/*------PHYSICS WORLD 2D PRIVATE STATIC METHODS--------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Returns the root of a body's island, flattening the path to it.
+    fn _find_root (parents: &mut Vec<usize>, index: usize) -> usize {
+
+        let mut root = index;
+
+        while parents[root] != root {
+            root = parents[root];
+        }
+
+        let mut current = index;
+
+        while parents[current] != root {
+
+            let next = parents[current];
+            parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns true if the body can move this step.
+    fn _is_moving (body: &RigidBody2D) -> bool {
+
+        match body.get_body_type () {
+
+            BodyType::Static => false,
+            BodyType::Kinematic => body.linear_velocity != Vec2::new () || body.angular_velocity != 0.0,
+            BodyType::Dynamic => !body.is_sleeping ()
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Runs a single solver pass over a contact.
+    fn _solve_constraint (constraint: &mut ContactConstraint, velocities: &mut [(Vec2, f32)], masses: &[(f32, f32)]) {
+
+        let index_a = constraint.body_a;
+        let index_b = constraint.body_b;
+        let normal = constraint.normal;
+        let tangent = Vec2 {x: normal.y, y: -normal.x};
+
+        for point in constraint.points.iter_mut () {
+
+            // Friction is limited by the normal impulse
+            let relative = PhysicsWorld2D::_get_relative_velocity (velocities, index_a, index_b, point);
+            let max_friction = constraint.friction * point.normal_impulse;
+            let impulse = Util::clamp (point.tangent_impulse - Vec2::dot (&relative, &tangent) * point.tangent_mass,
+                                       -max_friction,
+                                       max_friction);
+
+            let lambda = impulse - point.tangent_impulse;
+            point.tangent_impulse = impulse;
+
+            PhysicsWorld2D::_apply_impulse (velocities, masses, index_a, index_b, point, &(tangent * lambda));
+
+            // The total normal impulse can only push the bodies apart
+            let relative = PhysicsWorld2D::_get_relative_velocity (velocities, index_a, index_b, point);
+            let impulse = Util::max (point.normal_impulse + (point.bias - Vec2::dot (&relative, &normal)) * point.normal_mass, 0.0);
+
+            let lambda = impulse - point.normal_impulse;
+            point.normal_impulse = impulse;
+
+            PhysicsWorld2D::_apply_impulse (velocities, masses, index_a, index_b, point, &(normal * lambda));
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the velocity of the second body relative to the first at a contact point.
+    fn _get_relative_velocity (velocities: &[(Vec2, f32)], index_a: usize, index_b: usize, point: &ConstraintPoint) -> Vec2 {
+
+        let (linear_a, angular_a) = velocities[index_a];
+        let (linear_b, angular_b) = velocities[index_b];
+
+        linear_b + Vec2 {x: -angular_b * point.offset_b.y, y: angular_b * point.offset_b.x} -
+        linear_a - Vec2 {x: -angular_a * point.offset_a.y, y: angular_a * point.offset_a.x}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Applies an impulse to the second body at a contact point, and the opposite to the first.
+    fn _apply_impulse (velocities: &mut [(Vec2, f32)],
+                       masses: &[(f32, f32)],
+                       index_a: usize,
+                       index_b: usize,
+                       point: &ConstraintPoint,
+                       impulse: &Vec2) {
+
+        velocities[index_a].0 -= *impulse * masses[index_a].0;
+        velocities[index_a].1 -= Vec2::cross (&point.offset_a, impulse) * masses[index_a].1;
+        velocities[index_b].0 += *impulse * masses[index_b].0;
+        velocities[index_b].1 += Vec2::cross (&point.offset_b, impulse) * masses[index_b].1;
+    }
+}
+
+/*===============================================================================================*/
+/*------CONTACT CONSTRAINT STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The solver state of a pair of touching bodies.
+struct ContactConstraint {
+
+    body_a: usize,
+    body_b: usize,
+    normal: Vec2,
+    friction: f32,
+    points: Vec<ConstraintPoint>,
+}
+
+/*===============================================================================================*/
+/*------CONSTRAINT POINT STRUCT------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The solver state of a single contact point.
+struct ConstraintPoint {
+
+    offset_a: Vec2,
+    offset_b: Vec2,
+    position: Vec2,
+    normal_mass: f32,
+    tangent_mass: f32,
+    bias: f32,
+    normal_impulse: f32,
+    tangent_impulse: f32,
+}
@@ -0,0 +1,418 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::{BodyType, CollisionFilter};
+use ::physics::physics_2d::{Aabb2D, Shape2D, Transform2D};
+use ::util::math::Vec2;
+
+/*===============================================================================================*/
+/*------RIGID BODY 2D STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A 2D rigid body.
+///
+/// The mass and inertia are worked out from the shape and density.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::BodyType;
+/// # use ion_core::physics::physics_2d::{RigidBody2D, Shape2D};
+/// # use ion_core::util::math::Vec2;
+/// #
+/// let mut body = RigidBody2D::new (BodyType::Dynamic, Shape2D::Box {half_extents: Vec2 {x: 1.0, y: 1.0}});
+/// assert_eq! (body.get_mass (), 4.0);
+///
+/// body.set_density (0.5);
+/// assert_eq! (body.get_mass (), 2.0);
+///
+/// body.apply_impulse (&Vec2 {x: 4.0, y: 0.0});
+/// assert! (body.linear_velocity == Vec2 {x: 2.0, y: 0.0});
+///
+/// // Static bodies have infinite mass
+/// body.set_body_type (BodyType::Static);
+/// assert_eq! (body.get_inverse_mass (), 0.0);
+/// ```
+#[derive (Clone)]
+pub struct RigidBody2D {
+
+    // Public
+    /// The position of the centre of mass.
+    pub position: Vec2,
+    /// The counter-clockwise rotation in radians.
+    pub rotation: f32,
+    /// The linear velocity.
+    pub linear_velocity: Vec2,
+    /// The counter-clockwise angular velocity in radians per second.
+    pub angular_velocity: f32,
+    /// The friction coefficient, usually from 0 to 1.
+    pub friction: f32,
+    /// The restitution (bounciness), from 0 to 1.
+    pub restitution: f32,
+    /// How quickly the linear velocity slows down.
+    pub linear_damping: f32,
+    /// How quickly the angular velocity slows down.
+    pub angular_damping: f32,
+    /// The amount of world gravity applied to the body.
+    pub gravity_scale: f32,
+    /// Is the body a trigger? Triggers report collision events, but are not pushed apart.
+    pub is_trigger: bool,
+    /// Which bodies the body collides with.
+    pub filter: CollisionFilter,
+    /// Can the body fall asleep when it stops moving?
+    pub can_sleep: bool,
+
+    // Private
+    _body_type: BodyType,
+    _shape: Shape2D,
+    _density: f32,
+    _mass: f32,
+    _inverse_mass: f32,
+    _inertia: f32,
+    _inverse_inertia: f32,
+    _force: Vec2,
+    _torque: f32,
+    _is_sleeping: bool,
+    _sleep_time: f32,
+}
+
+/*===============================================================================================*/
+/*------RIGID BODY 2D PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RigidBody2D {
+
+    /// Applies a force at the centre of mass until the next step.
+    pub fn apply_force (&mut self, force: &Vec2) {
+
+        if self._body_type == BodyType::Dynamic {
+
+            self._force += *force;
+            self.wake ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Applies a force at a world space point until the next step.
+    pub fn apply_force_at_point (&mut self, force: &Vec2, point: &Vec2) {
+
+        if self._body_type == BodyType::Dynamic {
+
+            self._force += *force;
+            self._torque += Vec2::cross (&(*point - self.position), force);
+            self.wake ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Applies a torque until the next step.
+    pub fn apply_torque (&mut self, torque: f32) {
+
+        if self._body_type == BodyType::Dynamic {
+
+            self._torque += torque;
+            self.wake ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Applies an impulse at the centre of mass, changing the velocity immediately.
+    pub fn apply_impulse (&mut self, impulse: &Vec2) {
+
+        if self._body_type == BodyType::Dynamic {
+
+            self.linear_velocity += *impulse * self._inverse_mass;
+            self.wake ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Applies an impulse at a world space point, changing the velocity immediately.
+    pub fn apply_impulse_at_point (&mut self, impulse: &Vec2, point: &Vec2) {
+
+        if self._body_type == BodyType::Dynamic {
+
+            self.linear_velocity += *impulse * self._inverse_mass;
+            self.angular_velocity += Vec2::cross (&(*point - self.position), impulse) * self._inverse_inertia;
+            self.wake ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the world space bounds of the body.
+    pub fn get_aabb (&self) -> Aabb2D {
+        self._shape.get_aabb (&self.get_transform ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the body type.
+    pub fn get_body_type (&self) -> BodyType {
+        self._body_type
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the density.
+    pub fn get_density (&self) -> f32 {
+        self._density
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the rotational inertia.
+    pub fn get_inertia (&self) -> f32 {
+        self._inertia
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the inverse of the rotational inertia, which is zero for bodies that do not rotate.
+    pub fn get_inverse_inertia (&self) -> f32 {
+        self._inverse_inertia
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the inverse of the mass, which is zero for static and kinematic bodies.
+    pub fn get_inverse_mass (&self) -> f32 {
+        self._inverse_mass
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the mass.
+    pub fn get_mass (&self) -> f32 {
+        self._mass
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the collision shape.
+    pub fn get_shape (&self) -> &Shape2D {
+        &self._shape
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the position and rotation of the body.
+    pub fn get_transform (&self) -> Transform2D {
+        Transform2D::new (self.position, self.rotation)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the velocity of a world space point on the body.
+    pub fn get_velocity_at_point (&self, point: &Vec2) -> Vec2 {
+
+        let offset = *point - self.position;
+
+        self.linear_velocity + Vec2 {x: -self.angular_velocity * offset.y,
+                                     y:  self.angular_velocity * offset.x}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves the body by its velocity.
+    ///
+    /// It is called by the world each step, and does nothing to static or sleeping bodies.
+    pub fn integrate_position (&mut self, delta_time: f32) {
+
+        if self._body_type != BodyType::Static && !self._is_sleeping {
+
+            self.position += self.linear_velocity * delta_time;
+            self.rotation += self.angular_velocity * delta_time;
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Changes the velocity by gravity, the applied forces and damping, then clears the forces.
+    ///
+    /// It is called by the world each step, and does nothing to sleeping or non-dynamic bodies.
+    pub fn integrate_velocity (&mut self, gravity: &Vec2, delta_time: f32) {
+
+        if self._body_type == BodyType::Dynamic && !self._is_sleeping {
+
+            self.linear_velocity += (*gravity * self.gravity_scale + self._force * self._inverse_mass) * delta_time;
+            self.angular_velocity += self._torque * self._inverse_inertia * delta_time;
+
+            self.linear_velocity *= 1.0 / (1.0 + delta_time * self.linear_damping);
+            self.angular_velocity *= 1.0 / (1.0 + delta_time * self.angular_damping);
+        }
+
+        self._force = Vec2::new ();
+        self._torque = 0.0;
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Is the body asleep? Sleeping bodies are not simulated until something wakes them.
+    pub fn is_sleeping (&self) -> bool {
+        self._is_sleeping
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the body type, and updates the mass.
+    pub fn set_body_type (&mut self, body_type: BodyType) {
+
+        self._body_type = body_type;
+        self._update_mass ();
+        self.wake ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the density, and updates the mass.
+    pub fn set_density (&mut self, density: f32) {
+
+        self._density = density;
+        self._update_mass ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the collision shape, and updates the mass.
+    pub fn set_shape (&mut self, shape: Shape2D) {
+
+        self._shape = shape;
+        self._update_mass ();
+        self.wake ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Puts the body to sleep, and stops it.
+    pub fn sleep (&mut self) {
+
+        self._is_sleeping = true;
+        self._sleep_time = 0.0;
+        self._force = Vec2::new ();
+        self._torque = 0.0;
+        self.linear_velocity = Vec2::new ();
+        self.angular_velocity = 0.0;
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Updates how long the body has been resting, and returns it.
+    ///
+    /// The time is reset when the body moves faster than the tolerances, or cannot sleep.
+    ///
+    /// # Arguments
+    /// * `delta_time` - The length of the step.
+    /// * `linear_tolerance` - The speed below which the body is resting.
+    /// * `angular_tolerance` - The angular speed in radians per second below which the body is resting.
+    pub fn update_sleep_time (&mut self, delta_time: f32, linear_tolerance: f32, angular_tolerance: f32) -> f32 {
+
+        let speed_squared = Vec2::dot (&self.linear_velocity, &self.linear_velocity);
+
+        if !self.can_sleep ||
+           speed_squared > linear_tolerance * linear_tolerance ||
+           self.angular_velocity.abs () > angular_tolerance {
+
+            self._sleep_time = 0.0;
+        }
+
+        else {
+            self._sleep_time += delta_time;
+        }
+
+        self._sleep_time
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Wakes the body up.
+    pub fn wake (&mut self) {
+
+        self._is_sleeping = false;
+        self._sleep_time = 0.0;
+    }
+
+/*===============================================================================================*/
+/*------RIGID BODY 2D PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new rigid body at the origin, with a density of one.
+    pub fn new (body_type: BodyType, shape: Shape2D) -> RigidBody2D {
+
+        let mut body = RigidBody2D {
+
+            position: Vec2::new (),
+            rotation: 0.0,
+            linear_velocity: Vec2::new (),
+            angular_velocity: 0.0,
+            friction: 0.5,
+            restitution: 0.0,
+            linear_damping: 0.0,
+            angular_damping: 0.0,
+            gravity_scale: 1.0,
+            is_trigger: false,
+            filter: CollisionFilter::default (),
+            can_sleep: true,
+
+            _body_type: body_type,
+            _shape: shape,
+            _density: 1.0,
+            _mass: 0.0,
+            _inverse_mass: 0.0,
+            _inertia: 0.0,
+            _inverse_inertia: 0.0,
+            _force: Vec2::new (),
+            _torque: 0.0,
+            _is_sleeping: false,
+            _sleep_time: 0.0,
+        };
+
+        body._update_mass ();
+        body
+    }
+}
+
+/*===============================================================================================*/
+/*------RIGID BODY 2D PRIVATE METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RigidBody2D {
+
+    // Works out the mass and inertia from the shape and density.
+    fn _update_mass (&mut self) {
+
+        let (mass, inertia) = self._shape.get_mass_data (self._density);
+
+        self._mass = mass;
+        self._inertia = inertia;
+
+        if self._body_type == BodyType::Dynamic && mass > 0.0 {
+
+            self._inverse_mass = 1.0 / mass;
+            self._inverse_inertia = if inertia > 0.0 {1.0 / inertia} else {0.0};
+        }
+
+        else {
+
+            self._inverse_mass = 0.0;
+            self._inverse_inertia = 0.0;
+        }
+    }
+}
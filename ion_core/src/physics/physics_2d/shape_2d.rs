@@ -0,0 +1,278 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::physics_2d::{Aabb2D, Transform2D};
+use ::util::math::{Vec2, EPSILON};
+
+use std::cmp::Ordering;
+use std::f32::consts;
+
+/*===============================================================================================*/
+/*------SHAPE 2D ENUM----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The collision shape of a 2D rigid body.
+///
+/// Shapes are defined in the local space of the body, with the centre of mass at the origin.
+#[derive (Clone, Serialize, Deserialize)]
+pub enum Shape2D {
+
+    /// A circle.
+    Circle {
+        /// The radius of the circle.
+        radius: f32
+    },
+
+    /// A box.
+    Box {
+        /// Half of the width and height of the box.
+        half_extents: Vec2
+    },
+
+    /// A convex polygon, with its vertices in counter-clockwise order.
+    ///
+    /// Use `Shape2D::new_polygon` to build a valid polygon from a set of points.
+    Polygon {
+        /// The vertices of the polygon.
+        vertices: Vec<Vec2>
+    },
+}
+
+/*===============================================================================================*/
+/*------SHAPE 2D PUBLIC METHODS------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Shape2D {
+
+    /// Returns the vertices of the shape in counter-clockwise order.
+    ///
+    /// Circles have no vertices, and return an empty vector.
+    pub fn get_vertices (&self) -> Vec<Vec2> {
+
+        match *self {
+
+            Shape2D::Circle {..} => Vec::new (),
+
+            Shape2D::Box {half_extents} => {
+
+                vec! [Vec2 {x: -half_extents.x, y: -half_extents.y},
+                      Vec2 {x:  half_extents.x, y: -half_extents.y},
+                      Vec2 {x:  half_extents.x, y:  half_extents.y},
+                      Vec2 {x: -half_extents.x, y:  half_extents.y}]
+            },
+
+            Shape2D::Polygon {ref vertices} => vertices.clone ()
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the mass and the rotational inertia of the shape about its origin.
+    ///
+    /// # Arguments
+    /// * `density` - The mass per unit area.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::physics_2d::Shape2D;
+    /// # use ion_core::util::math::Vec2;
+    /// #
+    /// let shape = Shape2D::Box {half_extents: Vec2 {x: 1.0, y: 0.5}};
+    /// let (mass, inertia) = shape.get_mass_data (2.0);
+    ///
+    /// assert_eq! (mass, 4.0);
+    /// assert! ((inertia - 5.0 / 3.0).abs () < 0.0001);
+    /// ```
+    pub fn get_mass_data (&self, density: f32) -> (f32, f32) {
+
+        match *self {
+
+            Shape2D::Circle {radius} => {
+
+                let mass = density * consts::PI * radius * radius;
+                (mass, mass * radius * radius * 0.5)
+            },
+
+            Shape2D::Box {half_extents} => {
+
+                let mass = density * 4.0 * half_extents.x * half_extents.y;
+                (mass, mass * (half_extents.x * half_extents.x + half_extents.y * half_extents.y) / 3.0)
+            },
+
+            Shape2D::Polygon {ref vertices} => {
+
+                let mut area = 0.0;
+                let mut inertia = 0.0;
+
+                // Sum the triangles fanned out from the origin
+                for i in 0..vertices.len () {
+
+                    let p1 = vertices[i];
+                    let p2 = vertices[(i + 1) % vertices.len ()];
+                    let cross = Vec2::cross (&p1, &p2);
+
+                    area += cross * 0.5;
+                    inertia += cross * (Vec2::dot (&p1, &p1) + Vec2::dot (&p1, &p2) + Vec2::dot (&p2, &p2)) / 12.0;
+                }
+
+                (density * area, density * inertia)
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the world space bounds of the shape.
+    pub fn get_aabb (&self, transform: &Transform2D) -> Aabb2D {
+
+        match *self {
+
+            Shape2D::Circle {radius} => {
+
+                Aabb2D::new (transform.position - radius,
+                             transform.position + radius)
+            },
+
+            _ => {
+
+                let vertices: Vec<Vec2> = self.get_vertices ().iter ().map (|v| transform.apply (v)).collect ();
+                Aabb2D::from_points (&vertices)
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------SHAPE 2D PUBLIC STATIC METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a convex polygon wrapping a set of points.
+    ///
+    /// The polygon is the convex hull of the points, moved so its centroid is at the origin.
+    /// It fails if the points do not enclose an area.
+    ///
+    /// # Arguments
+    /// * `points` - The points to wrap. Interior points are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::physics_2d::Shape2D;
+    /// # use ion_core::util::math::Vec2;
+    /// #
+    /// let points = [Vec2 {x: 0.0, y: 0.0}, Vec2 {x: 4.0, y: 0.0}, Vec2 {x: 2.0, y: 1.0},
+    ///               Vec2 {x: 4.0, y: 2.0}, Vec2 {x: 0.0, y: 2.0}];
+    ///
+    /// let shape = Shape2D::new_polygon (&points).unwrap ();
+    /// let vertices = shape.get_vertices ();
+    ///
+    /// assert_eq! (vertices.len (), 4);
+    /// assert! (vertices[0] == Vec2 {x: -2.0, y: -1.0});
+    /// assert! (vertices[2] == Vec2 {x: 2.0, y: 1.0});
+    ///
+    /// assert! (Shape2D::new_polygon (&[Vec2::new (), Vec2::right (), Vec2::right () * 2.0]).is_err ());
+    /// ```
+    pub fn new_polygon (points: &[Vec2]) -> Result<Shape2D, ()> {
+
+        let hull = Shape2D::_get_convex_hull (points);
+
+        if hull.len () < 3 {
+            return Err (());
+        }
+
+        // Find the centroid
+        let mut area = 0.0;
+        let mut centroid = Vec2::new ();
+
+        for i in 0..hull.len () {
+
+            let p1 = hull[i];
+            let p2 = hull[(i + 1) % hull.len ()];
+            let cross = Vec2::cross (&p1, &p2);
+
+            area += cross * 0.5;
+            centroid += (p1 + p2) * (cross / 6.0);
+        }
+
+        if area < EPSILON {
+            return Err (());
+        }
+
+        centroid /= area;
+        Ok (Shape2D::Polygon {vertices: hull.iter ().map (|v| *v - centroid).collect ()})
+    }
+}
+
+/*===============================================================================================*/
+/*------SHAPE 2D PRIVATE STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Shape2D {
+
+    // Returns the convex hull of a set of points in counter-clockwise order.
+    // It uses the monotone chain algorithm, and drops collinear points.
+    fn _get_convex_hull (points: &[Vec2]) -> Vec<Vec2> {
+
+        let mut sorted = points.to_vec ();
+
+        sorted.sort_by (|a, b| {
+
+            match a.x.partial_cmp (&b.x).unwrap_or (Ordering::Equal) {
+
+                Ordering::Equal => a.y.partial_cmp (&b.y).unwrap_or (Ordering::Equal),
+                ordering => ordering
+            }
+        });
+
+        if sorted.len () < 3 {
+            return sorted;
+        }
+
+        let mut hull: Vec<Vec2> = Vec::with_capacity (sorted.len () + 1);
+
+        // Lower hull
+        for point in sorted.iter () {
+
+            while hull.len () >= 2 && !Shape2D::_is_left_turn (&hull[hull.len () - 2], &hull[hull.len () - 1], point) {
+                hull.pop ();
+            }
+
+            hull.push (*point);
+        }
+
+        // Upper hull, starting from the last point of the lower hull
+        let lower_len = hull.len () + 1;
+
+        for point in sorted.iter ().rev ().skip (1) {
+
+            while hull.len () >= lower_len && !Shape2D::_is_left_turn (&hull[hull.len () - 2], &hull[hull.len () - 1], point) {
+                hull.pop ();
+            }
+
+            hull.push (*point);
+        }
+
+        // The last point is the first point
+        hull.pop ();
+        hull
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns true if the path a, b, c turns counter-clockwise at b.
+    fn _is_left_turn (a: &Vec2, b: &Vec2, c: &Vec2) -> bool {
+        Vec2::cross (&(*b - *a), &(*c - *a)) > 0.0
+    }
+}
@@ -0,0 +1,94 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::physics_2d::Aabb2D;
+
+use std::cmp::Ordering;
+
+/*===============================================================================================*/
+/*------SWEEP AND PRUNE 2D STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Finds the pairs of overlapping boxes.
+///
+/// The boxes are sorted along the x axis, and each box is only tested against the boxes
+/// that start before it ends.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::physics_2d::{Aabb2D, SweepAndPrune2D};
+/// # use ion_core::util::math::Vec2;
+/// #
+/// let boxes = [(0, Aabb2D::new (Vec2 {x: 0.0, y: 0.0}, Vec2 {x: 2.0, y: 2.0})),
+///              (1, Aabb2D::new (Vec2 {x: 5.0, y: 0.0}, Vec2 {x: 6.0, y: 1.0})),
+///              (2, Aabb2D::new (Vec2 {x: 1.0, y: 1.0}, Vec2 {x: 5.5, y: 1.5})),
+///              (3, Aabb2D::new (Vec2 {x: 1.0, y: 3.0}, Vec2 {x: 2.0, y: 4.0}))];
+///
+/// assert_eq! (SweepAndPrune2D::find_pairs (&boxes), vec! [(0, 2), (1, 2)]);
+/// ```
+#[derive (Copy, Clone)]
+pub struct SweepAndPrune2D;
+
+/*===============================================================================================*/
+/*------SWEEP AND PRUNE 2D PUBLIC STATIC METHODS-------------------------------------------------*/
+/*===============================================================================================*/
+
+impl SweepAndPrune2D {
+
+    /// Returns the ids of the overlapping boxes.
+    ///
+    /// Each pair has the lower id first, and the pairs are sorted, so the result does not
+    /// depend on the order of the boxes.
+    ///
+    /// # Arguments
+    /// * `boxes` - The boxes, each with a unique id.
+    pub fn find_pairs (boxes: &[(usize, Aabb2D)]) -> Vec<(usize, usize)> {
+
+        let mut sorted: Vec<&(usize, Aabb2D)> = boxes.iter ().collect ();
+
+        sorted.sort_by (|a, b| {
+
+            match a.1.min.x.partial_cmp (&b.1.min.x).unwrap_or (Ordering::Equal) {
+
+                Ordering::Equal => a.0.cmp (&b.0),
+                ordering => ordering
+            }
+        });
+
+        let mut pairs = Vec::new ();
+
+        for i in 0..sorted.len () {
+
+            let &(id_a, ref aabb_a) = sorted[i];
+
+            for &&(id_b, ref aabb_b) in sorted[i + 1..].iter () {
+
+                // The rest of the boxes start after this one ends
+                if aabb_b.min.x > aabb_a.max.x {
+                    break;
+                }
+
+                if aabb_a.min.y <= aabb_b.max.y && aabb_a.max.y >= aabb_b.min.y {
+                    pairs.push (if id_a < id_b {(id_a, id_b)} else {(id_b, id_a)});
+                }
+            }
+        }
+
+        pairs.sort ();
+        pairs
+    }
+}
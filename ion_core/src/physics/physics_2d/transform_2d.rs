@@ -0,0 +1,108 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Mat3, Util, Vec2};
+
+/*===============================================================================================*/
+/*------TRANSFORM 2D STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The position and rotation of a 2D rigid body.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::physics_2d::Transform2D;
+/// # use ion_core::util::math::{Vec2, HALF_PI};
+/// #
+/// let transform = Transform2D::new (Vec2 {x: 2.0, y: 0.0}, HALF_PI);
+/// let point = transform.apply (&Vec2 {x: 1.0, y: 0.0});
+///
+/// assert! ((point.x - 2.0).abs () < 0.0001 && (point.y - 1.0).abs () < 0.0001);
+///
+/// let local = transform.inverse_apply (&point);
+/// assert! ((local.x - 1.0).abs () < 0.0001 && local.y.abs () < 0.0001);
+/// ```
+#[derive (Copy, Clone, Default)]
+pub struct Transform2D {
+
+    // Public
+    /// The position.
+    pub position: Vec2,
+    /// The counter-clockwise rotation in radians.
+    pub rotation: f32,
+}
+
+/*===============================================================================================*/
+/*------TRANSFORM 2D PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Transform2D {
+
+    /// Transforms a point from local to world space.
+    pub fn apply (&self, point: &Vec2) -> Vec2 {
+        self.rotate (point) + self.position
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Transforms a point from world to local space.
+    pub fn inverse_apply (&self, point: &Vec2) -> Vec2 {
+        self.inverse_rotate (&(*point - self.position))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Rotates a vector from local to world space.
+    pub fn rotate (&self, vector: &Vec2) -> Vec2 {
+
+        let (sin, cos) = self.rotation.sin_cos ();
+
+        Vec2 {x: cos * vector.x - sin * vector.y,
+              y: sin * vector.x + cos * vector.y}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Rotates a vector from world to local space.
+    pub fn inverse_rotate (&self, vector: &Vec2) -> Vec2 {
+
+        let (sin, cos) = self.rotation.sin_cos ();
+
+        Vec2 {x:  cos * vector.x + sin * vector.y,
+              y: -sin * vector.x + cos * vector.y}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the transform as a model matrix.
+    pub fn to_matrix (&self) -> Mat3 {
+        Mat3::translate (&self.position) * Mat3::rotate (Util::rad2deg (self.rotation))
+    }
+
+/*===============================================================================================*/
+/*------TRANSFORM 2D PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new transform.
+    ///
+    /// # Arguments
+    /// * `position` - The position.
+    /// * `rotation` - The counter-clockwise rotation in radians.
+    pub fn new (position: Vec2, rotation: f32) -> Transform2D {
+        Transform2D {position: position, rotation: rotation}
+    }
+}
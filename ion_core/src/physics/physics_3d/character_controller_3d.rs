@@ -0,0 +1,491 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::BodyHandle;
+use ::physics::physics_3d::{PhysicsWorld3D, RaycastHit3D, Shape3D, Transform3D};
+use ::util::math::{Quat, Util, Vec3, EPSILON};
+
+// Static variables
+// The most surfaces a single move slides along
+static MAX_SLIDE_ITERATIONS: usize = 4;
+// The most passes made to push the character out of the bodies it overlaps
+static MAX_DEPENETRATION_ITERATIONS: usize = 4;
+
+/*===============================================================================================*/
+/*------CHARACTER CONTROLLER 3D STRUCT-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A kinematic character controller.
+///
+/// The character is an upright capsule, moved by sweeping it through a physics world and
+/// sliding it along whatever it hits. It walks up steps no taller than the step height,
+/// and up slopes no steeper than the max slope. Steeper slopes are treated as walls.
+/// While grounded it is pulled down onto the ground, so it follows slopes and stairs
+/// on the way down rather than flying off them.
+///
+/// The character is not a body in the world, so bodies do not push it, and it does not
+/// push them. Standing on a moving body carries it along.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::BodyType;
+/// # use ion_core::physics::physics_3d::{CharacterController3D, PhysicsWorld3D, RigidBody3D, Shape3D};
+/// # use ion_core::util::math::Vec3;
+/// #
+/// let mut world = PhysicsWorld3D::new ();
+/// world.add_body (RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 10.0, y: 0.5, z: 10.0}}));
+///
+/// // A low step, and a tall wall at the back of it
+/// let mut step = RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 1.5, y: 0.1, z: 2.0}});
+/// step.position = Vec3 {x: 4.0, y: 0.6, z: 0.0};
+///
+/// let mut wall = RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 0.5, y: 2.0, z: 2.0}});
+/// wall.position = Vec3 {x: 6.0, y: 2.5, z: 0.0};
+///
+/// world.add_body (step);
+/// world.add_body (wall);
+/// world.step (1.0 / 60.0);
+///
+/// let mut character = CharacterController3D::new (0.5, 0.4);
+/// character.position = Vec3 {x: 0.0, y: 3.0, z: 0.0};
+///
+/// // Fall onto the ground
+/// let mut fall_speed = 0.0;
+///
+/// for _ in 0..60 {
+///
+///     fall_speed = if character.is_grounded () {0.0} else {fall_speed + 9.81 / 60.0};
+///     character.move_by (&world, &Vec3 {x: 0.0, y: -fall_speed / 60.0, z: 0.0}, 1.0 / 60.0);
+/// }
+///
+/// assert! (character.is_grounded ());
+/// assert! ((character.get_foot_position ().y - 0.5).abs () < 0.02);
+///
+/// // Walk up onto the step, and into the wall
+/// for _ in 0..120 {
+///     character.move_by (&world, &Vec3 {x: 4.0 / 60.0, y: -0.1, z: 0.0}, 1.0 / 60.0);
+/// }
+///
+/// assert! (character.is_grounded ());
+/// assert! ((character.get_foot_position ().y - 0.7).abs () < 0.02);
+/// assert! ((character.position.x - 5.1).abs () < 0.02);
+/// ```
+#[derive (Clone)]
+pub struct CharacterController3D {
+
+    // Public
+    /// The position of the centre of the capsule.
+    pub position: Vec3,
+    /// The tallest step the character can walk up.
+    pub step_height: f32,
+    /// The steepest slope in degrees the character can walk up.
+    pub max_slope: f32,
+    /// How far the character is pulled down onto the ground while it is grounded.
+    pub snap_distance: f32,
+    /// The gap kept between the character and the surfaces it touches.
+    pub skin_width: f32,
+    /// The layers of the bodies the character collides with.
+    pub mask: u32,
+
+    // Private
+    _shape: Shape3D,
+    _is_grounded: bool,
+    _ground_normal: Vec3,
+    _ground_body: Option<BodyHandle>,
+}
+
+/*===============================================================================================*/
+/*------CHARACTER CONTROLLER 3D PUBLIC METHODS---------------------------------------------------*/
+/*===============================================================================================*/
+
+impl CharacterController3D {
+
+    /// Returns the position of the bottom of the capsule.
+    pub fn get_foot_position (&self) -> Vec3 {
+
+        match self._shape {
+
+            Shape3D::Capsule {half_height, radius} => self.position - Vec3::up () * (half_height + radius),
+            _ => self.position
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the body the character is standing on, or none if it is not grounded.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::BodyType;
+    /// # use ion_core::physics::physics_3d::{CharacterController3D, PhysicsWorld3D, RigidBody3D, Shape3D};
+    /// # use ion_core::util::math::Vec3;
+    /// #
+    /// let mut world = PhysicsWorld3D::new ();
+    ///
+    /// let mut platform = RigidBody3D::new (BodyType::Kinematic, Shape3D::Box {half_extents: Vec3 {x: 2.0, y: 0.25, z: 2.0}});
+    /// platform.linear_velocity = Vec3 {x: 2.0, y: 0.5, z: 0.0};
+    ///
+    /// let platform = world.add_body (platform);
+    /// world.step (1.0 / 60.0);
+    ///
+    /// let mut character = CharacterController3D::new (0.5, 0.4);
+    /// character.position = Vec3 {x: 0.0, y: 1.5, z: 0.0};
+    ///
+    /// // Ride the platform for a second
+    /// for _ in 0..60 {
+    ///
+    ///     world.step (1.0 / 60.0);
+    ///     character.move_by (&world, &Vec3 {x: 0.0, y: -0.1, z: 0.0}, 1.0 / 60.0);
+    /// }
+    ///
+    /// let platform_position = world.get_body (platform).unwrap ().position;
+    ///
+    /// assert! (character.get_ground_body () == Some (platform));
+    /// assert! (character.position.x > 1.5);
+    /// assert! ((character.get_foot_position ().y - (platform_position.y + 0.25)).abs () < 0.02);
+    /// ```
+    pub fn get_ground_body (&self) -> Option<BodyHandle> {
+        self._ground_body
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the normal of the ground, which is up if the character is not grounded.
+    pub fn get_ground_normal (&self) -> Vec3 {
+        self._ground_normal
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the capsule of the character.
+    pub fn get_shape (&self) -> &Shape3D {
+        &self._shape
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Is the character standing on ground it can walk on?
+    pub fn is_grounded (&self) -> bool {
+        self._is_grounded
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves the character through a world, and returns how far it actually moved.
+    ///
+    /// It should be called after the world is stepped, with the same time step, so the
+    /// character is carried by exactly as far as the body it stands on moved. Gravity is
+    /// not applied, so the displacement should include any falling.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::BodyType;
+    /// # use ion_core::physics::physics_3d::{CharacterController3D, PhysicsWorld3D, RigidBody3D, Shape3D};
+    /// # use ion_core::util::math::{Quat, Vec3};
+    /// #
+    /// let mut world = PhysicsWorld3D::new ();
+    /// world.add_body (RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 20.0, y: 0.5, z: 20.0}}));
+    ///
+    /// // A gentle ramp up to the left, and a steep one to the right
+    /// let mut gentle = RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 4.0, y: 0.5, z: 2.0}});
+    /// gentle.position = Vec3 {x: -5.0, y: 0.5, z: 0.0};
+    /// gentle.rotation = Quat::from_axis_angle (&Vec3::back (), 20.0);
+    ///
+    /// let mut steep = RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 4.0, y: 0.5, z: 2.0}});
+    /// steep.position = Vec3 {x: 5.0, y: 0.5, z: 0.0};
+    /// steep.rotation = Quat::from_axis_angle (&Vec3::forward (), 60.0);
+    ///
+    /// world.add_body (gentle);
+    /// world.add_body (steep);
+    /// world.step (1.0 / 60.0);
+    ///
+    /// // Walking left climbs the gentle ramp
+    /// let mut character = CharacterController3D::new (0.5, 0.4);
+    /// character.position = Vec3 {x: -1.0, y: 1.41, z: 0.0};
+    ///
+    /// for _ in 0..120 {
+    ///     character.move_by (&world, &Vec3 {x: -0.05, y: -0.1, z: 0.0}, 1.0 / 60.0);
+    /// }
+    ///
+    /// assert! (character.is_grounded ());
+    /// assert! (character.position.x < -6.0 && character.position.y > 2.0);
+    ///
+    /// // Walking right stops at the foot of the steep ramp
+    /// let mut character = CharacterController3D::new (0.5, 0.4);
+    /// character.position = Vec3 {x: 1.0, y: 1.41, z: 0.0};
+    ///
+    /// for _ in 0..60 {
+    ///     character.move_by (&world, &Vec3 {x: 0.05, y: -0.1, z: 0.0}, 1.0 / 60.0);
+    /// }
+    ///
+    /// assert! (character.position.x < 4.5 && character.position.y < 1.6);
+    /// ```
+    pub fn move_by (&mut self, world: &PhysicsWorld3D, displacement: &Vec3, delta_time: f32) -> Vec3 {
+
+        let start = self.position;
+        let up = Vec3::up ();
+        let was_grounded = self._is_grounded;
+
+        // The body being stood on has already moved, so follow it
+        if let Some (body) = self._ground_body.and_then (|handle| world.get_body (handle)) {
+            self.position += body.get_velocity_at_point (&self.get_foot_position ()) * delta_time;
+        }
+
+        self._depenetrate (world);
+
+        let vertical = Vec3::dot (displacement, &up);
+        let horizontal = *displacement - up * vertical;
+
+        // Move across, stepping up onto anything low enough if the way is blocked
+        if Vec3::length (&horizontal) > EPSILON {
+
+            let before = self.position;
+            let is_blocked = self._slide (world, &horizontal, false);
+
+            if is_blocked && was_grounded && self.step_height > 0.0 {
+
+                let walked = self.position;
+                self.position = before;
+
+                if !self._step_up (world, &horizontal) ||
+                   Vec3::dot (&(self.position - before), &horizontal) <= Vec3::dot (&(walked - before), &horizontal) {
+
+                    self.position = walked;
+                }
+            }
+        }
+
+        // Move up or down, stopping on the ground rather than sliding down it
+        if vertical.abs () > EPSILON {
+            self._slide (world, &(up * vertical), true);
+        }
+
+        // Keep to the ground when walking down slopes and steps
+        if was_grounded && vertical <= 0.0 && self.snap_distance > 0.0 {
+
+            if let Some (hit) = self._sweep (world, &Vec3::down (), self.snap_distance + self.skin_width) {
+
+                if self._is_walkable (&hit.normal) {
+                    self.position += Vec3::down () * Util::max (hit.distance - self.skin_width, 0.0);
+                }
+            }
+        }
+
+        self._update_ground (world);
+        self.position - start
+    }
+
+/*===============================================================================================*/
+/*------CHARACTER CONTROLLER 3D PUBLIC STATIC METHODS--------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new character at the origin, which can walk up slopes of 45 degrees
+    /// and steps of 0.3.
+    ///
+    /// # Arguments
+    /// * `half_height` - Half of the distance between the centres of the ends of the capsule.
+    /// * `radius` - The radius of the capsule.
+    pub fn new (half_height: f32, radius: f32) -> CharacterController3D {
+
+        CharacterController3D {
+
+            position: Vec3::new (),
+            step_height: 0.3,
+            max_slope: 45.0,
+            snap_distance: 0.3,
+            skin_width: 0.01,
+            mask: 0xFFFF_FFFF,
+
+            _shape: Shape3D::Capsule {half_height: half_height, radius: radius},
+            _is_grounded: false,
+            _ground_normal: Vec3::up (),
+            _ground_body: None,
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------CHARACTER CONTROLLER 3D PRIVATE METHODS--------------------------------------------------*/
+/*===============================================================================================*/
+
+impl CharacterController3D {
+
+    // Pushes the character out of any bodies it overlaps.
+    fn _depenetrate (&mut self, world: &PhysicsWorld3D) {
+
+        for _ in 0..MAX_DEPENETRATION_ITERATIONS {
+
+            let overlapping = world.get_overlapping (&self._shape, &self._get_transform (), self.mask);
+
+            if overlapping.is_empty () {
+                break;
+            }
+
+            for &(_, ref manifold) in overlapping.iter () {
+
+                let depth = manifold.contacts.iter ().fold (0.0, |depth, contact| Util::max (depth, contact.penetration));
+                self.position -= manifold.normal * (depth + self.skin_width);
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the transform of the upright capsule.
+    fn _get_transform (&self) -> Transform3D {
+        Transform3D::new (self.position, Quat::identity ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns true if a surface is flat enough to walk on.
+    fn _is_walkable (&self, normal: &Vec3) -> bool {
+        Vec3::dot (normal, &Vec3::up ()) >= Util::deg2rad (self.max_slope).cos () - EPSILON
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Moves the character, sliding it along the surfaces it hits.
+    // Returns true if it hit a surface too steep to walk on.
+    fn _slide (&mut self, world: &PhysicsWorld3D, displacement: &Vec3, stop_on_ground: bool) -> bool {
+
+        let up = Vec3::up ();
+        let mut remaining = *displacement;
+        let mut previous_normal: Option<Vec3> = None;
+        let mut is_blocked = false;
+
+        for _ in 0..MAX_SLIDE_ITERATIONS {
+
+            let distance = Vec3::length (&remaining);
+
+            if distance < EPSILON {
+                break;
+            }
+
+            let direction = remaining / distance;
+
+            let hit = match self._sweep (world, &direction, distance + self.skin_width) {
+
+                Some (hit) => hit,
+                None => {
+
+                    self.position += remaining;
+                    break;
+                }
+            };
+
+            let travel = Util::clamp (hit.distance - self.skin_width, 0.0, distance);
+
+            self.position += direction * travel;
+            remaining = direction * (distance - travel);
+
+            let mut normal = hit.normal;
+
+            if self._is_walkable (&normal) {
+
+                if stop_on_ground {
+                    break;
+                }
+            }
+
+            else {
+
+                is_blocked = true;
+
+                // Moving across, steep slopes are walls that cannot be climbed
+                let slope = Vec3::dot (&normal, &up);
+                let flat = normal - up * slope;
+
+                if !stop_on_ground && slope > 0.0 && Vec3::length (&flat) > EPSILON {
+                    normal = Vec3::normalize (&flat);
+                }
+            }
+
+            // Slide along the surface, or along the crease it makes with the last one
+            remaining -= normal * Vec3::dot (&remaining, &normal);
+
+            if let Some (previous) = previous_normal {
+
+                if Vec3::dot (&remaining, &previous) < 0.0 {
+
+                    let crease = Vec3::cross (&previous, &normal);
+                    let length = Vec3::length (&crease);
+
+                    remaining = if length > EPSILON {crease * (Vec3::dot (&remaining, &crease) / (length * length))} else {Vec3::new ()};
+                }
+            }
+
+            previous_normal = Some (normal);
+        }
+
+        is_blocked
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Moves the character up, across, then back down onto whatever it moved over.
+    // Returns false if there was nothing to land on.
+    fn _step_up (&mut self, world: &PhysicsWorld3D, horizontal: &Vec3) -> bool {
+
+        let rise = match self._sweep (world, &Vec3::up (), self.step_height + self.skin_width) {
+
+            Some (hit) => Util::max (hit.distance - self.skin_width, 0.0),
+            None => self.step_height
+        };
+
+        self.position += Vec3::up () * rise;
+        self._slide (world, horizontal, false);
+
+        match self._sweep (world, &Vec3::down (), rise + self.skin_width * 2.0) {
+
+            Some (hit) => {
+
+                self.position += Vec3::down () * Util::max (hit.distance - self.skin_width, 0.0);
+                true
+            },
+
+            None => false
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Sweeps the capsule from where it is.
+    fn _sweep (&self, world: &PhysicsWorld3D, direction: &Vec3, distance: f32) -> Option<RaycastHit3D> {
+        world.sweep (&self._shape, &self._get_transform (), direction, distance, self.mask)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Checks for walkable ground just below the character.
+    fn _update_ground (&mut self, world: &PhysicsWorld3D) {
+
+        self._is_grounded = false;
+        self._ground_normal = Vec3::up ();
+        self._ground_body = None;
+
+        if let Some (hit) = self._sweep (world, &Vec3::down (), self.skin_width * 2.0) {
+
+            if self._is_walkable (&hit.normal) {
+
+                self._is_grounded = true;
+                self._ground_normal = hit.normal;
+                self._ground_body = Some (hit.body);
+            }
+        }
+    }
+}
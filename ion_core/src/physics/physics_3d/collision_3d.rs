@@ -0,0 +1,962 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::physics_3d::{ContactPoint3D, Manifold3D, Shape3D, Transform3D};
+use ::util::math::{Util, Vec3, EPSILON};
+
+use std::f32;
+
+// Static variables
+// The most iterations run by GJK and EPA
+static MAX_ITERATIONS: usize = 64;
+// GJK stops once a step gets less than this fraction closer
+static GJK_TOLERANCE: f32 = 0.000001;
+// GJK treats squared distances this small relative to the size of the simplex as touching
+static OVERLAP_TOLERANCE: f32 = 0.0000000001;
+// EPA stops once the polytope grows by less than this
+static EPA_TOLERANCE: f32 = 0.0001;
+// Below this distance the cores of round shapes are treated as overlapping
+static CORE_TOLERANCE: f32 = 0.0001;
+// How close to perpendicular to the normal a capsule must be to rest on its side
+static PARALLEL_TOLERANCE: f32 = 0.05;
+// How closely a face must line up with the contact normal to clip against it
+static FACE_ALIGNMENT: f32 = 0.9;
+// The most contact points kept between two shapes
+static MAX_CONTACTS: usize = 4;
+
+/*===============================================================================================*/
+/*------COLLISION 3D STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Finds the contact points and distances between 3D shapes.
+///
+/// The distance between shapes is found with GJK, and the penetration of overlapping
+/// shapes with EPA. Spheres and capsules are handled as a point and a segment with a
+/// radius, so shallow contacts with them are exact. Contacts between flat features are
+/// completed by clipping one face against the other.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::physics_3d::{Collision3D, Shape3D, Transform3D};
+/// # use ion_core::util::math::{Quat, Vec3};
+/// #
+/// let ground = Shape3D::Box {half_extents: Vec3 {x: 5.0, y: 1.0, z: 5.0}};
+/// let block = Shape3D::Box {half_extents: Vec3 {x: 1.0, y: 1.0, z: 1.0}};
+/// let ball = Shape3D::Sphere {radius: 1.0};
+///
+/// // A block resting on the ground touches it at its four bottom corners
+/// let block_transform = Transform3D::new (Vec3 {x: 0.0, y: 1.9, z: 0.0}, Quat::identity ());
+/// let manifold = Collision3D::collide (&ground, &Transform3D::default (), &block, &block_transform).unwrap ();
+///
+/// assert! (Vec3::distance (&manifold.normal, &Vec3::up ()) < 0.0001);
+/// assert_eq! (manifold.contacts.len (), 4);
+/// assert! ((manifold.contacts[0].penetration - 0.1).abs () < 0.0001);
+///
+/// // A ball touches it at a single point
+/// let ball_transform = Transform3D::new (Vec3 {x: 1.0, y: 1.5, z: 0.0}, Quat::identity ());
+/// let manifold = Collision3D::collide (&ground, &Transform3D::default (), &ball, &ball_transform).unwrap ();
+///
+/// assert_eq! (manifold.contacts.len (), 1);
+/// assert! ((manifold.contacts[0].penetration - 0.5).abs () < 0.0001);
+///
+/// // Separated shapes have no contacts, but have a distance
+/// let ball_transform = Transform3D::new (Vec3 {x: 0.0, y: 4.0, z: 0.0}, Quat::identity ());
+///
+/// assert! (Collision3D::collide (&ground, &Transform3D::default (), &ball, &ball_transform).is_none ());
+/// assert! ((Collision3D::get_distance (&ground, &Transform3D::default (), &ball, &ball_transform) - 2.0).abs () < 0.0001);
+/// ```
+#[derive (Copy, Clone)]
+pub struct Collision3D;
+
+/*===============================================================================================*/
+/*------COLLISION 3D PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Collision3D {
+
+    /// Returns the contacts between two shapes, or none if they do not overlap.
+    ///
+    /// The normal of the manifold points from the first shape to the second.
+    pub fn collide (shape_a: &Shape3D,
+                    transform_a: &Transform3D,
+                    shape_b: &Shape3D,
+                    transform_b: &Transform3D) -> Option<Manifold3D> {
+
+        let shapes = MinkowskiDifference {shape_a: shape_a, transform_a: transform_a,
+                                          shape_b: shape_b, transform_b: transform_b};
+
+        let (normal, depth, point_a, point_b) = match Collision3D::_get_penetration (&shapes) {
+
+            Some (penetration) => penetration,
+            None => return None
+        };
+
+        // Flat features use the normal of the face they were clipped against
+        match Collision3D::_clip_features (&shapes, &normal) {
+
+            Some ((face_normal, contacts)) => Some (Manifold3D {normal: face_normal, contacts: contacts}),
+
+            None => Some (Manifold3D {normal: normal,
+                                      contacts: vec! [ContactPoint3D {position: (point_a + point_b) * 0.5, penetration: depth}]})
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the closest points of two shapes, or none if they overlap.
+    pub fn get_closest_points (shape_a: &Shape3D,
+                               transform_a: &Transform3D,
+                               shape_b: &Shape3D,
+                               transform_b: &Transform3D) -> Option<(Vec3, Vec3)> {
+
+        let shapes = MinkowskiDifference {shape_a: shape_a, transform_a: transform_a,
+                                          shape_b: shape_b, transform_b: transform_b};
+
+        match Collision3D::_gjk (&shapes, true) {
+
+            GjkResult::Separated (point_a, point_b) => {
+
+                let offset = point_b - point_a;
+                let distance = Vec3::length (&offset);
+                let margin_a = shape_a.get_margin ();
+                let margin_b = shape_b.get_margin ();
+
+                if distance <= margin_a + margin_b {
+                    return None;
+                }
+
+                let normal = offset / distance;
+                Some ((point_a + normal * margin_a, point_b - normal * margin_b))
+            },
+
+            GjkResult::Overlapping (_) => None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance between two shapes, which is zero if they overlap.
+    pub fn get_distance (shape_a: &Shape3D,
+                         transform_a: &Transform3D,
+                         shape_b: &Shape3D,
+                         transform_b: &Transform3D) -> f32 {
+
+        match Collision3D::get_closest_points (shape_a, transform_a, shape_b, transform_b) {
+
+            Some ((point_a, point_b)) => Vec3::distance (&point_a, &point_b),
+            None => 0.0
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if two shapes overlap.
+    pub fn intersects (shape_a: &Shape3D,
+                       transform_a: &Transform3D,
+                       shape_b: &Shape3D,
+                       transform_b: &Transform3D) -> bool {
+
+        Collision3D::get_closest_points (shape_a, transform_a, shape_b, transform_b).is_none ()
+    }
+}
+
+/*===============================================================================================*/
+/*------COLLISION 3D PRIVATE STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Collision3D {
+
+    // Returns the normal, depth and deepest points of two overlapping shapes.
+    fn _get_penetration (shapes: &MinkowskiDifference) -> Option<(Vec3, f32, Vec3, Vec3)> {
+
+        let margin_a = shapes.shape_a.get_margin ();
+        let margin_b = shapes.shape_b.get_margin ();
+
+        // Round shapes overlap if their cores are closer than their radii
+        if margin_a + margin_b > 0.0 {
+
+            if let GjkResult::Separated (point_a, point_b) = Collision3D::_gjk (shapes, true) {
+
+                let offset = point_b - point_a;
+                let distance = Vec3::length (&offset);
+
+                if distance > margin_a + margin_b {
+                    return None;
+                }
+
+                if distance > CORE_TOLERANCE {
+
+                    let normal = offset / distance;
+
+                    return Some ((normal,
+                                  margin_a + margin_b - distance,
+                                  point_a + normal * margin_a,
+                                  point_b - normal * margin_b));
+                }
+            }
+        }
+
+        // The shapes overlap deeply, so search the surface of their difference
+        match Collision3D::_gjk (shapes, false) {
+
+            GjkResult::Separated (..) => None,
+            GjkResult::Overlapping (simplex) => Collision3D::_epa (shapes, simplex)
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Finds the closest points of two shapes with GJK.
+    // The simplex is returned if the shapes overlap.
+    fn _gjk (shapes: &MinkowskiDifference, use_core: bool) -> GjkResult {
+
+        let initial = shapes.transform_b.position - shapes.transform_a.position;
+        let direction = if Vec3::dot (&initial, &initial) > EPSILON {initial} else {Vec3::right ()};
+
+        let mut simplex = vec! [shapes.get_support (&direction, use_core)];
+        let mut weights = vec! [1.0];
+        let mut closest = simplex[0].point;
+
+        for _ in 0..MAX_ITERATIONS {
+
+            let distance_squared = Vec3::dot (&closest, &closest);
+            let size_squared = simplex.iter ().fold (0.0, |size, support| Util::max (size, Vec3::dot (&support.point, &support.point)));
+
+            // The origin is on the simplex, allowing for rounding errors
+            if distance_squared <= OVERLAP_TOLERANCE * size_squared {
+                return GjkResult::Overlapping (simplex);
+            }
+
+            let support = shapes.get_support (&-closest, use_core);
+            let support_distance = Vec3::dot (&closest, &support.point);
+
+            // No point of the shapes is any closer
+            if distance_squared - support_distance <= GJK_TOLERANCE * distance_squared {
+                break;
+            }
+
+            // The search has stalled on a flat simplex, so only a plane that separates the
+            // shapes proves they do not overlap
+            if simplex.iter ().any (|existing| existing.point == support.point) {
+
+                if support_distance <= 0.0 {
+                    return GjkResult::Overlapping (simplex);
+                }
+
+                break;
+            }
+
+            let mut candidate = simplex.clone ();
+            candidate.push (support);
+
+            let reduced = Collision3D::_get_closest_on_simplex (&candidate);
+
+            // The origin is inside the tetrahedron
+            if reduced.len () == 4 {
+                return GjkResult::Overlapping (candidate);
+            }
+
+            let reduced_closest = reduced.iter ().fold (Vec3::new (), |sum, &(index, weight)| sum + candidate[index].point * weight);
+
+            if Vec3::dot (&reduced_closest, &reduced_closest) >= distance_squared {
+
+                if support_distance <= 0.0 {
+                    return GjkResult::Overlapping (simplex);
+                }
+
+                break;
+            }
+
+            simplex = reduced.iter ().map (|&(index, _)| candidate[index]).collect ();
+            weights = reduced.iter ().map (|&(_, weight)| weight).collect ();
+            closest = reduced_closest;
+        }
+
+        let mut point_a = Vec3::new ();
+        let mut point_b = Vec3::new ();
+
+        for (support, weight) in simplex.iter ().zip (weights.iter ()) {
+
+            point_a += support.point_a * *weight;
+            point_b += support.point_b * *weight;
+        }
+
+        GjkResult::Separated (point_a, point_b)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Finds the penetration of two overlapping shapes with EPA.
+    fn _epa (shapes: &MinkowskiDifference, simplex: Vec<SupportPoint>) -> Option<(Vec3, f32, Vec3, Vec3)> {
+
+        let mut vertices = match Collision3D::_expand_simplex (shapes, simplex) {
+
+            Some (vertices) => vertices,
+            None => return None
+        };
+
+        // Start from the tetrahedron, with its faces facing outwards
+        let inside = vertices.iter ().fold (Vec3::new (), |sum, vertex| sum + vertex.point) * 0.25;
+        let mut faces: Vec<[usize; 3]> = vec! [[0, 1, 2], [0, 2, 3], [0, 3, 1], [1, 3, 2]];
+
+        for face in faces.iter_mut () {
+
+            let normal = Vec3::cross (&(vertices[face[1]].point - vertices[face[0]].point),
+                                      &(vertices[face[2]].point - vertices[face[0]].point));
+
+            if Vec3::dot (&normal, &(inside - vertices[face[0]].point)) > 0.0 {
+                face.swap (1, 2);
+            }
+        }
+
+        let mut closest = (faces[0], Vec3::up (), f32::MAX);
+
+        for _ in 0..MAX_ITERATIONS {
+
+            // Find the face closest to the origin
+            closest.2 = f32::MAX;
+
+            for face in faces.iter () {
+
+                let normal = Vec3::cross (&(vertices[face[1]].point - vertices[face[0]].point),
+                                          &(vertices[face[2]].point - vertices[face[0]].point));
+
+                let length = Vec3::length (&normal);
+
+                if length < EPSILON {
+                    continue;
+                }
+
+                let normal = normal / length;
+                let distance = Vec3::dot (&normal, &vertices[face[0]].point);
+
+                if distance < closest.2 {
+                    closest = (*face, normal, distance);
+                }
+            }
+
+            if closest.2 == f32::MAX {
+                return None;
+            }
+
+            let (_, normal, distance) = closest;
+            let support = shapes.get_support (&normal, false);
+
+            // The face is on the surface
+            if Vec3::dot (&support.point, &normal) - distance < EPA_TOLERANCE {
+                break;
+            }
+
+            // Replace the faces the new point can see with a fan from the point
+            let index = vertices.len ();
+            vertices.push (support);
+
+            let visible: Vec<bool> = faces.iter ().map (|face| {
+
+                let normal = Vec3::cross (&(vertices[face[1]].point - vertices[face[0]].point),
+                                          &(vertices[face[2]].point - vertices[face[0]].point));
+
+                Vec3::dot (&normal, &(vertices[index].point - vertices[face[0]].point)) > 0.0
+            }).collect ();
+
+            let mut horizon = Vec::new ();
+
+            for (face, _) in faces.iter ().zip (visible.iter ()).filter (|&(_, &is_visible)| is_visible) {
+
+                for edge in 0..3 {
+
+                    let (start, end) = (face[edge], face[(edge + 1) % 3]);
+
+                    let is_neighbour_visible = faces.iter ().zip (visible.iter ()).any (|(other, &is_visible)| {
+                        is_visible && (0..3).any (|i| other[i] == end && other[(i + 1) % 3] == start)
+                    });
+
+                    if !is_neighbour_visible {
+                        horizon.push ((start, end));
+                    }
+                }
+            }
+
+            let mut kept: Vec<[usize; 3]> = faces.iter ()
+                                                 .zip (visible.iter ())
+                                                 .filter (|&(_, &is_visible)| !is_visible)
+                                                 .map (|(face, _)| *face)
+                                                 .collect ();
+
+            kept.extend (horizon.iter ().map (|&(start, end)| [start, end, index]));
+
+            if kept.is_empty () {
+                break;
+            }
+
+            faces = kept;
+        }
+
+        let (face, normal, distance) = closest;
+
+        if distance <= 0.0 {
+            return None;
+        }
+
+        // Find the points on the shapes that the closest point of the face came from
+        let weights = Collision3D::_get_barycentric (&(normal * distance),
+                                                     &vertices[face[0]].point,
+                                                     &vertices[face[1]].point,
+                                                     &vertices[face[2]].point);
+
+        let mut point_a = Vec3::new ();
+        let mut point_b = Vec3::new ();
+
+        for i in 0..3 {
+
+            point_a += vertices[face[i]].point_a * weights[i as u8];
+            point_b += vertices[face[i]].point_b * weights[i as u8];
+        }
+
+        Some ((normal, distance, point_a, point_b))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Adds points to a simplex until it is a tetrahedron with some volume.
+    fn _expand_simplex (shapes: &MinkowskiDifference, simplex: Vec<SupportPoint>) -> Option<Vec<SupportPoint>> {
+
+        let mut vertices = simplex;
+        let axes = [Vec3::right (), Vec3::left (), Vec3::up (), Vec3::down (), Vec3::forward (), Vec3::back ()];
+
+        if vertices.len () == 1 {
+
+            for axis in axes.iter () {
+
+                let support = shapes.get_support (axis, false);
+
+                if Vec3::distance (&support.point, &vertices[0].point) > EPSILON {
+
+                    vertices.push (support);
+                    break;
+                }
+            }
+        }
+
+        if vertices.len () == 2 {
+
+            let line = Vec3::normalize (&(vertices[1].point - vertices[0].point));
+
+            // Search around the line, starting from the axis least like it
+            let axis = if line.x.abs () < line.y.abs () && line.x.abs () < line.z.abs () {Vec3::right ()}
+                       else if line.y.abs () < line.z.abs () {Vec3::up ()}
+                       else {Vec3::forward ()};
+
+            let perpendicular = Vec3::normalize (&Vec3::cross (&line, &axis));
+            let other = Vec3::cross (&line, &perpendicular);
+
+            for direction in [perpendicular, -perpendicular, other, -other].iter () {
+
+                let support = shapes.get_support (direction, false);
+                let offset = support.point - vertices[0].point;
+
+                if Vec3::length (&Vec3::cross (&offset, &line)) > EPSILON {
+
+                    vertices.push (support);
+                    break;
+                }
+            }
+        }
+
+        if vertices.len () == 3 {
+
+            let normal = Vec3::normalize (&Vec3::cross (&(vertices[1].point - vertices[0].point),
+                                                        &(vertices[2].point - vertices[0].point)));
+
+            for direction in [normal, -normal].iter () {
+
+                let support = shapes.get_support (direction, false);
+
+                if Vec3::dot (&(support.point - vertices[0].point), &normal).abs () > EPSILON {
+
+                    vertices.push (support);
+                    break;
+                }
+            }
+        }
+
+        if vertices.len () != 4 {
+            return None;
+        }
+
+        let volume = Vec3::dot (&(vertices[1].point - vertices[0].point),
+                                &Vec3::cross (&(vertices[2].point - vertices[0].point),
+                                              &(vertices[3].point - vertices[0].point)));
+
+        if volume.abs () < EPSILON {
+            return None;
+        }
+
+        Some (vertices)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the contacts between the flat features of two shapes, if they have them,
+    // and the normal of the face they were clipped against pointing from the first shape.
+    fn _clip_features (shapes: &MinkowskiDifference, normal: &Vec3) -> Option<(Vec3, Vec<ContactPoint3D>)> {
+
+        let feature_a = Collision3D::_get_feature (shapes.shape_a, shapes.transform_a, normal);
+        let feature_b = Collision3D::_get_feature (shapes.shape_b, shapes.transform_b, &-*normal);
+
+        // Clipping needs a face, and an edge or face to clip against it
+        if feature_a.len () < 2 || feature_b.len () < 2 || (feature_a.len () < 3 && feature_b.len () < 3) {
+            return None;
+        }
+
+        let get_face_normal = |face: &[Vec3]| {
+            Vec3::normalize (&Vec3::cross (&(face[1] - face[0]), &(face[2] - face[0])))
+        };
+
+        // The reference face is the one most aligned with the contact normal
+        let use_face_a = feature_a.len () >= 3 &&
+                         (feature_b.len () < 3 ||
+                          Vec3::dot (&get_face_normal (&feature_a), normal) + 0.001 >= Vec3::dot (&get_face_normal (&feature_b), &-*normal));
+
+        let (reference, incident) = if use_face_a {(&feature_a, &feature_b)} else {(&feature_b, &feature_a)};
+        let reference_normal = get_face_normal (reference);
+
+        // The shapes are touching at an edge rather than a face
+        if Vec3::dot (&reference_normal, &if use_face_a {*normal} else {-*normal}) < FACE_ALIGNMENT {
+            return None;
+        }
+
+        // Clip the incident feature to the sides of the reference face
+        let mut clipped = incident.clone ();
+
+        for i in 0..reference.len () {
+
+            let start = reference[i];
+            let end = reference[(i + 1) % reference.len ()];
+            let side = Vec3::normalize (&Vec3::cross (&(end - start), &reference_normal));
+
+            clipped = Collision3D::_clip_polygon (&clipped, &side, Vec3::dot (&side, &start));
+
+            if clipped.is_empty () {
+                return None;
+            }
+        }
+
+        // Keep the points below the reference face, halfway between the faces
+        let offset = Vec3::dot (&reference_normal, &reference[0]);
+
+        let contacts: Vec<ContactPoint3D> = clipped.iter ().filter_map (|point| {
+
+            let separation = Vec3::dot (&reference_normal, point) - offset;
+
+            if separation <= 0.0 {
+                Some (ContactPoint3D {position: *point - reference_normal * (separation * 0.5), penetration: -separation})
+            }
+
+            else {
+                None
+            }
+
+        }).collect ();
+
+        if contacts.is_empty () {
+            return None;
+        }
+
+        Some ((if use_face_a {reference_normal} else {-reference_normal}, Collision3D::_reduce_contacts (contacts)))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the world space feature of a shape facing a direction.
+    // This is a face for boxes and hulls, the side of a capsule lying across the direction,
+    // or nothing.
+    fn _get_feature (shape: &Shape3D, transform: &Transform3D, direction: &Vec3) -> Vec<Vec3> {
+
+        let local = transform.inverse_rotate (direction);
+
+        match *shape {
+
+            Shape3D::Sphere {..} => Vec::new (),
+
+            Shape3D::Capsule {half_height, radius} => {
+
+                if local.y.abs () < PARALLEL_TOLERANCE {
+
+                    let offset = Vec3::normalize (&local) * radius;
+
+                    vec! [transform.apply (&(Vec3 {x: 0.0, y: -half_height, z: 0.0} + offset)),
+                          transform.apply (&(Vec3 {x: 0.0, y:  half_height, z: 0.0} + offset))]
+                }
+
+                else {
+                    Vec::new ()
+                }
+            },
+
+            _ => {
+
+                let mut best_face = Vec::new ();
+                let mut best_dot = f32::MIN;
+
+                for face in shape.get_faces () {
+
+                    let dot = Vec3::dot (&Vec3::normalize (&Vec3::cross (&(face[1] - face[0]), &(face[2] - face[0]))), &local);
+
+                    if dot > best_dot {
+
+                        best_face = face;
+                        best_dot = dot;
+                    }
+                }
+
+                best_face.iter ().map (|vertex| transform.apply (vertex)).collect ()
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Clips a polygon or segment to the side of a plane where dot (normal, point) <= offset.
+    fn _clip_polygon (points: &[Vec3], normal: &Vec3, offset: f32) -> Vec<Vec3> {
+
+        let get_intersection = |start: &Vec3, end: &Vec3, start_distance: f32, end_distance: f32| {
+            *start + (*end - *start) * (start_distance / (start_distance - end_distance))
+        };
+
+        let distances: Vec<f32> = points.iter ().map (|point| Vec3::dot (normal, point) - offset).collect ();
+
+        // A segment is not a closed loop
+        if points.len () == 2 {
+
+            return match (distances[0] <= 0.0, distances[1] <= 0.0) {
+
+                (true, true) => points.to_vec (),
+                (false, false) => Vec::new (),
+                (true, false) => vec! [points[0], get_intersection (&points[0], &points[1], distances[0], distances[1])],
+                (false, true) => vec! [get_intersection (&points[0], &points[1], distances[0], distances[1]), points[1]]
+            };
+        }
+
+        let mut clipped = Vec::with_capacity (points.len () + 1);
+
+        for i in 0..points.len () {
+
+            let next = (i + 1) % points.len ();
+
+            if distances[i] <= 0.0 {
+                clipped.push (points[i]);
+            }
+
+            if (distances[i] <= 0.0) != (distances[next] <= 0.0) {
+                clipped.push (get_intersection (&points[i], &points[next], distances[i], distances[next]));
+            }
+        }
+
+        clipped
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reduces a set of contacts to the deepest, and those spanning the largest area.
+    fn _reduce_contacts (contacts: Vec<ContactPoint3D>) -> Vec<ContactPoint3D> {
+
+        if contacts.len () <= MAX_CONTACTS {
+            return contacts;
+        }
+
+        let find_best = |score: &Fn (&ContactPoint3D) -> f32| {
+
+            let mut best = 0;
+            let mut best_score = f32::MIN;
+
+            for (index, contact) in contacts.iter ().enumerate () {
+
+                let contact_score = score (contact);
+
+                if contact_score > best_score {
+
+                    best = index;
+                    best_score = contact_score;
+                }
+            }
+
+            best
+        };
+
+        let first = find_best (&|contact| contact.penetration);
+        let a = contacts[first].position;
+
+        let second = find_best (&|contact| Vec3::distance (&contact.position, &a));
+        let b = contacts[second].position;
+
+        let third = find_best (&|contact| Vec3::length (&Vec3::cross (&(b - a), &(contact.position - a))));
+        let c = contacts[third].position;
+
+        // The fourth point is the furthest on the other side of the first edge
+        let normal = Vec3::cross (&(b - a), &(c - a));
+        let fourth = find_best (&|contact| -Vec3::dot (&Vec3::cross (&(b - a), &(contact.position - a)), &normal));
+
+        vec! [contacts[first], contacts[second], contacts[third], contacts[fourth]]
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the point of a simplex closest to the origin.
+    // It is given as the indices of the points it is made from, and their weights.
+    // All four points of a tetrahedron are returned if it contains the origin.
+    fn _get_closest_on_simplex (simplex: &[SupportPoint]) -> Vec<(usize, f32)> {
+
+        let points: Vec<Vec3> = simplex.iter ().map (|support| support.point).collect ();
+
+        match points.len () {
+
+            1 => vec! [(0, 1.0)],
+            2 => Collision3D::_get_closest_on_segment (&points[0], &points[1]),
+            3 => Collision3D::_get_closest_on_triangle (&points[0], &points[1], &points[2]),
+            _ => Collision3D::_get_closest_on_tetrahedron (&points)
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the point of a segment closest to the origin.
+    fn _get_closest_on_segment (a: &Vec3, b: &Vec3) -> Vec<(usize, f32)> {
+
+        let direction = *b - *a;
+        let length_squared = Vec3::dot (&direction, &direction);
+        let t = if length_squared > 0.0 {-Vec3::dot (a, &direction) / length_squared} else {0.0};
+
+        if t <= 0.0 {
+            vec! [(0, 1.0)]
+        }
+
+        else if t >= 1.0 {
+            vec! [(1, 1.0)]
+        }
+
+        else {
+            vec! [(0, 1.0 - t), (1, t)]
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the point of a triangle closest to the origin, by finding the region it is in.
+    fn _get_closest_on_triangle (a: &Vec3, b: &Vec3, c: &Vec3) -> Vec<(usize, f32)> {
+
+        let ab = *b - *a;
+        let ac = *c - *a;
+
+        // Vertex a
+        let d1 = -Vec3::dot (&ab, a);
+        let d2 = -Vec3::dot (&ac, a);
+
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return vec! [(0, 1.0)];
+        }
+
+        // Vertex b
+        let d3 = -Vec3::dot (&ab, b);
+        let d4 = -Vec3::dot (&ac, b);
+
+        if d3 >= 0.0 && d4 <= d3 {
+            return vec! [(1, 1.0)];
+        }
+
+        // Edge ab
+        let vc = d1 * d4 - d3 * d2;
+
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+
+            let v = d1 / (d1 - d3);
+            return vec! [(0, 1.0 - v), (1, v)];
+        }
+
+        // Vertex c
+        let d5 = -Vec3::dot (&ab, c);
+        let d6 = -Vec3::dot (&ac, c);
+
+        if d6 >= 0.0 && d5 <= d6 {
+            return vec! [(2, 1.0)];
+        }
+
+        // Edge ac
+        let vb = d5 * d2 - d1 * d6;
+
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+
+            let w = d2 / (d2 - d6);
+            return vec! [(0, 1.0 - w), (2, w)];
+        }
+
+        // Edge bc
+        let va = d3 * d6 - d5 * d4;
+
+        if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+
+            let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+            return vec! [(1, 1.0 - w), (2, w)];
+        }
+
+        // Inside the face
+        let total = va + vb + vc;
+
+        if total <= 0.0 {
+            return Collision3D::_get_closest_on_segment (a, b);
+        }
+
+        let v = vb / total;
+        let w = vc / total;
+
+        vec! [(0, 1.0 - v - w), (1, v), (2, w)]
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the point of a tetrahedron closest to the origin.
+    fn _get_closest_on_tetrahedron (points: &[Vec3]) -> Vec<(usize, f32)> {
+
+        // Each face, and the point opposite it
+        let faces = [(0, 1, 2, 3), (0, 2, 3, 1), (0, 3, 1, 2), (1, 3, 2, 0)];
+
+        let mut closest = Vec::new ();
+        let mut closest_distance = f32::MAX;
+        let mut is_outside = false;
+
+        for &(i, j, k, opposite) in faces.iter () {
+
+            let normal = Vec3::cross (&(points[j] - points[i]), &(points[k] - points[i]));
+            let origin_side = -Vec3::dot (&points[i], &normal);
+            let opposite_side = Vec3::dot (&(points[opposite] - points[i]), &normal);
+
+            // The origin is on the other side of the face to the rest of the tetrahedron
+            if opposite_side.abs () < EPSILON * EPSILON || origin_side * opposite_side < 0.0 {
+
+                is_outside = true;
+
+                let indices = [i, j, k];
+                let face_closest: Vec<(usize, f32)> = Collision3D::_get_closest_on_triangle (&points[i], &points[j], &points[k])
+                                                          .iter ()
+                                                          .map (|&(index, weight)| (indices[index], weight))
+                                                          .collect ();
+
+                let point = face_closest.iter ().fold (Vec3::new (), |sum, &(index, weight)| sum + points[index] * weight);
+                let distance = Vec3::dot (&point, &point);
+
+                if distance < closest_distance {
+
+                    closest = face_closest;
+                    closest_distance = distance;
+                }
+            }
+        }
+
+        if is_outside {
+            closest
+        }
+
+        else {
+            vec! [(0, 0.25), (1, 0.25), (2, 0.25), (3, 0.25)]
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the barycentric coordinates of a point in the plane of a triangle.
+    fn _get_barycentric (point: &Vec3, a: &Vec3, b: &Vec3, c: &Vec3) -> Vec3 {
+
+        let ab = *b - *a;
+        let ac = *c - *a;
+        let ap = *point - *a;
+
+        let d00 = Vec3::dot (&ab, &ab);
+        let d01 = Vec3::dot (&ab, &ac);
+        let d11 = Vec3::dot (&ac, &ac);
+        let d20 = Vec3::dot (&ap, &ab);
+        let d21 = Vec3::dot (&ap, &ac);
+
+        let denominator = d00 * d11 - d01 * d01;
+
+        if denominator.abs () < EPSILON * EPSILON {
+            return Vec3 {x: 1.0, y: 0.0, z: 0.0};
+        }
+
+        let v = (d11 * d20 - d01 * d21) / denominator;
+        let w = (d00 * d21 - d01 * d20) / denominator;
+
+        Vec3 {x: 1.0 - v - w, y: v, z: w}
+    }
+}
+
+/*===============================================================================================*/
+/*------MINKOWSKI DIFFERENCE STRUCT--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Two shapes, whose difference is searched by GJK and EPA.
+struct MinkowskiDifference<'a> {
+
+    shape_a: &'a Shape3D,
+    transform_a: &'a Transform3D,
+    shape_b: &'a Shape3D,
+    transform_b: &'a Transform3D,
+}
+
+/*===============================================================================================*/
+/*------MINKOWSKI DIFFERENCE PRIVATE METHODS-----------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<'a> MinkowskiDifference<'a> {
+
+    // Returns the point of the difference furthest along a direction.
+    fn get_support (&self, direction: &Vec3, use_core: bool) -> SupportPoint {
+
+        let local_a = self.transform_a.inverse_rotate (direction);
+        let local_b = self.transform_b.inverse_rotate (&-*direction);
+
+        let point_a = self.transform_a.apply (&if use_core {self.shape_a.get_core_support (&local_a)} else {self.shape_a.get_support (&local_a)});
+        let point_b = self.transform_b.apply (&if use_core {self.shape_b.get_core_support (&local_b)} else {self.shape_b.get_support (&local_b)});
+
+        SupportPoint {point: point_a - point_b, point_a: point_a, point_b: point_b}
+    }
+}
+
+/*===============================================================================================*/
+/*------SUPPORT POINT STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// A point of the difference of two shapes, and the points of the shapes it came from.
+#[derive (Copy, Clone)]
+struct SupportPoint {
+
+    point: Vec3,
+    point_a: Vec3,
+    point_b: Vec3,
+}
+
+/*===============================================================================================*/
+/*------GJK RESULT ENUM--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The result of a GJK search.
+enum GjkResult {
+
+    // The closest points of the shapes
+    Separated (Vec3, Vec3),
+    // The simplex that contains the origin
+    Overlapping (Vec<SupportPoint>),
+}
@@ -0,0 +1,34 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::physics_3d::ContactPoint3D;
+use ::util::math::Vec3;
+
+/*===============================================================================================*/
+/*------MANIFOLD 3D STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The contact points between two overlapping 3D shapes.
+#[derive (Clone, Default)]
+pub struct Manifold3D {
+
+    // Public
+    /// The world space contact normal, pointing from the first shape to the second.
+    pub normal: Vec3,
+    /// The contact points. There are up to four of them.
+    pub contacts: Vec<ContactPoint3D>,
+}
@@ -0,0 +1,45 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The 3D physics module.
+//!
+//! Contains rigid bodies with sphere, box, capsule and convex hull shapes,
+//! the world that simulates them, raycast and sweep queries against it,
+//! and a character controller that walks through it.
+/*===============================================================================================*/
+
+// Modules
+mod character_controller_3d;
+mod collision_3d;
+mod contact_point_3d;
+mod manifold_3d;
+mod physics_world_3d;
+mod raycast_hit_3d;
+mod rigid_body_3d;
+mod shape_3d;
+mod transform_3d;
+
+pub use self::character_controller_3d::CharacterController3D;
+pub use self::collision_3d::Collision3D;
+pub use self::contact_point_3d::ContactPoint3D;
+pub use self::manifold_3d::Manifold3D;
+pub use self::physics_world_3d::PhysicsWorld3D;
+pub use self::raycast_hit_3d::RaycastHit3D;
+pub use self::rigid_body_3d::RigidBody3D;
+pub use self::shape_3d::Shape3D;
+pub use self::transform_3d::Transform3D;
@@ -0,0 +1,1169 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::{BodyHandle, BodyType, CollisionEvent, CollisionEventType};
+use ::physics::physics_3d::{Collision3D, Manifold3D, RaycastHit3D, RigidBody3D, Shape3D, Transform3D};
+use ::util::math::{Aabb, Ray, Util, Vec3};
+use ::util::spatial::Bvh;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::f32;
+
+// Static variables
+// The fraction of the overlap removed each step
+static BAUMGARTE: f32 = 0.2;
+// The overlap allowed between resting bodies, which stops contacts from jittering
+static LINEAR_SLOP: f32 = 0.01;
+// The closing speed below which bodies do not bounce
+static RESTITUTION_THRESHOLD: f32 = 1.0;
+// The speeds below which a body is resting
+static LINEAR_SLEEP_TOLERANCE: f32 = 0.05;
+static ANGULAR_SLEEP_TOLERANCE: f32 = 0.05;
+// How long an island must rest before it falls asleep
+static TIME_TO_SLEEP: f32 = 0.5;
+// How far a contact can move between steps and still reuse its impulses
+static CONTACT_MATCH_DISTANCE: f32 = 0.05;
+// How close a swept shape must get to a body to hit it
+static SWEEP_TOLERANCE: f32 = 0.001;
+// The most steps a sweep takes towards a single body
+static MAX_SWEEP_ITERATIONS: usize = 32;
+
+/*===============================================================================================*/
+/*------PHYSICS WORLD 3D STRUCT------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Simulates a set of 3D rigid bodies.
+///
+/// Each step integrates the bodies, finds the pairs whose bounds overlap with a bounding
+/// volume hierarchy, and pushes them apart with sequential impulses. The impulses of each
+/// contact are kept, and used as the starting guess on the next step, which keeps stacks
+/// stable. Groups of touching bodies that stop moving fall asleep together.
+///
+/// The hierarchy is also used by raycasts and sweeps. It is rebuilt at the end of each step,
+/// so queries between steps search the bodies where they were left by the last step.
+///
+/// The simulation is deterministic. Bodies and contacts are always processed in handle order,
+/// so the same bodies and steps give exactly the same results.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::{BodyType, CollisionEventType};
+/// # use ion_core::physics::physics_3d::{PhysicsWorld3D, RigidBody3D, Shape3D};
+/// # use ion_core::util::math::Vec3;
+/// #
+/// let mut world = PhysicsWorld3D::new ();
+///
+/// let ground = world.add_body (RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 10.0, y: 0.5, z: 10.0}}));
+///
+/// let mut ball = RigidBody3D::new (BodyType::Dynamic, Shape3D::Sphere {radius: 0.5});
+/// ball.position = Vec3 {x: 0.0, y: 5.0, z: 0.0};
+///
+/// let ball = world.add_body (ball);
+///
+/// for _ in 0..300 {
+///     world.step (1.0 / 60.0);
+/// }
+///
+/// // The ball lands, and falls asleep on the ground
+/// let events = world.drain_events ();
+///
+/// assert_eq! (events.len (), 1);
+/// assert! (events[0].event_type == CollisionEventType::Began);
+/// assert! (events[0].body_a == ground && events[0].body_b == ball);
+///
+/// let ball = world.get_body (ball).unwrap ();
+///
+/// assert! (ball.is_sleeping ());
+/// assert! ((ball.position.y - 1.0).abs () < 0.02);
+/// ```
+pub struct PhysicsWorld3D {
+
+    // Public
+    /// The acceleration applied to dynamic bodies.
+    pub gravity: Vec3,
+    /// The number of solver passes over the contacts each step.
+    pub velocity_iterations: u32,
+    /// Can bodies fall asleep?
+    pub allow_sleeping: bool,
+
+    // Private
+    _bodies: Vec<Option<RigidBody3D>>,
+    _free_slots: Vec<usize>,
+    _touching: BTreeMap<(BodyHandle, BodyHandle), bool>,
+    _impulses: BTreeMap<(usize, usize), Vec<(Vec3, f32, Vec3)>>,
+    _events: Vec<CollisionEvent>,
+    _tree: Bvh<usize>,
+    _is_tree_dirty: bool,
+}
+
+/*===============================================================================================*/
+/*------PHYSICS WORLD 3D PUBLIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PhysicsWorld3D {
+
+    /// Adds a body to the world, and returns its handle.
+    pub fn add_body (&mut self, body: RigidBody3D) -> BodyHandle {
+
+        self._is_tree_dirty = true;
+
+        match self._free_slots.pop () {
+
+            Some (index) => {
+
+                self._bodies[index] = Some (body);
+                BodyHandle::new (index)
+            },
+
+            None => {
+
+                self._bodies.push (Some (body));
+                BodyHandle::new (self._bodies.len () - 1)
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes a body from the world, and returns it.
+    ///
+    /// Contacts with the body end, and the bodies it was touching are woken up.
+    pub fn remove_body (&mut self, handle: BodyHandle) -> Option<RigidBody3D> {
+
+        let body = match self._bodies.get_mut (handle.get_index ()) {
+
+            Some (slot) => slot.take (),
+            None => return None
+        };
+
+        if body.is_some () {
+
+            let index = handle.get_index ();
+            let cached: Vec<(usize, usize)> = self._impulses.keys ()
+                                                            .filter (|pair| pair.0 == index || pair.1 == index)
+                                                            .cloned ()
+                                                            .collect ();
+
+            for pair in cached {
+                self._impulses.remove (&pair);
+            }
+
+            self._free_slots.push (index);
+            self._is_tree_dirty = true;
+
+            let pairs: Vec<(BodyHandle, BodyHandle)> = self._touching.keys ()
+                                                                     .filter (|pair| pair.0 == handle || pair.1 == handle)
+                                                                     .cloned ()
+                                                                     .collect ();
+
+            for pair in pairs {
+
+                let is_trigger = self._touching.remove (&pair).unwrap_or (false);
+                let other = if pair.0 == handle {pair.1} else {pair.0};
+
+                if let Some (body) = self.get_body_mut (other) {
+                    body.wake ();
+                }
+
+                self._events.push (CollisionEvent {event_type: CollisionEventType::Ended,
+                                                   body_a: pair.0,
+                                                   body_b: pair.1,
+                                                   is_trigger: is_trigger});
+            }
+        }
+
+        body
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the collision events since the last call, and clears them.
+    pub fn drain_events (&mut self) -> Vec<CollisionEvent> {
+        self._events.drain (..).collect ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a body, or none if the handle has been removed.
+    pub fn get_body (&self, handle: BodyHandle) -> Option<&RigidBody3D> {
+        self._bodies.get (handle.get_index ()).and_then (|slot| slot.as_ref ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable body, or none if the handle has been removed.
+    ///
+    /// The body may be moved, so queries search every body until the next step.
+    pub fn get_body_mut (&mut self, handle: BodyHandle) -> Option<&mut RigidBody3D> {
+
+        self._is_tree_dirty = true;
+        self._bodies.get_mut (handle.get_index ()).and_then (|slot| slot.as_mut ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of bodies in the world.
+    pub fn get_body_count (&self) -> usize {
+        self._bodies.len () - self._free_slots.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the handles of the bodies in the world, in order.
+    pub fn get_handles (&self) -> Vec<BodyHandle> {
+
+        self._bodies.iter ()
+                    .enumerate ()
+                    .filter (|&(_, slot)| slot.is_some ())
+                    .map (|(index, _)| BodyHandle::new (index))
+                    .collect ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the bodies a shape overlaps, and the contacts with each, in handle order.
+    ///
+    /// The normals of the contacts point from the shape to the body.
+    /// Triggers are ignored, as are bodies whose layer is not in the mask.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::BodyType;
+    /// # use ion_core::physics::physics_3d::{PhysicsWorld3D, RigidBody3D, Shape3D, Transform3D};
+    /// # use ion_core::util::math::{Quat, Vec3};
+    /// #
+    /// let mut world = PhysicsWorld3D::new ();
+    ///
+    /// let ground = world.add_body (RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 10.0, y: 0.5, z: 10.0}}));
+    /// world.step (1.0 / 60.0);
+    ///
+    /// let ball = Shape3D::Sphere {radius: 0.5};
+    /// let sunk = Transform3D::new (Vec3 {x: 0.0, y: 0.75, z: 0.0}, Quat::identity ());
+    ///
+    /// let overlapping = world.get_overlapping (&ball, &sunk, 0xFFFF_FFFF);
+    ///
+    /// assert_eq! (overlapping.len (), 1);
+    /// assert! (overlapping[0].0 == ground);
+    /// assert! (Vec3::distance (&overlapping[0].1.normal, &Vec3::down ()) < 0.0001);
+    /// assert! ((overlapping[0].1.contacts[0].penetration - 0.25).abs () < 0.0001);
+    /// ```
+    pub fn get_overlapping (&self, shape: &Shape3D, transform: &Transform3D, mask: u32) -> Vec<(BodyHandle, Manifold3D)> {
+
+        let mut overlapping = Vec::new ();
+
+        for index in self._get_candidates (&shape.get_aabb (transform)) {
+
+            let body = match self._bodies[index] {
+
+                Some (ref body) => body,
+                None => continue
+            };
+
+            if body.is_trigger || body.filter.layer & mask == 0 {
+                continue;
+            }
+
+            if let Some (manifold) = Collision3D::collide (shape, transform, body.get_shape (), &body.get_transform ()) {
+                overlapping.push ((BodyHandle::new (index), manifold));
+            }
+        }
+
+        overlapping
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the closest body hit by a ray, or none.
+    ///
+    /// Triggers are ignored, as are bodies whose layer is not in the mask.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::BodyType;
+    /// # use ion_core::physics::physics_3d::{PhysicsWorld3D, RigidBody3D, Shape3D};
+    /// # use ion_core::util::math::{Ray, Vec3};
+    /// #
+    /// let mut world = PhysicsWorld3D::new ();
+    ///
+    /// let mut near = RigidBody3D::new (BodyType::Static, Shape3D::Sphere {radius: 1.0});
+    /// near.position = Vec3 {x: 5.0, y: 0.0, z: 0.0};
+    /// near.filter.layer = 2;
+    ///
+    /// let mut far = RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 1.0, y: 1.0, z: 1.0}});
+    /// far.position = Vec3 {x: 10.0, y: 0.0, z: 0.0};
+    ///
+    /// let near = world.add_body (near);
+    /// let far = world.add_body (far);
+    /// world.step (1.0 / 60.0);
+    ///
+    /// let ray = Ray::new (&Vec3::new (), &Vec3::right ());
+    /// let hit = world.raycast (&ray, 100.0, 0xFFFF_FFFF).unwrap ();
+    ///
+    /// assert! (hit.body == near);
+    /// assert! ((hit.distance - 4.0).abs () < 0.0001);
+    /// assert! (Vec3::distance (&hit.normal, &Vec3::left ()) < 0.0001);
+    ///
+    /// // Leaving the layer of the sphere out of the mask lets the ray pass through it
+    /// let hit = world.raycast (&ray, 100.0, !2).unwrap ();
+    ///
+    /// assert! (hit.body == far);
+    /// assert! ((hit.distance - 9.0).abs () < 0.0001);
+    ///
+    /// // Nothing is hit within a short distance
+    /// assert! (world.raycast (&ray, 3.0, 0xFFFF_FFFF).is_none ());
+    /// ```
+    pub fn raycast (&self, ray: &Ray, max_distance: f32, mask: u32) -> Option<RaycastHit3D> {
+
+        let candidates = if self._is_tree_dirty {
+            self._get_all_indices ()
+        }
+
+        else {
+
+            let mut indices: Vec<usize> = self._tree.query_ray (ray, max_distance).iter ().map (|&(&index, _)| index).collect ();
+            indices.sort ();
+            indices
+        };
+
+        let mut closest: Option<RaycastHit3D> = None;
+
+        for index in candidates {
+
+            let body = match self._bodies[index] {
+
+                Some (ref body) => body,
+                None => continue
+            };
+
+            if body.is_trigger || body.filter.layer & mask == 0 {
+                continue;
+            }
+
+            let limit = closest.map_or (max_distance, |hit| hit.distance);
+
+            if let Some ((distance, normal)) = body.get_shape ().raycast (&body.get_transform (), ray, limit) {
+
+                if closest.map_or (true, |hit| distance < hit.distance) {
+
+                    closest = Some (RaycastHit3D {body: BodyHandle::new (index),
+                                                  point: ray.get_point (distance),
+                                                  normal: normal,
+                                                  distance: distance});
+                }
+            }
+        }
+
+        closest
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Advances the simulation.
+    ///
+    /// It should be called with a fixed time step, which keeps the simulation stable
+    /// and the results repeatable.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::BodyType;
+    /// # use ion_core::physics::physics_3d::{PhysicsWorld3D, RigidBody3D, Shape3D};
+    /// # use ion_core::util::math::{Quat, Vec3};
+    /// #
+    /// fn build_world () -> PhysicsWorld3D {
+    ///
+    ///     let mut world = PhysicsWorld3D::new ();
+    ///     world.add_body (RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 10.0, y: 0.5, z: 10.0}}));
+    ///
+    ///     for i in 0..5 {
+    ///
+    ///         let mut body = RigidBody3D::new (BodyType::Dynamic, Shape3D::Box {half_extents: Vec3 {x: 0.5, y: 0.5, z: 0.5}});
+    ///         body.position = Vec3 {x: 0.0, y: 1.0 + i as f32, z: 0.0};
+    ///         body.rotation = Quat::from_axis_angle (&Vec3::up (), i as f32 * 10.0);
+    ///
+    ///         world.add_body (body);
+    ///     }
+    ///
+    ///     world
+    /// }
+    ///
+    /// // The same world stepped the same way ends up in exactly the same state
+    /// let mut world_a = build_world ();
+    /// let mut world_b = build_world ();
+    ///
+    /// for _ in 0..240 {
+    ///
+    ///     world_a.step (1.0 / 60.0);
+    ///     world_b.step (1.0 / 60.0);
+    /// }
+    ///
+    /// for handle in world_a.get_handles () {
+    ///
+    ///     let a = world_a.get_body (handle).unwrap ();
+    ///     let b = world_b.get_body (handle).unwrap ();
+    ///
+    ///     assert! (a.position == b.position && a.rotation == b.rotation);
+    /// }
+    ///
+    /// // The stack stays standing, and falls asleep
+    /// for (i, handle) in world_a.get_handles ().into_iter ().skip (1).enumerate () {
+    ///
+    ///     let body = world_a.get_body (handle).unwrap ();
+    ///
+    ///     assert! (body.is_sleeping ());
+    ///     assert! ((body.position.y - (1.0 + i as f32)).abs () < 0.05);
+    ///     assert! (body.position.x.abs () < 0.05 && body.position.z.abs () < 0.05);
+    /// }
+    /// ```
+    pub fn step (&mut self, delta_time: f32) {
+
+        if delta_time <= 0.0 {
+            return;
+        }
+
+        let gravity = self.gravity;
+
+        for slot in self._bodies.iter_mut () {
+
+            if let Some (ref mut body) = *slot {
+                body.integrate_velocity (&gravity, delta_time);
+            }
+        }
+
+        if self._is_tree_dirty {
+            self._rebuild_tree ();
+        }
+
+        let (touching, manifolds) = self._find_contacts ();
+
+        // Contact with a moving body wakes a sleeping one
+        for &(index_a, index_b, _) in manifolds.iter () {
+
+            for &index in [index_a, index_b].iter () {
+
+                if let Some (ref mut body) = self._bodies[index] {
+
+                    if body.is_sleeping () {
+                        body.wake ();
+                    }
+                }
+            }
+        }
+
+        self._solve_contacts (&manifolds, delta_time);
+
+        for slot in self._bodies.iter_mut () {
+
+            if let Some (ref mut body) = *slot {
+                body.integrate_position (delta_time);
+            }
+        }
+
+        if self.allow_sleeping {
+            self._update_sleeping (&manifolds, delta_time);
+        }
+
+        self._update_events (touching);
+        self._rebuild_tree ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves a shape along a direction, and returns the first body it hits, or none.
+    ///
+    /// The distance of the hit is how far the shape can move before touching the body.
+    /// If the shape starts overlapping a body, it is hit at a distance of zero.
+    /// Triggers are ignored, as are bodies whose layer is not in the mask. A body the shape
+    /// only grazes may take too many steps to reach, in which case it is not hit and a
+    /// warning is logged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::BodyType;
+    /// # use ion_core::physics::physics_3d::{PhysicsWorld3D, RigidBody3D, Shape3D, Transform3D};
+    /// # use ion_core::util::math::{Quat, Vec3};
+    /// #
+    /// let mut world = PhysicsWorld3D::new ();
+    ///
+    /// let ground = world.add_body (RigidBody3D::new (BodyType::Static, Shape3D::Box {half_extents: Vec3 {x: 10.0, y: 0.5, z: 10.0}}));
+    /// world.step (1.0 / 60.0);
+    ///
+    /// // Drop a capsule standing upright onto the ground
+    /// let capsule = Shape3D::Capsule {half_height: 0.5, radius: 0.5};
+    /// let start = Transform3D::new (Vec3 {x: 0.0, y: 5.0, z: 0.0}, Quat::identity ());
+    ///
+    /// let hit = world.sweep (&capsule, &start, &Vec3::down (), 10.0, 0xFFFF_FFFF).unwrap ();
+    ///
+    /// assert! (hit.body == ground);
+    /// assert! ((hit.distance - 3.5).abs () < 0.01);
+    /// assert! (Vec3::distance (&hit.normal, &Vec3::up ()) < 0.01);
+    ///
+    /// // Moving away hits nothing
+    /// assert! (world.sweep (&capsule, &start, &Vec3::up (), 10.0, 0xFFFF_FFFF).is_none ());
+    /// ```
+    pub fn sweep (&self,
+                  shape: &Shape3D,
+                  transform: &Transform3D,
+                  direction: &Vec3,
+                  max_distance: f32,
+                  mask: u32) -> Option<RaycastHit3D> {
+
+        let direction = Vec3::normalize (direction);
+        let end = Transform3D::new (transform.position + direction * max_distance, transform.rotation);
+
+        let candidates = self._get_candidates (&Aabb::merge (&shape.get_aabb (transform), &shape.get_aabb (&end)));
+        let mut closest: Option<RaycastHit3D> = None;
+
+        for index in candidates {
+
+            let body = match self._bodies[index] {
+
+                Some (ref body) => body,
+                None => continue
+            };
+
+            if body.is_trigger || body.filter.layer & mask == 0 {
+                continue;
+            }
+
+            let limit = closest.map_or (max_distance, |hit| hit.distance);
+
+            if let Some ((distance, point, normal)) = PhysicsWorld3D::_sweep_body (shape, transform, &direction, limit, body) {
+
+                if closest.map_or (true, |hit| distance < hit.distance) {
+
+                    closest = Some (RaycastHit3D {body: BodyHandle::new (index),
+                                                  point: point,
+                                                  normal: normal,
+                                                  distance: distance});
+                }
+            }
+        }
+
+        closest
+    }
+
+/*===============================================================================================*/
+/*------PHYSICS WORLD 3D PUBLIC STATIC METHODS---------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty world with earth gravity.
+    pub fn new () -> PhysicsWorld3D {
+
+        PhysicsWorld3D {
+
+            gravity: Vec3 {x: 0.0, y: -9.81, z: 0.0},
+            velocity_iterations: 8,
+            allow_sleeping: true,
+
+            _bodies: Vec::new (),
+            _free_slots: Vec::new (),
+            _touching: BTreeMap::new (),
+            _impulses: BTreeMap::new (),
+            _events: Vec::new (),
+            _tree: Bvh::build (Vec::new ()),
+            _is_tree_dirty: false,
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for PhysicsWorld3D {
+
+    fn default () -> PhysicsWorld3D {
+        PhysicsWorld3D::new ()
+    }
+}
+
+/*===============================================================================================*/
+/*------PHYSICS WORLD 3D PRIVATE METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PhysicsWorld3D {
+
+    // Finds the touching pairs, and the contacts of the pairs that need solving.
+    fn _find_contacts (&self) -> (BTreeMap<(BodyHandle, BodyHandle), bool>, Vec<(usize, usize, Manifold3D)>) {
+
+        let mut touching = BTreeMap::new ();
+        let mut manifolds = Vec::new ();
+
+        // Neither body has moved, so they are still touching if they were before
+        for (pair, &was_trigger) in self._touching.iter () {
+
+            if let (Some (body_a), Some (body_b)) = (self.get_body (pair.0), self.get_body (pair.1)) {
+
+                if !PhysicsWorld3D::_is_moving (body_a) && !PhysicsWorld3D::_is_moving (body_b) &&
+                   body_a.filter.can_collide (&body_b.filter) {
+
+                    touching.insert (*pair, was_trigger);
+                }
+            }
+        }
+
+        // Only the bodies that can move need to search for new pairs
+        let mut pairs = BTreeSet::new ();
+
+        for (index, slot) in self._bodies.iter ().enumerate () {
+
+            if let Some (ref body) = *slot {
+
+                if PhysicsWorld3D::_is_moving (body) {
+
+                    for &other in self._tree.query_aabb (&body.get_aabb ()) {
+
+                        if other != index {
+                            pairs.insert (if index < other {(index, other)} else {(other, index)});
+                        }
+                    }
+                }
+            }
+        }
+
+        for (index_a, index_b) in pairs {
+
+            let body_a = self._bodies[index_a].as_ref ().unwrap ();
+            let body_b = self._bodies[index_b].as_ref ().unwrap ();
+            let pair = (BodyHandle::new (index_a), BodyHandle::new (index_b));
+            let is_trigger = body_a.is_trigger || body_b.is_trigger;
+
+            if !body_a.filter.can_collide (&body_b.filter) {
+                continue;
+            }
+
+            // Only dynamic bodies are pushed apart
+            if !is_trigger &&
+               body_a.get_body_type () != BodyType::Dynamic &&
+               body_b.get_body_type () != BodyType::Dynamic {
+
+                continue;
+            }
+
+            if let Some (manifold) = Collision3D::collide (body_a.get_shape (), &body_a.get_transform (),
+                                                           body_b.get_shape (), &body_b.get_transform ()) {
+
+                touching.insert (pair, is_trigger);
+
+                if !is_trigger {
+                    manifolds.push ((index_a, index_b, manifold));
+                }
+            }
+        }
+
+        (touching, manifolds)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the indices of the bodies whose bounds overlap an area, in order.
+    fn _get_candidates (&self, bounds: &Aabb) -> Vec<usize> {
+
+        if self._is_tree_dirty {
+            return self._get_all_indices ();
+        }
+
+        let mut indices: Vec<usize> = self._tree.query_aabb (bounds).into_iter ().cloned ().collect ();
+
+        indices.sort ();
+        indices
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the indices of every body, for queries when the hierarchy is out of date.
+    fn _get_all_indices (&self) -> Vec<usize> {
+
+        self._bodies.iter ()
+                    .enumerate ()
+                    .filter (|&(_, slot)| slot.is_some ())
+                    .map (|(index, _)| index)
+                    .collect ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Rebuilds the hierarchy around the current bounds of the bodies.
+    fn _rebuild_tree (&mut self) {
+
+        let items = self._bodies.iter ()
+                                .enumerate ()
+                                .filter_map (|(index, slot)| slot.as_ref ().map (|body| (body.get_aabb (), index)))
+                                .collect ();
+
+        self._tree = Bvh::build (items);
+        self._is_tree_dirty = false;
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Pushes the touching bodies apart with sequential impulses.
+    fn _solve_contacts (&mut self, manifolds: &[(usize, usize, Manifold3D)], delta_time: f32) {
+
+        let mut velocities = vec! [(Vec3::new (), Vec3::new ()); self._bodies.len ()];
+        let mut masses = vec! [0.0; self._bodies.len ()];
+
+        for (index, slot) in self._bodies.iter ().enumerate () {
+
+            if let Some (ref body) = *slot {
+
+                velocities[index] = (body.linear_velocity, body.angular_velocity);
+                masses[index] = body.get_inverse_mass ();
+            }
+        }
+
+        let impulses = &self._impulses;
+        let mut constraints: Vec<ContactConstraint> = manifolds.iter ().map (|&(index_a, index_b, ref manifold)| {
+
+            let cached = impulses.get (&(index_a, index_b));
+            let body_a = self._bodies[index_a].as_ref ().unwrap ();
+            let body_b = self._bodies[index_b].as_ref ().unwrap ();
+            let transform_a = body_a.get_transform ();
+
+            let inverse_mass_a = masses[index_a];
+            let inverse_mass_b = masses[index_b];
+
+            let normal = manifold.normal;
+            let (tangent_1, tangent_2) = PhysicsWorld3D::_get_tangents (&normal);
+            let directions = [normal, tangent_1, tangent_2];
+            let restitution = Util::max (body_a.restitution, body_b.restitution);
+
+            let points = manifold.contacts.iter ().map (|contact| {
+
+                let offset_a = contact.position - body_a.position;
+                let offset_b = contact.position - body_b.position;
+                let local_position = transform_a.inverse_apply (&contact.position);
+
+                let mut angular_a = [Vec3::new (); 3];
+                let mut angular_b = [Vec3::new (); 3];
+                let mut effective_masses = [0.0; 3];
+
+                for (i, direction) in directions.iter ().enumerate () {
+
+                    let cross_a = Vec3::cross (&offset_a, direction);
+                    let cross_b = Vec3::cross (&offset_b, direction);
+
+                    angular_a[i] = body_a.apply_inverse_inertia (&cross_a);
+                    angular_b[i] = body_b.apply_inverse_inertia (&cross_b);
+
+                    let k = inverse_mass_a + inverse_mass_b +
+                            Vec3::dot (&cross_a, &angular_a[i]) +
+                            Vec3::dot (&cross_b, &angular_b[i]);
+
+                    effective_masses[i] = if k > 0.0 {1.0 / k} else {0.0};
+                }
+
+                // Remove part of the overlap, or bounce if the bodies are closing quickly
+                let closing_speed = Vec3::dot (&(body_b.get_velocity_at_point (&contact.position) -
+                                                 body_a.get_velocity_at_point (&contact.position)), &normal);
+
+                let mut bias = BAUMGARTE / delta_time * Util::max (contact.penetration - LINEAR_SLOP, 0.0);
+
+                if closing_speed < -RESTITUTION_THRESHOLD {
+                    bias = Util::max (bias, -restitution * closing_speed);
+                }
+
+                // Start from the impulses of the same contact last step
+                let (normal_impulse, friction_impulse) = cached.and_then (|cached| {
+
+                    cached.iter ()
+                          .find (|&&(position, _, _)| Vec3::distance (&position, &local_position) < CONTACT_MATCH_DISTANCE)
+                          .map (|&(_, normal_impulse, friction_impulse)| (normal_impulse, friction_impulse))
+
+                }).unwrap_or ((0.0, Vec3::new ()));
+
+                ConstraintPoint {
+
+                    offset_a: offset_a,
+                    offset_b: offset_b,
+                    local_position: local_position,
+                    angular_a: angular_a,
+                    angular_b: angular_b,
+                    masses: effective_masses,
+                    bias: bias,
+                    impulses: [normal_impulse,
+                               Vec3::dot (&friction_impulse, &tangent_1),
+                               Vec3::dot (&friction_impulse, &tangent_2)],
+                }
+
+            }).collect ();
+
+            ContactConstraint {
+
+                body_a: index_a,
+                body_b: index_b,
+                directions: directions,
+                friction: (body_a.friction * body_b.friction).sqrt (),
+                points: points,
+            }
+
+        }).collect ();
+
+        for constraint in constraints.iter () {
+
+            for point in constraint.points.iter () {
+
+                for i in 0..3 {
+                    PhysicsWorld3D::_apply_impulse (&mut velocities, &masses, constraint, point, i, point.impulses[i]);
+                }
+            }
+        }
+
+        for _ in 0..self.velocity_iterations {
+
+            for constraint in constraints.iter_mut () {
+                PhysicsWorld3D::_solve_constraint (constraint, &mut velocities, &masses);
+            }
+        }
+
+        self._impulses = constraints.iter ().map (|constraint| {
+
+            ((constraint.body_a, constraint.body_b),
+             constraint.points.iter ().map (|point| {
+
+                 (point.local_position,
+                  point.impulses[0],
+                  constraint.directions[1] * point.impulses[1] + constraint.directions[2] * point.impulses[2])
+
+             }).collect ())
+
+        }).collect ();
+
+        for (index, slot) in self._bodies.iter_mut ().enumerate () {
+
+            if let Some (ref mut body) = *slot {
+
+                if body.get_body_type () == BodyType::Dynamic {
+
+                    body.linear_velocity = velocities[index].0;
+                    body.angular_velocity = velocities[index].1;
+                }
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Puts groups of touching bodies to sleep once they have all been resting long enough.
+    fn _update_sleeping (&mut self, manifolds: &[(usize, usize, Manifold3D)], delta_time: f32) {
+
+        // Join the touching dynamic bodies into islands
+        let mut parents: Vec<usize> = (0..self._bodies.len ()).collect ();
+
+        for &(index_a, index_b, _) in manifolds.iter () {
+
+            let is_dynamic = |index: usize| {
+                self._bodies[index].as_ref ().map_or (false, |body| body.get_body_type () == BodyType::Dynamic)
+            };
+
+            if is_dynamic (index_a) && is_dynamic (index_b) {
+
+                let root_a = PhysicsWorld3D::_find_root (&mut parents, index_a);
+                let root_b = PhysicsWorld3D::_find_root (&mut parents, index_b);
+
+                parents[root_a] = root_b;
+            }
+        }
+
+        // An island sleeps once its most recently moving body has rested long enough
+        let mut island_times = vec! [f32::MAX; self._bodies.len ()];
+
+        for (index, slot) in self._bodies.iter_mut ().enumerate () {
+
+            if let Some (ref mut body) = *slot {
+
+                if body.get_body_type () == BodyType::Dynamic && !body.is_sleeping () {
+
+                    let root = PhysicsWorld3D::_find_root (&mut parents, index);
+                    let time = body.update_sleep_time (delta_time, LINEAR_SLEEP_TOLERANCE, ANGULAR_SLEEP_TOLERANCE);
+
+                    island_times[root] = Util::min (island_times[root], time);
+                }
+            }
+        }
+
+        for (index, slot) in self._bodies.iter_mut ().enumerate () {
+
+            if let Some (ref mut body) = *slot {
+
+                if body.get_body_type () == BodyType::Dynamic && !body.is_sleeping () &&
+                   island_times[PhysicsWorld3D::_find_root (&mut parents, index)] >= TIME_TO_SLEEP {
+
+                    body.sleep ();
+                }
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reports the pairs that started or stopped touching.
+    fn _update_events (&mut self, touching: BTreeMap<(BodyHandle, BodyHandle), bool>) {
+
+        for (pair, &is_trigger) in self._touching.iter () {
+
+            if !touching.contains_key (pair) {
+
+                self._events.push (CollisionEvent {event_type: CollisionEventType::Ended,
+                                                   body_a: pair.0,
+                                                   body_b: pair.1,
+                                                   is_trigger: is_trigger});
+            }
+        }
+
+        for (pair, &is_trigger) in touching.iter () {
+
+            if !self._touching.contains_key (pair) {
+
+                self._events.push (CollisionEvent {event_type: CollisionEventType::Began,
+                                                   body_a: pair.0,
+                                                   body_b: pair.1,
+                                                   is_trigger: is_trigger});
+            }
+        }
+
+        self._touching = touching;
+    }
+
+/*===============================================================================================*/
+/*------PHYSICS WORLD 3D PRIVATE STATIC METHODS--------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Returns the root of a body's island, flattening the path to it.
+    fn _find_root (parents: &mut Vec<usize>, index: usize) -> usize {
+
+        let mut root = index;
+
+        while parents[root] != root {
+            root = parents[root];
+        }
+
+        let mut current = index;
+
+        while parents[current] != root {
+
+            let next = parents[current];
+            parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns two directions at right angles to a normal, and each other.
+    fn _get_tangents (normal: &Vec3) -> (Vec3, Vec3) {
+
+        let axis = if normal.x.abs () < 0.57 {Vec3::right ()} else {Vec3::up ()};
+        let tangent = Vec3::normalize (&Vec3::cross (normal, &axis));
+
+        (tangent, Vec3::cross (normal, &tangent))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns true if the body can move this step.
+    fn _is_moving (body: &RigidBody3D) -> bool {
+
+        match body.get_body_type () {
+
+            BodyType::Static => false,
+            BodyType::Kinematic => body.linear_velocity != Vec3::new () || body.angular_velocity != Vec3::new (),
+            BodyType::Dynamic => !body.is_sleeping ()
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Moves a shape towards a body until it touches, returning the distance, point and normal.
+    // Each step moves the shape up to the plane separating it from the body, so it never
+    // passes through. Returns None if the shape doesn't touch the body within the step limit.
+    fn _sweep_body (shape: &Shape3D,
+                    transform: &Transform3D,
+                    direction: &Vec3,
+                    max_distance: f32,
+                    body: &RigidBody3D) -> Option<(f32, Vec3, Vec3)> {
+
+        let body_transform = body.get_transform ();
+        let mut distance = 0.0;
+        let mut hit = (transform.position, -*direction);
+
+        for _ in 0..MAX_SWEEP_ITERATIONS {
+
+            let moved = Transform3D::new (transform.position + *direction * distance, transform.rotation);
+
+            let (point_a, point_b) = match Collision3D::get_closest_points (shape, &moved, body.get_shape (), &body_transform) {
+
+                Some (points) => points,
+
+                // The shape started inside the body
+                None => {
+
+                    return match Collision3D::collide (shape, &moved, body.get_shape (), &body_transform) {
+
+                        Some (manifold) => Some ((distance, manifold.contacts[0].position, -manifold.normal)),
+                        None => Some ((distance, hit.0, hit.1))
+                    };
+                }
+            };
+
+            let gap = Vec3::distance (&point_a, &point_b);
+            let normal = (point_a - point_b) / gap;
+
+            hit = (point_b, normal);
+
+            if gap < SWEEP_TOLERANCE {
+                return Some ((distance, point_b, normal));
+            }
+
+            // The shape is moving away from the body
+            let closing_speed = -Vec3::dot (direction, &normal);
+
+            if closing_speed <= 0.0 {
+                return None;
+            }
+
+            distance += (gap - SWEEP_TOLERANCE * 0.5) / closing_speed;
+
+            if distance > max_distance {
+                return None;
+            }
+        }
+
+        // The shape is still short of the body, so the distance reached isn't a hit
+        warn! ("Sweep did not converge on a body within {} steps.", MAX_SWEEP_ITERATIONS);
+        None
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Runs a single solver pass over a contact.
+    fn _solve_constraint (constraint: &mut ContactConstraint, velocities: &mut [(Vec3, Vec3)], masses: &[f32]) {
+
+        for i in 0..constraint.points.len () {
+
+            // Friction is limited by the normal impulse
+            let max_friction = constraint.friction * constraint.points[i].impulses[0];
+            let mut friction = [0.0; 2];
+
+            for j in 0..2 {
+
+                let point = &constraint.points[i];
+                let relative = PhysicsWorld3D::_get_relative_velocity (velocities, constraint, point, j + 1);
+
+                friction[j] = point.impulses[j + 1] - relative * point.masses[j + 1];
+            }
+
+            let length = (friction[0] * friction[0] + friction[1] * friction[1]).sqrt ();
+
+            if length > max_friction {
+
+                let scale = if length > 0.0 {max_friction / length} else {0.0};
+
+                friction[0] *= scale;
+                friction[1] *= scale;
+            }
+
+            for j in 0..2 {
+
+                let lambda = friction[j] - constraint.points[i].impulses[j + 1];
+                constraint.points[i].impulses[j + 1] = friction[j];
+
+                PhysicsWorld3D::_apply_impulse (velocities, masses, constraint, &constraint.points[i], j + 1, lambda);
+            }
+
+            // The total normal impulse can only push the bodies apart
+            let relative = PhysicsWorld3D::_get_relative_velocity (velocities, constraint, &constraint.points[i], 0);
+            let impulse = Util::max (constraint.points[i].impulses[0] +
+                                     (constraint.points[i].bias - relative) * constraint.points[i].masses[0], 0.0);
+
+            let lambda = impulse - constraint.points[i].impulses[0];
+            constraint.points[i].impulses[0] = impulse;
+
+            PhysicsWorld3D::_apply_impulse (velocities, masses, constraint, &constraint.points[i], 0, lambda);
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the speed of the second body relative to the first at a contact point,
+    // along one of the contact directions.
+    fn _get_relative_velocity (velocities: &[(Vec3, Vec3)],
+                               constraint: &ContactConstraint,
+                               point: &ConstraintPoint,
+                               direction: usize) -> f32 {
+
+        let (linear_a, angular_a) = velocities[constraint.body_a];
+        let (linear_b, angular_b) = velocities[constraint.body_b];
+
+        let relative = linear_b + Vec3::cross (&angular_b, &point.offset_b) -
+                       linear_a - Vec3::cross (&angular_a, &point.offset_a);
+
+        Vec3::dot (&relative, &constraint.directions[direction])
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Applies an impulse to the second body at a contact point along one of the contact
+    // directions, and the opposite to the first.
+    fn _apply_impulse (velocities: &mut [(Vec3, Vec3)],
+                       masses: &[f32],
+                       constraint: &ContactConstraint,
+                       point: &ConstraintPoint,
+                       direction: usize,
+                       impulse: f32) {
+
+        let linear = constraint.directions[direction] * impulse;
+
+        velocities[constraint.body_a].0 -= linear * masses[constraint.body_a];
+        velocities[constraint.body_a].1 -= point.angular_a[direction] * impulse;
+        velocities[constraint.body_b].0 += linear * masses[constraint.body_b];
+        velocities[constraint.body_b].1 += point.angular_b[direction] * impulse;
+    }
+}
+
+/*===============================================================================================*/
+/*------CONTACT CONSTRAINT STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The solver state of a pair of touching bodies.
+// The directions are the normal, then the two friction directions.
+struct ContactConstraint {
+
+    body_a: usize,
+    body_b: usize,
+    directions: [Vec3; 3],
+    friction: f32,
+    points: Vec<ConstraintPoint>,
+}
+
+/*===============================================================================================*/
+/*------CONSTRAINT POINT STRUCT------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The solver state of a single contact point.
+// The angular terms, masses and impulses are kept for each of the contact directions.
+struct ConstraintPoint {
+
+    offset_a: Vec3,
+    offset_b: Vec3,
+    local_position: Vec3,
+    angular_a: [Vec3; 3],
+    angular_b: [Vec3; 3],
+    masses: [f32; 3],
+    bias: f32,
+    impulses: [f32; 3],
+}
@@ -0,0 +1,38 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::BodyHandle;
+use ::util::math::Vec3;
+
+/*===============================================================================================*/
+/*------RAYCAST HIT 3D STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The closest body hit by a raycast or sweep.
+#[derive (Copy, Clone)]
+pub struct RaycastHit3D {
+
+    // Public
+    /// The body that was hit.
+    pub body: BodyHandle,
+    /// The world space point where the body was hit.
+    pub point: Vec3,
+    /// The surface normal of the body at the hit point.
+    pub normal: Vec3,
+    /// How far the ray or shape travelled before the hit.
+    pub distance: f32,
+}
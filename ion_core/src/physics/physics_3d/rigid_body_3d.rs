@@ -0,0 +1,447 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::{BodyType, CollisionFilter};
+use ::physics::physics_3d::{Shape3D, Transform3D};
+use ::util::math::{Aabb, Quat, Vec3};
+
+/*===============================================================================================*/
+/*------RIGID BODY 3D STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A 3D rigid body.
+///
+/// The mass and inertia are worked out from the shape and density. The inertia is kept
+/// in the local space of the body, and rotated into world space when it is used.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::BodyType;
+/// # use ion_core::physics::physics_3d::{RigidBody3D, Shape3D};
+/// # use ion_core::util::math::Vec3;
+/// #
+/// let mut body = RigidBody3D::new (BodyType::Dynamic, Shape3D::Box {half_extents: Vec3 {x: 1.0, y: 1.0, z: 1.0}});
+/// assert_eq! (body.get_mass (), 8.0);
+///
+/// body.set_density (0.5);
+/// assert_eq! (body.get_mass (), 4.0);
+///
+/// body.apply_impulse (&Vec3 {x: 4.0, y: 0.0, z: 0.0});
+/// assert! (body.linear_velocity == Vec3 {x: 1.0, y: 0.0, z: 0.0});
+///
+/// // An impulse off the centre of mass also spins the body
+/// body.apply_impulse_at_point (&Vec3 {x: 0.0, y: 0.0, z: 1.0}, &Vec3 {x: 1.0, y: 0.0, z: 0.0});
+/// assert! (body.angular_velocity.y < 0.0);
+///
+/// // Static bodies have infinite mass
+/// body.set_body_type (BodyType::Static);
+/// assert_eq! (body.get_inverse_mass (), 0.0);
+/// ```
+#[derive (Clone)]
+pub struct RigidBody3D {
+
+    // Public
+    /// The position of the centre of mass.
+    pub position: Vec3,
+    /// The rotation. It is normalized each step.
+    pub rotation: Quat,
+    /// The linear velocity.
+    pub linear_velocity: Vec3,
+    /// The angular velocity, as an axis scaled by the speed in radians per second.
+    pub angular_velocity: Vec3,
+    /// The friction coefficient, usually from 0 to 1.
+    pub friction: f32,
+    /// The restitution (bounciness), from 0 to 1.
+    pub restitution: f32,
+    /// How quickly the linear velocity slows down.
+    pub linear_damping: f32,
+    /// How quickly the angular velocity slows down.
+    pub angular_damping: f32,
+    /// The amount of world gravity applied to the body.
+    pub gravity_scale: f32,
+    /// Is the body a trigger? Triggers report collision events, but are not pushed apart.
+    pub is_trigger: bool,
+    /// Which bodies the body collides with.
+    pub filter: CollisionFilter,
+    /// Can the body fall asleep when it stops moving?
+    pub can_sleep: bool,
+
+    // Private
+    _body_type: BodyType,
+    _shape: Shape3D,
+    _density: f32,
+    _mass: f32,
+    _inverse_mass: f32,
+    _inertia: Vec3,
+    _inverse_inertia: Vec3,
+    _force: Vec3,
+    _torque: Vec3,
+    _is_sleeping: bool,
+    _sleep_time: f32,
+}
+
+/*===============================================================================================*/
+/*------RIGID BODY 3D PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RigidBody3D {
+
+    /// Applies a force at the centre of mass until the next step.
+    pub fn apply_force (&mut self, force: &Vec3) {
+
+        if self._body_type == BodyType::Dynamic {
+
+            self._force += *force;
+            self.wake ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Applies a force at a world space point until the next step.
+    pub fn apply_force_at_point (&mut self, force: &Vec3, point: &Vec3) {
+
+        if self._body_type == BodyType::Dynamic {
+
+            self._force += *force;
+            self._torque += Vec3::cross (&(*point - self.position), force);
+            self.wake ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Applies a world space torque until the next step.
+    pub fn apply_torque (&mut self, torque: &Vec3) {
+
+        if self._body_type == BodyType::Dynamic {
+
+            self._torque += *torque;
+            self.wake ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Applies an impulse at the centre of mass, changing the velocity immediately.
+    pub fn apply_impulse (&mut self, impulse: &Vec3) {
+
+        if self._body_type == BodyType::Dynamic {
+
+            self.linear_velocity += *impulse * self._inverse_mass;
+            self.wake ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Applies an impulse at a world space point, changing the velocity immediately.
+    pub fn apply_impulse_at_point (&mut self, impulse: &Vec3, point: &Vec3) {
+
+        if self._body_type == BodyType::Dynamic {
+
+            let angular_impulse = Vec3::cross (&(*point - self.position), impulse);
+
+            self.linear_velocity += *impulse * self._inverse_mass;
+            self.angular_velocity += self.apply_inverse_inertia (&angular_impulse);
+            self.wake ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Multiplies a world space vector by the world space inverse inertia.
+    ///
+    /// This turns an angular impulse into a change in angular velocity.
+    pub fn apply_inverse_inertia (&self, vector: &Vec3) -> Vec3 {
+
+        let local = Quat::conjugate (&self.rotation) * *vector;
+        self.rotation * (local * self._inverse_inertia)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the world space bounds of the body.
+    pub fn get_aabb (&self) -> Aabb {
+        self._shape.get_aabb (&self.get_transform ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the body type.
+    pub fn get_body_type (&self) -> BodyType {
+        self._body_type
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the density.
+    pub fn get_density (&self) -> f32 {
+        self._density
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the rotational inertia around the local axes.
+    pub fn get_inertia (&self) -> Vec3 {
+        self._inertia
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the inverse of the rotational inertia around the local axes,
+    /// which is zero for bodies that do not rotate.
+    pub fn get_inverse_inertia (&self) -> Vec3 {
+        self._inverse_inertia
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the inverse of the mass, which is zero for static and kinematic bodies.
+    pub fn get_inverse_mass (&self) -> f32 {
+        self._inverse_mass
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the mass.
+    pub fn get_mass (&self) -> f32 {
+        self._mass
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the collision shape.
+    pub fn get_shape (&self) -> &Shape3D {
+        &self._shape
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the position and rotation of the body.
+    pub fn get_transform (&self) -> Transform3D {
+        Transform3D::new (self.position, self.rotation)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the velocity of a world space point on the body.
+    pub fn get_velocity_at_point (&self, point: &Vec3) -> Vec3 {
+        self.linear_velocity + Vec3::cross (&self.angular_velocity, &(*point - self.position))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves and rotates the body by its velocity.
+    ///
+    /// It is called by the world each step, and does nothing to static or sleeping bodies.
+    pub fn integrate_position (&mut self, delta_time: f32) {
+
+        if self._body_type != BodyType::Static && !self._is_sleeping {
+
+            let spin = Quat {x: self.angular_velocity.x,
+                             y: self.angular_velocity.y,
+                             z: self.angular_velocity.z,
+                             w: 0.0} * self.rotation;
+
+            self.position += self.linear_velocity * delta_time;
+
+            self.rotation = Quat::normalize (&Quat {x: self.rotation.x + spin.x * 0.5 * delta_time,
+                                                    y: self.rotation.y + spin.y * 0.5 * delta_time,
+                                                    z: self.rotation.z + spin.z * 0.5 * delta_time,
+                                                    w: self.rotation.w + spin.w * 0.5 * delta_time});
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Changes the velocity by gravity, the applied forces and damping, then clears the forces.
+    ///
+    /// It is called by the world each step, and does nothing to sleeping or non-dynamic bodies.
+    pub fn integrate_velocity (&mut self, gravity: &Vec3, delta_time: f32) {
+
+        if self._body_type == BodyType::Dynamic && !self._is_sleeping {
+
+            let torque = self._torque;
+
+            self.linear_velocity += (*gravity * self.gravity_scale + self._force * self._inverse_mass) * delta_time;
+            self.angular_velocity += self.apply_inverse_inertia (&torque) * delta_time;
+
+            self.linear_velocity *= 1.0 / (1.0 + delta_time * self.linear_damping);
+            self.angular_velocity *= 1.0 / (1.0 + delta_time * self.angular_damping);
+        }
+
+        self._force = Vec3::new ();
+        self._torque = Vec3::new ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Is the body asleep? Sleeping bodies are not simulated until something wakes them.
+    pub fn is_sleeping (&self) -> bool {
+        self._is_sleeping
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the body type, and updates the mass.
+    pub fn set_body_type (&mut self, body_type: BodyType) {
+
+        self._body_type = body_type;
+        self._update_mass ();
+        self.wake ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the density, and updates the mass.
+    pub fn set_density (&mut self, density: f32) {
+
+        self._density = density;
+        self._update_mass ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the collision shape, and updates the mass.
+    pub fn set_shape (&mut self, shape: Shape3D) {
+
+        self._shape = shape;
+        self._update_mass ();
+        self.wake ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Puts the body to sleep, and stops it.
+    pub fn sleep (&mut self) {
+
+        self._is_sleeping = true;
+        self._sleep_time = 0.0;
+        self._force = Vec3::new ();
+        self._torque = Vec3::new ();
+        self.linear_velocity = Vec3::new ();
+        self.angular_velocity = Vec3::new ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Updates how long the body has been resting, and returns it.
+    ///
+    /// The time is reset when the body moves faster than the tolerances, or cannot sleep.
+    ///
+    /// # Arguments
+    /// * `delta_time` - The length of the step.
+    /// * `linear_tolerance` - The speed below which the body is resting.
+    /// * `angular_tolerance` - The angular speed in radians per second below which the body is resting.
+    pub fn update_sleep_time (&mut self, delta_time: f32, linear_tolerance: f32, angular_tolerance: f32) -> f32 {
+
+        let speed_squared = Vec3::dot (&self.linear_velocity, &self.linear_velocity);
+        let angular_speed_squared = Vec3::dot (&self.angular_velocity, &self.angular_velocity);
+
+        if !self.can_sleep ||
+           speed_squared > linear_tolerance * linear_tolerance ||
+           angular_speed_squared > angular_tolerance * angular_tolerance {
+
+            self._sleep_time = 0.0;
+        }
+
+        else {
+            self._sleep_time += delta_time;
+        }
+
+        self._sleep_time
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Wakes the body up.
+    pub fn wake (&mut self) {
+
+        self._is_sleeping = false;
+        self._sleep_time = 0.0;
+    }
+
+/*===============================================================================================*/
+/*------RIGID BODY 3D PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new rigid body at the origin, with a density of one.
+    pub fn new (body_type: BodyType, shape: Shape3D) -> RigidBody3D {
+
+        let mut body = RigidBody3D {
+
+            position: Vec3::new (),
+            rotation: Quat::identity (),
+            linear_velocity: Vec3::new (),
+            angular_velocity: Vec3::new (),
+            friction: 0.5,
+            restitution: 0.0,
+            linear_damping: 0.0,
+            angular_damping: 0.0,
+            gravity_scale: 1.0,
+            is_trigger: false,
+            filter: CollisionFilter::default (),
+            can_sleep: true,
+
+            _body_type: body_type,
+            _shape: shape,
+            _density: 1.0,
+            _mass: 0.0,
+            _inverse_mass: 0.0,
+            _inertia: Vec3::new (),
+            _inverse_inertia: Vec3::new (),
+            _force: Vec3::new (),
+            _torque: Vec3::new (),
+            _is_sleeping: false,
+            _sleep_time: 0.0,
+        };
+
+        body._update_mass ();
+        body
+    }
+}
+
+/*===============================================================================================*/
+/*------RIGID BODY 3D PRIVATE METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RigidBody3D {
+
+    // Works out the mass and inertia from the shape and density.
+    fn _update_mass (&mut self) {
+
+        let (mass, inertia) = self._shape.get_mass_data (self._density);
+
+        self._mass = mass;
+        self._inertia = inertia;
+
+        if self._body_type == BodyType::Dynamic && mass > 0.0 {
+
+            let invert = |value: f32| if value > 0.0 {1.0 / value} else {0.0};
+
+            self._inverse_mass = 1.0 / mass;
+            self._inverse_inertia = Vec3 {x: invert (inertia.x), y: invert (inertia.y), z: invert (inertia.z)};
+        }
+
+        else {
+
+            self._inverse_mass = 0.0;
+            self._inverse_inertia = Vec3::new ();
+        }
+    }
+}
@@ -0,0 +1,685 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::physics_3d::Transform3D;
+use ::util::math::{Aabb, Capsule, Ray, Sphere, Util, Vec3, EPSILON};
+
+use std::cmp::Ordering;
+use std::f32;
+use std::f32::consts;
+
+// Static variables
+// The corners of each box face in counter-clockwise order seen from outside.
+// The bits of a corner index give the signs of its x, y and z coordinates.
+static BOX_FACES: [[usize; 4]; 6] = [[0, 4, 6, 2], [1, 3, 7, 5],
+                                     [0, 1, 5, 4], [2, 6, 7, 3],
+                                     [0, 2, 3, 1], [4, 5, 7, 6]];
+
+/*===============================================================================================*/
+/*------SHAPE 3D ENUM----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The collision shape of a 3D rigid body.
+///
+/// Shapes are defined in the local space of the body, with the centre of mass at the origin.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::physics_3d::{Shape3D, Transform3D};
+/// # use ion_core::util::math::{Ray, Vec3};
+/// #
+/// let shape = Shape3D::Capsule {half_height: 1.0, radius: 0.5};
+///
+/// let aabb = shape.get_aabb (&Transform3D::default ());
+/// assert! (aabb.min == Vec3 {x: -0.5, y: -1.5, z: -0.5});
+///
+/// let ray = Ray::new (&Vec3 {x: 0.0, y: 5.0, z: 0.0}, &Vec3::down ());
+/// let (distance, normal) = shape.raycast (&Transform3D::default (), &ray, 10.0).unwrap ();
+///
+/// assert_eq! (distance, 3.5);
+/// assert! (normal == Vec3::up ());
+/// ```
+#[derive (Clone, Serialize, Deserialize)]
+pub enum Shape3D {
+
+    /// A sphere.
+    Sphere {
+        /// The radius of the sphere.
+        radius: f32
+    },
+
+    /// A box.
+    Box {
+        /// Half of the size of the box along each axis.
+        half_extents: Vec3
+    },
+
+    /// A capsule, lying along the y axis.
+    Capsule {
+        /// Half of the distance between the centres of the end caps.
+        half_height: f32,
+        /// The radius of the capsule.
+        radius: f32
+    },
+
+    /// A convex hull.
+    ///
+    /// Use `Shape3D::new_convex_hull` to build a valid hull from a set of points.
+    ConvexHull {
+        /// The vertices of the hull.
+        vertices: Vec<Vec3>,
+        /// The vertex indices of each face, in counter-clockwise order seen from outside.
+        faces: Vec<Vec<usize>>
+    },
+}
+
+/*===============================================================================================*/
+/*------SHAPE 3D PUBLIC METHODS------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Shape3D {
+
+    /// Returns the point of the shape furthest along a direction.
+    pub fn get_support (&self, direction: &Vec3) -> Vec3 {
+
+        let core = self.get_core_support (direction);
+        let margin = self.get_margin ();
+
+        if margin > 0.0 {
+            core + Vec3::normalize (direction) * margin
+        }
+
+        else {
+            core
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the point of the core shape furthest along a direction.
+    ///
+    /// Spheres and capsules are a point and a segment inflated by their radius.
+    /// The core is the shape before it is inflated.
+    pub fn get_core_support (&self, direction: &Vec3) -> Vec3 {
+
+        match *self {
+
+            Shape3D::Sphere {..} => Vec3::new (),
+
+            Shape3D::Box {half_extents} => {
+
+                Vec3 {x: if direction.x < 0.0 {-half_extents.x} else {half_extents.x},
+                      y: if direction.y < 0.0 {-half_extents.y} else {half_extents.y},
+                      z: if direction.z < 0.0 {-half_extents.z} else {half_extents.z}}
+            },
+
+            Shape3D::Capsule {half_height, ..} => {
+                Vec3 {x: 0.0, y: if direction.y < 0.0 {-half_height} else {half_height}, z: 0.0}
+            },
+
+            Shape3D::ConvexHull {ref vertices, ..} => {
+
+                let mut support = Vec3::new ();
+                let mut max_dot = f32::MIN;
+
+                for vertex in vertices.iter () {
+
+                    let dot = Vec3::dot (vertex, direction);
+
+                    if dot > max_dot {
+
+                        support = *vertex;
+                        max_dot = dot;
+                    }
+                }
+
+                support
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the radius the core shape is inflated by.
+    pub fn get_margin (&self) -> f32 {
+
+        match *self {
+
+            Shape3D::Sphere {radius} | Shape3D::Capsule {radius, ..} => radius,
+            _ => 0.0
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the vertices of the shape.
+    ///
+    /// Spheres and capsules have no vertices, and return an empty vector.
+    pub fn get_vertices (&self) -> Vec<Vec3> {
+
+        match *self {
+
+            Shape3D::Box {half_extents} => {
+
+                (0..8).map (|corner| Vec3 {x: if corner & 1 == 0 {-half_extents.x} else {half_extents.x},
+                                           y: if corner & 2 == 0 {-half_extents.y} else {half_extents.y},
+                                           z: if corner & 4 == 0 {-half_extents.z} else {half_extents.z}}).collect ()
+            },
+
+            Shape3D::ConvexHull {ref vertices, ..} => vertices.clone (),
+            _ => Vec::new ()
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the faces of the shape, each in counter-clockwise order seen from outside.
+    ///
+    /// Spheres and capsules have no faces, and return an empty vector.
+    pub fn get_faces (&self) -> Vec<Vec<Vec3>> {
+
+        let vertices = self.get_vertices ();
+
+        match *self {
+
+            Shape3D::Box {..} => {
+                BOX_FACES.iter ().map (|face| face.iter ().map (|&index| vertices[index]).collect ()).collect ()
+            },
+
+            Shape3D::ConvexHull {ref faces, ..} => {
+                faces.iter ().map (|face| face.iter ().map (|&index| vertices[index]).collect ()).collect ()
+            },
+
+            _ => Vec::new ()
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the mass, and the rotational inertia about each local axis.
+    ///
+    /// The inertia of a convex hull ignores its products of inertia, so it is exact
+    /// only for hulls that are symmetrical about their axes.
+    ///
+    /// # Arguments
+    /// * `density` - The mass per unit volume.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::physics_3d::Shape3D;
+    /// # use ion_core::util::math::Vec3;
+    /// #
+    /// let shape = Shape3D::Box {half_extents: Vec3 {x: 1.0, y: 2.0, z: 1.0}};
+    /// let (mass, inertia) = shape.get_mass_data (0.5);
+    ///
+    /// assert_eq! (mass, 8.0);
+    /// assert! ((inertia.x - 40.0 / 3.0).abs () < 0.0001 && (inertia.y - 16.0 / 3.0).abs () < 0.0001);
+    ///
+    /// // A hull of the same box has the same mass
+    /// let hull = Shape3D::new_convex_hull (&shape.get_vertices ()).unwrap ();
+    /// let (hull_mass, hull_inertia) = hull.get_mass_data (0.5);
+    ///
+    /// assert! ((hull_mass - mass).abs () < 0.0001);
+    /// assert! (Vec3::distance (&hull_inertia, &inertia) < 0.0001);
+    /// ```
+    pub fn get_mass_data (&self, density: f32) -> (f32, Vec3) {
+
+        match *self {
+
+            Shape3D::Sphere {radius} => {
+
+                let mass = density * 4.0 / 3.0 * consts::PI * radius * radius * radius;
+                let inertia = 0.4 * mass * radius * radius;
+
+                (mass, Vec3 {x: inertia, y: inertia, z: inertia})
+            },
+
+            Shape3D::Box {half_extents} => {
+
+                let mass = density * 8.0 * half_extents.x * half_extents.y * half_extents.z;
+                let squared = half_extents * half_extents;
+
+                (mass, Vec3 {x: mass * (squared.y + squared.z) / 3.0,
+                             y: mass * (squared.x + squared.z) / 3.0,
+                             z: mass * (squared.x + squared.y) / 3.0})
+            },
+
+            Shape3D::Capsule {half_height, radius} => {
+
+                let height = half_height * 2.0;
+                let radius_squared = radius * radius;
+
+                let cylinder_mass = density * consts::PI * radius_squared * height;
+                let caps_mass = density * 4.0 / 3.0 * consts::PI * radius_squared * radius;
+
+                let axial = cylinder_mass * radius_squared * 0.5 + caps_mass * radius_squared * 0.4;
+                let lateral = cylinder_mass * (height * height / 12.0 + radius_squared * 0.25) +
+                              caps_mass * (radius_squared * 0.4 + height * height * 0.25 + height * radius * 0.375);
+
+                (cylinder_mass + caps_mass, Vec3 {x: lateral, y: axial, z: lateral})
+            },
+
+            Shape3D::ConvexHull {..} => {
+
+                let mut volume = 0.0;
+                let mut second_moments = Vec3::new ();
+
+                // Sum the tetrahedra between the origin and each face triangle
+                for face in self.get_faces () {
+
+                    for i in 1..face.len () - 1 {
+
+                        let (a, b, c) = (face[0], face[i], face[i + 1]);
+                        let determinant = Vec3::dot (&a, &Vec3::cross (&b, &c));
+
+                        volume += determinant / 6.0;
+                        second_moments += (a * a + b * b + c * c + a * b + a * c + b * c) * (determinant / 60.0);
+                    }
+                }
+
+                (density * volume, Vec3 {x: density * (second_moments.y + second_moments.z),
+                                         y: density * (second_moments.x + second_moments.z),
+                                         z: density * (second_moments.x + second_moments.y)})
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the world space bounds of the shape.
+    pub fn get_aabb (&self, transform: &Transform3D) -> Aabb {
+
+        match *self {
+
+            Shape3D::Sphere {radius} => {
+                Aabb::from_centre_extents (&transform.position, &Vec3 {x: radius, y: radius, z: radius})
+            },
+
+            Shape3D::Capsule {half_height, radius} => {
+
+                let axis = transform.rotate (&Vec3 {x: 0.0, y: half_height, z: 0.0});
+                let extents = Vec3 {x: axis.x.abs () + radius, y: axis.y.abs () + radius, z: axis.z.abs () + radius};
+
+                Aabb::from_centre_extents (&transform.position, &extents)
+            },
+
+            _ => {
+
+                let vertices: Vec<Vec3> = self.get_vertices ().iter ().map (|v| transform.apply (v)).collect ();
+                Aabb::from_points (&vertices)
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance along a ray to the shape, and the world space surface normal.
+    ///
+    /// Rays starting inside the shape hit it at a distance of zero, with the normal facing
+    /// back along the ray.
+    ///
+    /// # Arguments
+    /// * `transform` - The transform of the shape.
+    /// * `ray` - The ray, in world space.
+    /// * `max_distance` - How far along the ray to look.
+    pub fn raycast (&self, transform: &Transform3D, ray: &Ray, max_distance: f32) -> Option<(f32, Vec3)> {
+
+        let local_ray = Ray {origin: transform.inverse_apply (&ray.origin),
+                             direction: transform.inverse_rotate (&ray.direction)};
+
+        let hit = match *self {
+
+            Shape3D::Sphere {radius} => {
+
+                local_ray.intersect_sphere (&Sphere::new (&Vec3::new (), radius)).map (|distance| {
+                    (distance, Vec3::normalize (&local_ray.get_point (distance)))
+                })
+            },
+
+            Shape3D::Capsule {half_height, radius} => {
+
+                let capsule = Capsule::new (&Vec3 {x: 0.0, y: -half_height, z: 0.0},
+                                            &Vec3 {x: 0.0, y:  half_height, z: 0.0},
+                                            radius);
+
+                local_ray.intersect_capsule (&capsule).map (|distance| {
+
+                    let point = local_ray.get_point (distance);
+                    let axis_point = Vec3 {x: 0.0, y: Util::clamp (point.y, -half_height, half_height), z: 0.0};
+
+                    (distance, Vec3::normalize (&(point - axis_point)))
+                })
+            },
+
+            _ => Shape3D::_raycast_faces (&self.get_faces (), &local_ray)
+        };
+
+        match hit {
+
+            Some ((distance, normal)) if distance <= max_distance => {
+
+                if distance > 0.0 {
+                    Some ((distance, transform.rotate (&normal)))
+                }
+
+                else {
+                    Some ((0.0, -ray.direction))
+                }
+            },
+
+            _ => None
+        }
+    }
+
+/*===============================================================================================*/
+/*------SHAPE 3D PUBLIC STATIC METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a convex hull wrapping a set of points.
+    ///
+    /// The hull is moved so its centroid is at the origin.
+    /// It fails if the points do not enclose a volume.
+    ///
+    /// # Arguments
+    /// * `points` - The points to wrap. Interior points are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::physics::physics_3d::Shape3D;
+    /// # use ion_core::util::math::Vec3;
+    /// #
+    /// // A square based pyramid, with a point inside it
+    /// let points = [Vec3 {x: -1.0, y: 0.0, z: -1.0}, Vec3 {x: 1.0, y: 0.0, z: -1.0},
+    ///               Vec3 {x: 1.0, y: 0.0, z: 1.0}, Vec3 {x: -1.0, y: 0.0, z: 1.0},
+    ///               Vec3 {x: 0.0, y: 4.0, z: 0.0}, Vec3 {x: 0.0, y: 1.0, z: 0.0}];
+    ///
+    /// let hull = Shape3D::new_convex_hull (&points).unwrap ();
+    ///
+    /// assert_eq! (hull.get_vertices ().len (), 5);
+    /// assert_eq! (hull.get_faces ().len (), 5);
+    ///
+    /// // The centroid of a pyramid is a quarter of the way up
+    /// assert! (Vec3::distance (&hull.get_support (&Vec3::up ()), &Vec3 {x: 0.0, y: 3.0, z: 0.0}) < 0.0001);
+    ///
+    /// // Flat sets of points have no volume
+    /// assert! (Shape3D::new_convex_hull (&points[0..4]).is_err ());
+    /// ```
+    pub fn new_convex_hull (points: &[Vec3]) -> Result<Shape3D, ()> {
+
+        let (vertices, faces) = try! (Shape3D::_build_convex_hull (points));
+        let hull = Shape3D::ConvexHull {vertices: vertices.clone (), faces: faces.clone ()};
+
+        // Find the centroid
+        let mut volume = 0.0;
+        let mut centroid = Vec3::new ();
+
+        for face in hull.get_faces () {
+
+            for i in 1..face.len () - 1 {
+
+                let (a, b, c) = (face[0], face[i], face[i + 1]);
+                let tetrahedron_volume = Vec3::dot (&a, &Vec3::cross (&b, &c)) / 6.0;
+
+                volume += tetrahedron_volume;
+                centroid += (a + b + c) * (tetrahedron_volume / 4.0);
+            }
+        }
+
+        if volume < EPSILON {
+            return Err (());
+        }
+
+        centroid /= volume;
+        Ok (Shape3D::ConvexHull {vertices: vertices.iter ().map (|v| *v - centroid).collect (), faces: faces})
+    }
+}
+
+/*===============================================================================================*/
+/*------SHAPE 3D PRIVATE STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Shape3D {
+
+    // Builds the convex hull of a set of points, adding one point at a time.
+    // Returns the hull vertices, and the faces as polygons of vertex indices.
+    fn _build_convex_hull (points: &[Vec3]) -> Result<(Vec<Vec3>, Vec<Vec<usize>>), ()> {
+
+        if points.len () < 4 {
+            return Err (());
+        }
+
+        // Scale the tolerance to the size of the points
+        let bounds = Aabb::from_points (points);
+        let tolerance = Vec3::distance (&bounds.min, &bounds.max) * 0.00001;
+
+        // Start with the largest tetrahedron that is easy to find
+        let first = 0;
+        let second = Shape3D::_find_furthest (points, |p| Vec3::distance (p, &points[first]));
+
+        let line = Vec3::normalize (&(points[second] - points[first]));
+        let third = Shape3D::_find_furthest (points, |p| Vec3::length (&Vec3::cross (&(*p - points[first]), &line)));
+
+        let normal = Vec3::normalize (&Vec3::cross (&line, &(points[third] - points[first])));
+        let fourth = Shape3D::_find_furthest (points, |p| Vec3::dot (&(*p - points[first]), &normal).abs ());
+
+        if Vec3::distance (&points[first], &points[second]) <= tolerance ||
+           Vec3::length (&Vec3::cross (&(points[third] - points[first]), &line)) <= tolerance ||
+           Vec3::dot (&(points[fourth] - points[first]), &normal).abs () <= tolerance {
+
+            return Err (());
+        }
+
+        let initial = [first, second, third, fourth];
+        let inside = (points[first] + points[second] + points[third] + points[fourth]) * 0.25;
+
+        let mut triangles: Vec<[usize; 3]> = vec! [[first, second, third], [first, third, fourth],
+                                                   [first, fourth, second], [second, fourth, third]];
+
+        for triangle in triangles.iter_mut () {
+
+            if Vec3::dot (&Shape3D::_get_triangle_normal (points, triangle), &(inside - points[triangle[0]])) > 0.0 {
+                triangle.swap (1, 2);
+            }
+        }
+
+        // Add the rest of the points
+        for (index, point) in points.iter ().enumerate () {
+
+            if initial.contains (&index) {
+                continue;
+            }
+
+            let visible: Vec<bool> = triangles.iter ().map (|triangle| {
+                Vec3::dot (&Shape3D::_get_triangle_normal (points, triangle), &(*point - points[triangle[0]])) > tolerance
+            }).collect ();
+
+            if !visible.contains (&true) {
+                continue;
+            }
+
+            // The horizon is the edges between the visible and the hidden triangles
+            let mut horizon = Vec::new ();
+
+            for (triangle, _) in triangles.iter ().zip (visible.iter ()).filter (|&(_, &is_visible)| is_visible) {
+
+                for edge in 0..3 {
+
+                    let (start, end) = (triangle[edge], triangle[(edge + 1) % 3]);
+
+                    // The neighbouring triangle has the same edge in the other direction
+                    let is_neighbour_visible = triangles.iter ().zip (visible.iter ()).any (|(other, &is_visible)| {
+                        is_visible && (0..3).any (|i| other[i] == end && other[(i + 1) % 3] == start)
+                    });
+
+                    if !is_neighbour_visible {
+                        horizon.push ((start, end));
+                    }
+                }
+            }
+
+            let mut kept: Vec<[usize; 3]> = triangles.iter ()
+                                                     .zip (visible.iter ())
+                                                     .filter (|&(_, &is_visible)| !is_visible)
+                                                     .map (|(triangle, _)| *triangle)
+                                                     .collect ();
+
+            kept.extend (horizon.iter ().map (|&(start, end)| [start, end, index]));
+            triangles = kept;
+        }
+
+        // Merge the triangles in each plane into a single face
+        let mut is_merged = vec! [false; triangles.len ()];
+        let mut faces: Vec<Vec<usize>> = Vec::new ();
+
+        for i in 0..triangles.len () {
+
+            if is_merged[i] {
+                continue;
+            }
+
+            let normal = Shape3D::_get_triangle_normal (points, &triangles[i]);
+            let offset = Vec3::dot (&normal, &points[triangles[i][0]]);
+            let mut face: Vec<usize> = Vec::new ();
+
+            for j in i..triangles.len () {
+
+                if !is_merged[j] &&
+                   Vec3::dot (&Shape3D::_get_triangle_normal (points, &triangles[j]), &normal) > 0.9999 &&
+                   (Vec3::dot (&normal, &points[triangles[j][0]]) - offset).abs () <= tolerance {
+
+                    is_merged[j] = true;
+
+                    for &vertex in triangles[j].iter () {
+
+                        if !face.contains (&vertex) {
+                            face.push (vertex);
+                        }
+                    }
+                }
+            }
+
+            // Sort the vertices counter-clockwise around the centre of the face
+            let centre = face.iter ().fold (Vec3::new (), |sum, &vertex| sum + points[vertex]) / face.len () as f32;
+            let u = Vec3::normalize (&(points[face[0]] - centre));
+            let v = Vec3::cross (&normal, &u);
+
+            face.sort_by (|&a, &b| {
+
+                let angle_a = Vec3::dot (&(points[a] - centre), &v).atan2 (Vec3::dot (&(points[a] - centre), &u));
+                let angle_b = Vec3::dot (&(points[b] - centre), &v).atan2 (Vec3::dot (&(points[b] - centre), &u));
+
+                angle_a.partial_cmp (&angle_b).unwrap_or (Ordering::Equal)
+            });
+
+            faces.push (face);
+        }
+
+        // Keep only the points used by the faces
+        let mut used: Vec<usize> = faces.iter ().flat_map (|face| face.iter ().cloned ()).collect ();
+
+        used.sort ();
+        used.dedup ();
+
+        let vertices = used.iter ().map (|&index| points[index]).collect ();
+        let faces = faces.iter ().map (|face| {
+            face.iter ().map (|vertex| used.binary_search (vertex).unwrap ()).collect ()
+        }).collect ();
+
+        Ok ((vertices, faces))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the index of the point with the largest score.
+    fn _find_furthest<F> (points: &[Vec3], score: F) -> usize
+        where F: Fn (&Vec3) -> f32 {
+
+        let mut furthest = 0;
+        let mut max_score = f32::MIN;
+
+        for (index, point) in points.iter ().enumerate () {
+
+            let point_score = score (point);
+
+            if point_score > max_score {
+
+                furthest = index;
+                max_score = point_score;
+            }
+        }
+
+        furthest
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the normal of a triangle of points.
+    fn _get_triangle_normal (points: &[Vec3], triangle: &[usize; 3]) -> Vec3 {
+
+        Vec3::normalize (&Vec3::cross (&(points[triangle[1]] - points[triangle[0]]),
+                                       &(points[triangle[2]] - points[triangle[0]])))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the distance along a ray to a convex polyhedron, and the normal of the face hit.
+    fn _raycast_faces (faces: &[Vec<Vec3>], ray: &Ray) -> Option<(f32, Vec3)> {
+
+        let mut near = 0.0;
+        let mut far = f32::MAX;
+        let mut normal = -ray.direction;
+
+        for face in faces.iter () {
+
+            let face_normal = Vec3::normalize (&Vec3::cross (&(face[1] - face[0]), &(face[2] - face[0])));
+            let distance = Vec3::dot (&face_normal, &(face[0] - ray.origin));
+            let speed = Vec3::dot (&face_normal, &ray.direction);
+
+            // Parallel to the face, and outside it
+            if speed.abs () < EPSILON {
+
+                if distance < 0.0 {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let t = distance / speed;
+
+            // Entering through the face
+            if speed < 0.0 {
+
+                if t > near {
+
+                    near = t;
+                    normal = face_normal;
+                }
+            }
+
+            else {
+                far = Util::min (far, t);
+            }
+
+            if near > far {
+                return None;
+            }
+        }
+
+        Some ((near, normal))
+    }
+}
@@ -0,0 +1,103 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Mat4, Quat, Vec3};
+
+/*===============================================================================================*/
+/*------TRANSFORM 3D STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The position and rotation of a 3D rigid body.
+///
+/// # Examples
+/// ```
+/// # use ion_core::physics::physics_3d::Transform3D;
+/// # use ion_core::util::math::{Quat, Vec3};
+/// #
+/// let transform = Transform3D::new (Vec3 {x: 0.0, y: 2.0, z: 0.0}, Quat::from_axis_angle (&Vec3::forward (), 90.0));
+/// let point = transform.apply (&Vec3::right ());
+///
+/// assert! (Vec3::distance (&point, &Vec3 {x: 0.0, y: 3.0, z: 0.0}) < 0.0001);
+/// assert! (Vec3::distance (&transform.inverse_apply (&point), &Vec3::right ()) < 0.0001);
+/// ```
+#[derive (Copy, Clone)]
+pub struct Transform3D {
+
+    // Public
+    /// The position.
+    pub position: Vec3,
+    /// The rotation. It should be normalized.
+    pub rotation: Quat,
+}
+
+/*===============================================================================================*/
+/*------TRANSFORM 3D PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Transform3D {
+
+    /// Transforms a point from local to world space.
+    pub fn apply (&self, point: &Vec3) -> Vec3 {
+        self.rotation * *point + self.position
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Transforms a point from world to local space.
+    pub fn inverse_apply (&self, point: &Vec3) -> Vec3 {
+        self.inverse_rotate (&(*point - self.position))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Rotates a vector from local to world space.
+    pub fn rotate (&self, vector: &Vec3) -> Vec3 {
+        self.rotation * *vector
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Rotates a vector from world to local space.
+    pub fn inverse_rotate (&self, vector: &Vec3) -> Vec3 {
+        Quat::conjugate (&self.rotation) * *vector
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the transform as a model matrix.
+    pub fn to_matrix (&self) -> Mat4 {
+        Mat4::translate (&self.position) * Quat::to_mat4 (&self.rotation)
+    }
+
+/*===============================================================================================*/
+/*------TRANSFORM 3D PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new transform.
+    pub fn new (position: Vec3, rotation: Quat) -> Transform3D {
+        Transform3D {position: position, rotation: rotation}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for Transform3D {
+
+    fn default () -> Transform3D {
+        Transform3D::new (Vec3::new (), Quat::identity ())
+    }
+}
@@ -0,0 +1,71 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::physics::physics_2d::PhysicsWorld2D;
+use ::physics::physics_3d::PhysicsWorld3D;
+
+/*===============================================================================================*/
+/*------PHYSICS MANAGER STRUCT-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The physics manager.
+///
+/// It owns the physics worlds, and steps them from the fixed update of the app.
+pub struct PhysicsManager {
+
+    // Public
+    /// The 2D physics world.
+    pub world_2d: PhysicsWorld2D,
+    /// The 3D physics world.
+    pub world_3d: PhysicsWorld3D,
+}
+
+/*===============================================================================================*/
+/*------PHYSICS MANAGER PUBLIC METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PhysicsManager {
+
+    /// Advances the physics worlds by a fixed time step.
+    pub fn fixed_update (&mut self, delta_time: f32) {
+
+        self.world_2d.step (delta_time);
+        self.world_3d.step (delta_time);
+    }
+
+/*===============================================================================================*/
+/*------PHYSICS MANAGER PUBLIC STATIC METHODS----------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new physics manager.
+    pub fn new () -> PhysicsManager {
+
+        PhysicsManager {
+            world_2d: PhysicsWorld2D::new (),
+            world_3d: PhysicsWorld3D::new (),
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for PhysicsManager {
+
+    fn default () -> PhysicsManager {
+        PhysicsManager::new ()
+    }
+}
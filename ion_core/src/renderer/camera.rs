@@ -0,0 +1,293 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::CommandBuffer;
+use ::util::math::{Colour, Frustum, Mat4, Ray, Util, Vec2, Vec3, Vec4};
+
+/*===============================================================================================*/
+/*------CAMERA PROJECTION ENUM-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The projection used by a camera.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CameraProjection {
+
+    /// Perspective projection, using the camera's field of view.
+    Perspective,
+    /// Orthographic projection, using the camera's orthographic size.
+    Orthographic,
+}
+
+/*===============================================================================================*/
+/*------CAMERA STRUCT----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A camera, used to view the scene.
+///
+/// It manages the view and projection matrices, the viewport and the clear colour.
+#[derive (Copy, Clone, Serialize, Deserialize)]
+pub struct Camera {
+
+    // Public
+    /// The projection type.
+    pub projection: CameraProjection,
+    /// The position of the camera.
+    pub position: Vec3,
+    /// The point the camera looks at.
+    pub target: Vec3,
+    /// The up direction of the camera.
+    pub up: Vec3,
+    /// The vertical field of view in degrees. Used for perspective projection.
+    pub fov: f32,
+    /// The height of the view volume. Used for orthographic projection.
+    pub ortho_size: f32,
+    /// The distance to the near clipping plane.
+    pub near: f32,
+    /// The distance to the far clipping plane.
+    pub far: f32,
+    /// The aspect ratio (width / height).
+    pub aspect: f32,
+    /// Should the aspect ratio be updated when the window is resized?
+    pub auto_aspect: bool,
+    /// The viewport rectangle (x, y, width, height), normalized to the render target size.
+    ///
+    /// The origin is the bottom left corner.
+    pub viewport: Vec4,
+    /// The colour the viewport is cleared to.
+    pub clear_colour: Colour,
+}
+
+/*===============================================================================================*/
+/*------CAMERA PUBLIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Camera {
+
+    /// Returns the view matrix.
+    pub fn get_view_matrix (&self) -> Mat4 {
+        Mat4::view (&self.position, &self.target, &self.up)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the projection matrix.
+    pub fn get_projection_matrix (&self) -> Mat4 {
+        self.get_projection_matrix_range (self.near, self.far)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the projection matrix, limited to a range of view distances.
+    ///
+    /// It is used to split the view frustum, e.g. for shadow cascades.
+    pub fn get_projection_matrix_range (&self, near: f32, far: f32) -> Mat4 {
+
+        match self.projection {
+
+            CameraProjection::Perspective => {
+                Mat4::perspective (Util::deg2rad (self.fov), self.aspect, near, far)
+            },
+
+            CameraProjection::Orthographic => {
+
+                let half_height = self.ortho_size / 2.0;
+                let half_width  = half_height * self.aspect;
+
+                Mat4::ortho (-half_width, half_width, half_height, -half_height, near, far)
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the combined view-projection matrix.
+    pub fn get_view_projection_matrix (&self) -> Mat4 {
+        self.get_projection_matrix () * self.get_view_matrix ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the view frustum, used to skip objects the camera can not see.
+    pub fn get_frustum (&self) -> Frustum {
+        Frustum::from_matrix (&self.get_view_projection_matrix ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the viewport in pixels (x, y, width, height), for a render target size.
+    pub fn get_viewport_pixels (&self, target_size: &Vec2) -> (i32, i32, u32, u32) {
+
+        (( self.viewport.x * target_size.x) as i32,
+         ( self.viewport.y * target_size.y) as i32,
+         ((self.viewport.z * target_size.x) as u32),
+         ((self.viewport.w * target_size.y) as u32))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Updates the aspect ratio from the render target size.
+    ///
+    /// Does nothing if `auto_aspect` is disabled.
+    pub fn on_resize (&mut self, target_size: &Vec2) {
+
+        let width  = self.viewport.z * target_size.x;
+        let height = self.viewport.w * target_size.y;
+
+        if self.auto_aspect && width > 0.0 && height > 0.0 {
+            self.aspect = width / height;
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Records the viewport and clear commands of the camera.
+    ///
+    /// Only the viewport is cleared. `target_size` is the size of the target in pixels,
+    /// which for the window is `Window::get_drawable_size`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::Camera;
+    /// # use ion_core::renderer::command::CommandBuffer;
+    /// # use ion_core::renderer::gpu::RenderTargetDesc;
+    /// # use ion_core::renderer::mock::RenderFactoryMock;
+    /// # use ion_core::renderer::traits::RenderFactory;
+    /// # use ion_core::util::math::{Colour, Vec2, Vec4};
+    /// #
+    /// let factory = RenderFactoryMock::new ();
+    /// let target = factory.create_render_target (&RenderTargetDesc::new (4, 2)).unwrap ();
+    ///
+    /// // A camera drawing to the left half of the target
+    /// let mut camera = Camera::new ();
+    /// camera.viewport     = Vec4 {x: 0.0, y: 0.0, z: 0.5, w: 1.0};
+    /// camera.clear_colour = Colour::blue ();
+    ///
+    /// let mut cb = CommandBuffer::new ();
+    /// cb.set_render_target (Some (target.clone ()));
+    /// cb.clear (Some (Colour::red ()), None, None);
+    /// camera.begin (&mut cb, &Vec2 {x: 4.0, y: 2.0});
+    ///
+    /// factory.execute_command_buffer (&cb);
+    ///
+    /// let image = factory.read_pixels (Some (target), 0, 0, 4, 2).unwrap ();
+    ///
+    /// assert_eq! (image.get_pixel (1, 0).b, 1.0);
+    /// assert_eq! (image.get_pixel (2, 0).r, 1.0);
+    /// ```
+    pub fn begin (&self, command_buffer: &mut CommandBuffer, target_size: &Vec2) {
+
+        let (x, y, width, height) = self.get_viewport_pixels (target_size);
+
+        // The clear ignores the viewport, so it is limited by the scissor rect
+        command_buffer.set_viewport (x, y, width, height);
+        command_buffer.set_scissor  (Some ((x, y, width, height)));
+        command_buffer.clear (Some (self.clear_colour), Some (1.0), Some (0));
+        command_buffer.set_scissor  (None);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a world space ray through a screen position.
+    ///
+    /// The screen position is in pixels, with the origin at the top left corner.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::Camera;
+    /// # use ion_core::util::math::{Vec2, Vec3};
+    /// #
+    /// let mut camera = Camera::new ();
+    /// camera.position = Vec3 {x: 0.0, y: 0.0, z: 5.0};
+    /// camera.target   = Vec3::new ();
+    ///
+    /// let size = Vec2 {x: 800.0, y: 600.0};
+    /// camera.on_resize (&size);
+    ///
+    /// let ray = camera.screen_to_world_ray (&Vec2 {x: 400.0, y: 300.0}, &size);
+    ///
+    /// assert! (ray.direction.x.abs () < 0.001);
+    /// assert! (ray.direction.y.abs () < 0.001);
+    /// assert! ((ray.direction.z + 1.0).abs () < 0.001);
+    /// ```
+    pub fn screen_to_world_ray (&self, screen_pos: &Vec2, target_size: &Vec2) -> Ray {
+
+        // Convert to normalized device coordinates within the viewport
+        let viewport_x = self.viewport.x * target_size.x;
+        let viewport_y = self.viewport.y * target_size.y;
+        let viewport_w = self.viewport.z * target_size.x;
+        let viewport_h = self.viewport.w * target_size.y;
+
+        let ndc_x = ((screen_pos.x - viewport_x) / viewport_w) * 2.0 - 1.0;
+        let ndc_y = (((target_size.y - screen_pos.y) - viewport_y) / viewport_h) * 2.0 - 1.0;
+
+        // Unproject points on the near and far planes
+        let inverse = Mat4::inverse (&self.get_view_projection_matrix ());
+        let near    = Camera::_unproject (&inverse, ndc_x, ndc_y, -1.0);
+        let far     = Camera::_unproject (&inverse, ndc_x, ndc_y,  1.0);
+
+        Ray::new (&near, &(far - near))
+    }
+
+/*===============================================================================================*/
+/*------CAMERA PUBLIC STATIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new perspective camera.
+    ///
+    /// It is positioned at the origin, looking down the negative z axis.
+    pub fn new () -> Camera {
+
+        Camera {projection: CameraProjection::Perspective,
+                position: Vec3::new (),
+                target: Vec3 {x: 0.0, y: 0.0, z: -1.0},
+                up: Vec3 {x: 0.0, y: 1.0, z: 0.0},
+                fov: 60.0,
+                ortho_size: 10.0,
+                near: 0.1,
+                far: 1000.0,
+                aspect: 4.0 / 3.0,
+                auto_aspect: true,
+                viewport: Vec4 {x: 0.0, y: 0.0, z: 1.0, w: 1.0},
+                clear_colour: Colour::black ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for Camera {
+
+    fn default () -> Camera {
+        Camera::new ()
+    }
+}
+
+/*===============================================================================================*/
+/*------CAMERA PRIVATE STATIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Camera {
+
+    // Transforms a point in normalized device coordinates by an inverse view-projection matrix.
+    fn _unproject (inverse: &Mat4, x: f32, y: f32, z: f32) -> Vec3 {
+
+        let point = *inverse * Vec4 {x: x, y: y, z: z, w: 1.0};
+
+        Vec3 {x: point.x / point.w,
+              y: point.y / point.w,
+              z: point.z / point.w}
+    }
+}
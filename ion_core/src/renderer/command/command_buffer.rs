@@ -0,0 +1,188 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::RenderCommand;
+use ::renderer::gpu::{IndexFormat, RenderState, UniformValue};
+use ::renderer::traits::{Buffer, Pipeline, RenderTarget, Texture};
+use ::util::math::Colour;
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------COMMAND BUFFER STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Records render commands to be executed by the render backend.
+///
+/// Nothing is drawn while recording.
+/// The buffer is submitted through `RenderManager::submit`, or directly to a `RenderFactory`.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::command::CommandBuffer;
+/// # use ion_core::util::math::Colour;
+/// #
+/// let mut cmd = CommandBuffer::new ();
+/// cmd.clear (Some (Colour::black ()), Some (1.0), None);
+///
+/// assert_eq! (cmd.len (), 1);
+/// ```
+#[derive (Clone, Default)]
+pub struct CommandBuffer {
+
+    // Private
+    _commands: Vec<RenderCommand>,
+}
+
+/*===============================================================================================*/
+/*------COMMAND BUFFER PUBLIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl CommandBuffer {
+
+    /// Sets the target that following commands draw into.
+    ///
+    /// `None` draws into the window.
+    pub fn set_render_target (&mut self, target: Option<Rc<RenderTarget>>) {
+        self._commands.push (RenderCommand::SetRenderTarget (target));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the viewport rectangle in pixels.
+    pub fn set_viewport (&mut self, x: i32, y: i32, width: u32, height: u32) {
+        self._commands.push (RenderCommand::SetViewport (x, y, width, height));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Limits drawing and clearing to a rectangle in pixels.
+    ///
+    /// `None` disables the scissor test.
+    pub fn set_scissor (&mut self, rect: Option<(i32, i32, u32, u32)>) {
+        self._commands.push (RenderCommand::SetScissor (rect));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Clears the current target.
+    ///
+    /// Only the attachments that are `Some` are cleared.
+    pub fn clear (&mut self, colour: Option<Colour>, depth: Option<f32>, stencil: Option<i32>) {
+        self._commands.push (RenderCommand::Clear {colour: colour, depth: depth, stencil: stencil});
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the blend, cull and depth state.
+    pub fn set_render_state (&mut self, state: RenderState) {
+        self._commands.push (RenderCommand::SetRenderState (state));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Binds a pipeline.
+    pub fn bind_pipeline (&mut self, pipeline: Rc<Pipeline>) {
+        self._commands.push (RenderCommand::BindPipeline (pipeline));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Binds a vertex buffer.
+    pub fn bind_vertex_buffer (&mut self, buffer: Rc<Buffer>) {
+        self._commands.push (RenderCommand::BindVertexBuffer (buffer));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Binds an index buffer.
+    pub fn bind_index_buffer (&mut self, buffer: Rc<Buffer>, format: IndexFormat) {
+        self._commands.push (RenderCommand::BindIndexBuffer (buffer, format));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Binds a texture to a texture slot.
+    pub fn bind_texture (&mut self, slot: u32, texture: Rc<Texture>) {
+        self._commands.push (RenderCommand::BindTexture (slot, texture));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets a uniform on the bound pipeline.
+    pub fn set_uniform (&mut self, name: &str, value: UniformValue) {
+        self._commands.push (RenderCommand::SetUniform (name.to_string (), value));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Draws vertices from the bound vertex buffer.
+    pub fn draw (&mut self, first_vertex: u32, vertex_count: u32) {
+        self._commands.push (RenderCommand::Draw (first_vertex, vertex_count));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Draws indexed vertices from the bound vertex and index buffers.
+    pub fn draw_indexed (&mut self, first_index: u32, index_count: u32) {
+        self._commands.push (RenderCommand::DrawIndexed (first_index, index_count));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Appends the commands of another command buffer.
+    pub fn append (&mut self, other: &CommandBuffer) {
+        self._commands.extend (other._commands.iter ().cloned ());
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes all recorded commands.
+    pub fn reset (&mut self) {
+        self._commands.clear ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the recorded commands.
+    pub fn get_commands (&self) -> &[RenderCommand] {
+        &self._commands
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of recorded commands.
+    pub fn len (&self) -> usize {
+        self._commands.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether any commands have been recorded.
+    pub fn is_empty (&self) -> bool {
+        self._commands.is_empty ()
+    }
+
+/*===============================================================================================*/
+/*------COMMAND BUFFER PUBLIC STATIC METHODS-----------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty command buffer.
+    pub fn new () -> CommandBuffer {
+        CommandBuffer {_commands: Vec::new ()}
+    }
+}
@@ -0,0 +1,29 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The command module.
+//!
+//! Rendering is done by recording commands into a `CommandBuffer`,  
+//! which is then executed by the active render backend.
+/*===============================================================================================*/
+
+mod command_buffer;
+mod render_command;
+
+pub use self::command_buffer::CommandBuffer;
+pub use self::render_command::RenderCommand;
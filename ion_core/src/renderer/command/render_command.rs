@@ -0,0 +1,66 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::{IndexFormat, RenderState, UniformValue};
+use ::renderer::traits::{Buffer, Pipeline, RenderTarget, Texture};
+use ::util::math::Colour;
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------RENDER COMMAND ENUM----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A single command recorded into a `CommandBuffer`.
+#[derive (Clone)]
+pub enum RenderCommand {
+
+    /// Sets the target that following commands draw into.  
+    /// `None` draws into the window.
+    SetRenderTarget (Option<Rc<RenderTarget>>),
+    /// Sets the viewport rectangle (x, y, width, height) in pixels.
+    SetViewport (i32, i32, u32, u32),
+    /// Limits drawing and clearing to a rectangle (x, y, width, height) in pixels.  
+    /// `None` disables the scissor test.
+    SetScissor (Option<(i32, i32, u32, u32)>),
+    /// Clears the current target.  
+    /// Only the attachments that are `Some` are cleared.
+    Clear {
+        /// The clear colour.
+        colour: Option<Colour>,
+        /// The clear depth.
+        depth: Option<f32>,
+        /// The clear stencil value.
+        stencil: Option<i32>
+    },
+    /// Sets the blend, cull and depth state.
+    SetRenderState (RenderState),
+    /// Binds a pipeline.
+    BindPipeline (Rc<Pipeline>),
+    /// Binds a vertex buffer.
+    BindVertexBuffer (Rc<Buffer>),
+    /// Binds an index buffer.
+    BindIndexBuffer (Rc<Buffer>, IndexFormat),
+    /// Binds a texture to a texture slot.
+    BindTexture (u32, Rc<Texture>),
+    /// Sets a uniform on the bound pipeline.
+    SetUniform (String, UniformValue),
+    /// Draws vertices from the bound vertex buffer (first vertex, vertex count).
+    Draw (u32, u32),
+    /// Draws indexed vertices from the bound index buffer (first index, index count).
+    DrawIndexed (u32, u32),
+}
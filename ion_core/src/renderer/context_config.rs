@@ -0,0 +1,87 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------CONTEXT PROFILE ENUM---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The profile of the graphics context.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ContextProfile {
+
+    /// Core profile. Deprecated functionality is unavailable.
+    Core,
+    /// Compatibility profile. Deprecated functionality is available.
+    Compatibility,
+    /// Embedded systems profile.
+    ES,
+}
+
+/*===============================================================================================*/
+/*------CONTEXT CONFIG STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Stores the settings used when creating the graphics context.
+#[derive (Copy, Clone, Deserialize, Serialize)]
+pub struct ContextConfig {
+
+    // Public
+    /// The major version of the graphics API.
+    pub context_version_major: u8,
+    /// The minor version of the graphics API.
+    pub context_version_minor: u8,
+    /// The context profile.
+    pub context_profile: ContextProfile,
+    /// Should buffer swaps wait for the vertical blank?
+    pub context_vsync: bool,
+    /// The number of MSAA samples. Zero disables multisampling.
+    pub context_msaa_samples: u8,
+    /// The number of bits in the depth buffer.
+    pub context_depth_bits: u8,
+    /// The number of bits in the stencil buffer.
+    pub context_stencil_bits: u8,
+}
+
+/*===============================================================================================*/
+/*------CONTEXT CONFIG PUBLIC STATIC METHODS-----------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ContextConfig {
+
+    /// Returns a new Context Config instance.
+    ///
+    /// It requests a 3.3 core profile, with vsync and a 24-bit depth / 8-bit stencil buffer.
+    pub fn new () -> ContextConfig {
+
+        ContextConfig {context_version_major: 3,
+                       context_version_minor: 3,
+                       context_profile: ContextProfile::Core,
+                       context_vsync: true,
+                       context_msaa_samples: 0,
+                       context_depth_bits: 24,
+                       context_stencil_bits: 8}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for ContextConfig {
+
+    fn default () -> ContextConfig {
+        ContextConfig::new ()
+    }
+}
@@ -0,0 +1,329 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::debug_draw::{DebugText, DebugVertex};
+use ::util::math::{Colour, Mat4, Vec3, Vec4};
+
+use std::f32::consts::PI;
+
+/*===============================================================================================*/
+/*------DEBUG DRAW LIST STRUCT-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Collects debug lines and labels, until they expire.
+///
+/// Every shape is broken down into lines. Each line and label has a duration in seconds.  
+/// A duration of zero draws it for a single frame.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::debug_draw::DebugDrawList;
+/// # use ion_core::util::math::{Colour, Vec3};
+/// #
+/// let mut list = DebugDrawList::new ();
+///
+/// list.aabb (&Vec3::new (), &Vec3 {x: 1.0, y: 1.0, z: 1.0}, &Colour::green (), 0.0);
+/// list.line (&Vec3::new (), &Vec3::up (), &Colour::red (), 1.0);
+///
+/// assert_eq! (list.get_line_count (), 13);
+///
+/// // The box only lasts a single frame
+/// list.update (0.5);
+/// assert_eq! (list.get_line_count (), 1);
+///
+/// list.update (0.5);
+/// assert_eq! (list.get_line_count (), 0);
+/// ```
+#[derive (Clone, Default)]
+pub struct DebugDrawList {
+
+    // Private
+    _vertices: Vec<DebugVertex>,
+    _line_durations: Vec<f32>,
+    _texts: Vec<DebugText>,
+}
+
+/*===============================================================================================*/
+/*------DEBUG DRAW LIST PUBLIC METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl DebugDrawList {
+
+    /// Adds a line.
+    pub fn line (&mut self, start: &Vec3, end: &Vec3, colour: &Colour, duration: f32) {
+
+        self._vertices.push (DebugVertex {position: *start, colour: *colour});
+        self._vertices.push (DebugVertex {position: *end,   colour: *colour});
+        self._line_durations.push (duration);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds an axis aligned box, from its minimum and maximum corners.
+    pub fn aabb (&mut self, min: &Vec3, max: &Vec3, colour: &Colour, duration: f32) {
+
+        let corners = [Vec3 {x: min.x, y: min.y, z: min.z},
+                       Vec3 {x: max.x, y: min.y, z: min.z},
+                       Vec3 {x: max.x, y: max.y, z: min.z},
+                       Vec3 {x: min.x, y: max.y, z: min.z},
+                       Vec3 {x: min.x, y: min.y, z: max.z},
+                       Vec3 {x: max.x, y: min.y, z: max.z},
+                       Vec3 {x: max.x, y: max.y, z: max.z},
+                       Vec3 {x: min.x, y: max.y, z: max.z}];
+
+        self._box_edges (&corners, colour, duration);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a box, by transforming a unit cube centred on the origin.
+    pub fn oriented_box (&mut self, transform: &Mat4, colour: &Colour, duration: f32) {
+
+        let mut corners = [Vec3::new (); 8];
+
+        for (i, corner) in corners.iter_mut ().enumerate () {
+
+            let x = if i & 1 == (i >> 1) & 1 {-0.5} else {0.5};
+            let y = if i & 2 == 0 {-0.5} else {0.5};
+            let z = if i & 4 == 0 {-0.5} else {0.5};
+
+            *corner = *transform * Vec3 {x: x, y: y, z: z};
+        }
+
+        self._box_edges (&corners, colour, duration);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a circle, facing along a normal.
+    pub fn circle (&mut self, centre: &Vec3, normal: &Vec3, radius: f32, colour: &Colour, duration: f32) {
+
+        let (u, v) = DebugDrawList::_get_perpendicular_axes (normal);
+        let segments = 32;
+
+        let mut previous = *centre + u * radius;
+
+        for i in 1..(segments + 1) {
+
+            let angle = i as f32 / segments as f32 * PI * 2.0;
+            let point = *centre + u * (angle.cos () * radius) + v * (angle.sin () * radius);
+
+            self.line (&previous, &point, colour, duration);
+            previous = point;
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a sphere, drawn as a circle around each axis.
+    pub fn sphere (&mut self, centre: &Vec3, radius: f32, colour: &Colour, duration: f32) {
+
+        self.circle (centre, &Vec3::right (), radius, colour, duration);
+        self.circle (centre, &Vec3::up (), radius, colour, duration);
+        self.circle (centre, &Vec3::forward (), radius, colour, duration);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds an arrow, with its head at the end.
+    pub fn arrow (&mut self, start: &Vec3, end: &Vec3, colour: &Colour, duration: f32) {
+
+        self.line (start, end, colour, duration);
+
+        let length = Vec3::distance (start, end);
+
+        if length <= 0.0 {
+            return;
+        }
+
+        let direction = (*end - *start) * (1.0 / length);
+        let (u, v) = DebugDrawList::_get_perpendicular_axes (&direction);
+
+        let head_length = length * 0.2;
+        let base = *end - direction * head_length;
+
+        for axis in &[u, v, u * -1.0, v * -1.0] {
+            self.line (end, &(base + *axis * (head_length * 0.5)), colour, duration);
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a grid on the XZ plane.
+    ///
+    /// # Arguments
+    /// * `centre` - The centre of the grid.
+    /// * `size` - The width and depth of the grid.
+    /// * `divisions` - The number of cells along each side.
+    pub fn grid (&mut self, centre: &Vec3, size: f32, divisions: u32, colour: &Colour, duration: f32) {
+
+        let half = size * 0.5;
+        let divisions = if divisions == 0 {1} else {divisions};
+
+        for i in 0..(divisions + 1) {
+
+            let offset = -half + size * i as f32 / divisions as f32;
+
+            self.line (&(*centre + Vec3 {x: offset, y: 0.0, z: -half}),
+                       &(*centre + Vec3 {x: offset, y: 0.0, z:  half}), colour, duration);
+
+            self.line (&(*centre + Vec3 {x: -half, y: 0.0, z: offset}),
+                       &(*centre + Vec3 {x:  half, y: 0.0, z: offset}), colour, duration);
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds the outline of the frustum of a view-projection matrix.
+    ///
+    /// Passing `Camera::get_view_projection_matrix` draws the volume the camera sees.
+    pub fn frustum (&mut self, view_projection: &Mat4, colour: &Colour, duration: f32) {
+
+        let inverse = Mat4::inverse (view_projection);
+        let mut corners = [Vec3::new (); 8];
+
+        for (i, corner) in corners.iter_mut ().enumerate () {
+
+            let x = if i & 1 == (i >> 1) & 1 {-1.0} else {1.0};
+            let y = if i & 2 == 0 {-1.0} else {1.0};
+            let z = if i & 4 == 0 {-1.0} else {1.0};
+
+            let point = inverse * Vec4 {x: x, y: y, z: z, w: 1.0};
+            *corner = Vec3 {x: point.x / point.w, y: point.y / point.w, z: point.z / point.w};
+        }
+
+        self._box_edges (&corners, colour, duration);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a label at a world position.
+    pub fn text (&mut self, position: &Vec3, text: &str, colour: &Colour, duration: f32) {
+
+        self._texts.push (DebugText {position: *position,
+                                     text: text.to_string (),
+                                     colour: *colour,
+                                     duration: duration});
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the line vertices, with two vertices per line.
+    pub fn get_vertices (&self) -> &[DebugVertex] {
+        &self._vertices
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of lines.
+    pub fn get_line_count (&self) -> usize {
+        self._line_durations.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the labels.
+    pub fn get_texts (&self) -> &[DebugText] {
+        &self._texts
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Advances time, and removes everything that has expired.
+    ///
+    /// This is called once a frame, after the list has been drawn.
+    pub fn update (&mut self, delta_time: f32) {
+
+        let mut kept = 0;
+
+        for i in 0..self._line_durations.len () {
+
+            let remaining = self._line_durations[i] - delta_time;
+
+            if remaining > 0.0 {
+
+                self._line_durations[kept] = remaining;
+                self._vertices[kept * 2]     = self._vertices[i * 2];
+                self._vertices[kept * 2 + 1] = self._vertices[i * 2 + 1];
+                kept += 1;
+            }
+        }
+
+        self._line_durations.truncate (kept);
+        self._vertices.truncate (kept * 2);
+
+        for text in &mut self._texts {
+            text.duration -= delta_time;
+        }
+
+        self._texts.retain (|t| t.duration > 0.0);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes all lines and labels.
+    pub fn clear (&mut self) {
+
+        self._vertices.clear ();
+        self._line_durations.clear ();
+        self._texts.clear ();
+    }
+
+/*===============================================================================================*/
+/*------DEBUG DRAW LIST PUBLIC STATIC METHODS----------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty list.
+    pub fn new () -> DebugDrawList {
+        DebugDrawList::default ()
+    }
+}
+
+/*===============================================================================================*/
+/*------DEBUG DRAW LIST PRIVATE METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl DebugDrawList {
+
+    // Adds the twelve edges of a box.
+    // The corners are ordered counter-clockwise around the near face, then the far face.
+    fn _box_edges (&mut self, corners: &[Vec3; 8], colour: &Colour, duration: f32) {
+
+        for i in 0..4 {
+
+            self.line (&corners[i],     &corners[(i + 1) % 4],     colour, duration);
+            self.line (&corners[i + 4], &corners[(i + 1) % 4 + 4], colour, duration);
+            self.line (&corners[i],     &corners[i + 4],           colour, duration);
+        }
+    }
+
+/*===============================================================================================*/
+/*------DEBUG DRAW LIST PRIVATE STATIC METHODS---------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Returns two unit axes perpendicular to a direction, and each other.
+    fn _get_perpendicular_axes (direction: &Vec3) -> (Vec3, Vec3) {
+
+        let normal = Vec3::normalize (direction);
+        let helper = if normal.y.abs () < 0.9 {Vec3::up ()} else {Vec3::right ()};
+
+        let u = Vec3::normalize (&Vec3::cross (&normal, &helper));
+        let v = Vec3::cross (&normal, &u);
+
+        (u, v)
+    }
+}
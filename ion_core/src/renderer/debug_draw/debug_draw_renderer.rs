@@ -0,0 +1,266 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::Camera;
+use ::renderer::command::CommandBuffer;
+use ::renderer::debug_draw::{DebugDrawList, DebugVertex};
+use ::renderer::gpu::{BlendMode, BufferDesc, BufferType, BufferUsage, BuiltinShader, CullMode};
+use ::renderer::gpu::{PipelineDesc, PrimitiveType, RenderState, ShaderStage, UniformValue};
+use ::renderer::sprite::SpriteBatch;
+use ::renderer::text::{Font, TextStyle};
+use ::renderer::traits::{Buffer, Pipeline, RenderFactory};
+use ::util::Bytes;
+use ::util::math::{Mat4, Vec2, Vec4};
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------DEBUG DRAW RENDERER STRUCT---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Draws a `DebugDrawList` on top of a camera's view.
+///
+/// Lines are drawn without depth testing, in a single draw call.  
+/// Labels are drawn in screen space at the projected position of their anchor,  
+/// and are skipped if no font has been set.
+///
+/// Each call to `render` writes its lines into a vertex buffer which no command buffer
+/// refers to, so the list can be recorded for several cameras into one command buffer.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::Camera;
+/// # use ion_core::renderer::command::CommandBuffer;
+/// # use ion_core::renderer::debug_draw::{DebugDrawList, DebugDrawRenderer};
+/// # use ion_core::renderer::mock::RenderFactoryMock;
+/// # use ion_core::renderer::traits::RenderFactory;
+/// # use ion_core::util::math::{Colour, Vec2, Vec3};
+/// #
+/// let factory = RenderFactoryMock::new ();
+/// let mut renderer = DebugDrawRenderer::new (&factory).unwrap ();
+///
+/// let mut list = DebugDrawList::new ();
+/// list.sphere (&Vec3::new (), 1.0, &Colour::white (), 0.0);
+/// list.grid (&Vec3::new (), 10.0, 10, &Colour::white (), 0.0);
+///
+/// let mut commands = CommandBuffer::new ();
+/// renderer.render (&factory, &mut commands, &list, &Camera::new (), &Vec2 {x: 800.0, y: 600.0}).unwrap ();
+/// factory.execute_command_buffer (&commands);
+///
+/// assert_eq! (factory.get_draw_call_count (), 1);
+/// assert_eq! (factory.get_vertex_count (), list.get_vertices ().len ());
+/// ```
+pub struct DebugDrawRenderer {
+
+    // Private
+    _pipeline: Rc<Pipeline>,
+    _vertex_buffers: Vec<Rc<Buffer>>,
+    _sprite_batch: SpriteBatch,
+    _font: Option<Font>,
+}
+
+/*===============================================================================================*/
+/*------DEBUG DRAW RENDERER PUBLIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl DebugDrawRenderer {
+
+    /// Sets the font used for labels.
+    ///
+    /// The font textures are created if they haven't been already.
+    pub fn set_font (&mut self, render_factory: &RenderFactory, mut font: Font) -> Result<(), ()> {
+
+        if font.get_textures ().len () < font.get_pages ().len () {
+            try! (font.load_textures (render_factory));
+        }
+
+        self._font = Some (font);
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Records the lines and labels of a list, as seen by a camera.
+    ///
+    /// # Arguments
+    /// * `render_factory` - The factory used to create or resize the vertex buffer.
+    /// * `command_buffer` - The command buffer the list is recorded into.
+    /// * `list` - The lines and labels to draw.
+    /// * `camera` - The camera the list is seen through.
+    /// * `target_size` - The size of the render target in pixels.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::Camera;
+    /// # use ion_core::renderer::command::{CommandBuffer, RenderCommand};
+    /// # use ion_core::renderer::debug_draw::{DebugDrawList, DebugDrawRenderer};
+    /// # use ion_core::renderer::mock::{BufferMock, RenderFactoryMock};
+    /// # use ion_core::util::math::{Colour, Vec2, Vec3};
+    /// #
+    /// let factory = RenderFactoryMock::new ();
+    /// let mut renderer = DebugDrawRenderer::new (&factory).unwrap ();
+    /// let mut commands = CommandBuffer::new ();
+    ///
+    /// // A different list for each camera, recorded into the same command buffer
+    /// for length in [1.0, 2.0].iter () {
+    ///
+    ///     let mut list = DebugDrawList::new ();
+    ///     list.line (&Vec3::new (), &Vec3 {x: *length, y: 0.0, z: 0.0}, &Colour::white (), 0.0);
+    ///
+    ///     renderer.render (&factory, &mut commands, &list, &Camera::new (), &Vec2 {x: 800.0, y: 600.0}).unwrap ();
+    /// }
+    ///
+    /// // The second camera's lines didn't overwrite the first's
+    /// let vertex_data: Vec<Vec<u8>> = commands.get_commands ().iter ().filter_map (|command| match *command {
+    ///
+    ///     RenderCommand::BindVertexBuffer (ref buffer) => buffer.as_any ().downcast_ref::<BufferMock> ()
+    ///                                                                     .map (|buffer| buffer.get_data ()),
+    ///     _ => None
+    ///
+    /// }).collect ();
+    ///
+    /// assert_eq! (vertex_data.len (), 2);
+    /// assert! (vertex_data[0] != vertex_data[1]);
+    /// ```
+    pub fn render (&mut self,
+                   render_factory: &RenderFactory,
+                   command_buffer: &mut CommandBuffer,
+                   list: &DebugDrawList,
+                   camera: &Camera,
+                   target_size: &Vec2) -> Result<(), ()> {
+
+        let (x, y, width, height) = camera.get_viewport_pixels (target_size);
+        let view_projection = camera.get_view_projection_matrix ();
+
+        command_buffer.set_viewport (x, y, width, height);
+
+        // Draw every line at once
+        if !list.get_vertices ().is_empty () {
+
+            // Debug vertices are made only of f32s, so they have no padding
+            let data = unsafe {Bytes::from_slice (list.get_vertices ())};
+            let buffer = try! (self._get_vertex_buffer (render_factory, data.len ()));
+
+            buffer.set_data (0, data);
+
+            command_buffer.set_render_state (DebugDrawRenderer::get_render_state ());
+            command_buffer.bind_pipeline (self._pipeline.clone ());
+            command_buffer.set_uniform ("u_view_projection", UniformValue::Mat4 (view_projection));
+            command_buffer.bind_vertex_buffer (buffer);
+            command_buffer.draw (0, list.get_vertices ().len () as u32);
+        }
+
+        // Draw the labels in pixels, from the bottom left of the viewport
+        if let Some (ref font) = self._font {
+
+            if list.get_texts ().is_empty () {
+                return Ok (());
+            }
+
+            self._sprite_batch.begin ();
+
+            for text in list.get_texts () {
+
+                let clip = view_projection * Vec4 {x: text.position.x, y: text.position.y, z: text.position.z, w: 1.0};
+
+                if clip.w <= 0.0 {
+                    continue;
+                }
+
+                let screen = Vec2 {x: (clip.x / clip.w * 0.5 + 0.5) * width as f32,
+                                   y: (clip.y / clip.w * 0.5 + 0.5) * height as f32};
+
+                let mut style = TextStyle::new ();
+                style.colour = text.colour;
+
+                font.draw_text (&mut self._sprite_batch, &text.text, &screen, &style);
+            }
+
+            let projection = Mat4::ortho (0.0, width as f32, height as f32, 0.0, -1.0, 1.0);
+            try! (self._sprite_batch.end (render_factory, command_buffer, &projection));
+        }
+
+        Ok (())
+    }
+
+/*===============================================================================================*/
+/*------DEBUG DRAW RENDERER PUBLIC STATIC METHODS------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns the render state debug lines are drawn with.
+    ///
+    /// Lines are alpha blended, and drawn over everything.
+    pub fn get_render_state () -> RenderState {
+
+        RenderState {blend_mode: BlendMode::Alpha,
+                     cull_mode: CullMode::None,
+                     depth_test: false,
+                     depth_write: false,
+                     .. RenderState::new ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new debug draw renderer.
+    ///
+    /// The built in line pipeline is created from the render factory.
+    pub fn new (render_factory: &RenderFactory) -> Result<DebugDrawRenderer, ()> {
+
+        let vertex_shader   = try! (render_factory.create_builtin_shader (BuiltinShader::DebugLine, ShaderStage::Vertex));
+        let fragment_shader = try! (render_factory.create_builtin_shader (BuiltinShader::DebugLine, ShaderStage::Fragment));
+
+        let mut desc = PipelineDesc::new (vertex_shader, fragment_shader, DebugVertex::get_vertex_layout ());
+        desc.primitive_type = PrimitiveType::Lines;
+
+        Ok (DebugDrawRenderer {_pipeline: try! (render_factory.create_pipeline (&desc)),
+                               _vertex_buffers: Vec::new (),
+                               _sprite_batch: try! (SpriteBatch::new (render_factory)),
+                               _font: None})
+    }
+}
+
+/*===============================================================================================*/
+/*------DEBUG DRAW RENDERER PRIVATE METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl DebugDrawRenderer {
+
+    // Returns a vertex buffer which no command buffer refers to,
+    // replacing it with a larger one if it's too small.
+    fn _get_vertex_buffer (&mut self, render_factory: &RenderFactory, size: usize) -> Result<Rc<Buffer>, ()> {
+
+        let unused = self._vertex_buffers.iter ().position (|buffer| Rc::strong_count (buffer) == 1);
+
+        if let Some (index) = unused {
+
+            if self._vertex_buffers[index].get_size () >= size {
+                return Ok (self._vertex_buffers[index].clone ());
+            }
+        }
+
+        let desc = BufferDesc::new (BufferType::Vertex, BufferUsage::Stream, size.next_power_of_two ());
+        let buffer = try! (render_factory.create_buffer (&desc, &[]));
+
+        match unused {
+
+            Some (index) => self._vertex_buffers[index] = buffer.clone (),
+            None => self._vertex_buffers.push (buffer.clone ())
+        }
+
+        Ok (buffer)
+    }
+}
@@ -0,0 +1,37 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Colour, Vec3};
+
+/*===============================================================================================*/
+/*------DEBUG TEXT STRUCT------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A label drawn at a world position.
+#[derive (Clone)]
+pub struct DebugText {
+
+    // Public
+    /// The world position the text is anchored to.
+    pub position: Vec3,
+    /// The text to draw.
+    pub text: String,
+    /// The colour of the text.
+    pub colour: Colour,
+    /// How long the text remains, in seconds.
+    pub duration: f32,
+}
@@ -0,0 +1,61 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::{VertexFormat, VertexLayout};
+use ::util::math::{Colour, Vec3};
+
+/*===============================================================================================*/
+/*------DEBUG VERTEX STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A single end of a debug line.
+#[repr (C)]
+#[derive (Copy, Clone, Default)]
+pub struct DebugVertex {
+
+    // Public
+    /// The world position of the vertex.
+    pub position: Vec3,
+    /// The colour of the vertex.
+    pub colour: Colour,
+}
+
+/*===============================================================================================*/
+/*------DEBUG VERTEX PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl DebugVertex {
+
+    /// Returns the vertex layout matching the vertex struct.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::debug_draw::DebugVertex;
+    /// # use std::mem;
+    /// #
+    /// assert_eq! (DebugVertex::get_vertex_layout ().stride, mem::size_of::<DebugVertex> ());
+    /// ```
+    pub fn get_vertex_layout () -> VertexLayout {
+
+        let mut layout = VertexLayout::new ();
+
+        layout.push ("a_position", VertexFormat::Float3)
+              .push ("a_colour",   VertexFormat::Float4);
+
+        layout
+    }
+}
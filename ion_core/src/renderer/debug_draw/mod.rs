@@ -0,0 +1,127 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The debug draw module.
+//!
+//! Lines, shapes and labels can be drawn from anywhere during a frame, using the functions  
+//! in this module. They are collected into a per-thread `DebugDrawList`, and the Render Manager  
+//! draws it on top of the scene for every camera when the frame is presented.
+//!
+//! Every call takes a duration in seconds. A duration of zero draws for a single frame.  
+//! As `box` is a reserved word, boxes are drawn with `aabb` or `oriented_box`.
+//!
+//! # Examples
+//! ```
+//! # use ion_core::renderer::debug_draw;
+//! # use ion_core::util::math::{Colour, Vec3};
+//! #
+//! debug_draw::arrow (&Vec3::new (), &Vec3::up (), &Colour::green (), 0.0);
+//! debug_draw::text (&Vec3::up (), "Up", &Colour::white (), 2.0);
+//!
+//! debug_draw::with_list (|list| assert_eq! (list.get_texts ().len (), 1));
+//! ```
+/*===============================================================================================*/
+
+mod debug_draw_list;
+mod debug_draw_renderer;
+mod debug_text;
+mod debug_vertex;
+
+pub use self::debug_draw_list::DebugDrawList;
+pub use self::debug_draw_renderer::DebugDrawRenderer;
+pub use self::debug_text::DebugText;
+pub use self::debug_vertex::DebugVertex;
+
+use ::util::math::{Colour, Mat4, Vec3};
+
+use std::cell::RefCell;
+
+// The list the functions of this module draw into.
+thread_local! (static DEBUG_DRAW_LIST: RefCell<DebugDrawList> = RefCell::new (DebugDrawList::new ()));
+
+/*===============================================================================================*/
+/*------PUBLIC FUNCTIONS-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Gives access to the debug draw list of the current thread.
+pub fn with_list<F, R> (f: F) -> R where F: FnOnce (&mut DebugDrawList) -> R {
+    DEBUG_DRAW_LIST.with (|list| f (&mut list.borrow_mut ()))
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Draws a line.
+pub fn line (start: &Vec3, end: &Vec3, colour: &Colour, duration: f32) {
+    with_list (|list| list.line (start, end, colour, duration));
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Draws an axis aligned box, from its minimum and maximum corners.
+pub fn aabb (min: &Vec3, max: &Vec3, colour: &Colour, duration: f32) {
+    with_list (|list| list.aabb (min, max, colour, duration));
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Draws a box, by transforming a unit cube centred on the origin.
+pub fn oriented_box (transform: &Mat4, colour: &Colour, duration: f32) {
+    with_list (|list| list.oriented_box (transform, colour, duration));
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Draws a circle, facing along a normal.
+pub fn circle (centre: &Vec3, normal: &Vec3, radius: f32, colour: &Colour, duration: f32) {
+    with_list (|list| list.circle (centre, normal, radius, colour, duration));
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Draws a sphere.
+pub fn sphere (centre: &Vec3, radius: f32, colour: &Colour, duration: f32) {
+    with_list (|list| list.sphere (centre, radius, colour, duration));
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Draws an arrow, with its head at the end.
+pub fn arrow (start: &Vec3, end: &Vec3, colour: &Colour, duration: f32) {
+    with_list (|list| list.arrow (start, end, colour, duration));
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Draws a grid on the XZ plane, with `divisions` cells along each side.
+pub fn grid (centre: &Vec3, size: f32, divisions: u32, colour: &Colour, duration: f32) {
+    with_list (|list| list.grid (centre, size, divisions, colour, duration));
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Draws the outline of the frustum of a view-projection matrix.
+pub fn frustum (view_projection: &Mat4, colour: &Colour, duration: f32) {
+    with_list (|list| list.frustum (view_projection, colour, duration));
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Draws a label at a world position.
+pub fn text (position: &Vec3, text: &str, colour: &Colour, duration: f32) {
+    with_list (|list| list.text (position, text, colour, duration));
+}
@@ -0,0 +1,146 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------FRAME CAPTURE STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Tracks the screenshots and recorded frames requested for the upcoming frames.
+///
+/// It only decides which file each presented frame is written to;
+/// the Render Manager reads back the framebuffer and saves the images.  
+/// Recorded frames are numbered, so they can be assembled into a video or compared
+/// against reference images.
+pub struct FrameCapture {
+
+    // Private
+    _screenshots: Vec<String>,
+    _record_prefix: String,
+    _record_remaining: u32,
+    _record_index: u32,
+}
+
+/*===============================================================================================*/
+/*------FRAME CAPTURE PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl FrameCapture {
+
+    /// Requests a screenshot of the next presented frame.
+    pub fn capture_screenshot (&mut self, path: &str) {
+        self._screenshots.push (path.to_string ());
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Starts recording the next `count` presented frames.
+    ///
+    /// Each frame is written to `<prefix>_<index>.png`, with the index padded to five digits.  
+    /// Starting a new recording replaces the one in progress.
+    pub fn record_frames (&mut self, prefix: &str, count: u32) {
+
+        self._record_prefix = prefix.to_string ();
+        self._record_remaining = count;
+        self._record_index = 0;
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Stops the recording in progress.
+    pub fn stop_recording (&mut self) {
+        self._record_remaining = 0;
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether frames are being recorded.
+    pub fn is_recording (&self) -> bool {
+        self._record_remaining > 0
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of frames left to record.
+    pub fn get_remaining_frames (&self) -> u32 {
+        self._record_remaining
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether the next presented frame has to be read back.
+    pub fn is_pending (&self) -> bool {
+        !self._screenshots.is_empty () || self.is_recording ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Advances to the next frame, and returns the paths it has to be written to.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::FrameCapture;
+    /// #
+    /// let mut capture = FrameCapture::new ();
+    ///
+    /// capture.capture_screenshot ("shot.png");
+    /// capture.record_frames ("trailer/frame", 2);
+    ///
+    /// assert_eq! (capture.next_frame (), vec! ["shot.png", "trailer/frame_00000.png"]);
+    /// assert_eq! (capture.next_frame (), vec! ["trailer/frame_00001.png"]);
+    /// assert! (capture.next_frame ().is_empty ());
+    /// assert! (!capture.is_recording ());
+    /// ```
+    pub fn next_frame (&mut self) -> Vec<String> {
+
+        let mut paths: Vec<String> = self._screenshots.drain (..).collect ();
+
+        if self._record_remaining > 0 {
+
+            paths.push (format! ("{}_{:05}.png", self._record_prefix, self._record_index));
+
+            self._record_remaining -= 1;
+            self._record_index += 1;
+        }
+
+        paths
+    }
+
+/*===============================================================================================*/
+/*------FRAME CAPTURE PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new frame capture, with nothing requested.
+    pub fn new () -> FrameCapture {
+
+        FrameCapture {
+
+            _screenshots: Vec::new (),
+            _record_prefix: String::new (),
+            _record_remaining: 0,
+            _record_index: 0,
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for FrameCapture {
+
+    fn default () -> FrameCapture {
+        FrameCapture::new ()
+    }
+}
@@ -0,0 +1,314 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::CommandBuffer;
+use ::renderer::gpu::RenderTargetDesc;
+use ::renderer::traits::RenderFactory;
+use ::util::image::{Image, ImageDiff};
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Static variables
+/// The environment variable that replaces the reference images with the rendered ones.
+///
+/// Set it to `1` to re-bless every golden test that runs.
+pub static GOLDEN_BLESS_VARIABLE: &'static str = "ION_BLESS_GOLDEN";
+
+/*===============================================================================================*/
+/*------GOLDEN TEST STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A rendering regression test, comparing a rendered frame against a stored reference image.
+///
+/// The scene is recorded into an offscreen target of the render backend, and read back.  
+/// On failure the rendered image and a diff image are written to the output directory,
+/// so the change can be reviewed. Running with `ION_BLESS_GOLDEN=1` replaces the references
+/// with the rendered images instead.  
+/// The reference images of the engine's own scenes are stored in `resources/golden`.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::GoldenTest;
+/// # use ion_core::renderer::gpu::{TextureDesc, TextureFormat};
+/// # use ion_core::renderer::mock::RenderFactoryMock;
+/// # use ion_core::renderer::sprite::{Sprite, SpriteBatch};
+/// # use ion_core::renderer::traits::RenderFactory;
+/// # use ion_core::util::math::{Colour, Mat4, Vec2};
+/// #
+/// let factory = RenderFactoryMock::new ();
+/// let checker = factory.create_texture (&TextureDesc::new (2, 2, TextureFormat::RGBA8),
+///                                       &[255, 255, 255, 255,   0,   0,   0, 255,
+///                                           0,   0,   0, 255, 255, 255, 255, 255]).unwrap ();
+///
+/// // An opaque square, a rotated translucent square over it, and a textured square
+/// let mut red = Sprite::new ();
+/// red.position = Vec2 {x: 24.0, y: 24.0};
+/// red.size     = Vec2 {x: 24.0, y: 24.0};
+/// red.tint     = Colour::red ();
+///
+/// let mut blue = red.clone ();
+/// blue.position = Vec2 {x: 34.0, y: 30.0};
+/// blue.rotation = 30.0;
+/// blue.tint     = Colour {r: 0.0, g: 0.0, b: 1.0, a: 0.5};
+/// blue.z_order  = 1;
+///
+/// let mut board = Sprite::from_texture (checker);
+/// board.position = Vec2 {x: 50.0, y: 50.0};
+/// board.size     = Vec2 {x: 16.0, y: 16.0};
+///
+/// let mut batch = SpriteBatch::new (&factory).unwrap ();
+/// let mut test  = GoldenTest::new ("sprites", concat! (env! ("CARGO_MANIFEST_DIR"), "/../resources/golden"));
+/// test.width  = 64;
+/// test.height = 64;
+///
+/// let result = test.run (&factory, |cb| {
+///
+///     cb.clear (Some (Colour::dark_grey ()), Some (1.0), None);
+///
+///     batch.begin ();
+///     batch.draw (&red);
+///     batch.draw (&blue);
+///     batch.draw (&board);
+///     batch.end (&factory, cb, &Mat4::ortho (0.0, 64.0, 64.0, 0.0, -1.0, 1.0)).unwrap ();
+/// });
+///
+/// assert! (result.is_ok ());
+/// ```
+pub struct GoldenTest {
+
+    // Public
+    /// The test name, used for the image file names.
+    pub name: String,
+    /// The directory the reference images are stored in.
+    pub reference_directory: String,
+    /// The directory the rendered and diff images of failed tests are written to.
+    pub output_directory: String,
+    /// The width of the rendered frame.
+    pub width: u32,
+    /// The height of the rendered frame.
+    pub height: u32,
+    /// The largest per-channel difference allowed for a pixel, in the 0-1 range.
+    pub tolerance: f32,
+    /// The number of pixels allowed to exceed the tolerance.
+    pub max_mismatched_pixels: usize,
+    /// The lowest perceptual similarity allowed, see `ImageDiff::similarity`.
+    pub min_similarity: f32,
+    /// Replace the reference image instead of comparing against it.  
+    /// Defaults to whether `ION_BLESS_GOLDEN` is set to `1`.
+    pub bless: bool,
+}
+
+/*===============================================================================================*/
+/*------GOLDEN TEST PUBLIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl GoldenTest {
+
+    /// Renders a scene offscreen, and checks it against the reference image.
+    ///
+    /// `record` is called with the target and viewport already set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::GoldenTest;
+    /// # use ion_core::renderer::mock::RenderFactoryMock;
+    /// # use ion_core::util::math::Colour;
+    /// # use std::env;
+    /// #
+    /// let directory = env::temp_dir ().join ("ion_golden_example").display ().to_string ();
+    /// let factory = RenderFactoryMock::new ();
+    ///
+    /// let mut test = GoldenTest::new ("clear", &directory);
+    /// test.output_directory = format! ("{}/failures", directory);
+    ///
+    /// // Store the reference, then compare against it
+    /// test.bless = true;
+    /// test.run (&factory, |cb| cb.clear (Some (Colour::blue ()), Some (1.0), None)).unwrap ();
+    ///
+    /// test.bless = false;
+    /// assert! (test.run (&factory, |cb| cb.clear (Some (Colour::blue ()), Some (1.0), None)).is_ok ());
+    /// assert! (test.run (&factory, |cb| cb.clear (Some (Colour::red ()), Some (1.0), None)).is_err ());
+    /// assert! (env::temp_dir ().join ("ion_golden_example/failures/clear.diff.png").exists ());
+    /// ```
+    pub fn run<F> (&self, factory: &RenderFactory, record: F) -> Result<(), ()>
+        where F: FnOnce (&mut CommandBuffer) {
+
+        let target = match factory.create_render_target (&RenderTargetDesc::new (self.width, self.height)) {
+
+            Ok (target) => target,
+            Err (_) => {
+
+                error! ("Golden test \"{}\" could not create its render target.", &self.name);
+                return Err (());
+            }
+        };
+
+        let mut command_buffer = CommandBuffer::new ();
+
+        command_buffer.set_render_target (Some (target.clone ()));
+        command_buffer.set_viewport (0, 0, self.width, self.height);
+        record (&mut command_buffer);
+        command_buffer.set_render_target (None);
+
+        factory.execute_command_buffer (&command_buffer);
+
+        match factory.read_pixels (Some (target), 0, 0, self.width, self.height) {
+
+            Ok (image) => self.check (&image),
+            Err (_) => {
+
+                error! ("Golden test \"{}\" could not read back its render target.", &self.name);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Checks an image against the reference image.
+    ///
+    /// A missing reference fails the test, unless blessing is enabled.
+    pub fn check (&self, image: &Image) -> Result<(), ()> {
+
+        let reference_path = format! ("{}/{}.png", self.reference_directory, self.name);
+
+        if self.bless {
+
+            info! ("Blessing golden image \"{}\".", &reference_path);
+
+            try! (GoldenTest::_create_directory (&self.reference_directory));
+            return image.save_png (&reference_path);
+        }
+
+        let reference = match Image::load (&reference_path) {
+
+            Ok (reference) => reference,
+            Err (_) => {
+
+                error! ("Golden test \"{}\" has no reference image. Run with {}=1 to create it.",
+                        &self.name, GOLDEN_BLESS_VARIABLE);
+
+                self._write_failure (image, None);
+                return Err (());
+            }
+        };
+
+        let diff = match ImageDiff::compare (&reference, image, self.tolerance) {
+
+            Ok (diff) => diff,
+            Err (_) => {
+
+                error! ("Golden test \"{}\" rendered a different size to its reference image.", &self.name);
+
+                self._write_failure (image, None);
+                return Err (());
+            }
+        };
+
+        if diff.mismatched_pixels > self.max_mismatched_pixels || diff.similarity < self.min_similarity {
+
+            error! ("Golden test \"{}\" failed. {} pixels mismatched, max difference {}, similarity {}.",
+                    &self.name, diff.mismatched_pixels, diff.max_difference, diff.similarity);
+
+            self._write_failure (image, Some (&diff));
+            return Err (());
+        }
+
+        Ok (())
+    }
+
+/*===============================================================================================*/
+/*------GOLDEN TEST PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new golden test, with references stored in `reference_directory`.
+    ///
+    /// Renders at 256x256, allowing a channel difference of 2 / 255 and a similarity of 0.99.
+    pub fn new (name: &str, reference_directory: &str) -> GoldenTest {
+
+        GoldenTest {
+
+            name: name.to_string (),
+            reference_directory: reference_directory.to_string (),
+            output_directory: "target/golden".to_string (),
+            width: 256,
+            height: 256,
+            tolerance: 2.0 / 255.0,
+            max_mismatched_pixels: 0,
+            min_similarity: 0.99,
+            bless: GoldenTest::is_bless_enabled (),
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether the bless environment variable is set.
+    pub fn is_bless_enabled () -> bool {
+
+        match env::var (GOLDEN_BLESS_VARIABLE) {
+
+            Ok (value) => value == "1",
+            Err (_) => false
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------GOLDEN TEST PRIVATE METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl GoldenTest {
+
+    // Writes the rendered image, and the diff image if there is one, to the output directory.
+    fn _write_failure (&self, image: &Image, diff: Option<&ImageDiff>) {
+
+        if GoldenTest::_create_directory (&self.output_directory).is_err () {
+            return;
+        }
+
+        match image.save_png (&format! ("{}/{}.actual.png", self.output_directory, self.name)) {
+            Ok (_) | Err (_) => {}
+        }
+
+        if let Some (diff) = diff {
+
+            match diff.diff_image.save_png (&format! ("{}/{}.diff.png", self.output_directory, self.name)) {
+                Ok (_) | Err (_) => {}
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------GOLDEN TEST PRIVATE STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Creates a directory and its parents, if it does not exist.
+    fn _create_directory (directory: &str) -> Result<(), ()> {
+
+        match fs::create_dir_all (Path::new (directory)) {
+
+            Ok (_) => Ok (()),
+            Err (e) => {
+
+                error! ("Could not create directory \"{}\".\n{}", directory, e);
+                Err (())
+            }
+        }
+    }
+}
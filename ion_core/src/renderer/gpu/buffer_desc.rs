@@ -0,0 +1,92 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------BUFFER TYPE ENUM-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Defines what a buffer is used for.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum BufferType {
+
+    /// Vertex data.
+    Vertex,
+    /// Index data.
+    Index,
+}
+
+/*===============================================================================================*/
+/*------BUFFER USAGE ENUM------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Hints how often the contents of a buffer will change.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum BufferUsage {
+
+    /// Set once, and drawn many times.
+    Static,
+    /// Changed occasionally, and drawn many times.
+    Dynamic,
+    /// Changed every frame.
+    Stream,
+}
+
+/*===============================================================================================*/
+/*------INDEX FORMAT ENUM------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The size of each index in an index buffer.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum IndexFormat {
+
+    /// 16-bit indices.
+    U16,
+    /// 32-bit indices.
+    U32,
+}
+
+/*===============================================================================================*/
+/*------BUFFER DESC STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Describes a buffer to be created by the render backend.
+#[derive (Copy, Clone, Debug)]
+pub struct BufferDesc {
+
+    // Public
+    /// The buffer type.
+    pub buffer_type: BufferType,
+    /// The buffer usage.
+    pub buffer_usage: BufferUsage,
+    /// The size of the buffer in bytes.
+    pub buffer_size: usize,
+}
+
+/*===============================================================================================*/
+/*------BUFFER DESC PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BufferDesc {
+
+    /// Returns a new buffer description.
+    pub fn new (buffer_type: BufferType, buffer_usage: BufferUsage, buffer_size: usize) -> BufferDesc {
+
+        BufferDesc {buffer_type: buffer_type,
+                    buffer_usage: buffer_usage,
+                    buffer_size: buffer_size}
+    }
+}
@@ -0,0 +1,58 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::post_process::PostEffectType;
+
+/*===============================================================================================*/
+/*------BUILTIN SHADER ENUM----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A shader provided by the render backend itself.
+///
+/// Engine systems such as the sprite batcher use these, so they work  
+/// without any shader files in the resource directory.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum BuiltinShader {
+
+    /// Textured, vertex coloured 2D quads.  
+    /// Takes `a_position` (Float2), `a_uv` (Float2) and `a_colour` (Float4) attributes,  
+    /// and the `u_view_projection` and `u_texture` uniforms.
+    Sprite,
+    /// Vertex coloured lines.  
+    /// Takes `a_position` (Float3) and `a_colour` (Float4) attributes,  
+    /// and the `u_view_projection` uniform.
+    DebugLine,
+    /// Forward lit meshes, using up to the given number of lights.  
+    /// Takes the attributes of the mesh `Vertex`, the `u_model`, `u_view_projection`, `u_colour`,  
+    /// `u_texture` and `u_ambient` uniforms, the light uniforms bound by the `LightCuller`,  
+    /// and the shadow uniforms bound by the `ShadowRenderer`.
+    ForwardLit (u32),
+    /// Depth only meshes, for shadow maps.  
+    /// Takes the attributes of the mesh `Vertex`, and the `u_model` and `u_view_projection` uniforms.  
+    /// The depth is also written to the first colour channel.
+    ShadowDepth,
+    /// A full-screen post-processing effect.  
+    /// Takes an `a_position` (Float2) attribute covering the screen, the `u_texture` and `u_texel_size`  
+    /// uniforms, and the parameters of the effect.
+    PostEffect (PostEffectType),
+    /// The bright pass of the bloom effect. Takes the same inputs as `PostEffect`.
+    BloomExtract,
+    /// A separable gaussian blur along `u_blur_direction`. Takes the same inputs as `PostEffect`.
+    BloomBlur,
+    /// A full-screen copy of `u_texture`. Takes the same inputs as `PostEffect`.
+    Blit,
+}
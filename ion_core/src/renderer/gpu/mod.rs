@@ -0,0 +1,39 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The gpu module.
+//!
+//! Contains the backend agnostic descriptions of GPU resources.  
+//! They are passed to the active `RenderFactory`, which creates the actual resources.
+/*===============================================================================================*/
+
+mod buffer_desc;
+mod builtin_shader;
+mod pipeline_desc;
+mod render_state;
+mod render_target_desc;
+mod texture_desc;
+mod uniform_value;
+
+pub use self::buffer_desc::{BufferDesc, BufferType, BufferUsage, IndexFormat};
+pub use self::builtin_shader::BuiltinShader;
+pub use self::pipeline_desc::{PipelineDesc, PrimitiveType, ShaderStage, VertexAttribute, VertexFormat, VertexLayout};
+pub use self::render_state::{BlendMode, CullMode, DepthFunc, RenderState};
+pub use self::render_target_desc::RenderTargetDesc;
+pub use self::texture_desc::{TextureDesc, TextureFilter, TextureFormat, TextureWrap};
+pub use self::uniform_value::UniformValue;
@@ -0,0 +1,213 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::traits::Shader;
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------SHADER STAGE ENUM------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The pipeline stage a shader runs in.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum ShaderStage {
+
+    /// Vertex shader.
+    Vertex,
+    /// Fragment shader.
+    Fragment,
+}
+
+/*===============================================================================================*/
+/*------PRIMITIVE TYPE ENUM----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// How vertices are assembled into primitives.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum PrimitiveType {
+
+    /// Every three vertices form a triangle.
+    Triangles,
+    /// Each vertex after the first two forms a triangle with the previous two.
+    TriangleStrip,
+    /// Every two vertices form a line.
+    Lines,
+    /// Every vertex is a point.
+    Points,
+}
+
+/*===============================================================================================*/
+/*------VERTEX FORMAT ENUM-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The data type of a single vertex attribute.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum VertexFormat {
+
+    /// One float.
+    Float,
+    /// Two floats.
+    Float2,
+    /// Three floats.
+    Float3,
+    /// Four floats.
+    Float4,
+    /// Four unsigned bytes, normalized to the 0-1 range.
+    UByte4Norm,
+}
+
+/*===============================================================================================*/
+/*------VERTEX FORMAT PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl VertexFormat {
+
+    /// Returns the number of components in the format.
+    pub fn get_component_count (&self) -> usize {
+
+        match *self {
+
+            VertexFormat::Float      => 1,
+            VertexFormat::Float2     => 2,
+            VertexFormat::Float3     => 3,
+            VertexFormat::Float4     => 4,
+            VertexFormat::UByte4Norm => 4,
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the size of the format in bytes.
+    pub fn get_size (&self) -> usize {
+
+        match *self {
+
+            VertexFormat::UByte4Norm => 4,
+            _ => self.get_component_count () * 4,
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------VERTEX ATTRIBUTE STRUCT------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Describes a single attribute of a vertex.
+#[derive (Clone, Debug)]
+pub struct VertexAttribute {
+
+    // Public
+    /// The attribute name, as used in the shader.
+    pub name: String,
+    /// The attribute format.
+    pub format: VertexFormat,
+    /// The offset of the attribute from the start of the vertex, in bytes.
+    pub offset: usize,
+}
+
+/*===============================================================================================*/
+/*------VERTEX LAYOUT STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Describes the layout of the vertices in a vertex buffer.
+#[derive (Clone, Debug, Default)]
+pub struct VertexLayout {
+
+    // Public
+    /// The vertex attributes.
+    pub attributes: Vec<VertexAttribute>,
+    /// The size of a single vertex, in bytes.
+    pub stride: usize,
+}
+
+/*===============================================================================================*/
+/*------VERTEX LAYOUT PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl VertexLayout {
+
+    /// Appends an attribute to the end of the vertex.
+    ///
+    /// The offset and stride are calculated automatically.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::gpu::{VertexFormat, VertexLayout};
+    /// #
+    /// let mut layout = VertexLayout::new ();
+    /// layout.push ("a_position", VertexFormat::Float3)
+    ///       .push ("a_colour",   VertexFormat::UByte4Norm);
+    ///
+    /// assert_eq! (layout.stride, 16);
+    /// assert_eq! (layout.attributes[1].offset, 12);
+    /// ```
+    pub fn push (&mut self, name: &str, format: VertexFormat) -> &mut Self {
+
+        self.attributes.push (VertexAttribute {name: name.to_string (),
+                                               format: format,
+                                               offset: self.stride});
+        self.stride += format.get_size ();
+        self
+    }
+
+/*===============================================================================================*/
+/*------VERTEX LAYOUT PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty vertex layout.
+    pub fn new () -> VertexLayout {
+        VertexLayout {attributes: Vec::new (), stride: 0}
+    }
+}
+
+/*===============================================================================================*/
+/*------PIPELINE DESC STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Describes a pipeline to be created by the render backend.
+///
+/// A pipeline combines a vertex and fragment shader with the layout of the vertices it draws.
+#[derive (Clone)]
+pub struct PipelineDesc {
+
+    // Public
+    /// The vertex shader.
+    pub vertex_shader: Rc<Shader>,
+    /// The fragment shader.
+    pub fragment_shader: Rc<Shader>,
+    /// The vertex layout.
+    pub vertex_layout: VertexLayout,
+    /// The primitive type.
+    pub primitive_type: PrimitiveType,
+}
+
+/*===============================================================================================*/
+/*------PIPELINE DESC PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PipelineDesc {
+
+    /// Returns a new pipeline description which draws triangles.
+    pub fn new (vertex_shader: Rc<Shader>, fragment_shader: Rc<Shader>, vertex_layout: VertexLayout) -> PipelineDesc {
+
+        PipelineDesc {vertex_shader: vertex_shader,
+                      fragment_shader: fragment_shader,
+                      vertex_layout: vertex_layout,
+                      primitive_type: PrimitiveType::Triangles}
+    }
+}
@@ -0,0 +1,137 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------BLEND MODE ENUM--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// How the output of a draw is blended with the render target.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum BlendMode {
+
+    /// No blending. The output replaces the target.
+    Opaque,
+    /// Standard alpha blending.
+    Alpha,
+    /// Alpha blending with premultiplied colours.
+    Premultiplied,
+    /// The output is added to the target.
+    Additive,
+    /// The output is multiplied with the target.
+    Multiply,
+}
+
+/*===============================================================================================*/
+/*------CULL MODE ENUM---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Which triangle faces are discarded.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CullMode {
+
+    /// Draw both faces.
+    None,
+    /// Discard back faces.
+    Back,
+    /// Discard front faces.
+    Front,
+}
+
+/*===============================================================================================*/
+/*------DEPTH FUNC ENUM--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The comparison used by the depth test.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DepthFunc {
+
+    /// Never passes.
+    Never,
+    /// Passes if the depth is less than the stored depth.
+    Less,
+    /// Passes if the depth is less than or equal to the stored depth.
+    LessEqual,
+    /// Passes if the depth is equal to the stored depth.
+    Equal,
+    /// Passes if the depth is greater than or equal to the stored depth.
+    GreaterEqual,
+    /// Passes if the depth is greater than the stored depth.
+    Greater,
+    /// Always passes.
+    Always,
+}
+
+/*===============================================================================================*/
+/*------RENDER STATE STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The fixed function state used when drawing.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RenderState {
+
+    // Public
+    /// The blend mode.
+    pub blend_mode: BlendMode,
+    /// The cull mode.
+    pub cull_mode: CullMode,
+    /// Is the depth test enabled?
+    pub depth_test: bool,
+    /// Is depth written to the depth buffer?
+    pub depth_write: bool,
+    /// The comparison used by the depth test.
+    pub depth_func: DepthFunc,
+}
+
+/*===============================================================================================*/
+/*------RENDER STATE PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderState {
+
+    /// Returns the default render state.
+    ///
+    /// It is opaque, back face culled, and depth tested.
+    pub fn new () -> RenderState {
+
+        RenderState {blend_mode: BlendMode::Opaque,
+                     cull_mode: CullMode::Back,
+                     depth_test: true,
+                     depth_write: true,
+                     depth_func: DepthFunc::LessEqual}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a render state for alpha blended geometry.
+    ///
+    /// It is depth tested, but doesn't write depth.
+    pub fn transparent () -> RenderState {
+
+        RenderState {blend_mode: BlendMode::Alpha,
+                     depth_write: false,
+                     .. RenderState::new ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for RenderState {
+
+    fn default () -> RenderState {
+        RenderState::new ()
+    }
+}
@@ -0,0 +1,53 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::TextureFormat;
+
+/*===============================================================================================*/
+/*------RENDER TARGET DESC STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Describes an offscreen render target to be created by the render backend.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub struct RenderTargetDesc {
+
+    // Public
+    /// The target width in pixels.
+    pub width: u32,
+    /// The target height in pixels.
+    pub height: u32,
+    /// The format of the colour attachment.
+    pub colour_format: TextureFormat,
+    /// Does the target have a depth / stencil attachment?
+    pub has_depth: bool,
+}
+
+/*===============================================================================================*/
+/*------RENDER TARGET DESC PUBLIC STATIC METHODS-------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderTargetDesc {
+
+    /// Returns a new render target description with an `RGBA8` colour and a depth attachment.
+    pub fn new (width: u32, height: u32) -> RenderTargetDesc {
+
+        RenderTargetDesc {width: width,
+                          height: height,
+                          colour_format: TextureFormat::RGBA8,
+                          has_depth: true}
+    }
+}
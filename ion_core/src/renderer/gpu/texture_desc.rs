@@ -0,0 +1,135 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+use ::util::image::Image;
+
+/*===============================================================================================*/
+/*------TEXTURE FORMAT ENUM----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The pixel format of a texture.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TextureFormat {
+
+    /// Single 8-bit channel.
+    R8,
+    /// Four 8-bit channels.
+    RGBA8,
+    /// Four 16-bit floating point channels.
+    RGBA16F,
+    /// Single 32-bit floating point channel. Used for shadow map depths.
+    R32F,
+    /// 24-bit depth with an 8-bit stencil.
+    Depth24Stencil8,
+}
+
+/*===============================================================================================*/
+/*------TEXTURE FORMAT PUBLIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl TextureFormat {
+
+    /// Returns the size of a single pixel in bytes.
+    pub fn get_pixel_size (&self) -> usize {
+
+        match *self {
+
+            TextureFormat::R8              => 1,
+            TextureFormat::RGBA8           => 4,
+            TextureFormat::RGBA16F         => 8,
+            TextureFormat::R32F            => 4,
+            TextureFormat::Depth24Stencil8 => 4,
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------TEXTURE FILTER ENUM----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// How a texture is sampled between texels.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TextureFilter {
+
+    /// Use the closest texel.
+    Nearest,
+    /// Blend between the surrounding texels.
+    Linear,
+}
+
+/*===============================================================================================*/
+/*------TEXTURE WRAP ENUM------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// How a texture is sampled outside of the 0-1 range.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TextureWrap {
+
+    /// Tile the texture.
+    Repeat,
+    /// Clamp to the edge texels.
+    Clamp,
+}
+
+/*===============================================================================================*/
+/*------TEXTURE DESC STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Describes a texture to be created by the render backend.
+#[derive (Copy, Clone, Debug)]
+pub struct TextureDesc {
+
+    // Public
+    /// The texture width in pixels.
+    pub width: u32,
+    /// The texture height in pixels.
+    pub height: u32,
+    /// The texture format.
+    pub format: TextureFormat,
+    /// The texture filter.
+    pub filter: TextureFilter,
+    /// The texture wrap mode.
+    pub wrap: TextureWrap,
+    /// Should mipmaps be generated?
+    pub has_mipmaps: bool,
+}
+
+/*===============================================================================================*/
+/*------TEXTURE DESC PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl TextureDesc {
+
+    /// Returns a new texture description.
+    ///
+    /// The texture is linearly filtered, repeats, and has no mipmaps.
+    pub fn new (width: u32, height: u32, format: TextureFormat) -> TextureDesc {
+
+        TextureDesc {width: width,
+                     height: height,
+                     format: format,
+                     filter: TextureFilter::Linear,
+                     wrap: TextureWrap::Repeat,
+                     has_mipmaps: false}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new texture description matching the size and format of an image.
+    pub fn from_image (image: &Image) -> TextureDesc {
+        TextureDesc::new (image.get_width (), image.get_height (), image.get_format ().get_texture_format ())
+    }
+}
@@ -0,0 +1,42 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Colour, Mat4, Vec2, Vec3, Vec4};
+
+/*===============================================================================================*/
+/*------UNIFORM VALUE ENUM-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A value that can be passed to a shader uniform.
+#[derive (Copy, Clone)]
+pub enum UniformValue {
+
+    /// Integer value. Also used for texture slots.
+    Int (i32),
+    /// Float value.
+    Float (f32),
+    /// 2D vector.
+    Vec2 (Vec2),
+    /// 3D vector.
+    Vec3 (Vec3),
+    /// 4D vector.
+    Vec4 (Vec4),
+    /// 4x4 matrix.
+    Mat4 (Mat4),
+    /// RGBA colour.
+    Colour (Colour),
+}
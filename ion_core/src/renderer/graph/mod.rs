@@ -0,0 +1,34 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The render graph module.
+//!
+//! Contains a declarative render graph, used to compose a frame from multiple passes.  
+//! Passes declare the render targets they read and write, and the graph orders them,  
+//! culls the ones that don't contribute to the frame, and allocates their targets.
+/*===============================================================================================*/
+
+mod render_graph;
+mod render_graph_resource;
+mod render_pass;
+mod render_pass_context;
+
+pub use self::render_graph::RenderGraph;
+pub use self::render_graph_resource::RenderGraphResource;
+pub use self::render_pass::RenderPass;
+pub use self::render_pass_context::RenderPassContext;
@@ -0,0 +1,470 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::CommandBuffer;
+use ::renderer::gpu::RenderTargetDesc;
+use ::renderer::graph::{RenderGraphResource, RenderPass, RenderPassContext};
+use ::renderer::traits::{RenderFactory, RenderTarget};
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------RESOURCE KIND ENUM-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Where the render target of a resource comes from.
+enum ResourceKind {
+
+    // Allocated by the graph, and only valid while the graph executes.
+    Transient (RenderTargetDesc),
+    // Owned outside of the graph. `None` is the window.
+    Imported (Option<Rc<RenderTarget>>),
+}
+
+/*===============================================================================================*/
+/*------RENDER GRAPH STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Composes a frame from passes that declare the render targets they read and write.
+///
+/// When compiled, the graph:
+///
+/// * Culls passes whose output never reaches an imported target, or a pass with side effects.
+/// * Orders the remaining passes so every target is written before it is read.  
+///   Passes writing the same target run in the order they were added.
+/// * Allocates the transient targets, sharing one render target between transient  
+///   targets with the same description whose lifetimes don't overlap.
+///
+/// As transient targets may share memory, passes should clear the targets they write.  
+/// Allocated targets are kept between executions, until the graph changes.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::command::CommandBuffer;
+/// # use ion_core::renderer::gpu::RenderTargetDesc;
+/// # use ion_core::renderer::graph::RenderGraph;
+/// # use ion_core::renderer::mock::RenderFactoryMock;
+/// # use ion_core::renderer::traits::RenderFactory;
+/// #
+/// let factory = RenderFactoryMock::new ();
+/// let mut graph = RenderGraph::new ();
+///
+/// let window  = graph.import_target ("window", None);
+/// let shadows = graph.create_target ("shadow_map", RenderTargetDesc::new (1024, 1024));
+/// let gbuffer = graph.create_target ("gbuffer", RenderTargetDesc::new (800, 600));
+/// let hdr     = graph.create_target ("hdr", RenderTargetDesc::new (800, 600));
+/// let bloom   = graph.create_target ("bloom", RenderTargetDesc::new (800, 600));
+/// let unused  = graph.create_target ("unused", RenderTargetDesc::new (800, 600));
+///
+/// // Passes can be added in any order
+/// graph.add_pass ("post").read (hdr).read (bloom).write (window).on_execute (|_, c| c.draw (0, 3));
+/// graph.add_pass ("bloom").read (hdr).write (bloom).on_execute (|_, c| c.draw (0, 3));
+/// graph.add_pass ("lighting").read (gbuffer).read (shadows).write (hdr).on_execute (|_, c| c.draw (0, 3));
+/// graph.add_pass ("gbuffer").write (gbuffer).on_execute (|_, c| c.draw (0, 3));
+/// graph.add_pass ("shadows").write (shadows).on_execute (|_, c| c.draw (0, 3));
+/// graph.add_pass ("debug").write (unused).on_execute (|_, c| c.draw (0, 3));
+///
+/// let mut commands = CommandBuffer::new ();
+/// graph.execute (&factory, &mut commands).unwrap ();
+///
+/// assert_eq! (graph.get_pass_order (), vec! ["gbuffer", "shadows", "lighting", "bloom", "post"]);
+/// assert_eq! (graph.get_culled_passes (), vec! ["debug"]);
+///
+/// // The bloom target reuses the G-buffer, as the G-buffer is no longer needed
+/// assert_eq! (graph.get_transient_target_count (), 3);
+/// assert_eq! (factory.get_resource_count (), 3);
+///
+/// // Clearing the graph forgets its transient targets
+/// graph.clear ();
+/// assert_eq! (graph.get_transient_target_count (), 0);
+/// ```
+pub struct RenderGraph {
+
+    // Private
+    _passes: Vec<RenderPass>,
+    _resources: Vec<(String, ResourceKind)>,
+    _order: Vec<usize>,
+    _culled: Vec<usize>,
+    _resource_slots: Vec<Option<usize>>,
+    _slot_descs: Vec<RenderTargetDesc>,
+    _slot_targets: Vec<(RenderTargetDesc, Rc<RenderTarget>)>,
+    _is_compiled: bool,
+}
+
+/*===============================================================================================*/
+/*------RENDER GRAPH PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderGraph {
+
+    /// Declares a render target owned outside of the graph.
+    ///
+    /// Passes writing imported targets are never culled. `None` refers to the window.
+    pub fn import_target (&mut self, name: &str, target: Option<Rc<RenderTarget>>) -> RenderGraphResource {
+
+        self._resources.push ((name.to_string (), ResourceKind::Imported (target)));
+        self._is_compiled = false;
+
+        RenderGraphResource::new (self._resources.len () - 1)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Declares a render target allocated by the graph.
+    pub fn create_target (&mut self, name: &str, desc: RenderTargetDesc) -> RenderGraphResource {
+
+        self._resources.push ((name.to_string (), ResourceKind::Transient (desc)));
+        self._is_compiled = false;
+
+        RenderGraphResource::new (self._resources.len () - 1)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a pass to the graph.
+    ///
+    /// The returned pass is used to declare its reads and writes, and what it executes.
+    pub fn add_pass (&mut self, name: &str) -> &mut RenderPass {
+
+        self._passes.push (RenderPass::new (name));
+        self._is_compiled = false;
+
+        self._passes.last_mut ().unwrap ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the name of a resource.
+    pub fn get_resource_name (&self, resource: RenderGraphResource) -> &str {
+        &self._resources[resource.get_index ()].0
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Culls, orders and allocates the passes.
+    ///
+    /// This is called by `execute` if the graph changed, but can be called earlier to  
+    /// validate the graph. It fails if a pass uses an unknown resource, or if the passes  
+    /// depend on each other in a cycle.
+    pub fn compile (&mut self) -> Result<(), ()> {
+
+        let pass_count = self._passes.len ();
+        let resource_count = self._resources.len ();
+
+        // Find the writers and readers of every resource
+        let mut writers: Vec<Vec<usize>> = vec! [Vec::new (); resource_count];
+        let mut readers: Vec<Vec<usize>> = vec! [Vec::new (); resource_count];
+
+        for (p, pass) in self._passes.iter ().enumerate () {
+
+            for resource in pass.get_reads ().iter ().chain (pass.get_writes ().iter ()) {
+
+                if resource.get_index () >= resource_count {
+
+                    error! ("Render pass \"{}\" uses an unknown resource.", pass.get_name ());
+                    return Err (());
+                }
+            }
+
+            for resource in pass.get_reads () {
+                readers[resource.get_index ()].push (p);
+            }
+
+            for resource in pass.get_writes () {
+                writers[resource.get_index ()].push (p);
+            }
+        }
+
+        // Work back from the passes with visible results, to find every pass they need
+        let mut is_live = vec! [false; pass_count];
+        let mut pending: Vec<usize> = (0..pass_count).filter (|&p| {
+
+            let pass = &self._passes[p];
+
+            pass.has_side_effects () || pass.get_writes ().iter ().any (|r| match self._resources[r.get_index ()].1 {
+
+                ResourceKind::Imported (_) => true,
+                ResourceKind::Transient (_) => false
+            })
+
+        }).collect ();
+
+        while let Some (p) = pending.pop () {
+
+            if is_live[p] {
+                continue;
+            }
+
+            is_live[p] = true;
+
+            for resource in self._passes[p].get_reads () {
+                pending.extend (writers[resource.get_index ()].iter ().filter (|&&w| w != p));
+            }
+
+            // Earlier writers of the same target contribute to its contents
+            for resource in self._passes[p].get_writes () {
+                pending.extend (writers[resource.get_index ()].iter ().filter (|&&w| w < p));
+            }
+        }
+
+        // Build the dependencies between the live passes
+        let mut dependencies: Vec<Vec<usize>> = vec! [Vec::new (); pass_count];
+
+        for p in (0..pass_count).filter (|&p| is_live[p]) {
+
+            for resource in self._passes[p].get_reads () {
+
+                let resource_writers = &writers[resource.get_index ()];
+                let earlier = RenderGraph::_has_earlier (resource_writers, p);
+
+                dependencies[p].extend (resource_writers.iter ().filter (|&&w| w != p && (w < p || !earlier)));
+            }
+
+            for resource in self._passes[p].get_writes () {
+
+                let index = resource.get_index ();
+
+                // Write after write, and write after a read of an earlier write
+                dependencies[p].extend (writers[index].iter ().filter (|&&w| w < p));
+                dependencies[p].extend (readers[index].iter ().filter (|&&r| {
+                    r < p && RenderGraph::_has_earlier (&writers[index], r)
+                }));
+            }
+        }
+
+        // Order the passes, preferring the order they were added in
+        let mut order = Vec::new ();
+        let mut is_done = vec! [false; pass_count];
+
+        loop {
+
+            let next = (0..pass_count).find (|&p| {
+                is_live[p] && !is_done[p] && dependencies[p].iter ().all (|&d| !is_live[d] || is_done[d])
+            });
+
+            match next {
+
+                Some (p) => {
+
+                    is_done[p] = true;
+                    order.push (p);
+                },
+
+                None => break
+            }
+        }
+
+        if order.len () != is_live.iter ().filter (|&&l| l).count () {
+
+            error! ("Render graph passes depend on each other in a cycle.");
+            return Err (());
+        }
+
+        // Find the lifetime of every transient resource, as positions in the order
+        let mut lifetimes: Vec<Option<(usize, usize)>> = vec! [None; resource_count];
+
+        for (position, &p) in order.iter ().enumerate () {
+
+            let pass = &self._passes[p];
+
+            for resource in pass.get_reads ().iter ().chain (pass.get_writes ().iter ()) {
+
+                let lifetime = &mut lifetimes[resource.get_index ()];
+
+                *lifetime = match *lifetime {
+
+                    Some ((first, _)) => Some ((first, position)),
+                    None => Some ((position, position))
+                };
+            }
+        }
+
+        // Assign the transient resources to slots, reusing slots that are no longer in use
+        let mut transients: Vec<usize> = (0..resource_count).filter (|&r| lifetimes[r].is_some ()).collect ();
+        transients.sort_by_key (|&r| lifetimes[r].unwrap ().0);
+
+        let mut resource_slots = vec! [None; resource_count];
+        let mut slot_descs: Vec<RenderTargetDesc> = Vec::new ();
+        let mut slot_last_use: Vec<usize> = Vec::new ();
+
+        for r in transients {
+
+            let desc = match self._resources[r].1 {
+
+                ResourceKind::Transient (desc) => desc,
+                ResourceKind::Imported (_) => continue
+            };
+
+            let (first, last) = lifetimes[r].unwrap ();
+            let slot = (0..slot_descs.len ()).find (|&s| slot_descs[s] == desc && slot_last_use[s] < first);
+
+            let slot = match slot {
+
+                Some (s) => s,
+                None => {
+
+                    slot_descs.push (desc);
+                    slot_last_use.push (last);
+                    slot_descs.len () - 1
+                }
+            };
+
+            slot_last_use[slot] = last;
+            resource_slots[r] = Some (slot);
+        }
+
+        self._culled = (0..pass_count).filter (|&p| !is_live[p]).collect ();
+        self._order = order;
+        self._resource_slots = resource_slots;
+        self._slot_descs = slot_descs;
+        self._is_compiled = true;
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Records every live pass into a command buffer, in order.
+    ///
+    /// The graph is compiled first if it changed, and any missing transient targets are created.
+    pub fn execute (&mut self, render_factory: &RenderFactory, command_buffer: &mut CommandBuffer) -> Result<(), ()> {
+
+        if !self._is_compiled {
+            try! (self.compile ());
+        }
+
+        // Create the targets of any slots that are new, or have changed
+        self._slot_targets.truncate (self._slot_descs.len ());
+
+        for (s, desc) in self._slot_descs.iter ().enumerate () {
+
+            let is_valid = self._slot_targets.get (s).map_or (false, |&(ref d, _)| d == desc);
+
+            if !is_valid {
+
+                let target = (*desc, try! (render_factory.create_render_target (desc)));
+
+                if s < self._slot_targets.len () {
+                    self._slot_targets[s] = target;
+                }
+
+                else {
+                    self._slot_targets.push (target);
+                }
+            }
+        }
+
+        // Resolve the target of every resource
+        let targets: Vec<Option<Rc<RenderTarget>>> = self._resources.iter ().enumerate ().map (|(r, resource)| {
+
+            match resource.1 {
+
+                ResourceKind::Imported (ref target) => target.clone (),
+                ResourceKind::Transient (_) => self._resource_slots[r].map (|s| self._slot_targets[s].1.clone ())
+            }
+
+        }).collect ();
+
+        for &p in &self._order {
+
+            let pass = &self._passes[p];
+
+            if let Some (resource) = pass.get_writes ().first () {
+                command_buffer.set_render_target (targets[resource.get_index ()].clone ());
+            }
+
+            pass.execute (&RenderPassContext::new (pass.get_name (), &targets), command_buffer);
+        }
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the names of the live passes, in the order they execute.
+    pub fn get_pass_order (&self) -> Vec<&str> {
+        self._order.iter ().map (|&p| self._passes[p].get_name ()).collect ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the names of the passes that were culled.
+    pub fn get_culled_passes (&self) -> Vec<&str> {
+        self._culled.iter ().map (|&p| self._passes[p].get_name ()).collect ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of render targets allocated for the transient resources.
+    pub fn get_transient_target_count (&self) -> usize {
+        self._slot_descs.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes every pass and resource.
+    ///
+    /// Allocated targets are kept, so they can be reused if the graph is rebuilt the same way.
+    pub fn clear (&mut self) {
+
+        self._passes.clear ();
+        self._resources.clear ();
+        self._order.clear ();
+        self._culled.clear ();
+        self._resource_slots.clear ();
+        self._slot_descs.clear ();
+        self._is_compiled = false;
+    }
+
+/*===============================================================================================*/
+/*------RENDER GRAPH PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty render graph.
+    pub fn new () -> RenderGraph {
+
+        RenderGraph {_passes: Vec::new (),
+                     _resources: Vec::new (),
+                     _order: Vec::new (),
+                     _culled: Vec::new (),
+                     _resource_slots: Vec::new (),
+                     _slot_descs: Vec::new (),
+                     _slot_targets: Vec::new (),
+                     _is_compiled: false}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for RenderGraph {
+
+    fn default () -> RenderGraph {
+        RenderGraph::new ()
+    }
+}
+
+/*===============================================================================================*/
+/*------RENDER GRAPH PRIVATE STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderGraph {
+
+    // Checks whether any pass in a list was added before another.
+    fn _has_earlier (passes: &[usize], pass: usize) -> bool {
+        passes.iter ().any (|&p| p < pass)
+    }
+}
@@ -0,0 +1,49 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------RENDER GRAPH RESOURCE STRUCT-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A handle to a render target declared in a `RenderGraph`.
+#[derive (Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RenderGraphResource {
+
+    // Private
+    _index: usize,
+}
+
+/*===============================================================================================*/
+/*------RENDER GRAPH RESOURCE PUBLIC METHODS-----------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderGraphResource {
+
+    /// Returns the index of the resource within its graph.
+    pub fn get_index (&self) -> usize {
+        self._index
+    }
+
+/*===============================================================================================*/
+/*------RENDER GRAPH RESOURCE PUBLIC STATIC METHODS----------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new handle to the resource at an index.
+    pub fn new (index: usize) -> RenderGraphResource {
+        RenderGraphResource {_index: index}
+    }
+}
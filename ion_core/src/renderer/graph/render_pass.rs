@@ -0,0 +1,139 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::CommandBuffer;
+use ::renderer::graph::{RenderGraphResource, RenderPassContext};
+
+/*===============================================================================================*/
+/*------RENDER PASS STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A single pass of a `RenderGraph`.
+///
+/// Passes are added with `RenderGraph::add_pass`, and declare their inputs and outputs  
+/// with the builder methods. The first target written is bound before the pass executes.
+pub struct RenderPass {
+
+    // Private
+    _name: String,
+    _reads: Vec<RenderGraphResource>,
+    _writes: Vec<RenderGraphResource>,
+    _has_side_effects: bool,
+    _execute: Option<Box<Fn (&RenderPassContext, &mut CommandBuffer)>>,
+}
+
+/*===============================================================================================*/
+/*------RENDER PASS PUBLIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderPass {
+
+    /// Declares a target the pass samples from.
+    pub fn read (&mut self, resource: RenderGraphResource) -> &mut Self {
+
+        if !self._reads.contains (&resource) {
+            self._reads.push (resource);
+        }
+
+        self
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Declares a target the pass draws into.
+    pub fn write (&mut self, resource: RenderGraphResource) -> &mut Self {
+
+        if !self._writes.contains (&resource) {
+            self._writes.push (resource);
+        }
+
+        self
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets whether the pass has effects outside the graph.
+    ///
+    /// Such passes are never culled, even if nothing reads what they write.
+    pub fn set_has_side_effects (&mut self, has_side_effects: bool) -> &mut Self {
+
+        self._has_side_effects = has_side_effects;
+        self
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the function that records the commands of the pass.
+    pub fn on_execute<F> (&mut self, execute: F) -> &mut Self
+        where F: Fn (&RenderPassContext, &mut CommandBuffer) + 'static {
+
+        self._execute = Some (Box::new (execute));
+        self
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the name of the pass.
+    pub fn get_name (&self) -> &str {
+        &self._name
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the targets the pass reads.
+    pub fn get_reads (&self) -> &[RenderGraphResource] {
+        &self._reads
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the targets the pass writes.
+    pub fn get_writes (&self) -> &[RenderGraphResource] {
+        &self._writes
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether the pass has effects outside the graph.
+    pub fn has_side_effects (&self) -> bool {
+        self._has_side_effects
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Records the commands of the pass.
+    pub fn execute (&self, context: &RenderPassContext, command_buffer: &mut CommandBuffer) {
+
+        if let Some (ref execute) = self._execute {
+            execute (context, command_buffer);
+        }
+    }
+
+/*===============================================================================================*/
+/*------RENDER PASS PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new pass, which reads and writes nothing.
+    pub fn new (name: &str) -> RenderPass {
+
+        RenderPass {_name: name.to_string (),
+                    _reads: Vec::new (),
+                    _writes: Vec::new (),
+                    _has_side_effects: false,
+                    _execute: None}
+    }
+}
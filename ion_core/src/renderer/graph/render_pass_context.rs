@@ -0,0 +1,76 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::graph::RenderGraphResource;
+use ::renderer::traits::{RenderTarget, Texture};
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------RENDER PASS CONTEXT STRUCT---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Gives an executing pass access to the targets allocated by the graph.
+pub struct RenderPassContext<'a> {
+
+    // Private
+    _pass_name: &'a str,
+    _targets: &'a [Option<Rc<RenderTarget>>],
+}
+
+/*===============================================================================================*/
+/*------RENDER PASS CONTEXT PUBLIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<'a> RenderPassContext<'a> {
+
+    /// Returns the name of the executing pass.
+    pub fn get_pass_name (&self) -> &str {
+        self._pass_name
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the render target of a resource.
+    ///
+    /// `None` is returned for the window, or for resources that have not been allocated.
+    pub fn get_target (&self, resource: RenderGraphResource) -> Option<Rc<RenderTarget>> {
+        self._targets.get (resource.get_index ()).and_then (|t| t.clone ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour texture of a resource, for sampling.
+    pub fn get_texture (&self, resource: RenderGraphResource) -> Option<Rc<Texture>> {
+        self.get_target (resource).map (|t| t.get_colour_texture ())
+    }
+
+/*===============================================================================================*/
+/*------RENDER PASS CONTEXT PUBLIC STATIC METHODS------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new context.
+    ///
+    /// # Arguments
+    /// * `pass_name` - The name of the executing pass.
+    /// * `targets` - The render target of every resource, by resource index.
+    pub fn new (pass_name: &'a str, targets: &'a [Option<Rc<RenderTarget>>]) -> RenderPassContext<'a> {
+
+        RenderPassContext {_pass_name: pass_name,
+                           _targets: targets}
+    }
+}
@@ -0,0 +1,234 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::Camera;
+use ::renderer::command::CommandBuffer;
+use ::renderer::gpu::{BuiltinShader, IndexFormat, PipelineDesc, RenderState, ShaderStage};
+use ::renderer::gpu::{TextureDesc, TextureFormat, UniformValue};
+use ::renderer::lighting::{Light, LightCuller, LightingConfig, ShadowRenderer};
+use ::renderer::mesh::{GpuMesh, Vertex};
+use ::renderer::traits::{Pipeline, RenderFactory, Texture};
+use ::util::math::{Colour, Frustum, Mat4, Sphere};
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------FORWARD RENDERER STRUCT------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Draws meshes lit by a set of lights, in a single pass.
+///
+/// Each mesh is lit by the most relevant lights only, which are selected on the CPU  
+/// from the world space bounding sphere of the mesh. Shadows are sampled from the maps  
+/// of a `ShadowRenderer`, when one is given. Meshes outside the camera frustum are skipped.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::Camera;
+/// # use ion_core::renderer::command::{CommandBuffer, RenderCommand};
+/// # use ion_core::renderer::gpu::UniformValue;
+/// # use ion_core::renderer::lighting::{ForwardRenderer, Light, LightingConfig};
+/// # use ion_core::renderer::mesh::{GpuMesh, Mesh, Vertex};
+/// # use ion_core::renderer::mock::RenderFactoryMock;
+/// # use ion_core::util::math::{Colour, Mat4, Vec3};
+/// #
+/// let factory = RenderFactoryMock::new ();
+///
+/// let mut config = LightingConfig::new ();
+/// config.lighting_max_lights_per_object = 2;
+///
+/// let mut renderer = ForwardRenderer::new (&factory, &config).unwrap ();
+///
+/// let mut mesh = Mesh::new ();
+/// mesh.vertices = vec! [Vertex::new (&Vec3 {x: 0.0, y: 0.0, z: 0.0}),
+///                       Vertex::new (&Vec3 {x: 1.0, y: 0.0, z: 0.0}),
+///                       Vertex::new (&Vec3 {x: 0.0, y: 1.0, z: 0.0})];
+/// mesh.indices = vec! [0, 1, 2];
+/// mesh.recalculate_bounds ();
+///
+/// let gpu_mesh = GpuMesh::new (&factory, &mesh).unwrap ();
+///
+/// let lights = [Light::new_directional (&Vec3::down ()),
+///               Light::new_point (&Vec3 {x: 1.0, y: 1.0, z: -4.0}, 5.0),
+///               Light::new_point (&Vec3 {x: 2.0, y: 1.0, z: -4.0}, 5.0)];
+///
+/// let mut command_buffer = CommandBuffer::new ();
+/// let model = Mat4::translate (&Vec3 {x: 0.0, y: 0.0, z: -5.0});
+///
+/// renderer.begin (&mut command_buffer, &Camera::new (), None);
+/// renderer.draw_mesh (&mut command_buffer, &gpu_mesh, &model, &Colour::white (), None, &lights);
+///
+/// // The camera looks down the negative z axis, so this mesh is skipped
+/// let behind = Mat4::translate (&Vec3 {x: 0.0, y: 0.0, z: 10.0});
+/// let command_count = command_buffer.len ();
+///
+/// renderer.draw_mesh (&mut command_buffer, &gpu_mesh, &behind, &Colour::white (), None, &lights);
+/// assert_eq! (command_buffer.len (), command_count);
+///
+/// let light_count = command_buffer.get_commands ().iter ().filter_map (|command| match *command {
+///     RenderCommand::SetUniform (ref name, UniformValue::Int (count)) if name == "u_light_count" => Some (count),
+///     _ => None,
+/// }).next ();
+///
+/// assert_eq! (light_count, Some (2));
+/// ```
+pub struct ForwardRenderer {
+
+    // Private
+    _pipeline: Rc<Pipeline>,
+    _white_texture: Rc<Texture>,
+    _culler: LightCuller,
+    _ambient_colour: Colour,
+    _shadow_slots: Vec<i32>,
+    _frustum: Frustum,
+}
+
+/*===============================================================================================*/
+/*------FORWARD RENDERER PUBLIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ForwardRenderer {
+
+    /// Records the per frame state of the forward pass.
+    ///
+    /// The viewport and clear commands of the camera are not recorded.  
+    /// The shadow renderer must have rendered the shadow maps for this frame already.
+    pub fn begin (&mut self, command_buffer: &mut CommandBuffer, camera: &Camera, shadows: Option<&ShadowRenderer>) {
+
+        command_buffer.set_render_state (RenderState::new ());
+        command_buffer.bind_pipeline (self._pipeline.clone ());
+        command_buffer.set_uniform ("u_view", UniformValue::Mat4 (camera.get_view_matrix ()));
+        command_buffer.set_uniform ("u_view_projection", UniformValue::Mat4 (camera.get_view_projection_matrix ()));
+        command_buffer.set_uniform ("u_ambient", UniformValue::Colour (self._ambient_colour));
+        command_buffer.set_uniform ("u_texture", UniformValue::Int (0));
+
+        self._frustum = camera.get_frustum ();
+
+        if let Some (shadows) = shadows {
+
+            shadows.bind (command_buffer, 1);
+            self._shadow_slots = shadows.get_shadow_slots ().to_vec ();
+        }
+
+        else {
+
+            // The shadow samplers still need valid textures
+            command_buffer.bind_texture (1, self._white_texture.clone ());
+            command_buffer.bind_texture (2, self._white_texture.clone ());
+            command_buffer.set_uniform ("u_shadow_map", UniformValue::Int (1));
+            command_buffer.set_uniform ("u_spot_shadow_map", UniformValue::Int (2));
+            command_buffer.set_uniform ("u_shadow_cascade_count", UniformValue::Int (0));
+
+            self._shadow_slots.clear ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Records the draw of a mesh, lit by the most relevant of the lights.
+    ///
+    /// Nothing is recorded if the mesh is outside the frustum of the camera given to `begin`.
+    ///
+    /// # Arguments
+    /// * `command_buffer` - The command buffer the draw is recorded into.
+    /// * `mesh` - The mesh to draw.
+    /// * `model` - The model matrix of the mesh.
+    /// * `colour` - The colour the mesh is tinted with.
+    /// * `texture` - The texture of the mesh, or None for a white texture.
+    /// * `lights` - Every light in the scene.
+    pub fn draw_mesh (&self,
+                      command_buffer: &mut CommandBuffer,
+                      mesh: &GpuMesh,
+                      model: &Mat4,
+                      colour: &Colour,
+                      texture: Option<Rc<Texture>>,
+                      lights: &[Light]) {
+
+        if !self._frustum.intersects_aabb (&mesh.bounds.transform (model)) {
+            return;
+        }
+
+        let bounds = Sphere::from_aabb (&mesh.bounds).transform (model);
+        let selected = self._culler.cull (lights, &bounds);
+
+        LightCuller::bind_lights (command_buffer, lights, &selected);
+
+        for (slot, &index) in selected.iter ().enumerate () {
+
+            let shadow_slot = self._shadow_slots.get (index).cloned ().unwrap_or (-1);
+            command_buffer.set_uniform (&format! ("u_light_shadow[{}]", slot), UniformValue::Int (shadow_slot));
+        }
+
+        command_buffer.set_uniform ("u_model", UniformValue::Mat4 (*model));
+        command_buffer.set_uniform ("u_colour", UniformValue::Colour (*colour));
+        command_buffer.bind_texture (0, texture.unwrap_or (self._white_texture.clone ()));
+        command_buffer.bind_vertex_buffer (mesh.vertex_buffer.clone ());
+        command_buffer.bind_index_buffer (mesh.index_buffer.clone (), IndexFormat::U32);
+        command_buffer.draw_indexed (0, mesh.index_count);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the ambient colour.
+    pub fn get_ambient_colour (&self) -> Colour {
+        self._ambient_colour
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the ambient colour, added to every lit surface.
+    pub fn set_ambient_colour (&mut self, colour: &Colour) {
+        self._ambient_colour = *colour;
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the light culler.
+    pub fn get_culler (&self) -> &LightCuller {
+        &self._culler
+    }
+
+/*===============================================================================================*/
+/*------FORWARD RENDERER PUBLIC STATIC METHODS---------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new forward renderer.
+    ///
+    /// The built in forward lit pipeline is created from the render factory,  
+    /// sized for the maximum lights per object of the config.
+    pub fn new (render_factory: &RenderFactory, config: &LightingConfig) -> Result<ForwardRenderer, ()> {
+
+        let shader = BuiltinShader::ForwardLit (config.lighting_max_lights_per_object);
+
+        let vertex_shader   = try! (render_factory.create_builtin_shader (shader, ShaderStage::Vertex));
+        let fragment_shader = try! (render_factory.create_builtin_shader (shader, ShaderStage::Fragment));
+
+        let pipeline = try! (render_factory.create_pipeline (&PipelineDesc::new (vertex_shader,
+                                                                                 fragment_shader,
+                                                                                 Vertex::get_vertex_layout ())));
+
+        let white_texture = try! (render_factory.create_texture (&TextureDesc::new (1, 1, TextureFormat::RGBA8),
+                                                                 &[255, 255, 255, 255]));
+
+        Ok (ForwardRenderer {_pipeline: pipeline,
+                             _white_texture: white_texture,
+                             _culler: LightCuller::from_config (config),
+                             _ambient_colour: config.lighting_ambient_colour,
+                             _shadow_slots: Vec::new (),
+                             _frustum: Frustum::default ()})
+    }
+}
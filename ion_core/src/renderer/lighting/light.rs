@@ -0,0 +1,234 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Colour, Mat4, Sphere, Util, Vec3};
+
+/*===============================================================================================*/
+/*------LIGHT TYPE ENUM--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The shape of the area a light affects.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum LightType {
+
+    /// Parallel rays along a direction, such as the sun. Affects everything.
+    Directional,
+    /// Rays in every direction from a position, up to a range.
+    Point,
+    /// Rays in a cone from a position, up to a range.
+    Spot,
+}
+
+/*===============================================================================================*/
+/*------LIGHT STRUCT-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A light source.
+///
+/// Point and spot lights fade out smoothly, reaching zero at their range.  
+/// Spot lights also fade from full strength at the inner cone angle, to zero at the outer angle.
+#[derive (Copy, Clone, Serialize, Deserialize)]
+pub struct Light {
+
+    // Public
+    /// The type of light.
+    pub light_type: LightType,
+    /// The colour of the light.
+    pub colour: Colour,
+    /// The brightness multiplier of the light.
+    pub intensity: f32,
+    /// The distance the light reaches. Ignored by directional lights.
+    pub range: f32,
+    /// The world position of the light. Ignored by directional lights.
+    pub position: Vec3,
+    /// The direction the light shines in. Ignored by point lights.
+    pub direction: Vec3,
+    /// The angle from the direction at which a spot light starts to fade, in degrees.
+    pub inner_cone_angle: f32,
+    /// The angle from the direction at which a spot light ends, in degrees.
+    pub outer_cone_angle: f32,
+    /// Does the light cast shadows? Point lights never do.
+    pub cast_shadows: bool,
+}
+
+/*===============================================================================================*/
+/*------LIGHT PUBLIC METHODS---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Light {
+
+    /// Returns the fraction of the light that reaches a distance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::lighting::Light;
+    /// # use ion_core::util::math::Vec3;
+    /// #
+    /// let light = Light::new_point (&Vec3::new (), 10.0);
+    ///
+    /// assert_eq! (light.get_attenuation (0.0), 1.0);
+    /// assert_eq! (light.get_attenuation (10.0), 0.0);
+    /// assert! (light.get_attenuation (5.0) > light.get_attenuation (6.0));
+    /// ```
+    pub fn get_attenuation (&self, distance: f32) -> f32 {
+
+        if self.light_type == LightType::Directional {
+            return 1.0;
+        }
+
+        if self.range <= 0.0 {
+            return 0.0;
+        }
+
+        let falloff = Util::clamp (1.0 - (distance / self.range) * (distance / self.range), 0.0, 1.0);
+        falloff * falloff
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if any part of a sphere is lit by the light.
+    pub fn affects_sphere (&self, sphere: &Sphere) -> bool {
+
+        match self.light_type {
+
+            LightType::Directional => true,
+            LightType::Point => Vec3::distance (&self.position, &sphere.centre) < self.range + sphere.radius,
+            LightType::Spot  => {
+
+                let offset = sphere.centre - self.position;
+                let distance = Vec3::length (&offset);
+
+                if distance >= self.range + sphere.radius {
+                    return false;
+                }
+
+                if distance <= sphere.radius {
+                    return true;
+                }
+
+                // Compare the angle to the sphere centre with the cone, widened by the sphere
+                let cos_angle = Util::clamp (Vec3::dot (&offset, &Vec3::normalize (&self.direction)) / distance, -1.0, 1.0);
+                let angle = cos_angle.acos ();
+                let angular_radius = (sphere.radius / distance).asin ();
+
+                angle - angular_radius <= Util::deg2rad (self.outer_cone_angle)
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns how strongly the light affects a sphere, or zero if it doesn't.
+    ///
+    /// This is the perceived brightness of the light, attenuated to the closest point  
+    /// of the sphere. It is used to rank the lights affecting an object.
+    pub fn get_influence (&self, sphere: &Sphere) -> f32 {
+
+        if !self.affects_sphere (sphere) {
+            return 0.0;
+        }
+
+        let brightness = (0.2126 * self.colour.r + 0.7152 * self.colour.g + 0.0722 * self.colour.b) * self.intensity;
+
+        match self.light_type {
+
+            LightType::Directional => brightness,
+            _ => {
+
+                let distance = Util::max (Vec3::distance (&self.position, &sphere.centre) - sphere.radius, 0.0);
+                brightness * self.get_attenuation (distance)
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the view-projection matrix of the shadow map of a spot light.
+    ///
+    /// The projection covers the outer cone of the light, out to its range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::lighting::Light;
+    /// # use ion_core::util::math::{Vec3, Vec4};
+    /// #
+    /// let light = Light::new_spot (&Vec3::new (), &Vec3::down (), 10.0, 20.0, 30.0);
+    /// let point = light.get_shadow_view_projection () * Vec4 {x: 0.0, y: -5.0, z: 0.0, w: 1.0};
+    ///
+    /// // A point straight below the light projects to the centre of the shadow map
+    /// assert! ((point.x / point.w).abs () < 0.001 && (point.y / point.w).abs () < 0.001);
+    /// ```
+    pub fn get_shadow_view_projection (&self) -> Mat4 {
+
+        let direction = Vec3::normalize (&self.direction);
+        let up = if Vec3::dot (&direction, &Vec3::up ()).abs () > 0.99 {Vec3::forward ()} else {Vec3::up ()};
+
+        let near = Util::max (self.range * 0.01, 0.05);
+        let fov  = Util::deg2rad (Util::clamp (self.outer_cone_angle * 2.0, 1.0, 170.0));
+
+        Mat4::perspective (fov, 1.0, near, self.range) * Mat4::view (&self.position, &(self.position + direction), &up)
+    }
+
+/*===============================================================================================*/
+/*------LIGHT PUBLIC STATIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new white directional light, shining in a direction.
+    pub fn new_directional (direction: &Vec3) -> Light {
+
+        Light {light_type: LightType::Directional,
+               colour: Colour::white (),
+               intensity: 1.0,
+               range: 0.0,
+               position: Vec3::new (),
+               direction: Vec3::normalize (direction),
+               inner_cone_angle: 0.0,
+               outer_cone_angle: 0.0,
+               cast_shadows: false}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new white point light.
+    pub fn new_point (position: &Vec3, range: f32) -> Light {
+
+        Light {light_type: LightType::Point,
+               position: *position,
+               range: range,
+               .. Light::new_directional (&Vec3::down ())}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new white spot light.
+    ///
+    /// # Arguments
+    /// * `position` - The position of the light.
+    /// * `direction` - The direction the light shines in.
+    /// * `range` - The distance the light reaches.
+    /// * `inner_cone_angle` - The angle at which the light starts to fade, in degrees.
+    /// * `outer_cone_angle` - The angle at which the light ends, in degrees.
+    pub fn new_spot (position: &Vec3, direction: &Vec3, range: f32, inner_cone_angle: f32, outer_cone_angle: f32) -> Light {
+
+        Light {light_type: LightType::Spot,
+               position: *position,
+               range: range,
+               inner_cone_angle: inner_cone_angle,
+               outer_cone_angle: outer_cone_angle,
+               .. Light::new_directional (direction)}
+    }
+}
@@ -0,0 +1,161 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::CommandBuffer;
+use ::renderer::gpu::UniformValue;
+use ::renderer::lighting::{Light, LightType, LightingConfig};
+use ::util::math::{Sphere, Util, Vec2, Vec4};
+
+use std::cmp::Ordering;
+
+/*===============================================================================================*/
+/*------LIGHT CULLER STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Selects the lights that matter most to an object.
+///
+/// Lights are ranked by their influence on the bounding sphere of the object,  
+/// and only the strongest are kept. Culling runs entirely on the CPU.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::lighting::{Light, LightCuller};
+/// # use ion_core::util::math::{Sphere, Vec3};
+/// #
+/// let mut sun = Light::new_directional (&Vec3::down ());
+/// sun.intensity = 0.5;
+///
+/// let lights = [sun,
+///               Light::new_point (&Vec3 {x: 2.0, y: 0.0, z: 0.0}, 5.0),
+///               Light::new_point (&Vec3 {x: 50.0, y: 0.0, z: 0.0}, 5.0),
+///               Light::new_spot (&Vec3 {x: 0.0, y: 3.0, z: 0.0}, &Vec3::up (), 10.0, 20.0, 30.0),
+///               Light::new_point (&Vec3 {x: 4.0, y: 0.0, z: 0.0}, 5.0)];
+///
+/// let culler = LightCuller::new (2);
+/// let object = Sphere::new (&Vec3::new (), 1.0);
+///
+/// // The distant point light and the spot light facing away are culled,
+/// // and the closest point light is stronger than the dim sun
+/// assert_eq! (culler.cull (&lights, &object), vec! [1, 0]);
+/// ```
+#[derive (Copy, Clone)]
+pub struct LightCuller {
+
+    // Private
+    _max_lights: usize,
+}
+
+/*===============================================================================================*/
+/*------LIGHT CULLER PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl LightCuller {
+
+    /// Returns the indices of the lights affecting a bounding sphere, strongest first.
+    ///
+    /// At most the maximum number of lights are returned.  
+    /// Lights with the same influence keep their order.
+    pub fn cull (&self, lights: &[Light], bounds: &Sphere) -> Vec<usize> {
+
+        let mut ranked: Vec<(usize, f32)> = lights.iter ()
+                                                  .map (|l| l.get_influence (bounds))
+                                                  .enumerate ()
+                                                  .filter (|&(_, influence)| influence > 0.0)
+                                                  .collect ();
+
+        ranked.sort_by (|a, b| b.1.partial_cmp (&a.1).unwrap_or (Ordering::Equal));
+        ranked.truncate (self._max_lights);
+
+        ranked.into_iter ().map (|(i, _)| i).collect ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the most lights kept for a single object.
+    pub fn get_max_lights (&self) -> usize {
+        self._max_lights
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the most lights kept for a single object.
+    pub fn set_max_lights (&mut self, max_lights: usize) {
+        self._max_lights = max_lights;
+    }
+
+/*===============================================================================================*/
+/*------LIGHT CULLER PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Records the uniforms of a set of lights, for the forward lit shader.
+    ///
+    /// # Arguments
+    /// * `command_buffer` - The command buffer the uniforms are recorded into.
+    /// * `lights` - Every light in the scene.
+    /// * `selected` - The indices of the lights to bind, as returned by `cull`.
+    pub fn bind_lights (command_buffer: &mut CommandBuffer, lights: &[Light], selected: &[usize]) {
+
+        command_buffer.set_uniform ("u_light_count", UniformValue::Int (selected.len () as i32));
+
+        for (slot, &index) in selected.iter ().enumerate () {
+
+            let light = &lights[index];
+            let light_type = match light.light_type {
+
+                LightType::Directional => 0.0,
+                LightType::Point       => 1.0,
+                LightType::Spot        => 2.0,
+            };
+
+            command_buffer.set_uniform (&format! ("u_light_position[{}]", slot),
+                                        UniformValue::Vec4 (Vec4 {x: light.position.x,
+                                                                  y: light.position.y,
+                                                                  z: light.position.z,
+                                                                  w: light_type}));
+
+            command_buffer.set_uniform (&format! ("u_light_direction[{}]", slot),
+                                        UniformValue::Vec4 (Vec4 {x: light.direction.x,
+                                                                  y: light.direction.y,
+                                                                  z: light.direction.z,
+                                                                  w: light.range}));
+
+            command_buffer.set_uniform (&format! ("u_light_colour[{}]", slot),
+                                        UniformValue::Vec4 (Vec4 {x: light.colour.r * light.intensity,
+                                                                  y: light.colour.g * light.intensity,
+                                                                  z: light.colour.b * light.intensity,
+                                                                  w: 1.0}));
+
+            command_buffer.set_uniform (&format! ("u_light_cone[{}]", slot),
+                                        UniformValue::Vec2 (Vec2 {x: Util::deg2rad (light.inner_cone_angle).cos (),
+                                                                  y: Util::deg2rad (light.outer_cone_angle).cos ()}));
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new light culler.
+    pub fn new (max_lights: usize) -> LightCuller {
+        LightCuller {_max_lights: max_lights}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new light culler, using the maximum lights per object of a config.
+    pub fn from_config (config: &LightingConfig) -> LightCuller {
+        LightCuller::new (config.lighting_max_lights_per_object as usize)
+    }
+}
@@ -0,0 +1,59 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::Colour;
+
+/*===============================================================================================*/
+/*------LIGHTING CONFIG STRUCT-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Stores the settings of the forward lighting path.
+#[derive (Copy, Clone, Deserialize, Serialize)]
+pub struct LightingConfig {
+
+    // Public
+    /// The most lights that can affect a single object.  
+    /// The forward lit shader is compiled with this many light slots.
+    pub lighting_max_lights_per_object: u32,
+    /// The colour of the ambient light.
+    pub lighting_ambient_colour: Colour,
+}
+
+/*===============================================================================================*/
+/*------LIGHTING CONFIG PUBLIC STATIC METHODS----------------------------------------------------*/
+/*===============================================================================================*/
+
+impl LightingConfig {
+
+    /// Returns a new Lighting Config instance.
+    ///
+    /// It allows eight lights per object, with a dim grey ambient light.
+    pub fn new () -> LightingConfig {
+
+        LightingConfig {lighting_max_lights_per_object: 8,
+                        lighting_ambient_colour: Colour {r: 0.1, g: 0.1, b: 0.1, a: 1.0}}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for LightingConfig {
+
+    fn default () -> LightingConfig {
+        LightingConfig::new ()
+    }
+}
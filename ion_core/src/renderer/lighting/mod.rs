@@ -0,0 +1,37 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The lighting module.
+//!
+//! Contains the light types, the CPU side light culling, the forward lit renderer,  
+//! and the shadow map renderer.
+/*===============================================================================================*/
+
+mod forward_renderer;
+mod light;
+mod light_culler;
+mod lighting_config;
+mod shadow_config;
+mod shadow_renderer;
+
+pub use self::forward_renderer::ForwardRenderer;
+pub use self::light::{Light, LightType};
+pub use self::light_culler::LightCuller;
+pub use self::lighting_config::LightingConfig;
+pub use self::shadow_config::{MAX_SHADOW_CASCADES, MAX_SPOT_SHADOWS, ShadowConfig};
+pub use self::shadow_renderer::ShadowRenderer;
@@ -0,0 +1,90 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+// Static variables
+/// The most cascades a directional light shadow can be split into.
+pub static MAX_SHADOW_CASCADES: u32 = 4;
+
+/// The most spot lights that can cast shadows at once.
+pub static MAX_SPOT_SHADOWS: u32 = 4;
+
+/*===============================================================================================*/
+/*------SHADOW CONFIG STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Stores the settings of shadow mapping.
+#[derive (Copy, Clone, Deserialize, Serialize)]
+pub struct ShadowConfig {
+
+    // Public
+    /// Are shadows rendered?
+    pub shadow_enabled: bool,
+    /// The resolution of each directional light cascade, in texels.
+    pub shadow_cascade_size: u32,
+    /// The number of cascades, up to `MAX_SHADOW_CASCADES`.
+    pub shadow_cascade_count: u32,
+    /// How the cascades are split, from 0 (uniform) to 1 (logarithmic).
+    pub shadow_cascade_split_lambda: f32,
+    /// The view distance directional light shadows reach.
+    pub shadow_distance: f32,
+    /// How far outside a cascade shadow casters are still rendered.
+    pub shadow_caster_distance: f32,
+    /// The resolution of each spot light shadow map, in texels.
+    pub shadow_spot_size: u32,
+    /// The most spot lights casting shadows, up to `MAX_SPOT_SHADOWS`.
+    pub shadow_spot_count: u32,
+    /// The depth offset applied before comparing against the shadow map.
+    pub shadow_depth_bias: f32,
+    /// The world space offset along the surface normal applied before sampling.
+    pub shadow_normal_bias: f32,
+    /// The radius of the PCF filter, in texels. Zero disables filtering.
+    pub shadow_pcf_radius: u32,
+}
+
+/*===============================================================================================*/
+/*------SHADOW CONFIG PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ShadowConfig {
+
+    /// Returns a new Shadow Config instance.
+    ///
+    /// It uses four 2048 texel cascades, and a 3x3 PCF filter.
+    pub fn new () -> ShadowConfig {
+
+        ShadowConfig {shadow_enabled: true,
+                      shadow_cascade_size: 2048,
+                      shadow_cascade_count: 4,
+                      shadow_cascade_split_lambda: 0.75,
+                      shadow_distance: 100.0,
+                      shadow_caster_distance: 50.0,
+                      shadow_spot_size: 1024,
+                      shadow_spot_count: 4,
+                      shadow_depth_bias: 0.002,
+                      shadow_normal_bias: 0.05,
+                      shadow_pcf_radius: 1}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for ShadowConfig {
+
+    fn default () -> ShadowConfig {
+        ShadowConfig::new ()
+    }
+}
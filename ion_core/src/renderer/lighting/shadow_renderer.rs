@@ -0,0 +1,361 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::Camera;
+use ::renderer::command::CommandBuffer;
+use ::renderer::gpu::{BuiltinShader, IndexFormat, PipelineDesc, RenderState, RenderTargetDesc};
+use ::renderer::gpu::{ShaderStage, TextureFormat, UniformValue};
+use ::renderer::lighting::{Light, LightType, MAX_SHADOW_CASCADES, MAX_SPOT_SHADOWS, ShadowConfig};
+use ::renderer::mesh::{GpuMesh, Vertex};
+use ::renderer::traits::{Pipeline, RenderFactory, RenderTarget};
+use ::util::math::{Cascade, Colour, Frustum, Mat4, Sphere, Util, Vec2, Vec3, Vec4};
+
+use std::cmp;
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------SHADOW RENDERER STRUCT-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Renders the shadow maps of the shadow casting lights.
+///
+/// The first shadow casting directional light gets a cascaded shadow map, fitted to the camera  
+/// frustum. Spot lights get a single shadow map each, up to the configured count.  
+/// The cascades, and the spot light maps, are each packed side by side into one atlas.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::Camera;
+/// # use ion_core::renderer::command::CommandBuffer;
+/// # use ion_core::renderer::lighting::{Light, ShadowConfig, ShadowRenderer};
+/// # use ion_core::renderer::mock::RenderFactoryMock;
+/// # use ion_core::util::math::Vec3;
+/// #
+/// let factory = RenderFactoryMock::new ();
+///
+/// let mut config = ShadowConfig::new ();
+/// config.shadow_cascade_count = 3;
+///
+/// let mut shadows = ShadowRenderer::new (&factory, &config).unwrap ();
+///
+/// let mut sun  = Light::new_directional (&Vec3 {x: 1.0, y: -1.0, z: 0.0});
+/// let mut spot = Light::new_spot (&Vec3 {x: 0.0, y: 5.0, z: 0.0}, &Vec3::down (), 10.0, 20.0, 30.0);
+/// sun.cast_shadows  = true;
+/// spot.cast_shadows = true;
+///
+/// let lights = [Light::new_point (&Vec3::new (), 5.0), sun, spot];
+///
+/// let mut command_buffer = CommandBuffer::new ();
+/// shadows.render (&mut command_buffer, &Camera::new (), &lights, &[]);
+///
+/// assert_eq! (shadows.get_cascades ().len (), 3);
+/// assert_eq! (shadows.get_shadow_slots (), &[-1, 0, 1]);
+/// ```
+pub struct ShadowRenderer {
+
+    // Private
+    _config: ShadowConfig,
+    _pipeline: Rc<Pipeline>,
+    _cascade_target: Rc<RenderTarget>,
+    _spot_target: Rc<RenderTarget>,
+    _cascades: Vec<Cascade>,
+    _spot_matrices: Vec<Mat4>,
+    _shadow_slots: Vec<i32>,
+}
+
+/*===============================================================================================*/
+/*------SHADOW RENDERER PUBLIC METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ShadowRenderer {
+
+    /// Records the rendering of the shadow maps.
+    ///
+    /// The render target is reset to the default target afterwards.
+    ///
+    /// # Arguments
+    /// * `command_buffer` - The command buffer the shadow passes are recorded into.
+    /// * `camera` - The camera the cascades are fitted to.
+    /// * `lights` - Every light in the scene.
+    /// * `casters` - The meshes casting shadows, with their model matrices.
+    pub fn render (&mut self,
+                   command_buffer: &mut CommandBuffer,
+                   camera: &Camera,
+                   lights: &[Light],
+                   casters: &[(&GpuMesh, Mat4)]) {
+
+        self._cascades.clear ();
+        self._spot_matrices.clear ();
+        self._shadow_slots = vec! [-1; lights.len ()];
+
+        if !self._config.shadow_enabled {
+            return;
+        }
+
+        command_buffer.set_render_state (RenderState::new ());
+        command_buffer.bind_pipeline (self._pipeline.clone ());
+
+        // Directional light cascades
+        let directional = lights.iter ().position (|l| l.cast_shadows && l.light_type == LightType::Directional);
+
+        if let Some (index) = directional {
+
+            let size = self._config.shadow_cascade_size;
+            let count = self._config.shadow_cascade_count as usize;
+            let direction = lights[index].direction;
+
+            let far = Util::min (camera.far, self._config.shadow_distance);
+            let splits = Cascade::compute_splits (camera.near, far, count, self._config.shadow_cascade_split_lambda);
+            let view = camera.get_view_matrix ();
+
+            ShadowRenderer::_begin_atlas (command_buffer, &self._cascade_target);
+
+            for i in 0..count {
+
+                let slice = camera.get_projection_matrix_range (splits[i], splits[i + 1]) * view;
+                let cascade = Cascade::new (&slice,
+                                            splits[i],
+                                            splits[i + 1],
+                                            &direction,
+                                            size,
+                                            self._config.shadow_caster_distance);
+
+                // Casters only need to overlap the cascade across the light direction
+                let bounds = cascade.bounds;
+                let is_visible = |sphere: &Sphere| {
+
+                    let offset = sphere.centre - bounds.centre;
+                    let across = offset - direction * Vec3::dot (&offset, &direction);
+
+                    Vec3::length (&across) <= bounds.radius + sphere.radius
+                };
+
+                command_buffer.set_viewport ((i as u32 * size) as i32, 0, size, size);
+                ShadowRenderer::_draw_casters (command_buffer, &cascade.view_projection, casters, is_visible);
+
+                self._cascades.push (cascade);
+            }
+
+            self._shadow_slots[index] = 0;
+        }
+
+        // Spot light maps
+        let spots: Vec<usize> = lights.iter ()
+                                      .enumerate ()
+                                      .filter (|&(_, l)| l.cast_shadows && l.light_type == LightType::Spot)
+                                      .map (|(i, _)| i)
+                                      .take (self._config.shadow_spot_count as usize)
+                                      .collect ();
+
+        if !spots.is_empty () {
+
+            let size = self._config.shadow_spot_size;
+
+            ShadowRenderer::_begin_atlas (command_buffer, &self._spot_target);
+
+            for (k, &index) in spots.iter ().enumerate () {
+
+                let light = &lights[index];
+                let view_projection = light.get_shadow_view_projection ();
+                let frustum = Frustum::from_matrix (&view_projection);
+
+                command_buffer.set_viewport ((k as u32 * size) as i32, 0, size, size);
+                ShadowRenderer::_draw_casters (command_buffer, &view_projection, casters, |sphere| {
+                    light.affects_sphere (sphere) && frustum.intersects_sphere (sphere)
+                });
+
+                self._spot_matrices.push (view_projection);
+                self._shadow_slots[index] = k as i32 + 1;
+            }
+        }
+
+        command_buffer.set_render_target (None);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Records the shadow maps and uniforms used by the forward lit shader.
+    ///
+    /// The cascade atlas is bound to `first_texture_slot`, and the spot light atlas to the next slot.
+    pub fn bind (&self, command_buffer: &mut CommandBuffer, first_texture_slot: u32) {
+
+        let cascade_count = self._config.shadow_cascade_count;
+        let spot_count    = self._config.shadow_spot_count;
+        let cascade_size  = self._config.shadow_cascade_size as f32;
+        let spot_size     = self._config.shadow_spot_size as f32;
+
+        command_buffer.bind_texture (first_texture_slot, self._cascade_target.get_colour_texture ());
+        command_buffer.bind_texture (first_texture_slot + 1, self._spot_target.get_colour_texture ());
+
+        command_buffer.set_uniform ("u_shadow_map", UniformValue::Int (first_texture_slot as i32));
+        command_buffer.set_uniform ("u_spot_shadow_map", UniformValue::Int (first_texture_slot as i32 + 1));
+        command_buffer.set_uniform ("u_shadow_cascade_count", UniformValue::Int (self._cascades.len () as i32));
+
+        let mut splits = Vec4::new ();
+
+        for (i, cascade) in self._cascades.iter ().enumerate () {
+
+            splits[i as u8] = cascade.split_far;
+
+            command_buffer.set_uniform (&format! ("u_shadow_matrices[{}]", i),
+                                        UniformValue::Mat4 (ShadowRenderer::_get_atlas_matrix (i as u32, cascade_count) *
+                                                            cascade.view_projection));
+        }
+
+        for (k, view_projection) in self._spot_matrices.iter ().enumerate () {
+
+            command_buffer.set_uniform (&format! ("u_spot_shadow_matrices[{}]", k),
+                                        UniformValue::Mat4 (ShadowRenderer::_get_atlas_matrix (k as u32, spot_count) *
+                                                            *view_projection));
+        }
+
+        command_buffer.set_uniform ("u_shadow_splits", UniformValue::Vec4 (splits));
+        command_buffer.set_uniform ("u_shadow_bias", UniformValue::Vec2 (Vec2 {x: self._config.shadow_depth_bias,
+                                                                               y: self._config.shadow_normal_bias}));
+        command_buffer.set_uniform ("u_shadow_pcf_radius", UniformValue::Int (self._config.shadow_pcf_radius as i32));
+        command_buffer.set_uniform ("u_shadow_texel_size", UniformValue::Vec4 (Vec4 {x: 1.0 / (cascade_size * cascade_count as f32),
+                                                                                     y: 1.0 / cascade_size,
+                                                                                     z: 1.0 / (spot_size * spot_count as f32),
+                                                                                     w: 1.0 / spot_size}));
+        command_buffer.set_uniform ("u_shadow_tile_size", UniformValue::Vec2 (Vec2 {x: 1.0 / cascade_count as f32,
+                                                                                    y: 1.0 / spot_count as f32}));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the cascades of the last rendered directional light shadow.
+    pub fn get_cascades (&self) -> &[Cascade] {
+        &self._cascades
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the view-projection matrices of the last rendered spot light shadows.
+    pub fn get_spot_matrices (&self) -> &[Mat4] {
+        &self._spot_matrices
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the shadow map used by each light in the last render.
+    ///
+    /// The values are -1 for no shadow, 0 for the cascades, and 1 or more for a spot light map.
+    pub fn get_shadow_slots (&self) -> &[i32] {
+        &self._shadow_slots
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the shadow config.
+    ///
+    /// The cascade and spot light counts are limited to the supported maximums.
+    pub fn get_config (&self) -> &ShadowConfig {
+        &self._config
+    }
+
+/*===============================================================================================*/
+/*------SHADOW RENDERER PUBLIC STATIC METHODS----------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new shadow renderer.
+    ///
+    /// The shadow map atlases and the depth pipeline are created from the render factory.
+    pub fn new (render_factory: &RenderFactory, config: &ShadowConfig) -> Result<ShadowRenderer, ()> {
+
+        let mut config = *config;
+        config.shadow_cascade_count = Util::clamp (config.shadow_cascade_count as f32, 1.0, MAX_SHADOW_CASCADES as f32) as u32;
+        config.shadow_spot_count = Util::clamp (config.shadow_spot_count as f32, 1.0, MAX_SPOT_SHADOWS as f32) as u32;
+        config.shadow_cascade_size = cmp::max (config.shadow_cascade_size, 1);
+        config.shadow_spot_size = cmp::max (config.shadow_spot_size, 1);
+
+        let vertex_shader   = try! (render_factory.create_builtin_shader (BuiltinShader::ShadowDepth, ShaderStage::Vertex));
+        let fragment_shader = try! (render_factory.create_builtin_shader (BuiltinShader::ShadowDepth, ShaderStage::Fragment));
+
+        let pipeline = try! (render_factory.create_pipeline (&PipelineDesc::new (vertex_shader,
+                                                                                 fragment_shader,
+                                                                                 Vertex::get_vertex_layout ())));
+
+        let cascade_target = try! (render_factory.create_render_target (&RenderTargetDesc {
+            width: config.shadow_cascade_size * config.shadow_cascade_count,
+            height: config.shadow_cascade_size,
+            colour_format: TextureFormat::R32F,
+            has_depth: true,
+        }));
+
+        let spot_target = try! (render_factory.create_render_target (&RenderTargetDesc {
+            width: config.shadow_spot_size * config.shadow_spot_count,
+            height: config.shadow_spot_size,
+            colour_format: TextureFormat::R32F,
+            has_depth: true,
+        }));
+
+        Ok (ShadowRenderer {_config: config,
+                            _pipeline: pipeline,
+                            _cascade_target: cascade_target,
+                            _spot_target: spot_target,
+                            _cascades: Vec::new (),
+                            _spot_matrices: Vec::new (),
+                            _shadow_slots: Vec::new ()})
+    }
+
+/*===============================================================================================*/
+/*------SHADOW RENDERER PRIVATE STATIC METHODS---------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Binds and clears a shadow map atlas. Cleared texels are at the far plane.
+    fn _begin_atlas (command_buffer: &mut CommandBuffer, target: &Rc<RenderTarget>) {
+
+        command_buffer.set_render_target (Some (target.clone ()));
+        command_buffer.set_viewport (0, 0, target.get_width (), target.get_height ());
+        command_buffer.clear (Some (Colour::white ()), Some (1.0), None);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Records the draws of the casters passing a visibility test.
+    fn _draw_casters<F> (command_buffer: &mut CommandBuffer,
+                         view_projection: &Mat4,
+                         casters: &[(&GpuMesh, Mat4)],
+                         is_visible: F)
+        where F: Fn (&Sphere) -> bool {
+
+        command_buffer.set_uniform ("u_view_projection", UniformValue::Mat4 (*view_projection));
+
+        for &(mesh, model) in casters {
+
+            if !is_visible (&Sphere::from_aabb (&mesh.bounds).transform (&model)) {
+                continue;
+            }
+
+            command_buffer.set_uniform ("u_model", UniformValue::Mat4 (model));
+            command_buffer.bind_vertex_buffer (mesh.vertex_buffer.clone ());
+            command_buffer.bind_index_buffer (mesh.index_buffer.clone (), IndexFormat::U32);
+            command_buffer.draw_indexed (0, mesh.index_count);
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the matrix mapping clip space to the texture coordinates of a tile in an atlas.
+    fn _get_atlas_matrix (tile: u32, tile_count: u32) -> Mat4 {
+
+        let tile_width = 1.0 / tile_count as f32;
+
+        Mat4::translate (&Vec3 {x: (tile as f32 + 0.5) * tile_width, y: 0.5, z: 0.5}) *
+        Mat4::scale (&Vec3 {x: 0.5 * tile_width, y: 0.5, z: 0.5})
+    }
+}
@@ -0,0 +1,236 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::CommandBuffer;
+use ::renderer::gpu::{PipelineDesc, RenderState, ShaderStage, UniformValue, VertexLayout};
+use ::renderer::material::MaterialParam;
+use ::renderer::traits::{Pipeline, RenderFactory};
+use ::util::Directory;
+use ::util::serialization::{Deserializer, Serializer};
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------MATERIAL STRUCT--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Describes how a surface is drawn.
+///
+/// A material references a vertex and fragment shader, and holds the parameters  
+/// passed to their uniforms along with the render state.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::command::{CommandBuffer, RenderCommand};
+/// # use ion_core::renderer::material::{Material, MaterialParam};
+/// # use ion_core::util::math::Colour;
+/// #
+/// let mut material = Material::new ("shaders/unlit.vert", "shaders/unlit.frag");
+/// material.set_param ("u_colour", MaterialParam::Colour (Colour::red ()));
+///
+/// let mut commands = CommandBuffer::new ();
+/// material.bind (&mut commands);
+///
+/// // The render state, followed by the colour uniform
+/// assert_eq! (commands.len (), 2);
+/// ```
+#[derive (Clone, Serialize, Deserialize)]
+pub struct Material {
+
+    // Public
+    /// The path of the vertex shader, relative to the resource directory.
+    pub vertex_shader: String,
+    /// The path of the fragment shader, relative to the resource directory.
+    pub fragment_shader: String,
+    /// The render state.
+    pub render_state: RenderState,
+    /// The parameters, by uniform name.
+    pub params: BTreeMap<String, MaterialParam>,
+}
+
+/*===============================================================================================*/
+/*------MATERIAL PUBLIC METHODS------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Material {
+
+    /// Sets a parameter.
+    pub fn set_param (&mut self, name: &str, param: MaterialParam) {
+        self.params.insert (name.to_string (), param);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a parameter.
+    pub fn get_param (&self, name: &str) -> Option<&MaterialParam> {
+        self.params.get (name)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates the textures referenced by the parameters.
+    pub fn load_textures (&mut self, render_factory: &RenderFactory) -> Result<(), ()> {
+
+        for param in self.params.values_mut () {
+
+            if let MaterialParam::Texture (ref mut handle) = *param {
+                try! (handle.load (render_factory));
+            }
+        }
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Compiles the shaders of the material, and creates a pipeline for a vertex layout.
+    pub fn create_pipeline (&self, render_factory: &RenderFactory, vertex_layout: VertexLayout) -> Result<Rc<Pipeline>, ()> {
+
+        let vertex_source   = try! (Material::_read_shader (&self.vertex_shader));
+        let fragment_source = try! (Material::_read_shader (&self.fragment_shader));
+
+        let vertex_shader   = try! (render_factory.create_shader (ShaderStage::Vertex,   &vertex_source));
+        let fragment_shader = try! (render_factory.create_shader (ShaderStage::Fragment, &fragment_source));
+
+        render_factory.create_pipeline (&PipelineDesc::new (vertex_shader, fragment_shader, vertex_layout))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Records the render state and parameters into a command buffer.
+    ///
+    /// The pipeline created from the material should be bound first.
+    pub fn bind (&self, command_buffer: &mut CommandBuffer) {
+        Material::bind_params (command_buffer, &self.render_state, self.params.iter (), 0);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Saves the material to a file.
+    pub fn save (&self, file_path: &str) -> Result<(), ()> {
+
+        match Serializer::to_file::<Material> (self, file_path) {
+
+            Ok (_) => Ok (()),
+            Err (e) => {
+
+                error! ("Could not save material \"{}\".\n{}", file_path, e);
+                Err (())
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------MATERIAL PUBLIC STATIC METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new material, with no parameters and the default render state.
+    pub fn new (vertex_shader: &str, fragment_shader: &str) -> Material {
+
+        Material {vertex_shader: vertex_shader.to_string (),
+                  fragment_shader: fragment_shader.to_string (),
+                  render_state: RenderState::new (),
+                  params: BTreeMap::new ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads a material from a file.
+    pub fn load (file_path: &str) -> Result<Material, ()> {
+
+        info! ("Loading material \"{}\".", file_path);
+
+        match Deserializer::from_file::<Material> (file_path) {
+
+            Ok (material) => Ok (material),
+            Err (e) => {
+
+                error! ("Material \"{}\" could not be loaded.\n{}", file_path, e);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Records a render state and set of parameters into a command buffer.
+    ///
+    /// Textures are bound to consecutive slots from `first_texture_slot`, in the order of the  
+    /// parameters, and the slot is passed to the uniform of the same name.
+    pub fn bind_params<'a, I> (command_buffer: &mut CommandBuffer, render_state: &RenderState, params: I, first_texture_slot: u32)
+        where I: Iterator<Item = (&'a String, &'a MaterialParam)> {
+
+        let mut texture_slot = first_texture_slot;
+
+        command_buffer.set_render_state (*render_state);
+
+        for (name, param) in params {
+
+            match *param {
+
+                MaterialParam::Texture (ref handle) => {
+
+                    match handle.get_texture () {
+
+                        Some (texture) => {
+
+                            command_buffer.bind_texture (texture_slot, texture);
+                            command_buffer.set_uniform (name, UniformValue::Int (texture_slot as i32));
+                            texture_slot += 1;
+                        },
+
+                        None => warn! ("Texture \"{}\" for material parameter \"{}\" is not loaded.", handle.path, name)
+                    }
+                },
+
+                _ => {
+
+                    if let Some (value) = param.get_uniform_value () {
+                        command_buffer.set_uniform (name, value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------MATERIAL PRIVATE STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Material {
+
+    // Reads the source of a shader, relative to the resource directory.
+    fn _read_shader (shader_path: &str) -> Result<String, ()> {
+
+        let path = format! ("{}{}", Directory::get_resource_directory (), shader_path);
+        let mut source = String::new ();
+
+        match File::open (&path).and_then (|mut file| file.read_to_string (&mut source)) {
+
+            Ok (_) => Ok (source),
+            Err (e) => {
+
+                error! ("Shader \"{}\" could not be read.\n{}", path, e);
+                Err (())
+            }
+        }
+    }
+}
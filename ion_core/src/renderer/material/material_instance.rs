@@ -0,0 +1,148 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::CommandBuffer;
+use ::renderer::gpu::RenderState;
+use ::renderer::material::{Material, MaterialParam};
+use ::renderer::traits::RenderFactory;
+
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------MATERIAL INSTANCE STRUCT-----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A material that shares the shader and parameters of another, but can override them.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::command::{CommandBuffer, RenderCommand};
+/// # use ion_core::renderer::gpu::UniformValue;
+/// # use ion_core::renderer::material::{Material, MaterialInstance, MaterialParam};
+/// # use ion_core::util::math::Colour;
+/// # use std::rc::Rc;
+/// #
+/// let mut material = Material::new ("shaders/unlit.vert", "shaders/unlit.frag");
+/// material.set_param ("u_colour", MaterialParam::Colour (Colour::red ()));
+///
+/// let mut instance = MaterialInstance::new (Rc::new (material));
+/// instance.set_param ("u_colour", MaterialParam::Colour (Colour::blue ()));
+///
+/// let mut commands = CommandBuffer::new ();
+/// instance.bind (&mut commands);
+///
+/// match commands.get_commands ()[1] {
+///     RenderCommand::SetUniform (_, UniformValue::Colour (c)) => assert_eq! (c.b, 1.0),
+///     _ => panic! ("Expected a colour uniform.")
+/// }
+/// ```
+#[derive (Clone)]
+pub struct MaterialInstance {
+
+    // Private
+    _material: Rc<Material>,
+    _overrides: BTreeMap<String, MaterialParam>,
+    _render_state: Option<RenderState>,
+}
+
+/*===============================================================================================*/
+/*------MATERIAL INSTANCE PUBLIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl MaterialInstance {
+
+    /// Returns the material the instance is based on.
+    pub fn get_material (&self) -> Rc<Material> {
+        self._material.clone ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Overrides a parameter of the material.
+    pub fn set_param (&mut self, name: &str, param: MaterialParam) {
+        self._overrides.insert (name.to_string (), param);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a parameter, falling back to the material if it isn't overridden.
+    pub fn get_param (&self, name: &str) -> Option<&MaterialParam> {
+        self._overrides.get (name).or_else (|| self._material.get_param (name))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes the override of a parameter.
+    pub fn clear_param (&mut self, name: &str) {
+        self._overrides.remove (name);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Overrides the render state of the material.
+    pub fn set_render_state (&mut self, render_state: RenderState) {
+        self._render_state = Some (render_state);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the render state, falling back to the material if it isn't overridden.
+    pub fn get_render_state (&self) -> RenderState {
+        self._render_state.unwrap_or (self._material.render_state)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates the textures referenced by the overridden parameters.
+    pub fn load_textures (&mut self, render_factory: &RenderFactory) -> Result<(), ()> {
+
+        for param in self._overrides.values_mut () {
+
+            if let MaterialParam::Texture (ref mut handle) = *param {
+                try! (handle.load (render_factory));
+            }
+        }
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Records the render state and parameters into a command buffer.
+    ///
+    /// The pipeline created from the material should be bound first.
+    pub fn bind (&self, command_buffer: &mut CommandBuffer) {
+
+        let mut params: BTreeMap<&String, &MaterialParam> = self._material.params.iter ().collect ();
+        params.extend (self._overrides.iter ());
+
+        Material::bind_params (command_buffer, &self.get_render_state (), params.into_iter (), 0);
+    }
+
+/*===============================================================================================*/
+/*------MATERIAL INSTANCE PUBLIC STATIC METHODS--------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new instance of a material, with no overrides.
+    pub fn new (material: Rc<Material>) -> MaterialInstance {
+
+        MaterialInstance {_material: material,
+                          _overrides: BTreeMap::new (),
+                          _render_state: None}
+    }
+}
@@ -0,0 +1,68 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::UniformValue;
+use ::renderer::material::TextureHandle;
+use ::util::math::{Colour, Mat4, Vec2, Vec3, Vec4};
+
+/*===============================================================================================*/
+/*------MATERIAL PARAM ENUM----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A typed material parameter, bound to a shader uniform of the same name.
+#[derive (Clone, Serialize, Deserialize)]
+pub enum MaterialParam {
+
+    /// Float value.
+    Float (f32),
+    /// 2D vector.
+    Vec2 (Vec2),
+    /// 3D vector.
+    Vec3 (Vec3),
+    /// 4D vector.
+    Vec4 (Vec4),
+    /// 4x4 matrix.
+    Mat4 (Mat4),
+    /// RGBA colour.
+    Colour (Colour),
+    /// Texture, bound to a texture slot.
+    Texture (TextureHandle),
+}
+
+/*===============================================================================================*/
+/*------MATERIAL PARAM PUBLIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl MaterialParam {
+
+    /// Returns the uniform value of the parameter.
+    ///
+    /// Textures return `None`, as they are bound to a slot instead.
+    pub fn get_uniform_value (&self) -> Option<UniformValue> {
+
+        match *self {
+
+            MaterialParam::Float (v)   => Some (UniformValue::Float (v)),
+            MaterialParam::Vec2 (v)    => Some (UniformValue::Vec2 (v)),
+            MaterialParam::Vec3 (v)    => Some (UniformValue::Vec3 (v)),
+            MaterialParam::Vec4 (v)    => Some (UniformValue::Vec4 (v)),
+            MaterialParam::Mat4 (v)    => Some (UniformValue::Mat4 (v)),
+            MaterialParam::Colour (v)  => Some (UniformValue::Colour (v)),
+            MaterialParam::Texture (_) => None,
+        }
+    }
+}
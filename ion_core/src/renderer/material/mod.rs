@@ -0,0 +1,32 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+/*===============================================================================================*/
+//! The material module.
+//!
+//! A material references a shader, and holds the parameters and render state used to draw with it.  
+//! Materials are serialized to files, and shared between objects using material instances.
+/*===============================================================================================*/
+
+mod material;
+mod material_instance;
+mod material_param;
+mod texture_handle;
+
+pub use self::material::Material;
+pub use self::material_instance::MaterialInstance;
+pub use self::material_param::MaterialParam;
+pub use self::texture_handle::TextureHandle;
@@ -0,0 +1,92 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::TextureDesc;
+use ::renderer::traits::{RenderFactory, Texture};
+use ::util::Directory;
+use ::util::image::Image;
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------TEXTURE HANDLE STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A reference to a texture, used by material parameters.
+///
+/// Only the path of the texture is serialized. The texture itself is created on demand.
+#[derive (Clone, Default, Serialize, Deserialize)]
+pub struct TextureHandle {
+
+    // Public
+    /// The path of the texture image, relative to the resource directory.
+    pub path: String,
+
+    // Private
+    #[serde (skip_serializing, skip_deserializing)]
+    _texture: Option<Rc<Texture>>,
+}
+
+/*===============================================================================================*/
+/*------TEXTURE HANDLE PUBLIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl TextureHandle {
+
+    /// Returns the texture, if it has been created.
+    pub fn get_texture (&self) -> Option<Rc<Texture>> {
+        self._texture.clone ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads the texture image, and creates the texture.
+    ///
+    /// Does nothing if the texture has already been created.
+    pub fn load (&mut self, render_factory: &RenderFactory) -> Result<(), ()> {
+
+        if self._texture.is_some () {
+            return Ok (());
+        }
+
+        let image = try! (Image::load (&format! ("{}{}", Directory::get_resource_directory (), self.path)));
+
+        let mut desc = TextureDesc::from_image (&image);
+        desc.has_mipmaps = true;
+
+        self._texture = Some (try! (render_factory.create_texture (&desc, image.get_data ())));
+        Ok (())
+    }
+
+/*===============================================================================================*/
+/*------TEXTURE HANDLE PUBLIC STATIC METHODS-----------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new handle to the texture at a path.
+    pub fn new (path: &str) -> TextureHandle {
+        TextureHandle {path: path.to_string (), _texture: None}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new handle to an existing texture.
+    ///
+    /// The handle has no path, so the texture is lost when serialized.
+    pub fn from_texture (texture: Rc<Texture>) -> TextureHandle {
+        TextureHandle {path: String::new (), _texture: Some (texture)}
+    }
+}
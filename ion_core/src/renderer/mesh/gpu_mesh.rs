@@ -0,0 +1,76 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::{BufferDesc, BufferType, BufferUsage};
+use ::renderer::mesh::{Mesh, SubMesh};
+use ::renderer::traits::{Buffer, RenderFactory};
+use ::util::math::Aabb;
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------GPU MESH STRUCT--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A mesh uploaded to the GPU.
+///
+/// The vertices use the layout of `Vertex`, and the indices are 32-bit.
+#[derive (Clone)]
+pub struct GpuMesh {
+
+    // Public
+    /// The vertex buffer.
+    pub vertex_buffer: Rc<Buffer>,
+    /// The index buffer.
+    pub index_buffer: Rc<Buffer>,
+    /// The total number of indices.
+    pub index_count: u32,
+    /// The sub meshes of the mesh.
+    pub sub_meshes: Vec<SubMesh>,
+    /// The bounding box of the mesh.
+    pub bounds: Aabb,
+}
+
+/*===============================================================================================*/
+/*------GPU MESH PUBLIC STATIC METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl GpuMesh {
+
+    /// Uploads a mesh using the render factory.
+    pub fn new (render_factory: &RenderFactory, mesh: &Mesh) -> Result<GpuMesh, ()> {
+
+        let vertex_data = mesh.get_vertex_data ();
+        let index_data  = mesh.get_index_data ();
+
+        let vertex_buffer = try! (render_factory.create_buffer (&BufferDesc::new (BufferType::Vertex,
+                                                                                  BufferUsage::Static,
+                                                                                  vertex_data.len ()),
+                                                                vertex_data));
+
+        let index_buffer = try! (render_factory.create_buffer (&BufferDesc::new (BufferType::Index,
+                                                                                 BufferUsage::Static,
+                                                                                 index_data.len ()),
+                                                               index_data));
+
+        Ok (GpuMesh {vertex_buffer: vertex_buffer,
+                     index_buffer: index_buffer,
+                     index_count: mesh.indices.len () as u32,
+                     sub_meshes: mesh.sub_meshes.clone (),
+                     bounds: mesh.bounds})
+    }
+}
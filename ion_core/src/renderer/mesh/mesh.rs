@@ -0,0 +1,290 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::mesh::{SubMesh, Vertex};
+use ::util::Bytes;
+use ::util::math::{Aabb, Vec3, Vec4};
+
+/*===============================================================================================*/
+/*------MESH STRUCT------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Indexed triangle geometry.
+///
+/// The indices are split into sub meshes, each of which is drawn with its own material.
+#[derive (Clone, Serialize, Deserialize)]
+pub struct Mesh {
+
+    // Public
+    /// The vertices of the mesh.
+    pub vertices: Vec<Vertex>,
+    /// The triangle indices of the mesh.
+    pub indices: Vec<u32>,
+    /// The sub meshes of the mesh.
+    pub sub_meshes: Vec<SubMesh>,
+    /// The bounding box of the mesh.
+    pub bounds: Aabb,
+}
+
+/*===============================================================================================*/
+/*------MESH PUBLIC METHODS----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Mesh {
+
+    /// Generates smooth vertex normals from the triangles of the mesh.
+    ///
+    /// Face normals are weighted by the area of the face.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::mesh::{Mesh, Vertex};
+    /// # use ion_core::util::math::Vec3;
+    /// #
+    /// let mut mesh = Mesh::new ();
+    ///
+    /// mesh.vertices = vec! [Vertex::new (&Vec3 {x: 0.0, y: 0.0, z: 0.0}),
+    ///                       Vertex::new (&Vec3 {x: 1.0, y: 0.0, z: 0.0}),
+    ///                       Vertex::new (&Vec3 {x: 0.0, y: 1.0, z: 0.0})];
+    /// mesh.indices  = vec! [0, 1, 2];
+    /// mesh.generate_normals ();
+    ///
+    /// assert! (mesh.vertices[0].normal == Vec3 {x: 0.0, y: 0.0, z: 1.0});
+    /// ```
+    pub fn generate_normals (&mut self) {
+
+        let is_missing = vec! [true; self.vertices.len ()];
+        self.generate_missing_normals (&is_missing);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Generates smooth vertex normals for the vertices flagged in `is_missing`,
+    /// and leaves the normals of the other vertices as they are.
+    ///
+    /// This is used when only some faces of an imported mesh have normals.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::mesh::{Mesh, Vertex};
+    /// # use ion_core::util::math::Vec3;
+    /// #
+    /// let mut mesh = Mesh::new ();
+    ///
+    /// mesh.vertices = vec! [Vertex::new (&Vec3 {x: 0.0, y: 0.0, z: 0.0}),
+    ///                       Vertex::new (&Vec3 {x: 1.0, y: 0.0, z: 0.0}),
+    ///                       Vertex::new (&Vec3 {x: 0.0, y: 1.0, z: 0.0})];
+    /// mesh.indices  = vec! [0, 1, 2];
+    /// mesh.vertices[0].normal = Vec3 {x: 1.0, y: 0.0, z: 0.0};
+    ///
+    /// mesh.generate_missing_normals (&[false, true, true]);
+    ///
+    /// assert! (mesh.vertices[0].normal == Vec3 {x: 1.0, y: 0.0, z: 0.0});
+    /// assert! (mesh.vertices[1].normal == Vec3 {x: 0.0, y: 0.0, z: 1.0});
+    /// ```
+    pub fn generate_missing_normals (&mut self, is_missing: &[bool]) {
+
+        let mut normals = vec! [Vec3::new (); self.vertices.len ()];
+
+        for triangle in self.indices.chunks (3) {
+
+            if triangle.len () < 3 {
+                break;
+            }
+
+            let (i0, i1, i2) = (triangle[0] as usize, triangle[1] as usize, triangle[2] as usize);
+
+            let p0 = self.vertices[i0].position;
+            let p1 = self.vertices[i1].position;
+            let p2 = self.vertices[i2].position;
+
+            // The length of the cross product is twice the area of the triangle
+            let face_normal = Vec3::cross (&(p1 - p0), &(p2 - p0));
+
+            normals[i0] += face_normal;
+            normals[i1] += face_normal;
+            normals[i2] += face_normal;
+        }
+
+        for ((vertex, normal), is_missing) in self.vertices.iter_mut ().zip (normals.iter ()).zip (is_missing.iter ()) {
+
+            if *is_missing {
+                vertex.normal = Vec3::normalize (normal);
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Generates vertex tangents from the normals and texture coordinates of the mesh.
+    ///
+    /// The normals must be present before generating tangents.
+    pub fn generate_tangents (&mut self) {
+
+        let is_missing = vec! [true; self.vertices.len ()];
+        self.generate_missing_tangents (&is_missing);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Generates vertex tangents for the vertices flagged in `is_missing`,
+    /// and leaves the tangents of the other vertices as they are.
+    ///
+    /// This is used when only some faces of an imported mesh have tangents.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::mesh::{Mesh, Vertex};
+    /// # use ion_core::util::math::{Vec2, Vec3, Vec4};
+    /// #
+    /// let mut mesh = Mesh::new ();
+    ///
+    /// mesh.vertices = vec! [Vertex::new (&Vec3 {x: 0.0, y: 0.0, z: 0.0}),
+    ///                       Vertex::new (&Vec3 {x: 1.0, y: 0.0, z: 0.0}),
+    ///                       Vertex::new (&Vec3 {x: 0.0, y: 1.0, z: 0.0})];
+    /// mesh.indices  = vec! [0, 1, 2];
+    /// mesh.vertices[1].uv = Vec2 {x: 1.0, y: 0.0};
+    /// mesh.vertices[2].uv = Vec2 {x: 0.0, y: 1.0};
+    /// mesh.vertices[0].tangent = Vec4 {x: 0.0, y: 1.0, z: 0.0, w: 1.0};
+    ///
+    /// mesh.generate_normals ();
+    /// mesh.generate_missing_tangents (&[false, true, true]);
+    ///
+    /// assert! (mesh.vertices[0].tangent == Vec4 {x: 0.0, y: 1.0, z: 0.0, w: 1.0});
+    /// assert! (mesh.vertices[1].tangent == Vec4 {x: 1.0, y: 0.0, z: 0.0, w: 1.0});
+    /// ```
+    pub fn generate_missing_tangents (&mut self, is_missing: &[bool]) {
+
+        let mut tangents   = vec! [Vec3::new (); self.vertices.len ()];
+        let mut bitangents = vec! [Vec3::new (); self.vertices.len ()];
+
+        for triangle in self.indices.chunks (3) {
+
+            if triangle.len () < 3 {
+                break;
+            }
+
+            let (i0, i1, i2) = (triangle[0] as usize, triangle[1] as usize, triangle[2] as usize);
+            let (v0, v1, v2) = (self.vertices[i0], self.vertices[i1], self.vertices[i2]);
+
+            let edge1 = v1.position - v0.position;
+            let edge2 = v2.position - v0.position;
+            let delta_uv1 = v1.uv - v0.uv;
+            let delta_uv2 = v2.uv - v0.uv;
+
+            let det = delta_uv1.x * delta_uv2.y - delta_uv2.x * delta_uv1.y;
+
+            // Skip triangles with degenerate texture coordinates
+            if det.abs () < 1e-8 {
+                continue;
+            }
+
+            let r = 1.0 / det;
+            let tangent   = (edge1 * delta_uv2.y - edge2 * delta_uv1.y) * r;
+            let bitangent = (edge2 * delta_uv1.x - edge1 * delta_uv2.x) * r;
+
+            for &index in &[i0, i1, i2] {
+
+                tangents[index]   += tangent;
+                bitangents[index] += bitangent;
+            }
+        }
+
+        for ((i, vertex), is_missing) in self.vertices.iter_mut ().enumerate ().zip (is_missing.iter ()) {
+
+            if !*is_missing {
+                continue;
+            }
+
+            let normal = vertex.normal;
+
+            // Gram-Schmidt orthogonalize
+            let mut tangent = Vec3::normalize (&(tangents[i] - normal * Vec3::dot (&normal, &tangents[i])));
+
+            // Fall back to any vector perpendicular to the normal
+            if tangent == Vec3::new () {
+
+                let axis = if normal.x.abs () < 0.9 {Vec3 {x: 1.0, y: 0.0, z: 0.0}}
+                           else                     {Vec3 {x: 0.0, y: 1.0, z: 0.0}};
+
+                tangent = Vec3::normalize (&Vec3::cross (&normal, &axis));
+            }
+
+            let handedness = if Vec3::dot (&Vec3::cross (&normal, &tangent), &bitangents[i]) < 0.0 {-1.0}
+                             else {1.0};
+
+            vertex.tangent = Vec4 {x: tangent.x, y: tangent.y, z: tangent.z, w: handedness};
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Recalculates the bounding box from the vertex positions.
+    pub fn recalculate_bounds (&mut self) {
+
+        self.bounds = Aabb::new ();
+
+        for vertex in &self.vertices {
+            self.bounds.expand (&vertex.position);
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the vertices as raw bytes, for uploading to a vertex buffer.
+    pub fn get_vertex_data (&self) -> &[u8] {
+
+        // Vertices are made only of f32s, so they have no padding
+        unsafe {Bytes::from_slice (&self.vertices)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the indices as raw bytes, for uploading to a 32-bit index buffer.
+    pub fn get_index_data (&self) -> &[u8] {
+        unsafe {Bytes::from_slice (&self.indices)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of triangles in the mesh.
+    pub fn get_triangle_count (&self) -> usize {
+        self.indices.len () / 3
+    }
+
+/*===============================================================================================*/
+/*------MESH PUBLIC STATIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty mesh.
+    pub fn new () -> Mesh {
+
+        Mesh {vertices: Vec::new (),
+              indices: Vec::new (),
+              sub_meshes: Vec::new (),
+              bounds: Aabb::new ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for Mesh {
+
+    fn default () -> Mesh {
+        Mesh::new ()
+    }
+}
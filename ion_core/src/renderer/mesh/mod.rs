@@ -0,0 +1,32 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+/*===============================================================================================*/
+//! The mesh module.
+//!
+//! Contains the CPU side representation of geometry.  
+//! Meshes are loaded through the Resource Manager, and uploaded using the active `RenderFactory`.
+/*===============================================================================================*/
+
+mod gpu_mesh;
+mod mesh;
+mod sub_mesh;
+mod vertex;
+
+pub use self::gpu_mesh::GpuMesh;
+pub use self::mesh::Mesh;
+pub use self::sub_mesh::SubMesh;
+pub use self::vertex::Vertex;
@@ -0,0 +1,51 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------SUB MESH STRUCT--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A range of indices in a mesh, drawn with a single material.
+#[derive (Clone, Default, Serialize, Deserialize)]
+pub struct SubMesh {
+
+    // Public
+    /// The name of the sub mesh.
+    pub name: String,
+    /// The name of the material used by the sub mesh.
+    pub material: String,
+    /// The first index of the sub mesh.
+    pub first_index: u32,
+    /// The number of indices in the sub mesh.
+    pub index_count: u32,
+}
+
+/*===============================================================================================*/
+/*------SUB MESH PUBLIC STATIC METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl SubMesh {
+
+    /// Returns a new sub mesh.
+    pub fn new (name: &str, first_index: u32) -> SubMesh {
+
+        SubMesh {name: name.to_string (),
+                 material: String::new (),
+                 first_index: first_index,
+                 index_count: 0}
+    }
+}
@@ -0,0 +1,97 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::{VertexFormat, VertexLayout};
+use ::util::math::{Colour, Vec2, Vec3, Vec4};
+
+/*===============================================================================================*/
+/*------VERTEX STRUCT----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A single mesh vertex.
+///
+/// Vertices are stored interleaved, and can be uploaded to a vertex buffer as is.
+#[repr (C)]
+#[derive (Copy, Clone, Serialize, Deserialize)]
+pub struct Vertex {
+
+    // Public
+    /// The position of the vertex.
+    pub position: Vec3,
+    /// The normal of the vertex.
+    pub normal: Vec3,
+    /// The texture coordinate of the vertex.
+    pub uv: Vec2,
+    /// The tangent of the vertex.
+    ///
+    /// The w component stores the handedness of the bitangent.
+    pub tangent: Vec4,
+    /// The colour of the vertex.
+    pub colour: Colour,
+}
+
+/*===============================================================================================*/
+/*------VERTEX PUBLIC STATIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Vertex {
+
+    /// Returns the vertex layout matching the vertex struct.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::mesh::Vertex;
+    /// # use std::mem;
+    /// #
+    /// assert_eq! (Vertex::get_vertex_layout ().stride, mem::size_of::<Vertex> ());
+    /// ```
+    pub fn get_vertex_layout () -> VertexLayout {
+
+        let mut layout = VertexLayout::new ();
+
+        layout.push ("a_position", VertexFormat::Float3)
+              .push ("a_normal",   VertexFormat::Float3)
+              .push ("a_uv",       VertexFormat::Float2)
+              .push ("a_tangent",  VertexFormat::Float4)
+              .push ("a_colour",   VertexFormat::Float4);
+
+        layout
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new vertex at a position.
+    ///
+    /// The remaining attributes are zeroed, except for the colour, which is white.
+    pub fn new (position: &Vec3) -> Vertex {
+
+        Vertex {position: *position,
+                normal: Vec3::new (),
+                uv: Vec2::new (),
+                tangent: Vec4::new (),
+                colour: Colour::new ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for Vertex {
+
+    fn default () -> Vertex {
+        Vertex::new (&Vec3::new ())
+    }
+}
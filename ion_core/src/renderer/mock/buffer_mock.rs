@@ -0,0 +1,100 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::{BufferDesc, BufferType, BufferUsage};
+use ::renderer::traits::Buffer;
+use ::util::traits::AsAny;
+
+use std::any::Any;
+use std::cell::RefCell;
+
+/*===============================================================================================*/
+/*------BUFFER MOCK STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A buffer which keeps its contents in system memory.
+pub struct BufferMock {
+
+    // Private
+    _desc: BufferDesc,
+    _data: RefCell<Vec<u8>>,
+}
+
+/*===============================================================================================*/
+/*------BUFFER MOCK PUBLIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BufferMock {
+
+    /// Returns a copy of the buffer contents.
+    pub fn get_data (&self) -> Vec<u8> {
+        self._data.borrow ().clone ()
+    }
+
+/*===============================================================================================*/
+/*------BUFFER MOCK PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new mock buffer, filled with `data`.
+    pub fn new (desc: &BufferDesc, data: &[u8]) -> BufferMock {
+
+        let buffer = BufferMock {_desc: *desc, _data: RefCell::new (vec! [0; desc.buffer_size])};
+        buffer.set_data (0, data);
+        buffer
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Buffer for BufferMock {
+
+    fn get_buffer_type (&self) -> BufferType {
+        self._desc.buffer_type
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_buffer_usage (&self) -> BufferUsage {
+        self._desc.buffer_usage
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_size (&self) -> usize {
+        self._desc.buffer_size
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn set_data (&self, offset: usize, data: &[u8]) {
+
+        let mut buffer_data = self._data.borrow_mut ();
+
+        for (dst, src) in buffer_data.iter_mut ().skip (offset).zip (data.iter ()) {
+            *dst = *src;
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for BufferMock {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
@@ -0,0 +1,39 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The mock module.
+//!
+//! Contains a render backend which records everything it is asked to do, and draws it in software.  
+//! It is used for testing rendering code without a window or GPU.
+/*===============================================================================================*/
+
+mod buffer_mock;
+mod pipeline_mock;
+mod render_factory_mock;
+mod render_target_mock;
+mod shader_mock;
+mod software_rasterizer;
+mod texture_mock;
+
+pub use self::buffer_mock::BufferMock;
+pub use self::pipeline_mock::PipelineMock;
+pub use self::render_factory_mock::RenderFactoryMock;
+pub use self::render_target_mock::RenderTargetMock;
+pub use self::shader_mock::ShaderMock;
+pub use self::software_rasterizer::SoftwareRasterizer;
+pub use self::texture_mock::TextureMock;
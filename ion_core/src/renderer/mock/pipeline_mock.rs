@@ -0,0 +1,70 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::{PipelineDesc, PrimitiveType, VertexLayout};
+use ::renderer::traits::Pipeline;
+use ::util::traits::AsAny;
+
+use std::any::Any;
+
+/*===============================================================================================*/
+/*------PIPELINE MOCK STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A pipeline which stores its description.
+pub struct PipelineMock {
+
+    // Public
+    /// The pipeline description.
+    pub desc: PipelineDesc,
+}
+
+/*===============================================================================================*/
+/*------PIPELINE MOCK PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PipelineMock {
+
+    /// Returns a new mock pipeline.
+    pub fn new (desc: &PipelineDesc) -> PipelineMock {
+        PipelineMock {desc: desc.clone ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Pipeline for PipelineMock {
+
+    fn get_vertex_layout (&self) -> &VertexLayout {
+        &self.desc.vertex_layout
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_primitive_type (&self) -> PrimitiveType {
+        self.desc.primitive_type
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for PipelineMock {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
@@ -0,0 +1,233 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::{CommandBuffer, RenderCommand};
+use ::renderer::gpu::{BufferDesc, BuiltinShader, PipelineDesc, RenderTargetDesc, ShaderStage, TextureDesc};
+use ::renderer::mock::{BufferMock, PipelineMock, RenderTargetMock, ShaderMock, SoftwareRasterizer, TextureMock};
+use ::renderer::traits::{Buffer, Pipeline, RenderFactory, RenderTarget, Shader, Texture};
+use ::util::image::Image;
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------RENDER FACTORY MOCK STRUCT---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A render factory which records every command it executes.
+///
+/// Resources are kept in system memory, and commands are drawn by a `SoftwareRasterizer`,
+/// so reading back pixels returns what was drawn.  
+/// Draws made without a bound pipeline or vertex buffer are recorded as errors.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::command::CommandBuffer;
+/// # use ion_core::renderer::mock::RenderFactoryMock;
+/// # use ion_core::renderer::traits::RenderFactory;
+/// #
+/// let factory = RenderFactoryMock::new ();
+/// let mut cmd = CommandBuffer::new ();
+/// cmd.draw (0, 3);
+///
+/// factory.execute_command_buffer (&cmd);
+///
+/// assert_eq! (factory.get_draw_call_count (), 1);
+/// assert_eq! (factory.get_errors ().len (), 1); // Nothing was bound
+/// ```
+#[derive (Default)]
+pub struct RenderFactoryMock {
+
+    // Private
+    _commands: RefCell<Vec<RenderCommand>>,
+    _errors: RefCell<Vec<String>>,
+    _resource_count: Cell<usize>,
+    _has_pipeline: Cell<bool>,
+    _has_vertex_buffer: Cell<bool>,
+    _has_index_buffer: Cell<bool>,
+    _rasterizer: RefCell<SoftwareRasterizer>,
+}
+
+/*===============================================================================================*/
+/*------RENDER FACTORY MOCK PUBLIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderFactoryMock {
+
+    /// Returns every command executed since the last reset.
+    pub fn get_executed_commands (&self) -> Vec<RenderCommand> {
+        self._commands.borrow ().clone ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of draw calls executed since the last reset.
+    pub fn get_draw_call_count (&self) -> usize {
+
+        self._commands.borrow ().iter ().filter (|c| match **c {
+
+            RenderCommand::Draw (..) | RenderCommand::DrawIndexed (..) => true,
+            _ => false
+
+        }).count ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of vertices drawn since the last reset.
+    ///
+    /// Indexed draws count each index as a vertex.
+    pub fn get_vertex_count (&self) -> usize {
+
+        self._commands.borrow ().iter ().map (|c| match *c {
+
+            RenderCommand::Draw (_, count) | RenderCommand::DrawIndexed (_, count) => count as usize,
+            _ => 0
+
+        }).fold (0, |a, b| a + b)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of resources created.
+    pub fn get_resource_count (&self) -> usize {
+        self._resource_count.get ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns any errors found while executing commands.
+    pub fn get_errors (&self) -> Vec<String> {
+        self._errors.borrow ().clone ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Clears the recorded commands and errors, and forgets the bound resources.
+    pub fn reset (&self) {
+
+        self._commands.borrow_mut ().clear ();
+        self._errors.borrow_mut ().clear ();
+
+        self._has_pipeline.set (false);
+        self._has_vertex_buffer.set (false);
+        self._has_index_buffer.set (false);
+
+        self._rasterizer.borrow_mut ().reset_bindings ();
+    }
+
+/*===============================================================================================*/
+/*------RENDER FACTORY MOCK PUBLIC STATIC METHODS------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new mock render factory.
+    pub fn new () -> RenderFactoryMock {
+        RenderFactoryMock::default ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl RenderFactory for RenderFactoryMock {
+
+    fn create_buffer (&self, desc: &BufferDesc, data: &[u8]) -> Result<Rc<Buffer>, ()> {
+
+        self._resource_count.set (self._resource_count.get () + 1);
+        Ok (Rc::new (BufferMock::new (desc, data)))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_texture (&self, desc: &TextureDesc, data: &[u8]) -> Result<Rc<Texture>, ()> {
+
+        self._resource_count.set (self._resource_count.get () + 1);
+        Ok (Rc::new (TextureMock::new (desc, data)))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_shader (&self, stage: ShaderStage, source: &str) -> Result<Rc<Shader>, ()> {
+
+        self._resource_count.set (self._resource_count.get () + 1);
+        Ok (Rc::new (ShaderMock::new (stage, source)))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_builtin_shader (&self, shader: BuiltinShader, stage: ShaderStage) -> Result<Rc<Shader>, ()> {
+        self.create_shader (stage, &format! ("builtin:{:?}", shader))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_pipeline (&self, desc: &PipelineDesc) -> Result<Rc<Pipeline>, ()> {
+
+        self._resource_count.set (self._resource_count.get () + 1);
+        Ok (Rc::new (PipelineMock::new (desc)))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_render_target (&self, desc: &RenderTargetDesc) -> Result<Rc<RenderTarget>, ()> {
+
+        self._resource_count.set (self._resource_count.get () + 1);
+        Ok (Rc::new (RenderTargetMock::new (desc)))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn read_pixels (&self, target: Option<Rc<RenderTarget>>, x: i32, y: i32, width: u32, height: u32) -> Result<Image, ()> {
+        Ok (self._rasterizer.borrow ().read_pixels (target, x, y, width, height))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn execute_command_buffer (&self, command_buffer: &CommandBuffer) {
+
+        for command in command_buffer.get_commands () {
+
+            // Track the bound state, and validate draws against it
+            match *command {
+
+                RenderCommand::BindPipeline (_)        => self._has_pipeline.set (true),
+                RenderCommand::BindVertexBuffer (_)    => self._has_vertex_buffer.set (true),
+                RenderCommand::BindIndexBuffer (_, _)  => self._has_index_buffer.set (true),
+
+                RenderCommand::Draw (_, _) => {
+
+                    if !self._has_pipeline.get () || !self._has_vertex_buffer.get () {
+                        self._errors.borrow_mut ().push ("Draw called without a bound pipeline and vertex buffer.".to_string ());
+                    }
+                }
+
+                RenderCommand::DrawIndexed (_, _) => {
+
+                    if !self._has_pipeline.get () || !self._has_vertex_buffer.get () || !self._has_index_buffer.get () {
+                        self._errors.borrow_mut ().push ("DrawIndexed called without a bound pipeline, vertex and index buffer.".to_string ());
+                    }
+                }
+
+                _ => {}
+            }
+
+            self._rasterizer.borrow_mut ().execute (command);
+            self._commands.borrow_mut ().push (command.clone ());
+        }
+
+        self._rasterizer.borrow ().flush ();
+    }
+}
@@ -0,0 +1,85 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::{RenderTargetDesc, TextureDesc};
+use ::renderer::mock::TextureMock;
+use ::renderer::traits::{RenderTarget, Texture};
+use ::util::traits::AsAny;
+
+use std::any::Any;
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------RENDER TARGET MOCK STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A render target backed by a mock texture.
+pub struct RenderTargetMock {
+
+    // Public
+    /// The render target description.
+    pub desc: RenderTargetDesc,
+
+    // Private
+    _colour_texture: Rc<Texture>,
+}
+
+/*===============================================================================================*/
+/*------RENDER TARGET MOCK PUBLIC STATIC METHODS-------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderTargetMock {
+
+    /// Returns a new mock render target.
+    pub fn new (desc: &RenderTargetDesc) -> RenderTargetMock {
+
+        let texture_desc = TextureDesc::new (desc.width, desc.height, desc.colour_format);
+
+        RenderTargetMock {desc: *desc,
+                          _colour_texture: Rc::new (TextureMock::new (&texture_desc, &[]))}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl RenderTarget for RenderTargetMock {
+
+    fn get_width (&self) -> u32 {
+        self.desc.width
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_height (&self) -> u32 {
+        self.desc.height
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_colour_texture (&self) -> Rc<Texture> {
+        self._colour_texture.clone ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for RenderTargetMock {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
@@ -0,0 +1,67 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::ShaderStage;
+use ::renderer::traits::Shader;
+use ::util::traits::AsAny;
+
+use std::any::Any;
+
+/*===============================================================================================*/
+/*------SHADER MOCK STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A shader which stores its source, without compiling it.
+pub struct ShaderMock {
+
+    // Public
+    /// The shader source.
+    pub source: String,
+
+    // Private
+    _stage: ShaderStage,
+}
+
+/*===============================================================================================*/
+/*------SHADER MOCK PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ShaderMock {
+
+    /// Returns a new mock shader.
+    pub fn new (stage: ShaderStage, source: &str) -> ShaderMock {
+        ShaderMock {source: source.to_string (), _stage: stage}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Shader for ShaderMock {
+
+    fn get_shader_stage (&self) -> ShaderStage {
+        self._stage
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for ShaderMock {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
@@ -0,0 +1,667 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+
+use ::renderer::command::RenderCommand;
+use ::renderer::gpu::{BlendMode, CullMode, IndexFormat, PrimitiveType, RenderState, TextureFormat, UniformValue, VertexFormat, VertexLayout};
+use ::renderer::mock::{BufferMock, TextureMock};
+use ::renderer::traits::{Buffer, Pipeline, RenderTarget, Texture};
+use ::util::image::{Image, ImageFormat};
+use ::util::math::{Colour, Mat4, Vec4};
+
+use std::cmp;
+use std::collections::HashMap;
+use std::f32;
+use std::mem;
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------RASTER VERTEX STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// A vertex after the vertex stage, in window coordinates.
+#[derive (Copy, Clone)]
+struct RasterVertex {
+
+    x: f32,
+    y: f32,
+    inv_w: f32,
+    colour: Colour,
+    uv: (f32, f32),
+}
+
+/*===============================================================================================*/
+/*------SOFTWARE RASTERIZER STRUCT---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Draws the commands executed by the mock render factory into images in system memory.
+///
+/// Only what simple scenes need is emulated. Shaders aren't run, instead each fragment is the
+/// `a_colour` attribute, multiplied by the `u_colour` uniform and the texture bound to the
+/// `u_texture` slot, sampled at `a_uv`. Positions are transformed by `u_model` and
+/// `u_view_projection`, if they are set.  
+/// Triangles are culled, scissored and blended like the OpenGL backend, but there is no depth buffer,
+/// so they are drawn in submission order. Lines and points aren't drawn, and triangles
+/// crossing the near plane are skipped rather than clipped.  
+/// Rows are stored from the bottom, like OpenGL, so render targets can be sampled as textures.
+pub struct SoftwareRasterizer {
+
+    // Private
+    _target: Option<Rc<RenderTarget>>,
+    _target_image: Option<Image>,
+    _window_image: Image,
+    _viewport: (i32, i32, u32, u32),
+    _scissor: Option<(i32, i32, u32, u32)>,
+    _render_state: RenderState,
+    _pipeline: Option<Rc<Pipeline>>,
+    _vertex_buffer: Option<Rc<Buffer>>,
+    _index_buffer: Option<(Rc<Buffer>, IndexFormat)>,
+    _textures: HashMap<u32, Rc<Texture>>,
+    _uniforms: HashMap<String, UniformValue>,
+}
+
+/*===============================================================================================*/
+/*------SOFTWARE RASTERIZER PUBLIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl SoftwareRasterizer {
+
+    /// Executes a single command.
+    pub fn execute (&mut self, command: &RenderCommand) {
+
+        match *command {
+
+            RenderCommand::SetRenderTarget (ref target) => {
+
+                self.flush ();
+
+                self._target_image = match *target {
+                    Some (ref target) => SoftwareRasterizer::_load_target_image (target),
+                    None => None
+                };
+
+                self._target = target.clone ();
+            },
+
+            RenderCommand::SetViewport (x, y, width, height) => {
+
+                self._viewport = (x, y, width, height);
+
+                if self._target.is_none () {
+                    self._grow_window_image (x, y, width, height);
+                }
+            },
+
+            RenderCommand::SetScissor (rect) => self._scissor = rect,
+
+            RenderCommand::Clear {colour: Some (colour), ..} => {
+
+                let scissor = self._scissor;
+
+                if let Some (image) = self._get_colour_buffer () {
+
+                    let (min_x, min_y, max_x, max_y) = SoftwareRasterizer::_get_bounds (image, scissor, None);
+
+                    for y in min_y..max_y {
+
+                        for x in min_x..max_x {
+                            image.set_pixel (x as u32, y as u32, &colour);
+                        }
+                    }
+                }
+            },
+
+            RenderCommand::SetRenderState (state) => self._render_state = state,
+
+            RenderCommand::BindPipeline (ref pipeline) => {
+
+                // Uniforms belong to the pipeline they were set on
+                self._pipeline = Some (pipeline.clone ());
+                self._uniforms.clear ();
+            },
+
+            RenderCommand::BindVertexBuffer (ref buffer)        => self._vertex_buffer = Some (buffer.clone ()),
+            RenderCommand::BindIndexBuffer (ref buffer, format) => self._index_buffer = Some ((buffer.clone (), format)),
+            RenderCommand::BindTexture (slot, ref texture)      => {self._textures.insert (slot, texture.clone ());},
+            RenderCommand::SetUniform (ref name, value)         => {self._uniforms.insert (name.clone (), value);},
+            RenderCommand::Draw (first, count)                  => self._draw (first, count, false),
+            RenderCommand::DrawIndexed (first, count)           => self._draw (first, count, true),
+
+            _ => {}
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Writes the pixels drawn into the current render target back to its colour texture.
+    pub fn flush (&self) {
+
+        if let (Some (target), Some (image)) = (self._target.as_ref (), self._target_image.as_ref ()) {
+            target.get_colour_texture ().set_data (image.get_data ());
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a rectangle of pixels from a render target, or the window if `target` is `None`.
+    ///
+    /// `x` and `y` are measured from the bottom left, and the image starts with the top row.  
+    /// Pixels outside the target are transparent black.
+    pub fn read_pixels (&self, target: Option<Rc<RenderTarget>>, x: i32, y: i32, width: u32, height: u32) -> Image {
+
+        self.flush ();
+
+        let target_image;
+        let source = match target {
+
+            Some (ref target) => {
+
+                target_image = SoftwareRasterizer::_load_target_image (target);
+                target_image.as_ref ()
+            },
+
+            None => Some (&self._window_image)
+        };
+
+        let mut image = Image::new (width, height, ImageFormat::RGBA8);
+
+        if let Some (source) = source {
+
+            for row in 0..height {
+
+                for column in 0..width {
+
+                    let (source_x, source_y) = (x + column as i32, y + row as i32);
+
+                    if source_x >= 0 && source_y >= 0 &&
+                       (source_x as u32) < source.get_width () && (source_y as u32) < source.get_height () {
+
+                        image.set_pixel (column, row, &source.get_pixel (source_x as u32, source_y as u32));
+                    }
+                }
+            }
+        }
+
+        image.flip_vertical ();
+        image
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Forgets the bound pipeline, buffers, textures and uniforms.
+    pub fn reset_bindings (&mut self) {
+
+        self._pipeline      = None;
+        self._vertex_buffer = None;
+        self._index_buffer  = None;
+
+        self._textures.clear ();
+        self._uniforms.clear ();
+    }
+
+/*===============================================================================================*/
+/*------SOFTWARE RASTERIZER PUBLIC STATIC METHODS------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new software rasterizer, drawing into an empty window.
+    pub fn new () -> SoftwareRasterizer {
+
+        SoftwareRasterizer {_target: None,
+                            _target_image: None,
+                            _window_image: Image::new (0, 0, ImageFormat::RGBA8),
+                            _viewport: (0, 0, 0, 0),
+                            _scissor: None,
+                            _render_state: RenderState::new (),
+                            _pipeline: None,
+                            _vertex_buffer: None,
+                            _index_buffer: None,
+                            _textures: HashMap::new (),
+                            _uniforms: HashMap::new ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for SoftwareRasterizer {
+
+    fn default () -> SoftwareRasterizer {
+        SoftwareRasterizer::new ()
+    }
+}
+
+/*===============================================================================================*/
+/*------SOFTWARE RASTERIZER PRIVATE METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl SoftwareRasterizer {
+
+    // Returns the image being drawn into, if the current target can be drawn into.
+    fn _get_colour_buffer (&mut self) -> Option<&mut Image> {
+
+        match self._target {
+
+            Some (_) => self._target_image.as_mut (),
+            None => Some (&mut self._window_image)
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // The window has no fixed size, so it grows to cover every viewport set on it.
+    fn _grow_window_image (&mut self, x: i32, y: i32, width: u32, height: u32) {
+
+        let old_width  = self._window_image.get_width ();
+        let old_height = self._window_image.get_height ();
+
+        let new_width  = cmp::max (old_width,  cmp::max (x, 0) as u32 + width);
+        let new_height = cmp::max (old_height, cmp::max (y, 0) as u32 + height);
+
+        if new_width == old_width && new_height == old_height {
+            return;
+        }
+
+        let old_image = mem::replace (&mut self._window_image, Image::new (new_width, new_height, ImageFormat::RGBA8));
+
+        for y in 0..old_height {
+
+            for x in 0..old_width {
+                self._window_image.set_pixel (x, y, &old_image.get_pixel (x, y));
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Draws vertices, or indexed vertices, from the bound buffers.
+    fn _draw (&mut self, first: u32, count: u32, is_indexed: bool) {
+
+        let pipeline = match self._pipeline {
+
+            Some (ref pipeline) => pipeline.clone (),
+            None => return
+        };
+
+        let vertex_data = match self._vertex_buffer {
+
+            Some (ref buffer) => match buffer.as_any ().downcast_ref::<BufferMock> () {
+
+                Some (buffer) => buffer.get_data (),
+                None => return
+            },
+
+            None => return
+        };
+
+        // Find the vertex of each index
+        let indices: Vec<usize> = if is_indexed {
+
+            let (index_data, format) = match self._index_buffer {
+
+                Some ((ref buffer, format)) => match buffer.as_any ().downcast_ref::<BufferMock> () {
+
+                    Some (buffer) => (buffer.get_data (), format),
+                    None => return
+                },
+
+                None => return
+            };
+
+            (first..first + count).filter_map (|i| SoftwareRasterizer::_read_index (&index_data, format, i as usize)).collect ()
+        }
+
+        else {
+            (first..first + count).map (|i| i as usize).collect ()
+        };
+
+        // Run the vertex stage
+        let layout          = pipeline.get_vertex_layout ();
+        let model           = self._get_matrix ("u_model");
+        let view_projection = self._get_matrix ("u_view_projection");
+        let (vx, vy, vw, vh) = self._viewport;
+
+        let vertices: Vec<Option<RasterVertex>> = indices.iter ().map (|&index| {
+
+            let position = SoftwareRasterizer::_read_attribute (&vertex_data, layout, index, "a_position", [0.0, 0.0, 0.0, 1.0]);
+            let colour   = SoftwareRasterizer::_read_attribute (&vertex_data, layout, index, "a_colour",   [1.0, 1.0, 1.0, 1.0]);
+            let uv       = SoftwareRasterizer::_read_attribute (&vertex_data, layout, index, "a_uv",       [0.0, 0.0, 0.0, 1.0]);
+
+            let clip = view_projection * (model * Vec4 {x: position[0], y: position[1], z: position[2], w: position[3]});
+
+            if clip.w <= 0.0 {
+                return None;
+            }
+
+            Some (RasterVertex {x: vx as f32 + (clip.x / clip.w + 1.0) * 0.5 * vw as f32,
+                                y: vy as f32 + (clip.y / clip.w + 1.0) * 0.5 * vh as f32,
+                                inv_w: 1.0 / clip.w,
+                                colour: Colour {r: colour[0], g: colour[1], b: colour[2], a: colour[3]},
+                                uv: (uv[0], uv[1])})
+
+        }).collect ();
+
+        // Assemble the triangles
+        let mut triangles = Vec::new ();
+
+        match pipeline.get_primitive_type () {
+
+            PrimitiveType::Triangles => {
+
+                for t in 0..vertices.len () / 3 {
+                    triangles.push ((t * 3, t * 3 + 1, t * 3 + 2));
+                }
+            },
+
+            PrimitiveType::TriangleStrip => {
+
+                // Every other triangle is reversed, to keep the winding the same
+                for i in 0..cmp::max (vertices.len (), 2) - 2 {
+                    triangles.push (if i % 2 == 0 {(i, i + 1, i + 2)} else {(i + 1, i, i + 2)});
+                }
+            },
+
+            PrimitiveType::Lines | PrimitiveType::Points => {}
+        }
+
+        // The fragment inputs
+        let tint = match self._uniforms.get ("u_colour") {
+
+            Some (&UniformValue::Colour (colour)) => colour,
+            Some (&UniformValue::Vec4 (v))        => Colour {r: v.x, g: v.y, b: v.z, a: v.w},
+            _ => Colour::white ()
+        };
+
+        let texture_slot = match self._uniforms.get ("u_texture") {
+
+            Some (&UniformValue::Int (slot)) => slot as u32,
+            _ => 0
+        };
+
+        let has_uv = layout.attributes.iter ().any (|a| a.name == "a_uv");
+
+        let texture = if has_uv {
+
+            match self._textures.get (&texture_slot) {
+
+                Some (texture) => SoftwareRasterizer::_load_texture_image (texture),
+                None => None
+            }
+        }
+
+        else {None};
+
+        let render_state = self._render_state;
+        let viewport     = self._viewport;
+        let scissor      = self._scissor;
+
+        let image = match self._get_colour_buffer () {
+
+            Some (image) => image,
+            None => return
+        };
+
+        for &(a, b, c) in &triangles {
+
+            if let (Some (v0), Some (v1), Some (v2)) = (vertices[a], vertices[b], vertices[c]) {
+
+                SoftwareRasterizer::_draw_triangle (image, viewport, scissor, &render_state, texture.as_ref (), &tint, [v0, v1, v2]);
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns a matrix uniform, or the identity matrix if it isn't set.
+    fn _get_matrix (&self, name: &str) -> Mat4 {
+
+        match self._uniforms.get (name) {
+
+            Some (&UniformValue::Mat4 (matrix)) => matrix,
+            _ => Mat4::identity ()
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------SOFTWARE RASTERIZER PRIVATE STATIC METHODS-----------------------------------------------*/
+/*===============================================================================================*/
+
+impl SoftwareRasterizer {
+
+    // Fills the pixels whose centres are inside a triangle.
+    //
+    // Edges are shared using the top-left rule, so adjacent triangles don't blend twice.
+    fn _draw_triangle (image: &mut Image,
+                       viewport: (i32, i32, u32, u32),
+                       scissor: Option<(i32, i32, u32, u32)>,
+                       render_state: &RenderState,
+                       texture: Option<&Image>,
+                       tint: &Colour,
+                       vertices: [RasterVertex; 3]) {
+
+        let edge = |a: &RasterVertex, b: &RasterVertex, x: f32, y: f32| (b.x - a.x) * (y - a.y) - (b.y - a.y) * (x - a.x);
+
+        let mut v = vertices;
+        let area = edge (&v[0], &v[1], v[2].x, v[2].y);
+
+        // Counter-clockwise triangles face the front
+        let is_culled = match render_state.cull_mode {
+
+            CullMode::None  => false,
+            CullMode::Back  => area < 0.0,
+            CullMode::Front => area > 0.0,
+        };
+
+        if is_culled || area == 0.0 {
+            return;
+        }
+
+        if area < 0.0 {
+            v.swap (1, 2);
+        }
+
+        let area = area.abs ();
+
+        // Edges going down, or flat edges going left, are on the top or left
+        let is_top_left = |a: &RasterVertex, b: &RasterVertex| b.y < a.y || (b.y == a.y && b.x < a.x);
+        let top_left = [is_top_left (&v[1], &v[2]), is_top_left (&v[2], &v[0]), is_top_left (&v[0], &v[1])];
+
+        // Clamp the bounds to the viewport, scissor rect and image
+        let (min_x, min_y, max_x, max_y) = SoftwareRasterizer::_get_bounds (image, scissor, Some (viewport));
+
+        let min_x = cmp::max (min_x, v.iter ().fold (f32::MAX, |m, p| m.min (p.x)).floor () as i32);
+        let min_y = cmp::max (min_y, v.iter ().fold (f32::MAX, |m, p| m.min (p.y)).floor () as i32);
+        let max_x = cmp::min (max_x, v.iter ().fold (f32::MIN, |m, p| m.max (p.x)).ceil () as i32);
+        let max_y = cmp::min (max_y, v.iter ().fold (f32::MIN, |m, p| m.max (p.y)).ceil () as i32);
+
+        for py in min_y..max_y {
+
+            for px in min_x..max_x {
+
+                let (x, y) = (px as f32 + 0.5, py as f32 + 0.5);
+                let weights = [edge (&v[1], &v[2], x, y), edge (&v[2], &v[0], x, y), edge (&v[0], &v[1], x, y)];
+
+                let is_inside = weights.iter ().zip (top_left.iter ()).all (|(&w, &is_top_left)| w > 0.0 || (w == 0.0 && is_top_left));
+
+                if !is_inside {
+                    continue;
+                }
+
+                // Interpolate the attributes with perspective correction
+                let b = [weights[0] / area * v[0].inv_w, weights[1] / area * v[1].inv_w, weights[2] / area * v[2].inv_w];
+                let w = b[0] + b[1] + b[2];
+
+                let interpolate = |f: &Fn (&RasterVertex) -> f32| (f (&v[0]) * b[0] + f (&v[1]) * b[1] + f (&v[2]) * b[2]) / w;
+
+                let mut colour = Colour {r: interpolate (&|p| p.colour.r) * tint.r,
+                                         g: interpolate (&|p| p.colour.g) * tint.g,
+                                         b: interpolate (&|p| p.colour.b) * tint.b,
+                                         a: interpolate (&|p| p.colour.a) * tint.a};
+
+                if let Some (texture) = texture {
+
+                    let sample = SoftwareRasterizer::_sample (texture, interpolate (&|p| p.uv.0), interpolate (&|p| p.uv.1));
+
+                    colour.r *= sample.r;
+                    colour.g *= sample.g;
+                    colour.b *= sample.b;
+                    colour.a *= sample.a;
+                }
+
+                let destination = image.get_pixel (px as u32, py as u32);
+                image.set_pixel (px as u32, py as u32, &SoftwareRasterizer::_blend (render_state.blend_mode, &colour, &destination));
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the pixel bounds (min x, min y, max x, max y) of an image inside the given rects.
+    // The maximum is exclusive.
+    fn _get_bounds (image: &Image,
+                    scissor: Option<(i32, i32, u32, u32)>,
+                    viewport: Option<(i32, i32, u32, u32)>) -> (i32, i32, i32, i32) {
+
+        let mut bounds = (0, 0, image.get_width () as i32, image.get_height () as i32);
+
+        for &(x, y, width, height) in scissor.iter ().chain (viewport.iter ()) {
+
+            bounds = (cmp::max (bounds.0, x),
+                      cmp::max (bounds.1, y),
+                      cmp::min (bounds.2, x + width  as i32),
+                      cmp::min (bounds.3, y + height as i32));
+        }
+
+        bounds
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Blends a fragment with the target, using the same factors as the OpenGL backend.
+    fn _blend (blend_mode: BlendMode, source: &Colour, destination: &Colour) -> Colour {
+
+        let (s, d) = (source, destination);
+
+        let (r, g, b) = match blend_mode {
+
+            BlendMode::Opaque        => return *source,
+            BlendMode::Premultiplied => (s.r + d.r * (1.0 - s.a), s.g + d.g * (1.0 - s.a), s.b + d.b * (1.0 - s.a)),
+            BlendMode::Additive      => (s.r * s.a + d.r, s.g * s.a + d.g, s.b * s.a + d.b),
+            BlendMode::Multiply      => (s.r * d.r, s.g * d.g, s.b * d.b),
+            BlendMode::Alpha         => (s.r * s.a + d.r * (1.0 - s.a), s.g * s.a + d.g * (1.0 - s.a), s.b * s.a + d.b * (1.0 - s.a)),
+        };
+
+        Colour {r: r, g: g, b: b, a: s.a + d.a * (1.0 - s.a)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the nearest texel to a texture coordinate, clamping to the edges.
+    fn _sample (texture: &Image, u: f32, v: f32) -> Colour {
+
+        let clamp = |value: f32, size: u32| cmp::min (cmp::max ((value * size as f32).floor () as i32, 0), size as i32 - 1) as u32;
+
+        if texture.get_width () == 0 || texture.get_height () == 0 {
+            return Colour::white ();
+        }
+
+        texture.get_pixel (clamp (u, texture.get_width ()), clamp (v, texture.get_height ()))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a vertex attribute as four floats. Missing components are taken from the default.
+    fn _read_attribute (data: &[u8], layout: &VertexLayout, index: usize, name: &str, default: [f32; 4]) -> [f32; 4] {
+
+        let mut value = default;
+
+        let attribute = match layout.attributes.iter ().find (|a| a.name == name) {
+
+            Some (attribute) => attribute,
+            None => return value
+        };
+
+        let offset = index * layout.stride + attribute.offset;
+
+        if offset + attribute.format.get_size () > data.len () {
+            return value;
+        }
+
+        for c in 0..attribute.format.get_component_count () {
+
+            value[c] = match attribute.format {
+
+                VertexFormat::UByte4Norm => data[offset + c] as f32 / 255.0,
+                _ => {
+
+                    let i = offset + c * 4;
+                    let bits = data[i] as u32 | (data[i + 1] as u32) << 8 | (data[i + 2] as u32) << 16 | (data[i + 3] as u32) << 24;
+
+                    unsafe {mem::transmute::<u32, f32> (bits)}
+                }
+            };
+        }
+
+        value
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads an index from an index buffer, if it is inside the buffer.
+    fn _read_index (data: &[u8], format: IndexFormat, i: usize) -> Option<usize> {
+
+        match format {
+
+            IndexFormat::U16 if i * 2 + 2 <= data.len () => Some ((data[i * 2] as usize) | (data[i * 2 + 1] as usize) << 8),
+            IndexFormat::U32 if i * 4 + 4 <= data.len () => {
+
+                Some ((data[i * 4] as usize) | (data[i * 4 + 1] as usize) << 8 |
+                      (data[i * 4 + 2] as usize) << 16 | (data[i * 4 + 3] as usize) << 24)
+            },
+
+            _ => None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the pixels of a mock texture, if it has a colour format.
+    fn _load_texture_image (texture: &Rc<Texture>) -> Option<Image> {
+
+        let texture = match texture.as_any ().downcast_ref::<TextureMock> () {
+
+            Some (texture) => texture,
+            None => return None
+        };
+
+        let format = match texture.get_format () {
+
+            TextureFormat::R8      => ImageFormat::R8,
+            TextureFormat::RGBA8   => ImageFormat::RGBA8,
+            TextureFormat::RGBA16F => ImageFormat::RGBA16F,
+            _ => return None
+        };
+
+        Image::from_data (texture.get_width (), texture.get_height (), format, texture.get_data ()).ok ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the pixels of a render target's colour texture.
+    fn _load_target_image (target: &Rc<RenderTarget>) -> Option<Image> {
+        SoftwareRasterizer::_load_texture_image (&target.get_colour_texture ())
+    }
+}
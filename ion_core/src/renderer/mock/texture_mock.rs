@@ -0,0 +1,112 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::{TextureDesc, TextureFormat};
+use ::renderer::traits::Texture;
+use ::util::traits::AsAny;
+
+use std::any::Any;
+use std::cell::RefCell;
+
+/*===============================================================================================*/
+/*------TEXTURE MOCK STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A texture which keeps its pixels in system memory.
+pub struct TextureMock {
+
+    // Private
+    _desc: TextureDesc,
+    _data: RefCell<Vec<u8>>,
+}
+
+/*===============================================================================================*/
+/*------TEXTURE MOCK PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl TextureMock {
+
+    /// Returns the texture description.
+    pub fn get_desc (&self) -> &TextureDesc {
+        &self._desc
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a copy of the texture pixels.
+    pub fn get_data (&self) -> Vec<u8> {
+        self._data.borrow ().clone ()
+    }
+
+/*===============================================================================================*/
+/*------TEXTURE MOCK PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new mock texture, filled with `data`.
+    pub fn new (desc: &TextureDesc, data: &[u8]) -> TextureMock {
+
+        let size    = (desc.width * desc.height) as usize * desc.format.get_pixel_size ();
+        let texture = TextureMock {_desc: *desc, _data: RefCell::new (vec! [0; size])};
+
+        if !data.is_empty () {
+            texture.set_data (data);
+        }
+
+        texture
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Texture for TextureMock {
+
+    fn get_width (&self) -> u32 {
+        self._desc.width
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_height (&self) -> u32 {
+        self._desc.height
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_format (&self) -> TextureFormat {
+        self._desc.format
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn set_data (&self, data: &[u8]) {
+
+        let mut texture_data = self._data.borrow_mut ();
+
+        for (dst, src) in texture_data.iter_mut ().zip (data.iter ()) {
+            *dst = *src;
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for TextureMock {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
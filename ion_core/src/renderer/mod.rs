@@ -20,10 +20,29 @@
 //! Contains the Render Manager, and any rendering related functionality.
 /*===============================================================================================*/
 
+pub mod command;
+pub mod debug_draw;
+pub mod gpu;
+pub mod graph;
+pub mod lighting;
+pub mod material;
+pub mod mesh;
+pub mod mock;
+pub mod post_process;
+pub mod sprite;
+pub mod text;
 pub mod traits;
 pub mod window;
+mod camera;
+mod context_config;
+mod frame_capture;
+mod golden_test;
 mod render_config;
 mod render_manager;
 
+pub use self::camera::{Camera, CameraProjection};
+pub use self::context_config::{ContextConfig, ContextProfile};
+pub use self::frame_capture::FrameCapture;
+pub use self::golden_test::{GOLDEN_BLESS_VARIABLE, GoldenTest};
 pub use self::render_config::RenderConfig;
 pub use self::render_manager::RenderManager;
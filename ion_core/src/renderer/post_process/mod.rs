@@ -0,0 +1,34 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+
+/*===============================================================================================*/
+//! The post-processing module.
+//!
+//! Contains a configurable chain of full-screen effects, applied to a rendered frame.  
+//! The default chain is loaded from the `postprocess` config file.
+/*===============================================================================================*/
+
+mod post_effect;
+mod post_effect_type;
+mod post_process_config;
+mod post_process_stack;
+
+pub use self::post_effect::PostEffect;
+pub use self::post_effect_type::PostEffectType;
+pub use self::post_process_config::PostProcessConfig;
+pub use self::post_process_stack::PostProcessStack;
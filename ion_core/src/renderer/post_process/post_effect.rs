@@ -0,0 +1,154 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::material::{MaterialParam, TextureHandle};
+use ::renderer::post_process::PostEffectType;
+use ::renderer::traits::RenderFactory;
+
+use std::collections::BTreeMap;
+
+/*===============================================================================================*/
+/*------POST EFFECT STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A single effect in a post-processing chain.
+///
+/// The parameters are passed to the uniforms of the effect shader, as with materials.
+#[derive (Clone, Serialize, Deserialize)]
+pub struct PostEffect {
+
+    // Public
+    /// The name of the effect, used to find it at runtime.
+    pub name: String,
+    /// The kind of effect.
+    pub effect_type: PostEffectType,
+    /// Is the effect applied?
+    pub enabled: bool,
+    /// The parameters, by uniform name.
+    pub params: BTreeMap<String, MaterialParam>,
+}
+
+/*===============================================================================================*/
+/*------POST EFFECT PUBLIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PostEffect {
+
+    /// Sets a parameter.
+    pub fn set_param (&mut self, name: &str, param: MaterialParam) {
+        self.params.insert (name.to_string (), param);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a parameter.
+    pub fn get_param (&self, name: &str) -> Option<&MaterialParam> {
+        self.params.get (name)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a float parameter, or a default value if it isn't set.
+    pub fn get_float (&self, name: &str, default: f32) -> f32 {
+
+        match self.params.get (name) {
+
+            Some (&MaterialParam::Float (value)) => value,
+            _ => default
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates the textures referenced by the parameters.
+    ///
+    /// Textures without a path are skipped.
+    pub fn load_textures (&mut self, render_factory: &RenderFactory) -> Result<(), ()> {
+
+        for param in self.params.values_mut () {
+
+            if let MaterialParam::Texture (ref mut handle) = *param {
+
+                if !handle.path.is_empty () {
+                    try! (handle.load (render_factory));
+                }
+            }
+        }
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the effect can be applied.
+    ///
+    /// Colour grading is ready once its lookup table is loaded. Other effects are always ready.
+    pub fn is_ready (&self) -> bool {
+
+        match self.effect_type {
+
+            PostEffectType::ColourGrading => match self.params.get ("u_lut") {
+
+                Some (&MaterialParam::Texture (ref handle)) => handle.get_texture ().is_some (),
+                _ => false
+            },
+
+            _ => true
+        }
+    }
+
+/*===============================================================================================*/
+/*------POST EFFECT PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, enabled effect with the default parameters of its type.
+    pub fn new (name: &str, effect_type: PostEffectType) -> PostEffect {
+
+        let mut effect = PostEffect {name: name.to_string (),
+                                     effect_type: effect_type,
+                                     enabled: true,
+                                     params: BTreeMap::new ()};
+
+        match effect_type {
+
+            PostEffectType::Tonemap => effect.set_param ("u_exposure", MaterialParam::Float (1.0)),
+            PostEffectType::Gamma   => effect.set_param ("u_gamma", MaterialParam::Float (0.0)),
+            PostEffectType::Bloom   => {
+
+                effect.set_param ("u_bloom_threshold", MaterialParam::Float (1.0));
+                effect.set_param ("u_bloom_intensity", MaterialParam::Float (0.5));
+                effect.set_param ("u_bloom_iterations", MaterialParam::Float (2.0));
+            },
+
+            PostEffectType::Fxaa => effect.set_param ("u_fxaa_edge_threshold", MaterialParam::Float (0.125)),
+            PostEffectType::Vignette => {
+
+                effect.set_param ("u_vignette_intensity", MaterialParam::Float (0.4));
+                effect.set_param ("u_vignette_radius", MaterialParam::Float (0.75));
+                effect.set_param ("u_vignette_softness", MaterialParam::Float (0.45));
+            },
+
+            PostEffectType::ColourGrading => {
+
+                effect.set_param ("u_lut", MaterialParam::Texture (TextureHandle::new ("")));
+                effect.set_param ("u_lut_strength", MaterialParam::Float (1.0));
+            }
+        }
+
+        effect
+    }
+}
@@ -0,0 +1,45 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------POST EFFECT TYPE ENUM--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The kind of a post-processing effect.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PostEffectType {
+
+    /// Maps HDR colours to the displayable range, with the ACES filmic curve.  
+    /// Takes the `u_exposure` parameter.
+    Tonemap,
+    /// Converts linear colours to gamma space.  
+    /// Takes the `u_gamma` parameter. A gamma of 0 uses the exact sRGB curve.
+    Gamma,
+    /// Adds a blurred glow around bright areas.  
+    /// Takes the `u_bloom_threshold`, `u_bloom_intensity` and `u_bloom_iterations` parameters.
+    Bloom,
+    /// Fast approximate anti-aliasing. Works best on gamma space colours.  
+    /// Takes the `u_fxaa_edge_threshold` parameter.
+    Fxaa,
+    /// Darkens the edges of the screen.  
+    /// Takes the `u_vignette_intensity`, `u_vignette_radius` and `u_vignette_softness` parameters.
+    Vignette,
+    /// Remaps colours with a lookup table.  
+    /// Takes the `u_lut` texture, a strip of square slices ordered by blue, and the `u_lut_strength`  
+    /// parameter. The effect is skipped until the path of the lookup table is set and loaded.
+    ColourGrading,
+}
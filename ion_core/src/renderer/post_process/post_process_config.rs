@@ -0,0 +1,67 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::post_process::{PostEffect, PostEffectType};
+
+/*===============================================================================================*/
+/*------POST PROCESS CONFIG STRUCT---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Stores the post-processing chain, loaded from the `postprocess` config file.
+#[derive (Clone, Deserialize, Serialize)]
+pub struct PostProcessConfig {
+
+    // Public
+    /// The effects, in the order they are applied.
+    pub post_process_effects: Vec<PostEffect>,
+}
+
+/*===============================================================================================*/
+/*------POST PROCESS CONFIG PUBLIC STATIC METHODS------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PostProcessConfig {
+
+    /// Returns a new Post Process Config instance.
+    ///
+    /// It applies bloom, tonemapping, gamma correction and FXAA.  
+    /// Colour grading and the vignette are present, but disabled.
+    pub fn new () -> PostProcessConfig {
+
+        let mut colour_grading = PostEffect::new ("colour_grading", PostEffectType::ColourGrading);
+        let mut vignette = PostEffect::new ("vignette", PostEffectType::Vignette);
+
+        colour_grading.enabled = false;
+        vignette.enabled = false;
+
+        PostProcessConfig {post_process_effects: vec! [PostEffect::new ("bloom", PostEffectType::Bloom),
+                                                       PostEffect::new ("tonemap", PostEffectType::Tonemap),
+                                                       colour_grading,
+                                                       vignette,
+                                                       PostEffect::new ("gamma", PostEffectType::Gamma),
+                                                       PostEffect::new ("fxaa", PostEffectType::Fxaa)]}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for PostProcessConfig {
+
+    fn default () -> PostProcessConfig {
+        PostProcessConfig::new ()
+    }
+}
@@ -0,0 +1,438 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::CommandBuffer;
+use ::renderer::gpu::{BufferDesc, BufferType, BufferUsage, BuiltinShader, CullMode, PipelineDesc};
+use ::renderer::gpu::{RenderState, RenderTargetDesc, ShaderStage, TextureFormat, UniformValue};
+use ::renderer::gpu::{VertexFormat, VertexLayout};
+use ::renderer::material::Material;
+use ::renderer::post_process::{PostEffect, PostEffectType, PostProcessConfig};
+use ::renderer::traits::{Buffer, Pipeline, RenderFactory, RenderTarget, Texture};
+use ::util::Bytes;
+use ::util::math::Vec2;
+
+use std::cmp;
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------POST PROCESS STACK STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Applies a chain of post-processing effects to a rendered frame.
+///
+/// Each effect is a full-screen pass, reading the output of the previous one. Intermediate  
+/// results are kept in HDR targets, which are reused between effects. Effects can be toggled,  
+/// reordered, added and removed at runtime.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::command::CommandBuffer;
+/// # use ion_core::renderer::gpu::RenderTargetDesc;
+/// # use ion_core::renderer::mock::RenderFactoryMock;
+/// # use ion_core::renderer::post_process::{PostProcessConfig, PostProcessStack};
+/// # use ion_core::renderer::traits::RenderFactory;
+/// #
+/// let factory = RenderFactoryMock::new ();
+/// let scene = factory.create_render_target (&RenderTargetDesc::new (320, 240)).unwrap ();
+///
+/// let mut stack = PostProcessStack::new (&factory, &PostProcessConfig::new ()).unwrap ();
+/// assert_eq! (stack.get_active_effects (), vec! ["bloom", "tonemap", "gamma", "fxaa"]);
+///
+/// stack.set_enabled ("bloom", false).unwrap ();
+/// stack.set_enabled ("vignette", true).unwrap ();
+/// stack.move_effect ("fxaa", 0).unwrap ();
+/// assert_eq! (stack.get_active_effects (), vec! ["fxaa", "tonemap", "vignette", "gamma"]);
+///
+/// let mut command_buffer = CommandBuffer::new ();
+/// stack.execute (&factory, &mut command_buffer, &scene, None).unwrap ();
+/// factory.execute_command_buffer (&command_buffer);
+///
+/// // One full-screen pass per effect
+/// assert_eq! (factory.get_draw_call_count (), 4);
+/// ```
+pub struct PostProcessStack {
+
+    // Private
+    _effects: Vec<PostEffect>,
+    _pipelines: Vec<(BuiltinShader, Rc<Pipeline>)>,
+    _vertex_buffer: Rc<Buffer>,
+    _targets: Vec<Rc<RenderTarget>>,
+    _bloom_targets: Vec<Rc<RenderTarget>>,
+}
+
+/*===============================================================================================*/
+/*------POST PROCESS STACK PUBLIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PostProcessStack {
+
+    /// Records the effect passes, from an input target to an output target.
+    ///
+    /// # Arguments
+    /// * `render_factory` - Used to create the intermediate targets and effect pipelines.
+    /// * `command_buffer` - The command buffer the passes are recorded into.
+    /// * `input` - The target holding the rendered frame.
+    /// * `output` - The target the result is written to, or None for the default target.  
+    /// The default target must be the same size as the input.
+    pub fn execute (&mut self,
+                    render_factory: &RenderFactory,
+                    command_buffer: &mut CommandBuffer,
+                    input: &Rc<RenderTarget>,
+                    output: Option<Rc<RenderTarget>>) -> Result<(), ()> {
+
+        let width  = input.get_width ();
+        let height = input.get_height ();
+
+        let (output_width, output_height) = match output {
+
+            Some (ref target) => (target.get_width (), target.get_height ()),
+            None => (width, height)
+        };
+
+        try! (self._update_targets (render_factory, width, height));
+
+        let active: Vec<usize> = (0..self._effects.len ()).filter (|&i| self._effects[i].enabled &&
+                                                                        self._effects[i].is_ready ())
+                                                            .collect ();
+
+        // Without any effects, the input is copied to the output
+        if active.is_empty () {
+
+            let pipeline = try! (self._get_pipeline (render_factory, BuiltinShader::Blit));
+
+            self._begin_pass (command_buffer, pipeline, output, output_width, output_height, input.get_colour_texture (), width, height);
+            self._draw_pass (command_buffer);
+
+            return Ok (());
+        }
+
+        let mut source = input.get_colour_texture ();
+
+        for (n, &index) in active.iter ().enumerate () {
+
+            let is_last = n + 1 == active.len ();
+            let effect_type = self._effects[index].effect_type;
+
+            let (target, target_width, target_height) = if is_last {(output.clone (), output_width, output_height)}
+                                                        else {(Some (self._targets[n % 2].clone ()), width, height)};
+
+            if effect_type == PostEffectType::Bloom {
+                try! (self._record_bloom (render_factory, command_buffer, index, source.clone (), width, height));
+            }
+
+            let pipeline = try! (self._get_pipeline (render_factory, BuiltinShader::PostEffect (effect_type)));
+
+            self._begin_pass (command_buffer, pipeline, target.clone (), target_width, target_height, source.clone (), width, height);
+
+            // Bloom blends the blurred highlights into the source, in the first free slot
+            let first_texture_slot = if effect_type == PostEffectType::Bloom {
+
+                command_buffer.bind_texture (1, self._bloom_targets[0].get_colour_texture ());
+                command_buffer.set_uniform ("u_bloom_texture", UniformValue::Int (1));
+                2
+            }
+
+            else {
+                1
+            };
+
+            Material::bind_params (command_buffer,
+                                   &PostProcessStack::get_render_state (),
+                                   self._effects[index].params.iter (),
+                                   first_texture_slot);
+
+            self._draw_pass (command_buffer);
+
+            if let Some (target) = target {
+                source = target.get_colour_texture ();
+            }
+        }
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the effects, in the order they are applied.
+    pub fn get_effects (&self) -> &[PostEffect] {
+        &self._effects
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the names of the effects that will be applied, in order.
+    pub fn get_active_effects (&self) -> Vec<&str> {
+
+        self._effects.iter ()
+                     .filter (|effect| effect.enabled && effect.is_ready ())
+                     .map (|effect| effect.name.as_str ())
+                     .collect ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns an effect by name.
+    pub fn get_effect_mut (&mut self, name: &str) -> Option<&mut PostEffect> {
+        self._effects.iter_mut ().find (|effect| effect.name == name)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Enables or disables an effect by name.
+    pub fn set_enabled (&mut self, name: &str, enabled: bool) -> Result<(), ()> {
+
+        match self.get_effect_mut (name) {
+
+            Some (effect) => {
+
+                effect.enabled = enabled;
+                Ok (())
+            },
+
+            None => {
+
+                error! ("Post effect \"{}\" does not exist.", name);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves an effect to a new position in the chain.
+    pub fn move_effect (&mut self, name: &str, index: usize) -> Result<(), ()> {
+
+        match self._effects.iter ().position (|effect| effect.name == name) {
+
+            Some (current) => {
+
+                let effect = self._effects.remove (current);
+                let index = cmp::min (index, self._effects.len ());
+
+                self._effects.insert (index, effect);
+                Ok (())
+            },
+
+            None => {
+
+                error! ("Post effect \"{}\" does not exist.", name);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds an effect to the end of the chain.
+    ///
+    /// The textures referenced by the effect are created from the render factory.
+    pub fn add_effect (&mut self, render_factory: &RenderFactory, mut effect: PostEffect) {
+
+        if effect.load_textures (render_factory).is_err () {
+            warn! ("Could not load the textures of post effect \"{}\".", effect.name);
+        }
+
+        self._effects.push (effect);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes an effect by name, and returns it.
+    pub fn remove_effect (&mut self, name: &str) -> Option<PostEffect> {
+
+        match self._effects.iter ().position (|effect| effect.name == name) {
+
+            Some (index) => Some (self._effects.remove (index)),
+            None => None
+        }
+    }
+
+/*===============================================================================================*/
+/*------POST PROCESS STACK PUBLIC STATIC METHODS-------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns the render state used by the full-screen passes.
+    ///
+    /// Passes are opaque, with culling and depth testing disabled.
+    pub fn get_render_state () -> RenderState {
+
+        RenderState {cull_mode: CullMode::None,
+                     depth_test: false,
+                     depth_write: false,
+                     .. RenderState::new ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new post-processing stack, with the effects of a config.
+    pub fn new (render_factory: &RenderFactory, config: &PostProcessConfig) -> Result<PostProcessStack, ()> {
+
+        // A single triangle covering the screen
+        static VERTICES: [f32; 6] = [-1.0, -1.0, 3.0, -1.0, -1.0, 3.0];
+
+        let data = unsafe {Bytes::from_slice (&VERTICES)};
+        let vertex_buffer = try! (render_factory.create_buffer (&BufferDesc::new (BufferType::Vertex,
+                                                                                  BufferUsage::Static,
+                                                                                  data.len ()),
+                                                                data));
+
+        let mut stack = PostProcessStack {_effects: Vec::new (),
+                                          _pipelines: Vec::new (),
+                                          _vertex_buffer: vertex_buffer,
+                                          _targets: Vec::new (),
+                                          _bloom_targets: Vec::new ()};
+
+        for effect in &config.post_process_effects {
+            stack.add_effect (render_factory, effect.clone ());
+        }
+
+        Ok (stack)
+    }
+
+/*===============================================================================================*/
+/*------POST PROCESS STACK PRIVATE METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Records the bright pass and blur of the bloom effect, into the first bloom target.
+    fn _record_bloom (&mut self,
+                      render_factory: &RenderFactory,
+                      command_buffer: &mut CommandBuffer,
+                      index: usize,
+                      source: Rc<Texture>,
+                      width: u32,
+                      height: u32) -> Result<(), ()> {
+
+        let extract = try! (self._get_pipeline (render_factory, BuiltinShader::BloomExtract));
+        let blur    = try! (self._get_pipeline (render_factory, BuiltinShader::BloomBlur));
+
+        let bloom_width  = self._bloom_targets[0].get_width ();
+        let bloom_height = self._bloom_targets[0].get_height ();
+        let iterations   = self._effects[index].get_float ("u_bloom_iterations", 2.0).max (1.0) as u32;
+
+        let bloom_a = self._bloom_targets[0].clone ();
+        let bloom_b = self._bloom_targets[1].clone ();
+
+        self._begin_pass (command_buffer, extract, Some (bloom_a.clone ()), bloom_width, bloom_height, source, width, height);
+
+        Material::bind_params (command_buffer,
+                               &PostProcessStack::get_render_state (),
+                               self._effects[index].params.iter (),
+                               1);
+
+        self._draw_pass (command_buffer);
+
+        // Separable blur, ping-ponging between the bloom targets
+        for _ in 0..iterations {
+
+            for &(from, to, direction) in &[(&bloom_a, &bloom_b, Vec2 {x: 1.0, y: 0.0}),
+                                            (&bloom_b, &bloom_a, Vec2 {x: 0.0, y: 1.0})] {
+
+                self._begin_pass (command_buffer,
+                                  blur.clone (),
+                                  Some (to.clone ()),
+                                  bloom_width,
+                                  bloom_height,
+                                  from.get_colour_texture (),
+                                  bloom_width,
+                                  bloom_height);
+
+                command_buffer.set_uniform ("u_blur_direction", UniformValue::Vec2 (direction));
+                self._draw_pass (command_buffer);
+            }
+        }
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Records the state shared by every full-screen pass.
+    fn _begin_pass (&self,
+                    command_buffer: &mut CommandBuffer,
+                    pipeline: Rc<Pipeline>,
+                    target: Option<Rc<RenderTarget>>,
+                    target_width: u32,
+                    target_height: u32,
+                    source: Rc<Texture>,
+                    source_width: u32,
+                    source_height: u32) {
+
+        command_buffer.set_render_target (target);
+        command_buffer.set_viewport (0, 0, target_width, target_height);
+        command_buffer.set_render_state (PostProcessStack::get_render_state ());
+        command_buffer.bind_pipeline (pipeline);
+        command_buffer.bind_texture (0, source);
+        command_buffer.set_uniform ("u_texture", UniformValue::Int (0));
+        command_buffer.set_uniform ("u_texel_size", UniformValue::Vec2 (Vec2 {x: 1.0 / source_width as f32,
+                                                                              y: 1.0 / source_height as f32}));
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Records the draw of the full-screen triangle.
+    fn _draw_pass (&self, command_buffer: &mut CommandBuffer) {
+
+        command_buffer.bind_vertex_buffer (self._vertex_buffer.clone ());
+        command_buffer.draw (0, 3);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the pipeline of a built in shader, creating it on first use.
+    fn _get_pipeline (&mut self, render_factory: &RenderFactory, shader: BuiltinShader) -> Result<Rc<Pipeline>, ()> {
+
+        if let Some (&(_, ref pipeline)) = self._pipelines.iter ().find (|&&(s, _)| s == shader) {
+            return Ok (pipeline.clone ());
+        }
+
+        let mut layout = VertexLayout::new ();
+        layout.push ("a_position", VertexFormat::Float2);
+
+        let vertex_shader   = try! (render_factory.create_builtin_shader (shader, ShaderStage::Vertex));
+        let fragment_shader = try! (render_factory.create_builtin_shader (shader, ShaderStage::Fragment));
+        let pipeline        = try! (render_factory.create_pipeline (&PipelineDesc::new (vertex_shader, fragment_shader, layout)));
+
+        self._pipelines.push ((shader, pipeline.clone ()));
+        Ok (pipeline)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Creates the intermediate targets, if they don't match the input size.
+    fn _update_targets (&mut self, render_factory: &RenderFactory, width: u32, height: u32) -> Result<(), ()> {
+
+        if self._targets.first ().map_or (false, |t| t.get_width () == width && t.get_height () == height) {
+            return Ok (());
+        }
+
+        let full = RenderTargetDesc {width: width,
+                                     height: height,
+                                     colour_format: TextureFormat::RGBA16F,
+                                     has_depth: false};
+
+        let half = RenderTargetDesc {width: cmp::max (width / 2, 1),
+                                     height: cmp::max (height / 2, 1),
+                                     .. full};
+
+        self._targets = vec! [try! (render_factory.create_render_target (&full)),
+                              try! (render_factory.create_render_target (&full))];
+
+        self._bloom_targets = vec! [try! (render_factory.create_render_target (&half)),
+                                    try! (render_factory.create_render_target (&half))];
+        Ok (())
+    }
+}
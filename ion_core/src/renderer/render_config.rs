@@ -14,6 +14,8 @@
 // limitations under the License.
 /*===============================================================================================*/
 
+use ::renderer::ContextConfig;
+use ::renderer::lighting::{LightingConfig, ShadowConfig};
 use ::renderer::window::WindowConfig;
 
 /*===============================================================================================*/
@@ -27,6 +29,15 @@ pub struct RenderConfig {
     // Public
     /// The window config.
     pub window_config: WindowConfig,
+    /// The graphics context config.
+    #[serde (default)]
+    pub context_config: ContextConfig,
+    /// The forward lighting config.
+    #[serde (default)]
+    pub lighting_config: LightingConfig,
+    /// The shadow mapping config.
+    #[serde (default)]
+    pub shadow_config: ShadowConfig,
 }
 
 /*===============================================================================================*/
@@ -37,7 +48,11 @@ impl RenderConfig {
 
     /// Returns a new Render Config instance.
     pub fn new () -> RenderConfig {
-        RenderConfig {window_config: WindowConfig::new ()}
+
+        RenderConfig {window_config: WindowConfig::new (),
+                      context_config: ContextConfig::new (),
+                      lighting_config: LightingConfig::new (),
+                      shadow_config: ShadowConfig::new ()}
     }
 }
 
@@ -15,13 +15,23 @@
 /*===============================================================================================*/
 
 use ::engine::App;
-use ::renderer::RenderConfig;
+use ::renderer::{Camera, FrameCapture, RenderConfig};
+use ::renderer::command::CommandBuffer;
+use ::renderer::debug_draw::{self, DebugDrawRenderer};
+use ::renderer::graph::RenderGraph;
+use ::renderer::lighting::{LightingConfig, ShadowConfig};
+use ::renderer::post_process::PostProcessConfig;
+use ::renderer::text::Font;
 use ::renderer::traits::RenderFactory;
 use ::renderer::traits::_detail::RenderFactoryDefault;
 use ::renderer::window::Window;
+use ::util::Directory;
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::fs;
+use std::path::Path;
 use std::rc::Rc;
+use std::time::Instant;
 
 /*===============================================================================================*/
 /*------RENDER MANAGER STRUCT--------------------------------------------------------------------*/
@@ -36,6 +46,14 @@ pub struct RenderManager {
 
     // Private
     _render_factory: Option<Box<RenderFactory>>,
+    _cameras: Vec<Rc<RefCell<Camera>>>,
+    _debug_renderer: Option<DebugDrawRenderer>,
+    _debug_font: Option<Font>,
+    _last_present: Option<Instant>,
+    _lighting_config: Cell<LightingConfig>,
+    _shadow_config: Cell<ShadowConfig>,
+    _post_process_config: RefCell<PostProcessConfig>,
+    _frame_capture: FrameCapture,
 }
 
 /*===============================================================================================*/
@@ -65,8 +83,23 @@ impl RenderManager {
             }
         }
 
+        self._lighting_config.set (render_config.lighting_config);
+        self._shadow_config.set (render_config.shadow_config);
+
+        // Load the post-processing chain
+        match resource_mgr.borrow ().load_config::<PostProcessConfig> ("postprocess") {
+
+            Ok (config) => *self._post_process_config.borrow_mut () = config,
+            Err (_) => {
+
+                match resource_mgr.borrow ().new_config::<PostProcessConfig> ("postprocess") {
+                    Ok (_) | Err (_) => {}
+                }
+            }
+        }
+
         // Initialize the window
-        self.window.borrow_mut ().init (&render_config.window_config);
+        self.window.borrow_mut ().init (&render_config.window_config, &render_config.context_config);
     }
 
 /*-----------------------------------------------------------------------------------------------*/
@@ -78,6 +111,93 @@ impl RenderManager {
         self._render_factory = Some (render_factory);
     }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the active render factory.
+    ///
+    /// It is used for creating GPU resources.
+    pub fn get_render_factory (&self) -> Option<&RenderFactory> {
+        self._render_factory.as_ref ().map (|f| &**f)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a camera to the Render Manager.
+    ///
+    /// The aspect ratio of the camera is kept in sync with the window size.
+    pub fn add_camera (&mut self, camera: Rc<RefCell<Camera>>) {
+
+        let mut window = self.window.borrow_mut ();
+        let weak_camera = Rc::downgrade (&camera);
+
+        camera.borrow_mut ().on_resize (&window.get_size ());
+        window.add_resize_listener (Box::new (move |size| {
+
+            if let Some (camera) = weak_camera.upgrade () {
+                camera.borrow_mut ().on_resize (&size);
+            }
+        }));
+
+        self._cameras.push (camera);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the lighting config loaded from the renderer config.
+    pub fn get_lighting_config (&self) -> LightingConfig {
+        self._lighting_config.get ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the shadow config loaded from the renderer config.
+    pub fn get_shadow_config (&self) -> ShadowConfig {
+        self._shadow_config.get ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the post-processing chain loaded from the `postprocess` config.
+    pub fn get_post_process_config (&self) -> PostProcessConfig {
+        self._post_process_config.borrow ().clone ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the cameras added to the Render Manager.
+    pub fn get_cameras (&self) -> &[Rc<RefCell<Camera>>] {
+        &self._cameras
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Submits a command buffer to the active render backend for execution.
+    pub fn submit (&self, command_buffer: &CommandBuffer) {
+
+        if let Some (ref factory) = self._render_factory {
+            factory.execute_command_buffer (command_buffer);
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Executes a render graph, and submits its commands to the active render backend.
+    pub fn execute_graph (&self, graph: &mut RenderGraph) -> Result<(), ()> {
+
+        let factory = match self._render_factory {
+
+            Some (ref factory) => factory,
+            None => return Err (())
+        };
+
+        let mut command_buffer = CommandBuffer::new ();
+
+        try! (graph.execute (&**factory, &mut command_buffer));
+        factory.execute_command_buffer (&command_buffer);
+
+        Ok (())
+    }
+
 /*-----------------------------------------------------------------------------------------------*/
 
     /// Processes the window events.
@@ -85,6 +205,89 @@ impl RenderManager {
         self.window.borrow_mut ().process_events ();
     }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the font debug draw labels are drawn with.
+    pub fn set_debug_font (&mut self, font: Font) {
+
+        match self._debug_renderer {
+
+            Some (ref mut renderer) => {
+
+                if let Some (factory) = self._render_factory.as_ref () {
+
+                    if renderer.set_font (&**factory, font).is_err () {
+                        warn! ("Could not create the debug font textures.");
+                    }
+                }
+            },
+
+            None => self._debug_font = Some (font)
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Saves the next presented frame as a PNG image.
+    ///
+    /// The path is relative to the persistent data directory.
+    pub fn capture_screenshot (&mut self, path: &str) {
+        self._frame_capture.capture_screenshot (path);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Saves the next `count` presented frames as numbered PNG images.
+    ///
+    /// Frames are written to `<prefix>_00000.png`, `<prefix>_00001.png`, and so on,
+    /// relative to the persistent data directory.
+    pub fn record_frames (&mut self, prefix: &str, count: u32) {
+        self._frame_capture.record_frames (prefix, count);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Stops the frame recording in progress.
+    pub fn stop_recording (&mut self) {
+        self._frame_capture.stop_recording ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns whether frames are being recorded.
+    pub fn is_recording (&self) -> bool {
+        self._frame_capture.is_recording ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Presents the rendered frame to the window.
+    ///
+    /// The debug draw list is drawn on top of the scene first,
+    /// and the frame is captured if a screenshot or recording was requested.  
+    /// This is called once per frame from the main loop.
+    pub fn present (&mut self) {
+
+        let now = Instant::now ();
+        let delta_time = match self._last_present {
+
+            Some (last) => {
+
+                let elapsed = now.duration_since (last);
+                elapsed.as_secs () as f32 + elapsed.subsec_nanos () as f32 / 1_000_000_000.0
+            },
+
+            None => 0.0
+        };
+
+        self._draw_debug ();
+        self._write_captures ();
+        self.window.borrow_mut ().present ();
+
+        debug_draw::with_list (|list| list.update (delta_time));
+        self._last_present = Some (now);
+    }
+
 /*-----------------------------------------------------------------------------------------------*/
 
     /// Releases the Render Manager.
@@ -93,6 +296,8 @@ impl RenderManager {
         info! ("Releasing the Render Manager");
 
         self.window.borrow_mut ().release ();
+        self._cameras.clear ();
+        self._debug_renderer = None;
         self._render_factory = None;
     }
 
@@ -107,6 +312,117 @@ impl RenderManager {
 
             window: Rc::new (RefCell::new (Window::new ())),
             _render_factory: Some (Box::new (RenderFactoryDefault)),
+            _cameras: Vec::new (),
+            _debug_renderer: None,
+            _debug_font: None,
+            _last_present: None,
+            _lighting_config: Cell::new (LightingConfig::new ()),
+            _shadow_config: Cell::new (ShadowConfig::new ()),
+            _post_process_config: RefCell::new (PostProcessConfig::new ()),
+            _frame_capture: FrameCapture::new (),
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------RENDER MANAGER PRIVATE METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderManager {
+
+    // Draws the debug draw list over the view of every camera.
+    fn _draw_debug (&mut self) {
+
+        let factory = match self._render_factory {
+
+            Some (ref factory) => factory,
+            None => return
+        };
+
+        // The renderer is created on first use, once the backend has a context
+        if self._debug_renderer.is_none () {
+
+            let font = self._debug_font.take ();
+
+            self._debug_renderer = DebugDrawRenderer::new (&**factory).ok ().map (|mut renderer| {
+
+                if let Some (font) = font {
+
+                    if renderer.set_font (&**factory, font).is_err () {
+                        warn! ("Could not create the debug font textures.");
+                    }
+                }
+
+                renderer
+            });
+
+            if self._debug_renderer.is_none () {
+                return;
+            }
+        }
+
+        let renderer = self._debug_renderer.as_mut ().unwrap ();
+        let cameras = &self._cameras;
+        let target_size = self.window.borrow ().get_drawable_size ();
+        let mut command_buffer = CommandBuffer::new ();
+
+        debug_draw::with_list (|list| {
+
+            for camera in cameras {
+
+                if renderer.render (&**factory, &mut command_buffer, list, &camera.borrow (), &target_size).is_err () {
+                    warn! ("Could not draw the debug draw list.");
+                }
+            }
+        });
+
+        factory.execute_command_buffer (&command_buffer);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads back the window framebuffer, and writes it to the requested capture paths.
+    fn _write_captures (&mut self) {
+
+        if !self._frame_capture.is_pending () {
+            return;
+        }
+
+        let paths = self._frame_capture.next_frame ();
+        let size = self.window.borrow ().get_drawable_size ();
+
+        let image = match self._render_factory {
+
+            Some (ref factory) => factory.read_pixels (None, 0, 0, size.x as u32, size.y as u32),
+            None => Err (())
+        };
+
+        let image = match image {
+
+            Ok (image) => image,
+            Err (_) => {
+
+                warn! ("Could not read back the framebuffer for capture.");
+                return;
+            }
+        };
+
+        let data_dir = Directory::get_persistent_data_directory ();
+
+        for path in paths {
+
+            let full_path = format! ("{}{}", data_dir, path);
+
+            if let Some (parent) = Path::new (&full_path).parent () {
+
+                if let Err (e) = fs::create_dir_all (parent) {
+                    warn! ("Could not create the capture directory \"{}\".\n{}", parent.display (), e);
+                }
+            }
+
+            if image.save_png (&full_path).is_err () {
+                warn! ("Could not save the captured frame \"{}\".", &full_path);
+            }
         }
     }
 }
@@ -0,0 +1,32 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The sprite module.
+//!
+//! Contains the sprite type, and the batcher which draws them.
+/*===============================================================================================*/
+
+mod sprite;
+mod sprite_batch;
+mod sprite_batch_stats;
+mod sprite_vertex;
+
+pub use self::sprite::Sprite;
+pub use self::sprite_batch::SpriteBatch;
+pub use self::sprite_batch_stats::SpriteBatchStats;
+pub use self::sprite_vertex::SpriteVertex;
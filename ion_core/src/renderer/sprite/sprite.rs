@@ -0,0 +1,161 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::material::Material;
+use ::renderer::traits::Texture;
+use ::util::math::{Colour, Mat3, Vec2, Vec4};
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------SPRITE STRUCT----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A textured, tinted quad drawn by a `SpriteBatch`.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::sprite::Sprite;
+/// # use ion_core::util::math::Vec2;
+/// #
+/// let mut sprite = Sprite::new ();
+/// sprite.position = Vec2 {x: 10.0, y: 5.0};
+/// sprite.size     = Vec2 {x: 2.0, y: 2.0};
+/// sprite.pivot    = Vec2 {x: 0.0, y: 0.0};
+///
+/// let corners = sprite.get_corners ();
+///
+/// assert_eq! (corners[0].x, 10.0);
+/// assert_eq! (corners[2].y, 7.0);
+/// ```
+#[derive (Clone)]
+pub struct Sprite {
+
+    // Public
+    /// The texture of the sprite.  
+    /// Sprites without a texture are drawn in their tint colour.
+    pub texture: Option<Rc<Texture>>,
+    /// The material of the sprite.  
+    /// Sprites without a material use the built in sprite shader.
+    pub material: Option<Rc<Material>>,
+    /// The region of the texture drawn, in texture coordinates.  
+    /// `x` and `y` are the top left corner, and `z` and `w` the bottom right corner.
+    pub region: Vec4,
+    /// The world position of the pivot.
+    pub position: Vec2,
+    /// The size of the sprite in world units.
+    pub size: Vec2,
+    /// The point the sprite is positioned and rotated around,  
+    /// from (0, 0) at the bottom left corner to (1, 1) at the top right corner.
+    pub pivot: Vec2,
+    /// The rotation of the sprite in degrees, counter-clockwise.
+    pub rotation: f32,
+    /// The colour the texture is multiplied with.
+    pub tint: Colour,
+    /// The draw order of the sprite.  
+    /// Sprites with a higher z-order are drawn on top.
+    pub z_order: i32,
+}
+
+/*===============================================================================================*/
+/*------SPRITE PUBLIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Sprite {
+
+    /// Sets the region of the texture drawn, in pixels from the top left corner.
+    ///
+    /// Does nothing if the sprite has no texture.
+    pub fn set_texture_region (&mut self, x: u32, y: u32, width: u32, height: u32) {
+
+        if let Some (ref texture) = self.texture {
+
+            let texture_width  = texture.get_width () as f32;
+            let texture_height = texture.get_height () as f32;
+
+            self.region = Vec4 {x: x as f32 / texture_width,
+                                y: y as f32 / texture_height,
+                                z: (x + width) as f32 / texture_width,
+                                w: (y + height) as f32 / texture_height};
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the model matrix of the sprite.
+    ///
+    /// It maps the unit quad, offset by the pivot, into world space.
+    pub fn get_transform (&self) -> Mat3 {
+        Mat3::translate (&self.position) * Mat3::rotate (self.rotation) * Mat3::scale (&self.size)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the world positions of the corners of the sprite.
+    ///
+    /// The corners are ordered counter-clockwise from the bottom left.
+    pub fn get_corners (&self) -> [Vec2; 4] {
+
+        let transform = self.get_transform ();
+        let mut corners = [Vec2::new (); 4];
+
+        for (i, &(x, y)) in [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].iter ().enumerate () {
+            corners[i] = transform * Vec2 {x: x - self.pivot.x, y: y - self.pivot.y};
+        }
+
+        corners
+    }
+
+/*===============================================================================================*/
+/*------SPRITE PUBLIC STATIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, white unit sprite at the origin, pivoted around its centre.
+    pub fn new () -> Sprite {
+
+        Sprite {texture: None,
+                material: None,
+                region: Vec4 {x: 0.0, y: 0.0, z: 1.0, w: 1.0},
+                position: Vec2::new (),
+                size: Vec2 {x: 1.0, y: 1.0},
+                pivot: Vec2 {x: 0.5, y: 0.5},
+                rotation: 0.0,
+                tint: Colour::new (),
+                z_order: 0}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new sprite drawing a whole texture, sized to the texture in pixels.
+    pub fn from_texture (texture: Rc<Texture>) -> Sprite {
+
+        let mut sprite = Sprite::new ();
+
+        sprite.size = Vec2 {x: texture.get_width () as f32, y: texture.get_height () as f32};
+        sprite.texture = Some (texture);
+        sprite
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for Sprite {
+
+    fn default () -> Sprite {
+        Sprite::new ()
+    }
+}
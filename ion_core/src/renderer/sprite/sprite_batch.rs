@@ -0,0 +1,422 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::command::CommandBuffer;
+use ::renderer::gpu::{BlendMode, BufferDesc, BufferType, BufferUsage, BuiltinShader, CullMode, IndexFormat};
+use ::renderer::gpu::{PipelineDesc, RenderState, ShaderStage, TextureDesc, TextureFormat, UniformValue};
+use ::renderer::material::Material;
+use ::renderer::sprite::{Sprite, SpriteBatchStats, SpriteVertex};
+use ::renderer::traits::{Buffer, Pipeline, RenderFactory, Texture};
+use ::util::Bytes;
+use ::util::math::{Mat4, Vec2};
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------SPRITE BATCH STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Draws large numbers of sprites with as few draw calls as possible.
+///
+/// Sprites are collected between `begin` and `end`. When the batch ends, they are sorted by  
+/// z-order, then by material and texture, and written into a single vertex and index buffer.  
+/// A draw call is only recorded when the material or texture changes.
+///
+/// Sprites with the same z-order may be drawn in any order.
+///
+/// Each batch is written into its own buffers, so several batches can be recorded into one
+/// command buffer before it is executed. Buffers are reused once no command buffer refers to them.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::command::CommandBuffer;
+/// # use ion_core::renderer::gpu::{TextureDesc, TextureFormat};
+/// # use ion_core::renderer::mock::RenderFactoryMock;
+/// # use ion_core::renderer::sprite::{Sprite, SpriteBatch};
+/// # use ion_core::renderer::traits::RenderFactory;
+/// # use ion_core::util::math::Mat4;
+/// #
+/// let factory = RenderFactoryMock::new ();
+/// let grass = factory.create_texture (&TextureDesc::new (16, 16, TextureFormat::RGBA8), &[]).unwrap ();
+/// let stone = factory.create_texture (&TextureDesc::new (16, 16, TextureFormat::RGBA8), &[]).unwrap ();
+///
+/// let mut batch = SpriteBatch::new (&factory).unwrap ();
+/// let mut commands = CommandBuffer::new ();
+///
+/// // Interleave the textures, all on the same layer
+/// batch.begin ();
+///
+/// for i in 0..10 {
+///
+///     let texture = if i % 2 == 0 {grass.clone ()} else {stone.clone ()};
+///     batch.draw (&Sprite::from_texture (texture));
+/// }
+///
+/// batch.end (&factory, &mut commands, &Mat4::identity ()).unwrap ();
+/// factory.execute_command_buffer (&commands);
+///
+/// // One draw call per texture
+/// assert_eq! (batch.get_stats ().draw_calls, 2);
+/// assert_eq! (batch.get_stats ().vertex_count, 40);
+/// assert_eq! (factory.get_draw_call_count (), 2);
+/// assert_eq! (factory.get_vertex_count (), 60);
+/// assert! (factory.get_errors ().is_empty ());
+/// ```
+pub struct SpriteBatch {
+
+    // Private
+    _sprites: Vec<Sprite>,
+    _vertices: Vec<SpriteVertex>,
+    _indices: Vec<u32>,
+    _pipeline: Rc<Pipeline>,
+    _material_pipelines: Vec<(Rc<Material>, Rc<Pipeline>)>,
+    _white_texture: Rc<Texture>,
+    _buffers: Vec<(Option<Rc<Buffer>>, Option<Rc<Buffer>>)>,
+    _stats: SpriteBatchStats,
+}
+
+/*===============================================================================================*/
+/*------SPRITE BATCH PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl SpriteBatch {
+
+    /// Starts a new batch, discarding any sprites that weren't drawn.
+    pub fn begin (&mut self) {
+        self._sprites.clear ();
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a sprite to the batch.
+    pub fn draw (&mut self, sprite: &Sprite) {
+        self._sprites.push (sprite.clone ());
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Ends the batch, and records the draw calls for its sprites into a command buffer.
+    ///
+    /// # Arguments
+    /// * `render_factory` - The factory used to create or resize the batch buffers.
+    /// * `command_buffer` - The command buffer the sprites are recorded into.
+    /// * `view_projection` - The view projection matrix of the camera the sprites are drawn by.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::command::{CommandBuffer, RenderCommand};
+    /// # use ion_core::renderer::mock::{BufferMock, RenderFactoryMock};
+    /// # use ion_core::renderer::sprite::{Sprite, SpriteBatch};
+    /// # use ion_core::util::math::{Mat4, Vec2};
+    /// #
+    /// let factory = RenderFactoryMock::new ();
+    /// let mut batch = SpriteBatch::new (&factory).unwrap ();
+    /// let mut commands = CommandBuffer::new ();
+    ///
+    /// // Two batches recorded before the command buffer is executed, such as one per camera
+    /// for x in [0.0, 100.0].iter () {
+    ///
+    ///     let mut sprite = Sprite::new ();
+    ///     sprite.position = Vec2 {x: *x, y: 0.0};
+    ///
+    ///     batch.begin ();
+    ///     batch.draw (&sprite);
+    ///     batch.end (&factory, &mut commands, &Mat4::identity ()).unwrap ();
+    /// }
+    ///
+    /// // The second batch didn't overwrite the geometry of the first
+    /// let vertex_data: Vec<Vec<u8>> = commands.get_commands ().iter ().filter_map (|command| match *command {
+    ///
+    ///     RenderCommand::BindVertexBuffer (ref buffer) => buffer.as_any ().downcast_ref::<BufferMock> ()
+    ///                                                                     .map (|buffer| buffer.get_data ()),
+    ///     _ => None
+    ///
+    /// }).collect ();
+    ///
+    /// assert_eq! (vertex_data.len (), 2);
+    /// assert! (vertex_data[0] != vertex_data[1]);
+    /// ```
+    pub fn end (&mut self, render_factory: &RenderFactory, command_buffer: &mut CommandBuffer, view_projection: &Mat4) -> Result<(), ()> {
+
+        self._stats = SpriteBatchStats::new ();
+        self._stats.sprite_count = self._sprites.len ();
+
+        if self._sprites.is_empty () {
+            return Ok (());
+        }
+
+        // Sort the sprites, so those sharing a material and texture are adjacent
+        self._sprites.sort_by_key (|s| (s.z_order,
+                                        SpriteBatch::_get_material_key (s),
+                                        SpriteBatch::_get_texture_key (s)));
+
+        // Build the geometry, splitting it into runs with the same material and texture
+        let mut runs: Vec<(usize, usize)> = Vec::new ();
+
+        self._vertices.clear ();
+        self._indices.clear ();
+
+        for (i, sprite) in self._sprites.iter ().enumerate () {
+
+            if i == 0 || !SpriteBatch::_can_share_draw (&self._sprites[i - 1], sprite) {
+                runs.push ((i, 0));
+            }
+
+            runs.last_mut ().unwrap ().1 += 1;
+            SpriteBatch::_append_quad (&mut self._vertices, &mut self._indices, sprite);
+        }
+
+        // Buffers still referred to by a command buffer may not have been drawn yet,
+        // so the geometry goes into a pair which no command buffer refers to
+        let slot = match self._buffers.iter ().position (|&(ref vertex_buffer, ref index_buffer)| {
+            SpriteBatch::_is_unused (vertex_buffer) && SpriteBatch::_is_unused (index_buffer)
+        }) {
+
+            Some (slot) => slot,
+            None => {
+
+                self._buffers.push ((None, None));
+                self._buffers.len () - 1
+            }
+        };
+
+        let (ref mut vertex_slot, ref mut index_slot) = self._buffers[slot];
+
+        // Sprite vertices are made only of f32s, so they have no padding
+        let vertex_buffer = try! (SpriteBatch::_upload (render_factory,
+                                                        vertex_slot,
+                                                        BufferType::Vertex,
+                                                        unsafe {Bytes::from_slice (&self._vertices)}));
+
+        let index_buffer = try! (SpriteBatch::_upload (render_factory,
+                                                       index_slot,
+                                                       BufferType::Index,
+                                                       unsafe {Bytes::from_slice (&self._indices)}));
+
+        command_buffer.bind_vertex_buffer (vertex_buffer);
+        command_buffer.bind_index_buffer (index_buffer, IndexFormat::U32);
+
+        // Record one draw per run, only rebinding what changed
+        for (r, &(first, count)) in runs.iter ().enumerate () {
+
+            let material_changed = r == 0 || SpriteBatch::_get_material_key (&self._sprites[runs[r - 1].0]) !=
+                                             SpriteBatch::_get_material_key (&self._sprites[first]);
+
+            if material_changed {
+
+                match self._sprites[first].material.clone () {
+
+                    Some (material) => {
+
+                        let pipeline = try! (self._get_material_pipeline (render_factory, &material));
+
+                        command_buffer.bind_pipeline (pipeline);
+                        Material::bind_params (command_buffer, &material.render_state, material.params.iter (), 1);
+                    },
+
+                    None => {
+
+                        command_buffer.bind_pipeline (self._pipeline.clone ());
+                        command_buffer.set_render_state (SpriteBatch::get_render_state ());
+                    }
+                }
+
+                command_buffer.set_uniform ("u_view_projection", UniformValue::Mat4 (*view_projection));
+                command_buffer.set_uniform ("u_texture", UniformValue::Int (0));
+            }
+
+            // Every run has a new texture or material, and materials bind their textures from slot 1
+            let texture = self._sprites[first].texture.clone ().unwrap_or (self._white_texture.clone ());
+
+            command_buffer.bind_texture (0, texture);
+            command_buffer.draw_indexed ((first * 6) as u32, (count * 6) as u32);
+        }
+
+        self._stats.draw_calls = runs.len ();
+        self._stats.vertex_count = self._vertices.len ();
+        self._stats.index_count = self._indices.len ();
+        self._sprites.clear ();
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of sprites added since the batch began.
+    pub fn get_sprite_count (&self) -> usize {
+        self._sprites.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the statistics of the last batch that ended.
+    pub fn get_stats (&self) -> SpriteBatchStats {
+        self._stats
+    }
+
+/*===============================================================================================*/
+/*------SPRITE BATCH PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns the render state used for sprites without a material.
+    ///
+    /// Sprites are alpha blended, with culling and depth testing disabled.
+    pub fn get_render_state () -> RenderState {
+
+        RenderState {blend_mode: BlendMode::Alpha,
+                     cull_mode: CullMode::None,
+                     depth_test: false,
+                     depth_write: false,
+                     .. RenderState::new ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new sprite batch.
+    ///
+    /// The built in sprite pipeline is created from the render factory.
+    pub fn new (render_factory: &RenderFactory) -> Result<SpriteBatch, ()> {
+
+        let vertex_shader   = try! (render_factory.create_builtin_shader (BuiltinShader::Sprite, ShaderStage::Vertex));
+        let fragment_shader = try! (render_factory.create_builtin_shader (BuiltinShader::Sprite, ShaderStage::Fragment));
+
+        let pipeline = try! (render_factory.create_pipeline (&PipelineDesc::new (vertex_shader,
+                                                                                 fragment_shader,
+                                                                                 SpriteVertex::get_vertex_layout ())));
+
+        let white_texture = try! (render_factory.create_texture (&TextureDesc::new (1, 1, TextureFormat::RGBA8),
+                                                                 &[255, 255, 255, 255]));
+
+        Ok (SpriteBatch {_sprites: Vec::new (),
+                         _vertices: Vec::new (),
+                         _indices: Vec::new (),
+                         _pipeline: pipeline,
+                         _material_pipelines: Vec::new (),
+                         _white_texture: white_texture,
+                         _buffers: Vec::new (),
+                         _stats: SpriteBatchStats::new ()})
+    }
+}
+
+/*===============================================================================================*/
+/*------SPRITE BATCH PRIVATE METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl SpriteBatch {
+
+    // Returns the pipeline of a material, creating it the first time it's used.
+    fn _get_material_pipeline (&mut self, render_factory: &RenderFactory, material: &Rc<Material>) -> Result<Rc<Pipeline>, ()> {
+
+        let key = &**material as *const Material;
+
+        if let Some (&(_, ref pipeline)) = self._material_pipelines.iter ().find (|&&(ref m, _)| &**m as *const Material == key) {
+            return Ok (pipeline.clone ());
+        }
+
+        let pipeline = try! (material.create_pipeline (render_factory, SpriteVertex::get_vertex_layout ()));
+
+        self._material_pipelines.push ((material.clone (), pipeline.clone ()));
+        Ok (pipeline)
+    }
+
+/*===============================================================================================*/
+/*------SPRITE BATCH PRIVATE STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Returns a key identifying the material of a sprite.
+    fn _get_material_key (sprite: &Sprite) -> usize {
+
+        match sprite.material {
+
+            Some (ref material) => &**material as *const Material as usize,
+            None => 0
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns a key identifying the texture of a sprite.
+    fn _get_texture_key (sprite: &Sprite) -> usize {
+
+        match sprite.texture {
+
+            Some (ref texture) => &**texture as *const Texture as *const u8 as usize,
+            None => 0
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Checks whether two sprites can be drawn by the same draw call.
+    fn _can_share_draw (a: &Sprite, b: &Sprite) -> bool {
+
+        SpriteBatch::_get_material_key (a) == SpriteBatch::_get_material_key (b) &&
+        SpriteBatch::_get_texture_key (a) == SpriteBatch::_get_texture_key (b)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Appends the vertices and indices of a sprite.
+    fn _append_quad (vertices: &mut Vec<SpriteVertex>, indices: &mut Vec<u32>, sprite: &Sprite) {
+
+        let base = vertices.len () as u32;
+        let corners = sprite.get_corners ();
+
+        // The region's top edge is at the top of the sprite
+        let uvs = [Vec2 {x: sprite.region.x, y: sprite.region.w},
+                   Vec2 {x: sprite.region.z, y: sprite.region.w},
+                   Vec2 {x: sprite.region.z, y: sprite.region.y},
+                   Vec2 {x: sprite.region.x, y: sprite.region.y}];
+
+        for i in 0..4 {
+
+            vertices.push (SpriteVertex {position: corners[i],
+                                         uv: uvs[i],
+                                         colour: sprite.tint});
+        }
+
+        indices.extend_from_slice (&[base, base + 1, base + 2, base + 2, base + 3, base]);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Checks whether a buffer is only referred to by the batch, so it can be written to.
+    fn _is_unused (buffer: &Option<Rc<Buffer>>) -> bool {
+        buffer.as_ref ().map_or (true, |buffer| Rc::strong_count (buffer) == 1)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Writes data into a buffer, replacing it with a larger one if it's too small.
+    fn _upload (render_factory: &RenderFactory, buffer: &mut Option<Rc<Buffer>>, buffer_type: BufferType, data: &[u8]) -> Result<Rc<Buffer>, ()> {
+
+        if let Some (ref b) = *buffer {
+
+            if b.get_size () >= data.len () {
+
+                b.set_data (0, data);
+                return Ok (b.clone ());
+            }
+        }
+
+        let desc = BufferDesc::new (buffer_type, BufferUsage::Stream, data.len ().next_power_of_two ());
+        let new_buffer = try! (render_factory.create_buffer (&desc, data));
+
+        *buffer = Some (new_buffer.clone ());
+        Ok (new_buffer)
+    }
+}
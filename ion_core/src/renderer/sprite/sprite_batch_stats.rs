@@ -0,0 +1,47 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------SPRITE BATCH STATS STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Statistics on the last batch drawn by a `SpriteBatch`.
+#[derive (Copy, Clone, Debug, Default, PartialEq)]
+pub struct SpriteBatchStats {
+
+    // Public
+    /// The number of sprites drawn.
+    pub sprite_count: usize,
+    /// The number of draw calls recorded.
+    pub draw_calls: usize,
+    /// The number of vertices uploaded.
+    pub vertex_count: usize,
+    /// The number of indices drawn.
+    pub index_count: usize,
+}
+
+/*===============================================================================================*/
+/*------SPRITE BATCH STATS PUBLIC STATIC METHODS-------------------------------------------------*/
+/*===============================================================================================*/
+
+impl SpriteBatchStats {
+
+    /// Returns new, zeroed statistics.
+    pub fn new () -> SpriteBatchStats {
+        SpriteBatchStats::default ()
+    }
+}
@@ -0,0 +1,123 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------ATLAS PACKER STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Packs rectangles into a fixed size atlas, using rows of shelves.
+///
+/// Each rectangle is placed on the first shelf it fits on. A new shelf is opened  
+/// below the last one when none fit. Packing works best when rectangles are added  
+/// from tallest to shortest.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::text::AtlasPacker;
+/// #
+/// let mut packer = AtlasPacker::new (64, 64, 1);
+///
+/// assert_eq! (packer.pack (30, 20), Some ((1, 1)));
+/// assert_eq! (packer.pack (30, 20), Some ((32, 1)));
+/// assert_eq! (packer.pack (30, 20), Some ((1, 22)));
+/// assert_eq! (packer.pack (80, 10), None);
+/// ```
+#[derive (Clone, Debug)]
+pub struct AtlasPacker {
+
+    // Private
+    _width: u32,
+    _height: u32,
+    _padding: u32,
+    _shelves: Vec<(u32, u32, u32)>, // (y, height, used width)
+}
+
+/*===============================================================================================*/
+/*------ATLAS PACKER PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AtlasPacker {
+
+    /// Finds space for a rectangle.
+    ///
+    /// # Return value
+    /// The position of the top left corner of the rectangle,  
+    /// or `None` if the atlas has no room left for it.
+    pub fn pack (&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+
+        let padded_width  = width + self._padding;
+        let padded_height = height + self._padding;
+
+        // Try to fit the rectangle on an existing shelf
+        for shelf in &mut self._shelves {
+
+            if padded_height <= shelf.1 && shelf.2 + padded_width <= self._width {
+
+                let x = shelf.2;
+
+                shelf.2 += padded_width;
+                return Some ((x, shelf.0));
+            }
+        }
+
+        // Open a new shelf below the last
+        let y = match self._shelves.last () {
+
+            Some (&(y, height, _)) => y + height,
+            None => self._padding
+        };
+
+        if y + padded_height > self._height || self._padding + padded_width > self._width {
+            return None;
+        }
+
+        self._shelves.push ((y, padded_height, self._padding + padded_width));
+        Some ((self._padding, y))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the width of the atlas.
+    pub fn get_width (&self) -> u32 {
+        self._width
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the height of the atlas.
+    pub fn get_height (&self) -> u32 {
+        self._height
+    }
+
+/*===============================================================================================*/
+/*------ATLAS PACKER PUBLIC STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty packer.
+    ///
+    /// # Arguments
+    /// * `width` - The width of the atlas.
+    /// * `height` - The height of the atlas.
+    /// * `padding` - The space left between rectangles, and around the edge of the atlas.
+    pub fn new (width: u32, height: u32, padding: u32) -> AtlasPacker {
+
+        AtlasPacker {_width: width,
+                     _height: height,
+                     _padding: padding,
+                     _shelves: Vec::new ()}
+    }
+}
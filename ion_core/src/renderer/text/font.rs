@@ -0,0 +1,600 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate rusttype;
+
+use ::renderer::gpu::TextureDesc;
+use ::renderer::sprite::{Sprite, SpriteBatch};
+use ::renderer::text::{AtlasPacker, Glyph, TextLayout, TextStyle};
+use ::renderer::traits::{RenderFactory, Texture};
+use ::util::image::{Image, ImageFormat};
+use ::util::math::{Util, Vec2, Vec4};
+
+use self::rusttype::{FontCollection, Scale, point};
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------FONT STRUCT------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A set of glyphs, packed into one or more atlas pages.
+///
+/// Fonts are created by rasterizing a TrueType font at a fixed size, or from a BMFont  
+/// descriptor and its pre-rendered pages. The atlas pages are kept on the CPU, so text can  
+/// be measured and laid out headlessly. `load_textures` uploads them for drawing.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::text::{Font, TextStyle};
+/// # use ion_core::util::image::{Image, ImageFormat};
+/// #
+/// let source = "info face=\"Test\" size=16\n\
+///               common lineHeight=18 base=14 scaleW=64 scaleH=64 pages=1\n\
+///               page id=0 file=\"test_0.png\"\n\
+///               char id=65 x=0 y=0 width=10 height=14 xoffset=0 yoffset=0 xadvance=11 page=0\n\
+///               char id=86 x=10 y=0 width=10 height=14 xoffset=0 yoffset=0 xadvance=11 page=0\n\
+///               kerning first=65 second=86 amount=-2";
+///
+/// let font = Font::from_bmfont (source, |_| Ok (Image::new (64, 64, ImageFormat::RGBA8))).unwrap ();
+///
+/// assert_eq! (font.get_line_height (), 18.0);
+/// assert_eq! (font.measure_text ("AV", &TextStyle::new ()).x, 20.0);
+/// ```
+pub struct Font {
+
+    // Private
+    _size: f32,
+    _line_height: f32,
+    _ascent: f32,
+    _glyphs: HashMap<char, Glyph>,
+    _kerning: HashMap<(char, char), f32>,
+    _pages: Vec<Image>,
+    _textures: Vec<Rc<Texture>>,
+}
+
+/*===============================================================================================*/
+/*------FONT PUBLIC METHODS----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Font {
+
+    /// Returns the size the font was created at, in pixels.
+    pub fn get_size (&self) -> f32 {
+        self._size
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance between the tops of two lines, in pixels.
+    pub fn get_line_height (&self) -> f32 {
+        self._line_height
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance from the top of a line to its baseline, in pixels.
+    pub fn get_ascent (&self) -> f32 {
+        self._ascent
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the glyph of a character.
+    pub fn get_glyph (&self, character: char) -> Option<&Glyph> {
+        self._glyphs.get (&character)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds or replaces the glyph of a character.
+    pub fn insert_glyph (&mut self, character: char, glyph: Glyph) {
+        self._glyphs.insert (character, glyph);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the adjustment to the advance between two characters, in pixels.
+    pub fn get_kerning (&self, first: char, second: char) -> f32 {
+        self._kerning.get (&(first, second)).cloned ().unwrap_or (0.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the adjustment to the advance between two characters, in pixels.
+    pub fn set_kerning (&mut self, first: char, second: char, amount: f32) {
+        self._kerning.insert ((first, second), amount);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the atlas pages.
+    pub fn get_pages (&self) -> &[Image] {
+        &self._pages
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the textures created from the atlas pages.
+    ///
+    /// This is empty until `load_textures` is called.
+    pub fn get_textures (&self) -> &[Rc<Texture>] {
+        &self._textures
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates a texture for each atlas page.
+    pub fn load_textures (&mut self, render_factory: &RenderFactory) -> Result<(), ()> {
+
+        let mut textures = Vec::new ();
+
+        for page in &self._pages {
+
+            let page = page.convert (ImageFormat::RGBA8);
+            textures.push (try! (render_factory.create_texture (&TextureDesc::from_image (&page), page.get_data ())));
+        }
+
+        self._textures = textures;
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the size of a block of text, in pixels.
+    ///
+    /// Only the font metrics are used, so this works without a render backend.
+    pub fn measure_text (&self, text: &str, style: &TextStyle) -> Vec2 {
+        TextLayout::new (self, text, style).size
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a sprite for each glyph of a block of text to a sprite batch.
+    ///
+    /// The y axis of the world is assumed to point up, so the text hangs down from `position`.  
+    /// `load_textures` must have been called first.
+    ///
+    /// # Arguments
+    /// * `batch` - The sprite batch the glyphs are drawn with.
+    /// * `text` - The text to draw.
+    /// * `position` - The top left corner of the text, in world units.
+    /// * `style` - The style of the text.
+    pub fn draw_text (&self, batch: &mut SpriteBatch, text: &str, position: &Vec2, style: &TextStyle) {
+
+        if self._textures.len () < self._pages.len () {
+
+            warn! ("Font textures are not loaded, and text can not be drawn.");
+            return;
+        }
+
+        for glyph in TextLayout::new (self, text, style).glyphs {
+
+            let mut sprite = Sprite::new ();
+
+            sprite.texture  = Some (self._textures[glyph.page].clone ());
+            sprite.region   = glyph.region;
+            sprite.position = Vec2 {x: position.x + glyph.position.x, y: position.y - glyph.position.y};
+            sprite.size     = glyph.size;
+            sprite.pivot    = Vec2 {x: 0.0, y: 1.0};
+            sprite.tint     = style.colour;
+            sprite.z_order  = style.z_order;
+
+            batch.draw (&sprite);
+        }
+    }
+
+/*===============================================================================================*/
+/*------FONT PUBLIC STATIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new font with no glyphs or pages.
+    ///
+    /// Glyphs can be added with `insert_glyph`, which is useful for measuring text in tests.
+    pub fn new (size: f32, line_height: f32, ascent: f32) -> Font {
+
+        Font {_size: size,
+              _line_height: line_height,
+              _ascent: ascent,
+              _glyphs: HashMap::new (),
+              _kerning: HashMap::new (),
+              _pages: Vec::new (),
+              _textures: Vec::new ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Rasterizes a TrueType or OpenType font into a single atlas page.
+    ///
+    /// # Arguments
+    /// * `data` - The contents of the font file.
+    /// * `size` - The size to rasterize the font at, in pixels.
+    /// * `characters` - The characters to include in the font.
+    pub fn from_ttf (data: &[u8], size: f32, characters: &str) -> Result<Font, ()> {
+
+        let ttf = match FontCollection::from_bytes (data.to_vec ()).and_then (|c| c.into_font ()) {
+
+            Ok (ttf) => ttf,
+            Err (e) => {
+
+                error! ("Font could not be parsed.\n{}", e);
+                return Err (());
+            }
+        };
+
+        let scale = Scale::uniform (size);
+        let v_metrics = ttf.v_metrics (scale);
+
+        let mut font = Font::new (size,
+                                  (v_metrics.ascent - v_metrics.descent + v_metrics.line_gap).ceil (),
+                                  v_metrics.ascent.ceil ());
+
+        // Rasterize every glyph, at the baseline of the origin
+        let mut chars: Vec<char> = characters.chars ().collect ();
+        let mut bitmaps = HashMap::new ();
+
+        chars.sort ();
+        chars.dedup ();
+
+        for &c in &chars {
+
+            let glyph = ttf.glyph (c);
+
+            if glyph.id ().0 == 0 {
+
+                warn! ("Font has no glyph for '{}'.", c);
+                continue;
+            }
+
+            let scaled = glyph.scaled (scale);
+            let advance = scaled.h_metrics ().advance_width;
+            let positioned = scaled.positioned (point (0.0, 0.0));
+
+            match positioned.pixel_bounding_box () {
+
+                Some (bounds) => {
+
+                    let width  = bounds.width () as u32;
+                    let height = bounds.height () as u32;
+                    let mut coverage = vec! [0u8; (width * height) as usize];
+
+                    positioned.draw (|x, y, v| {
+                        coverage[(y * width + x) as usize] = (Util::clamp (v, 0.0, 1.0) * 255.0).round () as u8;
+                    });
+
+                    let mut glyph = Glyph::new (advance);
+
+                    glyph.size   = Vec2 {x: width as f32, y: height as f32};
+                    glyph.offset = Vec2 {x: bounds.min.x as f32, y: font._ascent + bounds.min.y as f32};
+
+                    font._glyphs.insert (c, glyph);
+                    bitmaps.insert (c, (width, height, coverage));
+                },
+
+                None => {font._glyphs.insert (c, Glyph::new (advance));}
+            }
+        }
+
+        // Pack the glyphs into the smallest atlas they fit in, from tallest to shortest
+        let mut packed: Vec<char> = bitmaps.keys ().cloned ().collect ();
+        packed.sort_by (|a, b| match bitmaps[b].1.cmp (&bitmaps[a].1) {
+
+            Ordering::Equal => a.cmp (b),
+            ordering => ordering
+        });
+
+        let (mut atlas_width, mut atlas_height) = (64, 64);
+        let mut positions;
+
+        loop {
+
+            let mut packer = AtlasPacker::new (atlas_width, atlas_height, 1);
+            positions = packed.iter ().map (|c| packer.pack (bitmaps[c].0, bitmaps[c].1)).collect::<Vec<_>> ();
+
+            if positions.iter ().all (|p| p.is_some ()) {
+                break;
+            }
+
+            if atlas_width > atlas_height {atlas_height *= 2;} else {atlas_width *= 2;}
+        }
+
+        // Copy the glyphs into the atlas, as white with the coverage in the alpha channel
+        let mut atlas = Image::new (atlas_width, atlas_height, ImageFormat::RGBA8);
+
+        for pixel in atlas.get_data_mut ().chunks_mut (4) {
+            pixel.copy_from_slice (&[255, 255, 255, 0]);
+        }
+
+        for (c, position) in packed.iter ().zip (positions.iter ()) {
+
+            let (x, y) = position.unwrap ();
+            let (width, height, ref coverage) = bitmaps[c];
+
+            for row in 0..height {
+
+                for col in 0..width {
+
+                    let index = (((y + row) * atlas_width + x + col) * 4 + 3) as usize;
+                    atlas.get_data_mut ()[index] = coverage[(row * width + col) as usize];
+                }
+            }
+
+            let glyph = font._glyphs.get_mut (c).unwrap ();
+
+            glyph.region = Vec4 {x: x as f32 / atlas_width as f32,
+                                 y: y as f32 / atlas_height as f32,
+                                 z: (x + width) as f32 / atlas_width as f32,
+                                 w: (y + height) as f32 / atlas_height as f32};
+        }
+
+        // Store the kerning of every pair which has any
+        for &first in &chars {
+
+            for &second in &chars {
+
+                let amount = ttf.pair_kerning (scale, first, second);
+
+                if amount != 0.0 {
+                    font._kerning.insert ((first, second), amount);
+                }
+            }
+        }
+
+        font._pages.push (atlas);
+        Ok (font)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates a font from a text BMFont descriptor.
+    ///
+    /// # Arguments
+    /// * `source` - The contents of the `.fnt` file.
+    /// * `load_page` - Loads a page image, given the file name in the descriptor.
+    ///
+    /// Fails if a glyph is on a page the descriptor doesn't have.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::renderer::text::Font;
+    /// # use ion_core::util::image::{Image, ImageFormat};
+    /// #
+    /// let source = "common lineHeight=16 base=12 scaleW=64 scaleH=64 pages=1\n\
+    ///               page id=0 file=\"font_0.png\"\n\
+    ///               char id=65 x=0 y=0 width=8 height=12 xoffset=0 yoffset=0 xadvance=9 page=0\n";
+    ///
+    /// let load_page = |_: &str| Ok (Image::new (64, 64, ImageFormat::RGBA8));
+    ///
+    /// assert! (Font::from_bmfont (source, load_page).is_ok ());
+    /// assert! (Font::from_bmfont (&source.replace ("page=0", "page=1"), load_page).is_err ());
+    /// ```
+    pub fn from_bmfont<F> (source: &str, mut load_page: F) -> Result<Font, ()>
+        where F: FnMut (&str) -> Result<Image, ()> {
+
+        let mut font = Font::new (0.0, 0.0, 0.0);
+        let mut scale_width  = 1.0;
+        let mut scale_height = 1.0;
+
+        for line in source.lines () {
+
+            let (tag, attributes) = Font::_parse_bmfont_line (line);
+            let get = |name: &str| -> f32 {attributes.get (name).and_then (|v| v.parse ().ok ()).unwrap_or (0.0)};
+
+            match tag.as_str () {
+
+                "info" => font._size = get ("size").abs (),
+
+                "common" => {
+
+                    font._line_height = get ("lineHeight");
+                    font._ascent      = get ("base");
+                    scale_width       = get ("scaleW").max (1.0);
+                    scale_height      = get ("scaleH").max (1.0);
+                },
+
+                "page" => {
+
+                    let id = get ("id") as usize;
+                    let file = attributes.get ("file").cloned ().unwrap_or (String::new ());
+
+                    if id != font._pages.len () {
+
+                        error! ("BMFont page \"{}\" is out of order.", file);
+                        return Err (());
+                    }
+
+                    font._pages.push (try! (load_page (&file)));
+                },
+
+                "char" => {
+
+                    let c = match ::std::char::from_u32 (get ("id") as u32) {
+
+                        Some (c) => c,
+                        None => continue
+                    };
+
+                    let (x, y, width, height) = (get ("x"), get ("y"), get ("width"), get ("height"));
+
+                    font._glyphs.insert (c, Glyph {region: Vec4 {x: x / scale_width,
+                                                                 y: y / scale_height,
+                                                                 z: (x + width) / scale_width,
+                                                                 w: (y + height) / scale_height},
+                                                   size: Vec2 {x: width, y: height},
+                                                   offset: Vec2 {x: get ("xoffset"), y: get ("yoffset")},
+                                                   advance: get ("xadvance"),
+                                                   page: get ("page") as usize});
+                },
+
+                "kerning" => {
+
+                    let first  = ::std::char::from_u32 (get ("first") as u32);
+                    let second = ::std::char::from_u32 (get ("second") as u32);
+
+                    if let (Some (first), Some (second)) = (first, second) {
+                        font._kerning.insert ((first, second), get ("amount"));
+                    }
+                },
+
+                _ => {}
+            }
+        }
+
+        if font._line_height <= 0.0 {
+
+            error! ("BMFont descriptor has no common block.");
+            return Err (());
+        }
+
+        // Every glyph must be drawn from a page that was loaded
+        if let Some ((c, glyph)) = font._glyphs.iter ().find (|&(_, glyph)| glyph.page >= font._pages.len ()) {
+
+            error! ("BMFont glyph '{}' is on page {}, but there are only {} pages.", c, glyph.page, font._pages.len ());
+            return Err (());
+        }
+
+        Ok (font)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads a font file.
+    ///
+    /// `.ttf` and `.otf` files are rasterized at `size` with the printable ASCII characters.  
+    /// `.fnt` files are loaded as text BMFont descriptors, with their pages loaded  
+    /// from the same directory, and `size` is ignored.
+    pub fn load (file_path: &str, size: f32) -> Result<Font, ()> {
+
+        info! ("Loading font \"{}\".", file_path);
+
+        let mut data = Vec::new ();
+
+        match File::open (Path::new (file_path)).and_then (|mut file| file.read_to_end (&mut data)) {
+
+            Ok (_) => {},
+            Err (e) => {
+
+                error! ("Font \"{}\" could not be read.\n{}", file_path, e);
+                return Err (());
+            }
+        }
+
+        let path = Path::new (file_path);
+        let extension = path.extension ().and_then (|e| e.to_str ()).unwrap_or ("").to_lowercase ();
+
+        let font = match extension.as_str () {
+
+            "ttf" | "otf" => {
+
+                let ascii: String = (32u8..127).map (|c| c as char).collect ();
+                Font::from_ttf (&data, size, &ascii)
+            },
+
+            "fnt" => {
+
+                let base_dir = path.parent ().unwrap_or (Path::new (""));
+
+                match String::from_utf8 (data) {
+
+                    Ok (source) => Font::from_bmfont (&source, |page| {
+                        Image::load (&base_dir.join (page).to_string_lossy ())
+                    }),
+
+                    Err (_) => {
+
+                        error! ("Only text BMFont descriptors are supported.");
+                        Err (())
+                    }
+                }
+            },
+
+            _ => {
+
+                error! ("Font format \"{}\" is not supported.", extension);
+                Err (())
+            }
+        };
+
+        if font.is_err () {
+            error! ("Font \"{}\" could not be loaded.", file_path);
+        }
+
+        font
+    }
+}
+
+/*===============================================================================================*/
+/*------FONT PRIVATE STATIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Font {
+
+    // Splits a BMFont line into its tag and attributes.
+    // Quoted values may contain spaces.
+    fn _parse_bmfont_line (line: &str) -> (String, HashMap<String, String>) {
+
+        let mut attributes = HashMap::new ();
+        let line = line.trim ();
+
+        let (tag, mut rest) = match line.find (' ') {
+
+            Some (i) => (&line[..i], &line[i..]),
+            None => (line, "")
+        };
+
+        loop {
+
+            rest = rest.trim_left ();
+
+            let equals = match rest.find ('=') {
+
+                Some (i) => i,
+                None => break
+            };
+
+            let key = rest[..equals].trim ().to_string ();
+            rest = &rest[equals + 1..];
+
+            let value = if rest.starts_with ('"') {
+
+                let end = rest[1..].find ('"').map (|i| i + 1).unwrap_or (rest.len ());
+                let value = rest[1..end].to_string ();
+
+                rest = if end < rest.len () {&rest[end + 1..]} else {""};
+                value
+            }
+
+            else {
+
+                let end = rest.find (' ').unwrap_or (rest.len ());
+                let value = rest[..end].to_string ();
+
+                rest = &rest[end..];
+                value
+            };
+
+            attributes.insert (key, value);
+        }
+
+        (tag.to_string (), attributes)
+    }
+}
@@ -0,0 +1,58 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Vec2, Vec4};
+
+/*===============================================================================================*/
+/*------GLYPH STRUCT-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The metrics and atlas region of a single character in a font.
+///
+/// All measurements are in pixels, at the size the font was created with.
+#[derive (Copy, Clone, Default)]
+pub struct Glyph {
+
+    // Public
+    /// The region of the atlas page holding the glyph, in texture coordinates.  
+    /// `x` and `y` are the top left corner, and `z` and `w` the bottom right corner.
+    pub region: Vec4,
+    /// The size of the glyph image.
+    pub size: Vec2,
+    /// The offset from the pen position at the top of the line, to the top left of the glyph image.
+    pub offset: Vec2,
+    /// How far the pen moves after the glyph.
+    pub advance: f32,
+    /// The atlas page holding the glyph.
+    pub page: usize,
+}
+
+/*===============================================================================================*/
+/*------GLYPH PUBLIC STATIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Glyph {
+
+    /// Returns a new glyph with no image, which only advances the pen.
+    ///
+    /// This is used for whitespace.
+    pub fn new (advance: f32) -> Glyph {
+
+        Glyph {advance: advance,
+               .. Glyph::default ()}
+    }
+}
@@ -0,0 +1,35 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! The text module.
+//!
+//! Contains fonts, glyph atlas packing and text layout.  
+//! Laid out text is drawn through the sprite batcher.
+/*===============================================================================================*/
+
+mod atlas_packer;
+mod font;
+mod glyph;
+mod text_layout;
+mod text_style;
+
+pub use self::atlas_packer::AtlasPacker;
+pub use self::font::Font;
+pub use self::glyph::Glyph;
+pub use self::text_layout::{LayoutGlyph, TextLayout};
+pub use self::text_style::{TextAlign, TextStyle};
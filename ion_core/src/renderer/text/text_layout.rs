@@ -0,0 +1,232 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::text::{Font, TextAlign, TextStyle};
+use ::util::math::{Vec2, Vec4};
+
+/*===============================================================================================*/
+/*------LAYOUT GLYPH STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A glyph placed by a `TextLayout`.
+#[derive (Copy, Clone)]
+pub struct LayoutGlyph {
+
+    // Public
+    /// The character the glyph represents.
+    pub character: char,
+    /// The top left corner of the glyph, relative to the top left of the text.  
+    /// The y axis points down.
+    pub position: Vec2,
+    /// The scaled size of the glyph.
+    pub size: Vec2,
+    /// The region of the atlas page holding the glyph, in texture coordinates.
+    pub region: Vec4,
+    /// The atlas page holding the glyph.
+    pub page: usize,
+}
+
+/*===============================================================================================*/
+/*------TEXT LAYOUT STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The position of every glyph in a block of text.
+///
+/// Text is split into lines at newlines, and wrapped between words when a line would  
+/// be wider than the maximum width of the style. Words wider than the maximum width are  
+/// placed on their own line. Kerning is applied between each pair of characters.
+///
+/// Layout only needs the font metrics, so it works without a render backend.
+///
+/// # Examples
+/// ```
+/// # use ion_core::renderer::text::{Font, Glyph, TextAlign, TextLayout, TextStyle};
+/// # use ion_core::util::math::Vec2;
+/// #
+/// // A monospaced font, where every glyph is 10 pixels wide
+/// let mut font = Font::new (20.0, 20.0, 16.0);
+///
+/// for c in "abc ".chars () {
+///
+///     let mut glyph = Glyph::new (10.0);
+///     glyph.size = Vec2 {x: 10.0, y: 16.0};
+///     font.insert_glyph (c, glyph);
+/// }
+///
+/// let mut style = TextStyle::new ();
+/// style.max_width = 45.0;
+/// style.align = TextAlign::Right;
+///
+/// let layout = TextLayout::new (&font, "ab abc", &style);
+///
+/// assert_eq! (layout.line_count, 2);
+/// assert_eq! (layout.size.x, 45.0);
+/// assert_eq! (layout.size.y, 40.0);
+///
+/// // The first line is right aligned, and the second line starts below it
+/// assert_eq! (layout.glyphs[0].position.x, 25.0);
+/// assert_eq! (layout.glyphs[2].position.y, 20.0);
+/// ```
+#[derive (Clone)]
+pub struct TextLayout {
+
+    // Public
+    /// The placed glyphs, in the order of the text.  
+    /// Characters without an image, such as spaces, are not included.
+    pub glyphs: Vec<LayoutGlyph>,
+    /// The size of the text block.
+    pub size: Vec2,
+    /// The number of lines.
+    pub line_count: usize,
+}
+
+/*===============================================================================================*/
+/*------TEXT LAYOUT PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl TextLayout {
+
+    /// Lays out text with a font and style.
+    pub fn new (font: &Font, text: &str, style: &TextStyle) -> TextLayout {
+
+        let lines = TextLayout::_break_lines (font, text, style);
+        let line_advance = font.get_line_height () * style.scale * style.line_spacing;
+
+        let widths: Vec<f32> = lines.iter ().map (|l| TextLayout::measure_line (font, l, style.scale)).collect ();
+        let widest = widths.iter ().fold (0.0, |a: f32, &b| a.max (b));
+
+        let align_width = if style.max_width > 0.0 {style.max_width} else {widest};
+        let mut glyphs = Vec::new ();
+
+        for (i, line) in lines.iter ().enumerate () {
+
+            let mut x = match style.align {
+
+                TextAlign::Left   => 0.0,
+                TextAlign::Centre => (align_width - widths[i]) * 0.5,
+                TextAlign::Right  => align_width - widths[i],
+            };
+
+            let y = i as f32 * line_advance;
+            let mut previous = None;
+
+            for c in line.chars () {
+
+                if let Some (p) = previous {
+                    x += font.get_kerning (p, c) * style.scale;
+                }
+
+                if let Some (glyph) = font.get_glyph (c) {
+
+                    if glyph.size.x > 0.0 && glyph.size.y > 0.0 {
+
+                        glyphs.push (LayoutGlyph {character: c,
+                                                  position: Vec2 {x: x + glyph.offset.x * style.scale,
+                                                                  y: y + glyph.offset.y * style.scale},
+                                                  size: Vec2 {x: glyph.size.x * style.scale,
+                                                              y: glyph.size.y * style.scale},
+                                                  region: glyph.region,
+                                                  page: glyph.page});
+                    }
+
+                    x += glyph.advance * style.scale;
+                }
+
+                previous = Some (c);
+            }
+        }
+
+        let height = match lines.len () {
+
+            0 => 0.0,
+            n => (n - 1) as f32 * line_advance + font.get_line_height () * style.scale
+        };
+
+        TextLayout {glyphs: glyphs,
+                    size: Vec2 {x: align_width, y: height},
+                    line_count: lines.len ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the width of a single line of text, without wrapping.
+    pub fn measure_line (font: &Font, line: &str, scale: f32) -> f32 {
+
+        let mut width = 0.0;
+        let mut previous = None;
+
+        for c in line.chars () {
+
+            if let Some (p) = previous {
+                width += font.get_kerning (p, c);
+            }
+
+            if let Some (glyph) = font.get_glyph (c) {
+                width += glyph.advance;
+            }
+
+            previous = Some (c);
+        }
+
+        width * scale
+    }
+}
+
+/*===============================================================================================*/
+/*------TEXT LAYOUT PRIVATE STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl TextLayout {
+
+    // Splits text into lines at newlines, and wraps them to the maximum width.
+    fn _break_lines (font: &Font, text: &str, style: &TextStyle) -> Vec<String> {
+
+        let mut lines = Vec::new ();
+
+        for paragraph in text.split ('\n') {
+
+            let paragraph = paragraph.trim_right_matches ('\r');
+
+            if style.max_width <= 0.0 {
+
+                lines.push (paragraph.to_string ());
+                continue;
+            }
+
+            let mut line = String::new ();
+
+            for word in paragraph.split (' ') {
+
+                let candidate = if line.is_empty () {word.to_string ()} else {format! ("{} {}", line, word)};
+
+                if !line.is_empty () && TextLayout::measure_line (font, &candidate, style.scale) > style.max_width {
+
+                    lines.push (line);
+                    line = word.to_string ();
+                }
+
+                else {
+                    line = candidate;
+                }
+            }
+
+            lines.push (line);
+        }
+
+        lines
+    }
+}
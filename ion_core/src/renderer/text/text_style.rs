@@ -0,0 +1,86 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::Colour;
+
+/*===============================================================================================*/
+/*------TEXT ALIGN ENUM--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The horizontal alignment of each line of text.
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub enum TextAlign {
+
+    /// Lines start at the left edge.
+    Left,
+    /// Lines are centred.
+    Centre,
+    /// Lines end at the right edge.
+    Right,
+}
+
+/*===============================================================================================*/
+/*------TEXT STYLE STRUCT------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Controls how text is laid out and drawn.
+#[derive (Copy, Clone)]
+pub struct TextStyle {
+
+    // Public
+    /// The scale applied to the font size.
+    pub scale: f32,
+    /// The width lines are wrapped at, in scaled pixels.  
+    /// Lines are not wrapped if this is zero.
+    pub max_width: f32,
+    /// The alignment of each line.  
+    /// Lines are aligned within `max_width` if it is set, or else within the widest line.
+    pub align: TextAlign,
+    /// The multiplier applied to the line height of the font.
+    pub line_spacing: f32,
+    /// The colour of the text.
+    pub colour: Colour,
+    /// The z-order of the sprites the text is drawn with.
+    pub z_order: i32,
+}
+
+/*===============================================================================================*/
+/*------TEXT STYLE PUBLIC STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl TextStyle {
+
+    /// Returns a new style, drawing white, unwrapped, left aligned text at the font size.
+    pub fn new () -> TextStyle {
+
+        TextStyle {scale: 1.0,
+                   max_width: 0.0,
+                   align: TextAlign::Left,
+                   line_spacing: 1.0,
+                   colour: Colour::new (),
+                   z_order: 0}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for TextStyle {
+
+    fn default () -> TextStyle {
+        TextStyle::new ()
+    }
+}
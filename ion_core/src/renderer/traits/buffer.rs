@@ -0,0 +1,49 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::{BufferType, BufferUsage};
+use ::util::traits::AsAny;
+
+/*===============================================================================================*/
+/*------BUFFER TRAIT-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A block of GPU memory holding vertex or index data.
+///
+/// Buffers are created by the `RenderFactory`.
+pub trait Buffer: AsAny {
+
+    /// Returns the buffer type.
+    fn get_buffer_type (&self) -> BufferType;
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the buffer usage.
+    fn get_buffer_usage (&self) -> BufferUsage;
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the size of the buffer in bytes.
+    fn get_size (&self) -> usize;
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Updates the contents of the buffer, starting at `offset` bytes.
+    ///
+    /// Data that would be written past the end of the buffer is ignored.
+    fn set_data (&self, offset: usize, data: &[u8]);
+}
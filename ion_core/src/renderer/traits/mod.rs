@@ -21,8 +21,18 @@
 /*===============================================================================================*/
 
 pub mod _detail;
+mod buffer;
+mod pipeline;
 mod render_factory;
+mod render_target;
+mod shader;
+mod texture;
 mod window_backend;
 
+pub use self::buffer::Buffer;
+pub use self::pipeline::Pipeline;
 pub use self::render_factory::RenderFactory;
+pub use self::render_target::RenderTarget;
+pub use self::shader::Shader;
+pub use self::texture::Texture;
 pub use self::window_backend::WindowBackend;
@@ -0,0 +1,37 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::{PrimitiveType, VertexLayout};
+use ::util::traits::AsAny;
+
+/*===============================================================================================*/
+/*------PIPELINE TRAIT---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A linked set of shaders, along with the vertex layout and primitive type they draw.
+///
+/// Pipelines are created by the `RenderFactory`.
+pub trait Pipeline: AsAny {
+
+    /// Returns the vertex layout.
+    fn get_vertex_layout (&self) -> &VertexLayout;
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the primitive type.
+    fn get_primitive_type (&self) -> PrimitiveType;
+}
@@ -14,18 +14,91 @@
 // limitations under the License.
 /*===============================================================================================*/
 
-use ::renderer::traits::WindowBackend;
+use ::renderer::command::CommandBuffer;
+use ::renderer::gpu::{BufferDesc, BuiltinShader, PipelineDesc, RenderTargetDesc, ShaderStage, TextureDesc};
+use ::renderer::traits::{Buffer, Pipeline, RenderTarget, Shader, Texture, WindowBackend};
 use ::renderer::traits::_detail::WindowBackendDefault;
+use ::util::image::Image;
+
+use std::rc::Rc;
 
 /*===============================================================================================*/
 /*------RENDER FACTORY TRAIT---------------------------------------------------------------------*/
 /*===============================================================================================*/
 
-/// Used for accessing the functionality with renderer plugins.
+/// The entry point to a render backend.
+///
+/// Render plugins return an implementation of this trait from `get_factory`.
+/// It creates the window backend and GPU resources, and executes recorded command buffers.
+///
+/// The default implementation can not create any resources, and ignores all commands.
 pub trait RenderFactory {
 
     /// Gets a new instance of the Window Backend.
     fn get_window_backend (&self) -> Box<WindowBackend> {
         Box::new (WindowBackendDefault)
     }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates a new buffer, filled with `data`.
+    ///
+    /// If `data` is smaller than the buffer, the remainder is left uninitialized.
+    fn create_buffer (&self, _desc: &BufferDesc, _data: &[u8]) -> Result<Rc<Buffer>, ()> {
+        Err (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates a new texture, filled with `data`.
+    ///
+    /// If `data` is empty, the texture contents are left uninitialized.
+    fn create_texture (&self, _desc: &TextureDesc, _data: &[u8]) -> Result<Rc<Texture>, ()> {
+        Err (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Compiles a new shader from source.
+    fn create_shader (&self, _stage: ShaderStage, _source: &str) -> Result<Rc<Shader>, ()> {
+        Err (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Compiles one stage of a shader provided by the backend.
+    fn create_builtin_shader (&self, _shader: BuiltinShader, _stage: ShaderStage) -> Result<Rc<Shader>, ()> {
+        Err (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates a new pipeline.
+    fn create_pipeline (&self, _desc: &PipelineDesc) -> Result<Rc<Pipeline>, ()> {
+        Err (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates a new render target.
+    fn create_render_target (&self, _desc: &RenderTargetDesc) -> Result<Rc<RenderTarget>, ()> {
+        Err (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Reads back a region of a render target as an `RGBA8` image, with the rows ordered top to bottom.
+    ///
+    /// The region origin is the bottom left corner. A target of None reads the default target,  
+    /// which must be read before the frame is presented.
+    fn read_pixels (&self, _target: Option<Rc<RenderTarget>>, _x: i32, _y: i32, _width: u32, _height: u32) -> Result<Image, ()> {
+        Err (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Executes the commands in a command buffer, in order.
+    fn execute_command_buffer (&self, _command_buffer: &CommandBuffer) {
+
+    }
 }
@@ -0,0 +1,44 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::traits::Texture;
+use ::util::traits::AsAny;
+
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------RENDER TARGET TRAIT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An offscreen surface that can be rendered into, and then sampled as a texture.
+///
+/// Render targets are created by the `RenderFactory`.
+pub trait RenderTarget: AsAny {
+
+    /// Returns the target width in pixels.
+    fn get_width (&self) -> u32;
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the target height in pixels.
+    fn get_height (&self) -> u32;
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour attachment.
+    fn get_colour_texture (&self) -> Rc<Texture>;
+}
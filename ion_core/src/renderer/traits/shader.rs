@@ -0,0 +1,32 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::ShaderStage;
+use ::util::traits::AsAny;
+
+/*===============================================================================================*/
+/*------SHADER TRAIT-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A compiled shader program for a single pipeline stage.
+///
+/// Shaders are created by the `RenderFactory`, and combined into a `Pipeline`.
+pub trait Shader: AsAny {
+
+    /// Returns the stage the shader runs in.
+    fn get_shader_stage (&self) -> ShaderStage;
+}
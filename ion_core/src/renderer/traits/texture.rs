@@ -0,0 +1,49 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::TextureFormat;
+use ::util::traits::AsAny;
+
+/*===============================================================================================*/
+/*------TEXTURE TRAIT----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A 2D image stored on the GPU.
+///
+/// Textures are created by the `RenderFactory`.
+pub trait Texture: AsAny {
+
+    /// Returns the texture width in pixels.
+    fn get_width (&self) -> u32;
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the texture height in pixels.
+    fn get_height (&self) -> u32;
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the texture format.
+    fn get_format (&self) -> TextureFormat;
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Replaces the contents of the texture.
+    ///
+    /// The data must be tightly packed, in the texture format.
+    fn set_data (&self, data: &[u8]);
+}
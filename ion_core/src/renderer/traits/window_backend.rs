@@ -14,6 +14,7 @@
 // limitations under the License.
 /*===============================================================================================*/
 
+use ::renderer::ContextConfig;
 use ::renderer::window::WindowConfig;
 use ::util::traits::AsAny;
 use ::util::math::Vec2;
@@ -30,8 +31,8 @@ use std::os::raw::c_void;
 /// Window Manager via a `get_window` function.
 pub trait WindowBackend: AsAny {
 
-    /// Initializes the window.
-    fn init (&mut self, _config: &WindowConfig) {
+    /// Initializes the window, and creates the graphics context.
+    fn init (&mut self, _config: &WindowConfig, _context_config: &ContextConfig) {
 
         warn! ("The default window backend is currently being used.\n\
                 While the application will continue running, it may not behave as expected.");
@@ -72,6 +73,22 @@ pub trait WindowBackend: AsAny {
 
     }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Presents the rendered frame to the window.
+    fn present (&mut self) {
+
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the size of the drawable area in pixels.
+    ///
+    /// It can differ from the window size on high DPI displays.
+    fn get_drawable_size (&self) -> Result<Vec2, ()> {
+        Err (())
+    }
+
 /*-----------------------------------------------------------------------------------------------*/
 
     /// Get a raw pointer to the platform display.
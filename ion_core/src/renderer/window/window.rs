@@ -15,11 +15,15 @@
 /*===============================================================================================*/
 
 use ::engine::App;
+use ::renderer::ContextConfig;
 use ::renderer::traits::WindowBackend;
 use ::renderer::traits::_detail::WindowBackendDefault;
 use ::renderer::window::WindowConfig;
 use ::util::math::Vec2;
 
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
 /*===============================================================================================*/
 /*------WINDOW STRUCT----------------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -29,6 +33,8 @@ pub struct Window {
 
     // Private
     _window_backend: Option<Box<WindowBackend>>,
+    _size: Rc<Cell<Vec2>>,
+    _resize_listeners: Rc<RefCell<Vec<Box<Fn (Vec2)>>>>,
 }
 
 /*===============================================================================================*/
@@ -38,11 +44,12 @@ pub struct Window {
 impl Window {
     
     /// Initializes the window.
-    pub fn init (&mut self, config: &WindowConfig) {
+    pub fn init (&mut self, config: &WindowConfig, context_config: &ContextConfig) {
 
         info! ("Creating the window.");
+        self._size.set (config.window_size);
         self._register_callbacks ();
-        self._window_backend.as_mut ().unwrap ().init (config);
+        self._window_backend.as_mut ().unwrap ().init (config, context_config);
     }
 
 /*-----------------------------------------------------------------------------------------------*/
@@ -52,6 +59,35 @@ impl Window {
         self._window_backend = Some (backend);
     }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the size of the window.
+    pub fn get_size (&self) -> Vec2 {
+        self._size.get ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the size of the drawable area of the window in pixels.
+    ///
+    /// It can be larger than the window size on high DPI displays.  
+    /// Falls back to the window size if the backend can't report it.
+    pub fn get_drawable_size (&self) -> Vec2 {
+
+        match self._window_backend {
+
+            Some (ref backend) => backend.get_drawable_size ().unwrap_or (self.get_size ()),
+            None => self.get_size ()
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Adds a listener, called with the new size when the window is resized.
+    pub fn add_resize_listener (&mut self, listener: Box<Fn (Vec2)>) {
+        self._resize_listeners.borrow_mut ().push (listener);
+    }
+
 /*-----------------------------------------------------------------------------------------------*/
 
     /// Processes window events.
@@ -59,6 +95,13 @@ impl Window {
         self._window_backend.as_mut ().unwrap ().process_events ();
     }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Presents the rendered frame.
+    pub fn present (&mut self) {
+        self._window_backend.as_mut ().unwrap ().present ();
+    }
+
 /*-----------------------------------------------------------------------------------------------*/
 
     /// Releases the window.
@@ -76,7 +119,9 @@ impl Window {
     pub fn new () -> Window {
 
         Window {
-            _window_backend: Some (Box::new (WindowBackendDefault))
+            _window_backend: Some (Box::new (WindowBackendDefault)),
+            _size: Rc::new (Cell::new (Vec2::new ())),
+            _resize_listeners: Rc::new (RefCell::new (Vec::new ())),
         }
     }
 }
@@ -99,11 +144,23 @@ impl Window {
     // Registers callbacks.
     fn _register_callbacks (&mut self) {
 
+        let size      = self._size.clone ();
+        let listeners = self._resize_listeners.clone ();
+
         let window_backend = self._window_backend.as_mut ().unwrap ();
 
         window_backend.set_create_callback (Box::new (Window::_callback_window_create));
         window_backend.set_move_callback   (Box::new (Window::_callback_window_move));
-        window_backend.set_resize_callback (Box::new (Window::_callback_window_resized));
+        window_backend.set_resize_callback (Box::new (move |new_size| {
+
+            size.set (new_size);
+
+            for listener in listeners.borrow ().iter () {
+                listener (new_size);
+            }
+
+            Window::_callback_window_resized (new_size);
+        }));
         window_backend.set_close_callback  (Box::new (Window::_callback_window_closed));
     }
 
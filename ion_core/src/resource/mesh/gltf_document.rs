@@ -0,0 +1,159 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use std::collections::BTreeMap;
+
+/*===============================================================================================*/
+/*------GLTF DOCUMENT STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The subset of a glTF 2.0 document needed to import meshes.
+#[derive (Deserialize)]
+pub struct GltfDocument {
+
+    // Public
+    /// The buffers of the document.
+    #[serde (default)]
+    pub buffers: Vec<GltfBuffer>,
+    /// The buffer views of the document.
+    #[serde (default, rename = "bufferViews")]
+    pub buffer_views: Vec<GltfBufferView>,
+    /// The accessors of the document.
+    #[serde (default)]
+    pub accessors: Vec<GltfAccessor>,
+    /// The meshes of the document.
+    #[serde (default)]
+    pub meshes: Vec<GltfMesh>,
+    /// The materials of the document.
+    #[serde (default)]
+    pub materials: Vec<GltfMaterial>,
+}
+
+/*===============================================================================================*/
+/*------GLTF BUFFER STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A glTF buffer.
+#[derive (Deserialize)]
+pub struct GltfBuffer {
+
+    // Public
+    /// The location of the buffer data. Absent for the binary chunk of a GLB file.
+    pub uri: Option<String>,
+    /// The length of the buffer, in bytes.
+    #[serde (rename = "byteLength")]
+    pub byte_length: usize,
+}
+
+/*===============================================================================================*/
+/*------GLTF BUFFER VIEW STRUCT------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A glTF buffer view.
+#[derive (Copy, Clone, Deserialize)]
+pub struct GltfBufferView {
+
+    // Public
+    /// The index of the buffer.
+    pub buffer: usize,
+    /// The offset into the buffer, in bytes.
+    #[serde (default, rename = "byteOffset")]
+    pub byte_offset: usize,
+    /// The length of the view, in bytes.
+    #[serde (rename = "byteLength")]
+    pub byte_length: usize,
+    /// The stride between elements, in bytes. Absent if the elements are tightly packed.
+    #[serde (rename = "byteStride")]
+    pub byte_stride: Option<usize>,
+}
+
+/*===============================================================================================*/
+/*------GLTF ACCESSOR STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A glTF accessor.
+#[derive (Deserialize)]
+pub struct GltfAccessor {
+
+    // Public
+    /// The index of the buffer view.
+    #[serde (rename = "bufferView")]
+    pub buffer_view: Option<usize>,
+    /// The offset into the buffer view, in bytes.
+    #[serde (default, rename = "byteOffset")]
+    pub byte_offset: usize,
+    /// The data type of the components.
+    #[serde (rename = "componentType")]
+    pub component_type: u32,
+    /// Are integer components normalized to the [0, 1] or [-1, 1] range?
+    #[serde (default)]
+    pub normalized: bool,
+    /// The number of elements.
+    pub count: usize,
+    /// The element type ("SCALAR", "VEC2", "VEC3", "VEC4", etc).
+    #[serde (rename = "type")]
+    pub accessor_type: String,
+}
+
+/*===============================================================================================*/
+/*------GLTF MESH STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A glTF mesh.
+#[derive (Deserialize)]
+pub struct GltfMesh {
+
+    // Public
+    /// The name of the mesh.
+    #[serde (default)]
+    pub name: String,
+    /// The primitives of the mesh.
+    pub primitives: Vec<GltfPrimitive>,
+}
+
+/*===============================================================================================*/
+/*------GLTF PRIMITIVE STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A glTF mesh primitive.
+#[derive (Deserialize)]
+pub struct GltfPrimitive {
+
+    // Public
+    /// The accessor index of each vertex attribute.
+    pub attributes: BTreeMap<String, usize>,
+    /// The accessor index of the indices.
+    pub indices: Option<usize>,
+    /// The index of the material.
+    pub material: Option<usize>,
+    /// The primitive topology. Triangles if absent.
+    pub mode: Option<u32>,
+}
+
+/*===============================================================================================*/
+/*------GLTF MATERIAL STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A glTF material.
+#[derive (Deserialize)]
+pub struct GltfMaterial {
+
+    // Public
+    /// The name of the material.
+    #[serde (default)]
+    pub name: String,
+}
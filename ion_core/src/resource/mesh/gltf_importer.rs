@@ -0,0 +1,687 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::mesh::{Mesh, SubMesh, Vertex};
+use ::resource::mesh::MeshImporter;
+use ::resource::mesh::gltf_document::GltfDocument;
+use ::util::math::{Colour, Vec2, Vec3, Vec4};
+use ::util::serialization::Deserializer;
+
+use std::fs::File;
+use std::io::Read;
+use std::mem;
+use std::path::Path;
+use std::str;
+
+// The file extensions handled by the importer.
+static GLTF_EXTENSIONS: [&'static str; 2] = ["gltf", "glb"];
+
+// GLB container constants.
+const GLB_MAGIC:      u32 = 0x46546C67;
+const GLB_CHUNK_JSON: u32 = 0x4E4F534A;
+const GLB_CHUNK_BIN:  u32 = 0x004E4942;
+
+// Accessor component types.
+const COMPONENT_BYTE:           u32 = 5120;
+const COMPONENT_UNSIGNED_BYTE:  u32 = 5121;
+const COMPONENT_SHORT:          u32 = 5122;
+const COMPONENT_UNSIGNED_SHORT: u32 = 5123;
+const COMPONENT_UNSIGNED_INT:   u32 = 5125;
+const COMPONENT_FLOAT:          u32 = 5126;
+
+// The triangle list primitive mode.
+const MODE_TRIANGLES: u32 = 4;
+
+/*===============================================================================================*/
+/*------GLTF IMPORTER STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Imports glTF 2.0 files.
+///
+/// Both JSON (`.gltf`) and binary (`.glb`) files are supported. Buffers can be embedded as base64  
+/// data URIs, stored in external files, or stored in the binary chunk of a GLB file.
+///
+/// Every triangle primitive of every mesh in the file is imported as a sub mesh.  
+/// Node transforms, sparse accessors and non-triangle primitives are not supported.
+#[derive (Copy, Clone)]
+pub struct GltfImporter;
+
+/*===============================================================================================*/
+/*------GLTF IMPORTER PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl MeshImporter for GltfImporter {
+
+    fn get_extensions (&self) -> &[&str] {
+        &GLTF_EXTENSIONS
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Imports a glTF file.
+    ///
+    /// Normals are generated for the primitives which lack them, and tangents are generated
+    /// if any primitive lacks them. Attributes with the wrong type, component type
+    /// or element count fail the import.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::resource::{GltfImporter, MeshImporter};
+    /// # use std::path::Path;
+    /// #
+    /// // A single triangle, with the positions embedded as base64
+    /// let gltf = r#"{
+    ///     "buffers": [{"byteLength": 36, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAA"}],
+    ///     "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    ///     "accessors": [{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3"}],
+    ///     "meshes": [{"name": "triangle", "primitives": [{"attributes": {"POSITION": 0}}]}]
+    /// }"#;
+    ///
+    /// let mesh = GltfImporter.import (gltf.as_bytes (), Path::new ("")).unwrap ();
+    ///
+    /// assert_eq! (mesh.vertices.len (), 3);
+    /// assert_eq! (mesh.indices, vec! [0, 1, 2]);
+    /// assert_eq! (mesh.sub_meshes[0].name, "triangle");
+    /// assert_eq! (mesh.vertices[0].normal.z, 1.0);
+    ///
+    /// // Colours must have three or four components
+    /// let gltf = gltf.replace (r#""type": "VEC3"}]"#, r#""type": "VEC3"}, {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2"}]"#)
+    ///                .replace (r#""POSITION": 0"#, r#""POSITION": 0, "COLOR_0": 1"#);
+    ///
+    /// assert! (GltfImporter.import (gltf.as_bytes (), Path::new ("")).is_err ());
+    /// ```
+    fn import (&self, data: &[u8], base_dir: &Path) -> Result<Mesh, ()> {
+
+        // Split binary files into the JSON and binary chunks
+        let (json, binary_chunk) = if data.starts_with (b"glTF") {
+            try! (GltfImporter::_parse_glb (data))
+        }
+
+        else {
+            (data, None)
+        };
+
+        let json = match str::from_utf8 (json) {
+
+            Ok (json) => json,
+            Err (e) => {
+
+                error! ("glTF document is not valid UTF-8.\n{}", e);
+                return Err (());
+            }
+        };
+
+        let document = match Deserializer::from_string::<GltfDocument> (json) {
+
+            Ok (document) => document,
+            Err (e) => {
+
+                error! ("glTF document could not be parsed.\n{}", e);
+                return Err (());
+            }
+        };
+
+        let buffers = try! (GltfImporter::_load_buffers (&document, binary_chunk, base_dir));
+
+        let mut mesh = Mesh::new ();
+        let mut is_missing_normal = Vec::new ();
+        let mut is_missing_tangent = Vec::new ();
+
+        for gltf_mesh in &document.meshes {
+
+            for (primitive_index, primitive) in gltf_mesh.primitives.iter ().enumerate () {
+
+                if primitive.mode.unwrap_or (MODE_TRIANGLES) != MODE_TRIANGLES {
+
+                    warn! ("Skipping non-triangle primitive in glTF mesh \"{}\".", gltf_mesh.name);
+                    continue;
+                }
+
+                let position_accessor = match primitive.attributes.get ("POSITION") {
+
+                    Some (&accessor) => accessor,
+                    None => {
+
+                        warn! ("Skipping glTF primitive without positions in mesh \"{}\".", gltf_mesh.name);
+                        continue;
+                    }
+                };
+
+                // Read the vertex attributes
+                let first_vertex = mesh.vertices.len ();
+                let (positions, _) = try! (GltfImporter::_read_attribute (&document, &buffers, position_accessor,
+                                                                          "POSITION", &[3], None));
+
+                for position in positions.chunks (3) {
+                    mesh.vertices.push (Vertex::new (&Vec3 {x: position[0], y: position[1], z: position[2]}));
+                }
+
+                let vertex_count = mesh.vertices.len () - first_vertex;
+
+                {
+                    let vertices = &mut mesh.vertices[first_vertex..];
+
+                    if let Some (&accessor) = primitive.attributes.get ("NORMAL") {
+
+                        let (normals, _) = try! (GltfImporter::_read_attribute (&document, &buffers, accessor,
+                                                                                "NORMAL", &[3], Some (vertex_count)));
+
+                        for (vertex, normal) in vertices.iter_mut ().zip (normals.chunks (3)) {
+                            vertex.normal = Vec3 {x: normal[0], y: normal[1], z: normal[2]};
+                        }
+
+                        is_missing_normal.extend ((0..vertex_count).map (|_| false));
+                    }
+
+                    else {
+                        is_missing_normal.extend ((0..vertex_count).map (|_| true));
+                    }
+
+                    if let Some (&accessor) = primitive.attributes.get ("TEXCOORD_0") {
+
+                        let (uvs, _) = try! (GltfImporter::_read_attribute (&document, &buffers, accessor,
+                                                                            "TEXCOORD_0", &[2], Some (vertex_count)));
+
+                        for (vertex, uv) in vertices.iter_mut ().zip (uvs.chunks (2)) {
+                            vertex.uv = Vec2 {x: uv[0], y: uv[1]};
+                        }
+                    }
+
+                    if let Some (&accessor) = primitive.attributes.get ("TANGENT") {
+
+                        let (tangents, _) = try! (GltfImporter::_read_attribute (&document, &buffers, accessor,
+                                                                                 "TANGENT", &[4], Some (vertex_count)));
+
+                        for (vertex, tangent) in vertices.iter_mut ().zip (tangents.chunks (4)) {
+                            vertex.tangent = Vec4 {x: tangent[0], y: tangent[1], z: tangent[2], w: tangent[3]};
+                        }
+
+                        is_missing_tangent.extend ((0..vertex_count).map (|_| false));
+                    }
+
+                    else {
+                        is_missing_tangent.extend ((0..vertex_count).map (|_| true));
+                    }
+
+                    if let Some (&accessor) = primitive.attributes.get ("COLOR_0") {
+
+                        let (colours, components) = try! (GltfImporter::_read_attribute (&document, &buffers, accessor,
+                                                                                          "COLOR_0", &[3, 4], Some (vertex_count)));
+
+                        for (vertex, colour) in vertices.iter_mut ().zip (colours.chunks (components)) {
+
+                            vertex.colour = Colour {r: colour[0],
+                                                    g: colour[1],
+                                                    b: colour[2],
+                                                    a: if components > 3 {colour[3]} else {1.0}};
+                        }
+                    }
+                }
+
+                // Read the indices, or generate them for non-indexed primitives
+                let first_index = mesh.indices.len ();
+
+                match primitive.indices {
+
+                    Some (accessor) => {
+
+                        let indices = try! (GltfImporter::_read_indices (&document, &buffers, accessor));
+
+                        if indices.iter ().any (|&index| index as usize >= vertex_count) {
+
+                            error! ("glTF mesh \"{}\" has an out of range index.", gltf_mesh.name);
+                            return Err (());
+                        }
+
+                        mesh.indices.extend (indices.iter ().map (|&index| index + first_vertex as u32));
+                    },
+
+                    None => mesh.indices.extend ((0..vertex_count).map (|index| (index + first_vertex) as u32))
+                }
+
+                // Create the sub mesh
+                let name = if gltf_mesh.primitives.len () > 1 {format! ("{}_{}", gltf_mesh.name, primitive_index)}
+                           else {gltf_mesh.name.clone ()};
+
+                let mut sub_mesh = SubMesh::new (&name, first_index as u32);
+                sub_mesh.index_count = (mesh.indices.len () - first_index) as u32;
+
+                if let Some (material) = primitive.material.and_then (|m| document.materials.get (m)) {
+                    sub_mesh.material = material.name.clone ();
+                }
+
+                mesh.sub_meshes.push (sub_mesh);
+            }
+        }
+
+        if mesh.vertices.is_empty () {
+
+            error! ("glTF document contains no triangle meshes.");
+            return Err (());
+        }
+
+        // Only the primitives without normals have them generated
+        if is_missing_normal.iter ().any (|is_missing| *is_missing) {
+            mesh.generate_missing_normals (&is_missing_normal);
+        }
+
+        // Likewise for the primitives without tangents
+        if is_missing_tangent.iter ().any (|is_missing| *is_missing) {
+            mesh.generate_missing_tangents (&is_missing_tangent);
+        }
+
+        mesh.recalculate_bounds ();
+        Ok (mesh)
+    }
+}
+
+/*===============================================================================================*/
+/*------GLTF IMPORTER PRIVATE STATIC METHODS-----------------------------------------------------*/
+/*===============================================================================================*/
+
+impl GltfImporter {
+
+    // Splits a GLB file into its JSON and binary chunks.
+    fn _parse_glb (data: &[u8]) -> Result<(&[u8], Option<&[u8]>), ()> {
+
+        if data.len () < 12 || GltfImporter::_read_u32 (data, 0) != GLB_MAGIC || GltfImporter::_read_u32 (data, 4) != 2 {
+
+            error! ("Invalid GLB header.");
+            return Err (());
+        }
+
+        let mut json   = None;
+        let mut binary = None;
+        let mut offset = 12;
+
+        while offset + 8 <= data.len () {
+
+            let chunk_length = GltfImporter::_read_u32 (data, offset) as usize;
+            let chunk_type   = GltfImporter::_read_u32 (data, offset + 4);
+            let chunk_start  = offset + 8;
+
+            if chunk_start + chunk_length > data.len () {
+
+                error! ("GLB chunk exceeds the length of the file.");
+                return Err (());
+            }
+
+            let chunk = &data[chunk_start..chunk_start + chunk_length];
+
+            match chunk_type {
+
+                GLB_CHUNK_JSON if json.is_none ()   => json   = Some (chunk),
+                GLB_CHUNK_BIN  if binary.is_none () => binary = Some (chunk),
+                _ => {}
+            }
+
+            offset = chunk_start + chunk_length;
+        }
+
+        match json {
+
+            Some (json) => Ok ((json, binary)),
+            None => {
+
+                error! ("GLB file has no JSON chunk.");
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Loads the data of every buffer in the document.
+    fn _load_buffers (document: &GltfDocument, binary_chunk: Option<&[u8]>, base_dir: &Path) -> Result<Vec<Vec<u8>>, ()> {
+
+        let mut buffers = Vec::with_capacity (document.buffers.len ());
+
+        for buffer in &document.buffers {
+
+            let data = match buffer.uri {
+
+                // Stored in the binary chunk of a GLB file
+                None => match binary_chunk {
+
+                    Some (chunk) => chunk.to_vec (),
+                    None => {
+
+                        error! ("glTF buffer has no URI, and there is no binary chunk.");
+                        return Err (());
+                    }
+                },
+
+                // Embedded as a base64 data URI
+                Some (ref uri) if uri.starts_with ("data:") => {
+
+                    match uri.find (',') {
+
+                        Some (start) => try! (GltfImporter::_decode_base64 (&uri[start + 1..])),
+                        None => {
+
+                            error! ("Invalid glTF data URI.");
+                            return Err (());
+                        }
+                    }
+                },
+
+                // Stored in an external file
+                Some (ref uri) => {
+
+                    let path = base_dir.join (uri);
+                    let mut data = Vec::new ();
+
+                    match File::open (&path).and_then (|mut file| file.read_to_end (&mut data)) {
+
+                        Ok (_) => data,
+                        Err (e) => {
+
+                            error! ("glTF buffer \"{}\" could not be read.\n{}", path.display (), e);
+                            return Err (());
+                        }
+                    }
+                }
+            };
+
+            if data.len () < buffer.byte_length {
+
+                error! ("glTF buffer is shorter than its declared length.");
+                return Err (());
+            }
+
+            buffers.push (data);
+        }
+
+        Ok (buffers)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the bytes, stride and component layout of an accessor.
+    fn _get_accessor_data<'a> (document: &GltfDocument,
+                               buffers: &'a [Vec<u8>],
+                               accessor_index: usize) -> Result<(&'a [u8], usize, usize, usize, u32), ()> {
+
+        let accessor = match document.accessors.get (accessor_index) {
+
+            Some (accessor) => accessor,
+            None => {
+
+                error! ("Invalid glTF accessor index {}.", accessor_index);
+                return Err (());
+            }
+        };
+
+        let view = match accessor.buffer_view.and_then (|v| document.buffer_views.get (v)) {
+
+            Some (view) => *view,
+            None => {
+
+                error! ("glTF accessor {} has no valid buffer view.", accessor_index);
+                return Err (());
+            }
+        };
+
+        let component_count = match &*accessor.accessor_type {
+
+            "SCALAR" => 1,
+            "VEC2"   => 2,
+            "VEC3"   => 3,
+            "VEC4"   => 4,
+            _ => {
+
+                error! ("Unsupported glTF accessor type \"{}\".", accessor.accessor_type);
+                return Err (());
+            }
+        };
+
+        let component_size = match accessor.component_type {
+
+            COMPONENT_BYTE  | COMPONENT_UNSIGNED_BYTE  => 1,
+            COMPONENT_SHORT | COMPONENT_UNSIGNED_SHORT => 2,
+            COMPONENT_UNSIGNED_INT | COMPONENT_FLOAT   => 4,
+            _ => {
+
+                error! ("Unsupported glTF component type {}.", accessor.component_type);
+                return Err (());
+            }
+        };
+
+        let buffer = match buffers.get (view.buffer) {
+
+            Some (buffer) => buffer,
+            None => {
+
+                error! ("Invalid glTF buffer index {}.", view.buffer);
+                return Err (());
+            }
+        };
+
+        let element_size = component_count * component_size;
+        let stride = view.byte_stride.unwrap_or (element_size);
+        let start  = view.byte_offset + accessor.byte_offset;
+        let end    = view.byte_offset + view.byte_length;
+
+        // Make sure every element lies within the buffer view
+        if end > buffer.len () || start > end ||
+           (accessor.count > 0 && start + (accessor.count - 1) * stride + element_size > end) {
+
+            error! ("glTF accessor {} exceeds the bounds of its buffer view.", accessor_index);
+            return Err (());
+        }
+
+        Ok ((&buffer[start..end], accessor.count, stride, component_count, accessor.component_type))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a vertex attribute accessor as floats, checking its type and element count.
+    // Returns the values, and the number of components per element.
+    fn _read_attribute (document: &GltfDocument,
+                        buffers: &[Vec<u8>],
+                        accessor_index: usize,
+                        name: &str,
+                        component_counts: &[usize],
+                        vertex_count: Option<usize>) -> Result<(Vec<f32>, usize), ()> {
+
+        let (values, component_count) = try! (GltfImporter::_read_floats (document, buffers, accessor_index));
+        let accessor = &document.accessors[accessor_index];
+
+        if !component_counts.contains (&component_count) {
+
+            error! ("glTF {} accessor {} has the wrong type \"{}\".", name, accessor_index, accessor.accessor_type);
+            return Err (());
+        }
+
+        // Texture coordinates and colours may also be normalized integers
+        let is_valid_component_type = match accessor.component_type {
+
+            COMPONENT_FLOAT => true,
+            COMPONENT_UNSIGNED_BYTE | COMPONENT_UNSIGNED_SHORT => {
+                accessor.normalized && (name == "TEXCOORD_0" || name == "COLOR_0")
+            },
+            _ => false
+        };
+
+        if !is_valid_component_type {
+
+            error! ("glTF {} accessor {} has an unsupported component type {}.", name, accessor_index, accessor.component_type);
+            return Err (());
+        }
+
+        if let Some (vertex_count) = vertex_count {
+
+            if accessor.count != vertex_count {
+
+                error! ("glTF {} accessor {} has {} elements, but the primitive has {} vertices.",
+                        name, accessor_index, accessor.count, vertex_count);
+                return Err (());
+            }
+        }
+
+        Ok ((values, component_count))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads an accessor as floats. Returns the values, and the number of components per element.
+    fn _read_floats (document: &GltfDocument, buffers: &[Vec<u8>], accessor_index: usize) -> Result<(Vec<f32>, usize), ()> {
+
+        let (data, count, stride, component_count, component_type) =
+            try! (GltfImporter::_get_accessor_data (document, buffers, accessor_index));
+
+        let normalized = document.accessors[accessor_index].normalized;
+        let mut values = Vec::with_capacity (count * component_count);
+
+        for element in 0..count {
+
+            for component in 0..component_count {
+
+                let value = match component_type {
+
+                    COMPONENT_FLOAT => {
+
+                        let offset = element * stride + component * 4;
+                        unsafe {mem::transmute::<u32, f32> (GltfImporter::_read_u32 (data, offset))}
+                    },
+
+                    COMPONENT_BYTE => {
+
+                        let value = data[element * stride + component] as i8 as f32;
+                        if normalized {(value / 127.0).max (-1.0)} else {value}
+                    },
+
+                    COMPONENT_UNSIGNED_BYTE => {
+
+                        let value = data[element * stride + component] as f32;
+                        if normalized {value / 255.0} else {value}
+                    },
+
+                    COMPONENT_SHORT => {
+
+                        let value = GltfImporter::_read_u16 (data, element * stride + component * 2) as i16 as f32;
+                        if normalized {(value / 32767.0).max (-1.0)} else {value}
+                    },
+
+                    COMPONENT_UNSIGNED_SHORT => {
+
+                        let value = GltfImporter::_read_u16 (data, element * stride + component * 2) as f32;
+                        if normalized {value / 65535.0} else {value}
+                    },
+
+                    _ => GltfImporter::_read_u32 (data, element * stride + component * 4) as f32
+                };
+
+                values.push (value);
+            }
+        }
+
+        Ok ((values, component_count))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a scalar accessor as indices.
+    fn _read_indices (document: &GltfDocument, buffers: &[Vec<u8>], accessor_index: usize) -> Result<Vec<u32>, ()> {
+
+        let (data, count, stride, component_count, component_type) =
+            try! (GltfImporter::_get_accessor_data (document, buffers, accessor_index));
+
+        if component_count != 1 {
+
+            error! ("glTF index accessor {} is not a scalar.", accessor_index);
+            return Err (());
+        }
+
+        let mut indices = Vec::with_capacity (count);
+
+        for element in 0..count {
+
+            let offset = element * stride;
+
+            indices.push (match component_type {
+
+                COMPONENT_UNSIGNED_BYTE  => data[offset] as u32,
+                COMPONENT_UNSIGNED_SHORT => GltfImporter::_read_u16 (data, offset) as u32,
+                COMPONENT_UNSIGNED_INT   => GltfImporter::_read_u32 (data, offset),
+                _ => {
+
+                    error! ("Invalid component type for glTF index accessor {}.", accessor_index);
+                    return Err (());
+                }
+            });
+        }
+
+        Ok (indices)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Decodes base64 data, ignoring padding and whitespace.
+    fn _decode_base64 (input: &str) -> Result<Vec<u8>, ()> {
+
+        let mut output = Vec::with_capacity (input.len () * 3 / 4);
+        let mut buffer = 0u32;
+        let mut bits   = 0;
+
+        for byte in input.bytes () {
+
+            let value = match byte {
+
+                b'A'...b'Z' => byte - b'A',
+                b'a'...b'z' => byte - b'a' + 26,
+                b'0'...b'9' => byte - b'0' + 52,
+                b'+' | b'-' => 62,
+                b'/' | b'_' => 63,
+                b'=' | b' ' | b'\r' | b'\n' | b'\t' => continue,
+                _ => {
+
+                    error! ("Invalid character in base64 data.");
+                    return Err (());
+                }
+            };
+
+            buffer = (buffer << 6) | value as u32;
+            bits  += 6;
+
+            if bits >= 8 {
+
+                bits -= 8;
+                output.push ((buffer >> bits) as u8);
+                buffer &= (1 << bits) - 1;
+            }
+        }
+
+        Ok (output)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a little endian u16.
+    fn _read_u16 (data: &[u8], offset: usize) -> u16 {
+        data[offset] as u16 | (data[offset + 1] as u16) << 8
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a little endian u32.
+    fn _read_u32 (data: &[u8], offset: usize) -> u32 {
+
+        data[offset] as u32 | (data[offset + 1] as u32) << 8 |
+        (data[offset + 2] as u32) << 16 | (data[offset + 3] as u32) << 24
+    }
+}
@@ -0,0 +1,42 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::mesh::Mesh;
+
+use std::path::Path;
+
+/*===============================================================================================*/
+/*------MESH IMPORTER TRAIT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Converts a mesh file format into a `Mesh`.
+///
+/// Importers are registered with the mesh loader, and selected by file extension.
+pub trait MeshImporter {
+
+    /// Returns the file extensions handled by the importer, in lowercase and without a dot.
+    fn get_extensions (&self) -> &[&str];
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Imports a mesh from the contents of a file.
+    ///
+    /// # Arguments
+    /// * `data` - The contents of the file.
+    /// * `base_dir` - The directory of the file. Used to resolve external references.
+    fn import (&self, data: &[u8], base_dir: &Path) -> Result<Mesh, ()>;
+}
@@ -0,0 +1,119 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::mesh::Mesh;
+use ::resource::mesh::{GltfImporter, MeshImporter, ObjImporter};
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/*===============================================================================================*/
+/*------MESH LOADER STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Allows the loading of mesh files.
+///
+/// Wavefront OBJ and glTF 2.0 files are supported by default.  
+/// Additional formats can be supported by registering a `MeshImporter`.
+pub struct MeshLoader {
+
+    // Private
+    _importers: Vec<Box<MeshImporter>>,
+}
+
+/*===============================================================================================*/
+/*------MESH LOADER PUBLIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl MeshLoader {
+
+    /// Registers a mesh importer.
+    ///
+    /// Importers registered later take priority over earlier ones for the same extension.
+    pub fn register_importer (&mut self, importer: Box<MeshImporter>) {
+        self._importers.push (importer);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads the mesh file at a given path.
+    pub fn load_mesh (&self, mesh_path: &str) -> Result<Mesh, ()> {
+
+        info! ("Loading mesh \"{}\".", mesh_path);
+
+        let path = Path::new (mesh_path);
+        let extension = path.extension ().and_then (|e| e.to_str ()).unwrap_or ("").to_lowercase ();
+
+        // Find an importer for the file extension
+        let importer = match self._importers.iter ().rev ().find (|i| i.get_extensions ().contains (&&*extension)) {
+
+            Some (importer) => importer,
+            None => {
+
+                error! ("No importer is registered for mesh file \"{}\".", mesh_path);
+                return Err (());
+            }
+        };
+
+        // Read the file contents
+        let mut data = Vec::new ();
+
+        match File::open (path).and_then (|mut file| file.read_to_end (&mut data)) {
+
+            Ok (_) => {},
+            Err (e) => {
+
+                error! ("Mesh file \"{}\" could not be read.\n{}", mesh_path, e);
+                return Err (());
+            }
+        }
+
+        let base_dir = path.parent ().unwrap_or (Path::new (""));
+
+        match importer.import (&data, base_dir) {
+
+            Ok (mesh) => Ok (mesh),
+            Err (_) => {
+
+                error! ("Mesh file \"{}\" could not be imported.", mesh_path);
+                Err (())
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------MESH LOADER PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new mesh loader, with the built in importers registered.
+    pub fn new () -> MeshLoader {
+
+        MeshLoader {
+            _importers: vec! [Box::new (ObjImporter) as Box<MeshImporter>, Box::new (GltfImporter)]
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for MeshLoader {
+
+    fn default () -> MeshLoader {
+        MeshLoader::new ()
+    }
+}
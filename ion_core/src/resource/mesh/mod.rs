@@ -0,0 +1,33 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+/*===============================================================================================*/
+//! The mesh module.
+//!
+//! Contains the mesh loader, and the built in mesh importers.
+/*===============================================================================================*/
+
+// Modules
+mod gltf_document;
+mod gltf_importer;
+mod mesh_importer;
+mod mesh_loader;
+mod obj_importer;
+
+pub use self::gltf_importer::GltfImporter;
+pub use self::mesh_importer::MeshImporter;
+pub use self::mesh_loader::MeshLoader;
+pub use self::obj_importer::ObjImporter;
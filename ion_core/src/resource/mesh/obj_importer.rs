@@ -0,0 +1,328 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::mesh::{Mesh, SubMesh, Vertex};
+use ::resource::mesh::MeshImporter;
+use ::util::math::{Colour, Vec2, Vec3};
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::str;
+
+// The file extensions handled by the importer.
+static OBJ_EXTENSIONS: [&'static str; 1] = ["obj"];
+
+/*===============================================================================================*/
+/*------OBJ IMPORTER STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Imports Wavefront OBJ files.
+///
+/// Polygons are triangulated as fans. Objects, groups and material changes start a new sub mesh.  
+/// Normals are generated for the faces which lack them, and tangents are always generated.
+#[derive (Copy, Clone)]
+pub struct ObjImporter;
+
+/*===============================================================================================*/
+/*------OBJ IMPORTER PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl MeshImporter for ObjImporter {
+
+    fn get_extensions (&self) -> &[&str] {
+        &OBJ_EXTENSIONS
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Imports an OBJ file.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::resource::{MeshImporter, ObjImporter};
+    /// # use std::path::Path;
+    /// #
+    /// let obj = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvt 0 0\nvt 1 0\nvt 1 1\nvt 0 1\nf 1/1 2/2 3/3 4/4\n";
+    /// let mesh = ObjImporter.import (obj.as_bytes (), Path::new ("")).unwrap ();
+    ///
+    /// assert_eq! (mesh.vertices.len (), 4);
+    /// assert_eq! (mesh.get_triangle_count (), 2);
+    /// assert_eq! (mesh.vertices[0].normal.z, 1.0);
+    /// assert_eq! (mesh.vertices[0].tangent.x, 1.0);
+    ///
+    /// // Faces with normals keep them, even when other faces lack them
+    /// let obj = "v 0 0 0\nv 1 0 0\nv 1 1 0\nvn 1 0 0\nf 1//1 2//1 3//1\nf 1 2 3\n";
+    /// let mesh = ObjImporter.import (obj.as_bytes (), Path::new ("")).unwrap ();
+    ///
+    /// assert_eq! (mesh.vertices[0].normal.x, 1.0);
+    /// assert_eq! (mesh.vertices[3].normal.z, 1.0);
+    /// ```
+    fn import (&self, data: &[u8], _base_dir: &Path) -> Result<Mesh, ()> {
+
+        let source = match str::from_utf8 (data) {
+
+            Ok (source) => source,
+            Err (e) => {
+
+                error! ("OBJ file is not valid UTF-8.\n{}", e);
+                return Err (());
+            }
+        };
+
+        let mut positions: Vec<Vec3> = Vec::new ();
+        let mut colours: Vec<Colour> = Vec::new ();
+        let mut uvs: Vec<Vec2> = Vec::new ();
+        let mut normals: Vec<Vec3> = Vec::new ();
+
+        let mut mesh = Mesh::new ();
+        let mut vertex_map: HashMap<(usize, Option<usize>, Option<usize>), u32> = HashMap::new ();
+        let mut is_missing_normal = Vec::new ();
+
+        mesh.sub_meshes.push (SubMesh::new ("default", 0));
+
+        for (line_number, line) in source.lines ().enumerate () {
+
+            let mut tokens = line.split_whitespace ();
+
+            let keyword = match tokens.next () {
+                Some (keyword) => keyword,
+                None => continue
+            };
+
+            let values: Vec<&str> = tokens.collect ();
+
+            match keyword {
+
+                // Vertex position, with optional vertex colour
+                "v" => {
+
+                    let floats = try! (ObjImporter::_parse_floats (&values, 3, line_number));
+                    positions.push (Vec3 {x: floats[0], y: floats[1], z: floats[2]});
+
+                    if floats.len () >= 6 {
+                        colours.push (Colour {r: floats[3], g: floats[4], b: floats[5], a: 1.0});
+                    }
+
+                    else {
+                        colours.push (Colour::new ());
+                    }
+                },
+
+                // Texture coordinate
+                "vt" => {
+
+                    let floats = try! (ObjImporter::_parse_floats (&values, 2, line_number));
+                    uvs.push (Vec2 {x: floats[0], y: floats[1]});
+                },
+
+                // Vertex normal
+                "vn" => {
+
+                    let floats = try! (ObjImporter::_parse_floats (&values, 3, line_number));
+                    normals.push (Vec3 {x: floats[0], y: floats[1], z: floats[2]});
+                },
+
+                // Face
+                "f" => {
+
+                    if values.len () < 3 {
+
+                        error! ("OBJ face on line {} has less than three vertices.", line_number + 1);
+                        return Err (());
+                    }
+
+                    let mut face = Vec::with_capacity (values.len ());
+
+                    for value in &values {
+
+                        let key = try! (ObjImporter::_parse_face_vertex (value,
+                                                                         positions.len (),
+                                                                         uvs.len (),
+                                                                         normals.len (),
+                                                                         line_number));
+
+                        let index = match vertex_map.get (&key) {
+                            Some (&index) => index,
+                            None => {
+
+                                let mut vertex = Vertex::new (&positions[key.0]);
+                                vertex.colour = colours[key.0];
+
+                                if let Some (uv) = key.1 {
+                                    vertex.uv = uvs[uv];
+                                }
+
+                                if let Some (normal) = key.2 {
+                                    vertex.normal = normals[normal];
+                                }
+
+                                mesh.vertices.push (vertex);
+                                is_missing_normal.push (key.2.is_none ());
+
+                                (mesh.vertices.len () - 1) as u32
+                            }
+                        };
+
+                        vertex_map.insert (key, index);
+                        face.push (index);
+                    }
+
+                    // Triangulate the polygon as a fan
+                    for i in 1..face.len () - 1 {
+                        mesh.indices.extend_from_slice (&[face[0], face[i], face[i + 1]]);
+                    }
+                },
+
+                // Object or group
+                "o" | "g" => {
+
+                    let name = values.join (" ");
+                    ObjImporter::_begin_sub_mesh (&mut mesh, &name);
+                },
+
+                // Material
+                "usemtl" => {
+
+                    let name = mesh.sub_meshes.last ().unwrap ().name.clone ();
+
+                    if mesh.sub_meshes.last ().unwrap ().first_index as usize != mesh.indices.len () {
+                        ObjImporter::_begin_sub_mesh (&mut mesh, &name);
+                    }
+
+                    mesh.sub_meshes.last_mut ().unwrap ().material = values.join (" ");
+                },
+
+                _ => {}
+            }
+        }
+
+        // Close the last sub mesh, and remove any that are empty
+        ObjImporter::_end_sub_mesh (&mut mesh);
+        mesh.sub_meshes.retain (|sub_mesh| sub_mesh.index_count > 0);
+
+        // Only the faces without normals have them generated
+        if is_missing_normal.iter ().any (|is_missing| *is_missing) {
+            mesh.generate_missing_normals (&is_missing_normal);
+        }
+
+        mesh.generate_tangents ();
+        mesh.recalculate_bounds ();
+
+        Ok (mesh)
+    }
+}
+
+/*===============================================================================================*/
+/*------OBJ IMPORTER PRIVATE STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ObjImporter {
+
+    // Parses at least a number of floats from a list of values.
+    fn _parse_floats (values: &[&str], count: usize, line_number: usize) -> Result<Vec<f32>, ()> {
+
+        let floats: Result<Vec<f32>, _> = values.iter ().map (|v| v.parse::<f32> ()).collect ();
+
+        match floats {
+
+            Ok (ref floats) if floats.len () >= count => Ok (floats.clone ()),
+            _ => {
+
+                error! ("Invalid OBJ data on line {}.", line_number + 1);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Parses a face vertex in the form "v", "v/vt", "v//vn" or "v/vt/vn".
+    fn _parse_face_vertex (value: &str,
+                           position_count: usize,
+                           uv_count: usize,
+                           normal_count: usize,
+                           line_number: usize) -> Result<(usize, Option<usize>, Option<usize>), ()> {
+
+        let mut parts = value.split ('/');
+
+        let position = try! (ObjImporter::_resolve_index (parts.next (), position_count, line_number));
+        let uv       = try! (ObjImporter::_resolve_index (parts.next (), uv_count,       line_number));
+        let normal   = try! (ObjImporter::_resolve_index (parts.next (), normal_count,   line_number));
+
+        match position {
+
+            Some (position) => Ok ((position, uv, normal)),
+            None => {
+
+                error! ("OBJ face on line {} is missing a vertex position.", line_number + 1);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Converts a one based, possibly negative index into a zero based index.
+    fn _resolve_index (value: Option<&str>, count: usize, line_number: usize) -> Result<Option<usize>, ()> {
+
+        let value = match value {
+            Some (value) if !value.is_empty () => value,
+            _ => return Ok (None)
+        };
+
+        let index = match value.parse::<i64> () {
+            Ok (index) if index > 0 => index - 1,
+            Ok (index) if index < 0 => count as i64 + index,
+            _ => -1
+        };
+
+        if index < 0 || index >= count as i64 {
+
+            error! ("Invalid OBJ index \"{}\" on line {}.", value, line_number + 1);
+            return Err (());
+        }
+
+        Ok (Some (index as usize))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Closes the current sub mesh, and starts a new one.
+    fn _begin_sub_mesh (mesh: &mut Mesh, name: &str) {
+
+        ObjImporter::_end_sub_mesh (mesh);
+
+        let first_index = mesh.indices.len () as u32;
+        let material    = mesh.sub_meshes.last ().unwrap ().material.clone ();
+
+        let mut sub_mesh = SubMesh::new (name, first_index);
+        sub_mesh.material = material;
+
+        mesh.sub_meshes.push (sub_mesh);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Sets the index count of the current sub mesh.
+    fn _end_sub_mesh (mesh: &mut Mesh) {
+
+        let index_count = mesh.indices.len () as u32;
+        let sub_mesh = mesh.sub_meshes.last_mut ().unwrap ();
+
+        sub_mesh.index_count = index_count - sub_mesh.first_index;
+    }
+}
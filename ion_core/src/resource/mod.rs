@@ -33,9 +33,13 @@
 
 // Modules
 mod config;
+mod mesh;
 mod plugin;
 mod resource_manager;
 
 pub use self::resource_manager::ResourceManager;
+pub use self::mesh::{GltfImporter, MeshImporter, MeshLoader, ObjImporter};
 pub use self::plugin::PluginInfo;
+pub use self::plugin::PluginLoader;
+pub use self::plugin::PluginReport;
 pub use self::plugin::PluginType;
@@ -24,9 +24,11 @@
 mod plugin_config;
 mod plugin_loader;
 mod plugin_info;
+mod plugin_report;
 mod plugin_enum;
 
 pub use self::plugin_config::PluginConfig;
 pub use self::plugin_loader::PluginLoader;
 pub use self::plugin_info::PluginInfo;
+pub use self::plugin_report::PluginReport;
 pub use self::plugin_enum::PluginType;
@@ -16,9 +16,11 @@
 
 extern crate libloading;
 
+use ::audio::AudioBackend;
 use ::engine::App;
 use ::resource::ResourceManager;
 use ::resource::plugin::PluginConfig;
+use ::resource::plugin::PluginReport;
 use ::resource::{PluginInfo, PluginType};
 use ::renderer::traits::RenderFactory;
 use ::util::Directory;
@@ -27,6 +29,16 @@ use self::libloading::{Library, Symbol};
 
 use std::path::Path;
 
+/*===============================================================================================*/
+/*------STATIC VARIABLES-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The entry points a plugin can export to the engine.
+static PLUGIN_SYMBOLS: [&'static str; 3] = ["get_plugin_info", "get_factory", "get_audio_backend"];
+
+// The entry points that register a plugin type, one of which every plugin needs.
+static PLUGIN_TYPE_SYMBOLS: [&'static str; 1] = ["get_factory"];
+
 /*===============================================================================================*/
 /*------PLUGIN LOADER STRUCT---------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -81,36 +93,32 @@ impl PluginLoader {
     /// Registers a plugin with the plugin loader.
     pub fn register_plugin (&self, plugin_path: &str) -> Option<Library> {
 
-        // Check if plugin exists
-        if !Path::new (plugin_path).exists () {
-
-            warn! ("Plugin \"{}\" does not exist.", plugin_path);
-            return None;
-        }
+        // Open the library
+        let lib = match PluginLoader::_open_library (plugin_path) {
 
-        // Get the plugin type
-        let plug_type = match self.get_plugin_info (plugin_path) {
+            Ok (lib) => lib,
+            Err (e) => {
 
-            Ok (info) => info.plug_type,
-            Err (_) => return None
+                warn! ("Could not load plugin \"{}\".\n{}", plugin_path, e);
+                return None;
+            }
         };
 
-        // Open the library
-        match Library::new (plugin_path) {
-
-            Ok (lib) => {
+        // Get the plugin type
+        let plug_type = match PluginLoader::_read_plugin_info (&lib) {
 
-                // From the plugin type, call the correct function
-                match plug_type {
+            Ok (info) => info.plug_type,
+            Err (e) => {
 
-                    PluginType::RenderBackend => return self._load_plugin_render_backend (plugin_path, lib),
-                }
+                warn! ("Could not load plugin \"{}\".\n{}", plugin_path, e);
+                return None;
             }
-
-            Err (e) => warn! ("Could not load plugin \"{}\".\n{}", plugin_path, e)
         };
 
-        None
+        // From the plugin type, call the correct function
+        match plug_type {
+            PluginType::RenderBackend => self._load_plugin_render_backend (plugin_path, lib),
+        }
     }
 
 /*-----------------------------------------------------------------------------------------------*/
@@ -118,40 +126,85 @@ impl PluginLoader {
     /// Retrieves information on a plugin.
     pub fn get_plugin_info (&self, plugin_path: &str) -> Result<PluginInfo, ()> {
 
-        // Check if plugin exists
-        if !Path::new (plugin_path).exists () {
+        let info = PluginLoader::_open_library (plugin_path)
+            .and_then (|lib| PluginLoader::_read_plugin_info (&lib));
 
-            warn! ("Plugin \"{}\" does not exist.", plugin_path);
-            return Err (());
-        }
+        info.map_err (|e| warn! ("Could not load plugin \"{}\".\n{}", plugin_path, e))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Inspects a plugin without registering it.
+    ///
+    /// Nothing is logged. Any problem that would stop the plugin from loading
+    /// is stored in the returned report instead.
+    pub fn inspect_plugin (&self, plugin_path: &str) -> PluginReport {
+
+        let mut report = PluginReport::new (plugin_path);
 
         // Open the library
-        match Library::new (plugin_path) {
+        let lib = match PluginLoader::_open_library (plugin_path) {
 
-            Ok (lib) => {
+            Ok (lib) => lib,
+            Err (e) => {
 
-                // Get the get info function
-                let get_plugin_info: Symbol<unsafe extern fn () -> PluginInfo> = unsafe {
+                report.plug_errors.push (e);
+                return report;
+            }
+        };
 
-                    match lib.get (b"get_plugin_info\0") {
+        // Check which of the entry points are exported
+        for symbol in PLUGIN_SYMBOLS.iter () {
 
-                        Ok (f) => f,
-                        Err (e) => {
+            let symbol_name = format! ("{}\0", symbol);
 
-                            warn! ("Could not find function \"get_plugin_info\" in plugin \"{}\".\n{}", plugin_path, e);
-                            return Err (());
-                        }
-                    }
-                };
+            if unsafe {lib.get::<unsafe extern fn ()> (symbol_name.as_bytes ()).is_ok ()} {
+                report.plug_symbols.push (symbol.to_string ());
+            }
+        }
+
+        // Get the plugin info
+        match PluginLoader::_read_plugin_info (&lib) {
+
+            Ok (info) => report.plug_info = Some (info),
+            Err (e) => report.plug_errors.push (e)
+        }
+
+        // Check the entry points required by the plugin type. Without the plugin info the type
+        // is unknown, so the plugin is only expected to export one of them.
+        let required: Vec<&str> = match report.plug_info {
+
+            Some (ref info) => match info.plug_type {
+                PluginType::RenderBackend => vec! ["get_factory"],
+            },
+
+            None => {
 
-                // Call the get info function
-                return Ok (unsafe {get_plugin_info ()});
+                if PLUGIN_TYPE_SYMBOLS.iter ().any (|s| report.plug_symbols.iter ().any (|r| r == s)) {
+                    Vec::new ()
+                }
+
+                else {
+                    PLUGIN_TYPE_SYMBOLS.to_vec ()
+                }
             }
+        };
+
+        for symbol in required {
 
-            Err (e) => warn! ("Could not load plugin \"{}\".\n{}", plugin_path, e)
+            if !report.plug_symbols.iter ().any (|s| s == symbol) {
+                report.plug_errors.push (format! ("Missing function \"{}\".", symbol));
+            }
         }
 
-        Err (())
+        report
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the plugin file extension for the current platform.
+    pub fn get_plugin_extension (&self) -> &str {
+        &self._plug_ext
     }
 
 /*===============================================================================================*/
@@ -210,8 +263,55 @@ impl PluginLoader {
             render_mgr.borrow_mut ().register_plugin (unsafe {get_factory ()});
         }
 
+        // The audio backend is optional
+        {
+            let get_audio_backend: Result<Symbol<unsafe extern fn () -> Box<AudioBackend>>, _> = unsafe {
+                lib.get (b"get_audio_backend\0")
+            };
+
+            if let Ok (get_audio_backend) = get_audio_backend {
+
+                let audio_mgr = App::get_instance ().unwrap ().audio_mgr.clone ();
+                audio_mgr.borrow_mut ().set_backend (unsafe {get_audio_backend ()});
+
+                info! ("Registered audio backend from plugin \"{}\"", plugin_path);
+            }
+        }
+
         // Call the register plugin and add library to list
         info! ("Registered plugin \"{}\"", plugin_path);
         Some (lib)
     }
+
+/*===============================================================================================*/
+/*------PLUGIN LOADER PRIVATE STATIC METHODS-----------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Opens a plugin library, describing why it couldn't be opened on failure.
+    fn _open_library (plugin_path: &str) -> Result<Library, String> {
+
+        // Check if plugin exists
+        if !Path::new (plugin_path).exists () {
+            return Err ("File does not exist.".to_string ());
+        }
+
+        Library::new (plugin_path).map_err (|e| format! ("Could not open library.\n{}", e))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Calls the get info function of an open plugin library.
+    fn _read_plugin_info (lib: &Library) -> Result<PluginInfo, String> {
+
+        let get_plugin_info: Symbol<unsafe extern fn () -> PluginInfo> = unsafe {
+
+            match lib.get (b"get_plugin_info\0") {
+
+                Ok (f) => f,
+                Err (_) => return Err ("Missing function \"get_plugin_info\".".to_string ())
+            }
+        };
+
+        Ok (unsafe {get_plugin_info ()})
+    }
 }
@@ -0,0 +1,61 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+use ::resource::PluginInfo;
+
+/*===============================================================================================*/
+/*------PLUGIN REPORT STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Stores the result of inspecting a plugin library.
+#[derive (Debug)]
+pub struct PluginReport {
+
+    // Public
+    /// The path to the plugin library.
+    pub plug_path: String,
+    /// The plugin information, if it could be retrieved.
+    pub plug_info: Option<PluginInfo>,
+    /// The engine entry points exported by the plugin.
+    pub plug_symbols: Vec<String>,
+    /// Any problems found while inspecting the plugin.
+    pub plug_errors: Vec<String>,
+}
+
+/*===============================================================================================*/
+/*------PLUGIN REPORT PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PluginReport {
+
+    /// Returns whether the plugin can be loaded by the engine.
+    pub fn is_valid (&self) -> bool {
+        self.plug_info.is_some () && self.plug_errors.is_empty ()
+    }
+
+/*===============================================================================================*/
+/*------PLUGIN REPORT PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty plugin report.
+    pub fn new (plugin_path: &str) -> PluginReport {
+
+        PluginReport {plug_path: plugin_path.to_string (),
+                      plug_info: None,
+                      plug_symbols: Vec::new (),
+                      plug_errors: Vec::new ()}
+    }
+}
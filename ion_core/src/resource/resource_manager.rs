@@ -16,9 +16,15 @@
 
 extern crate serde;
 
+use ::audio::{AudioClip, AudioStream};
+use ::renderer::material::Material;
+use ::renderer::mesh::Mesh;
+use ::renderer::text::Font;
 use ::resource::config::ConfigLoader;
+use ::resource::mesh::{MeshImporter, MeshLoader};
 use ::resource::plugin::PluginLoader;
 use ::util::Directory;
+use ::util::image::Image;
 
 use self::serde::{Deserialize, Serialize};
 
@@ -34,6 +40,7 @@ pub struct ResourceManager {
 
     // Private
     _config_loader: Rc<RefCell<ConfigLoader>>,
+    _mesh_loader:   Rc<RefCell<MeshLoader>>,
     _plugin_loader: Rc<RefCell<PluginLoader>>,
 }
 
@@ -78,6 +85,71 @@ impl ResourceManager {
         self._config_loader.borrow ().save_config::<T> (p_config_dir, config_name, config_data)
     }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads a mesh file.
+    ///
+    /// The path is relative to the resource directory.
+    pub fn load_mesh (&self, mesh_path: &str) -> Result<Mesh, ()> {
+
+        let mesh_path = &format! ("{}{}", Directory::get_resource_directory (), mesh_path);
+        self._mesh_loader.borrow ().load_mesh (mesh_path)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads an image file.
+    ///
+    /// The path is relative to the resource directory.
+    pub fn load_image (&self, image_path: &str) -> Result<Image, ()> {
+        Image::load (&format! ("{}{}", Directory::get_resource_directory (), image_path))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads a material file.
+    ///
+    /// The path is relative to the resource directory. Textures are not created until  
+    /// `Material::load_textures` is called.
+    pub fn load_material (&self, material_path: &str) -> Result<Material, ()> {
+        Material::load (&format! ("{}{}", Directory::get_resource_directory (), material_path))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads a font file.
+    ///
+    /// The path is relative to the resource directory. TrueType fonts are rasterized  
+    /// at `size` pixels, while BMFont fonts use the size they were generated at.
+    pub fn load_font (&self, font_path: &str, size: f32) -> Result<Font, ()> {
+        Font::load (&format! ("{}{}", Directory::get_resource_directory (), font_path), size)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads an audio clip from a WAV or Ogg Vorbis file.
+    ///
+    /// The path is relative to the resource directory.
+    pub fn load_audio_clip (&self, clip_path: &str) -> Result<AudioClip, ()> {
+        AudioClip::load (&format! ("{}{}", Directory::get_resource_directory (), clip_path))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Opens a WAV or Ogg Vorbis file for streaming.
+    ///
+    /// The path is relative to the resource directory.
+    pub fn open_audio_stream (&self, stream_path: &str) -> Result<AudioStream, ()> {
+        AudioStream::open (&format! ("{}{}", Directory::get_resource_directory (), stream_path))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Registers an importer for an additional mesh format.
+    pub fn register_mesh_importer (&self, importer: Box<MeshImporter>) {
+        self._mesh_loader.borrow_mut ().register_importer (importer);
+    }
+
 /*-----------------------------------------------------------------------------------------------*/
 
     /// Returns a pointer to the config loader instance.
@@ -102,6 +174,7 @@ impl ResourceManager {
         ResourceManager {
 
             _config_loader: Rc::new (RefCell::new (ConfigLoader {})),
+            _mesh_loader:   Rc::new (RefCell::new (MeshLoader::new ())),
             _plugin_loader: Rc::new (RefCell::new (PluginLoader::new ()))
         }
     }
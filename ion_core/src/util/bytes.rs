@@ -0,0 +1,55 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use std::mem;
+use std::slice;
+
+/*===============================================================================================*/
+/*------BYTES STRUCT-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Utilities for working with raw byte data.
+#[derive (Copy, Clone)]
+pub struct Bytes;
+
+/*===============================================================================================*/
+/*------BYTES PUBLIC STATIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Bytes {
+
+    /// Reinterprets a slice of plain data as a slice of bytes.
+    ///
+    /// This is mainly used for uploading vertex and index data to the render backend.
+    ///
+    /// # Safety
+    /// `T` must have no padding bytes, as reading them is undefined behaviour.
+    /// Primitive numbers, and `#[repr (C)]` structs made only of the same primitive type, are safe.  
+    /// This includes structs of the math types, such as vertices, which are all `#[repr (C)]` over `f32`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::Bytes;
+    /// #
+    /// let indices: [u16; 3] = [0, 1, 2];
+    /// assert_eq! (unsafe {Bytes::from_slice (&indices)}.len (), 6);
+    /// ```
+    pub unsafe fn from_slice<T: Copy> (data: &[T]) -> &[u8] {
+
+        slice::from_raw_parts (data.as_ptr () as *const u8, data.len () * mem::size_of::<T> ())
+    }
+}
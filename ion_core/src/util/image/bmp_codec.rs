@@ -0,0 +1,240 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::image::{Image, ImageFormat};
+
+/*===============================================================================================*/
+/*------BMP CODEC STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Decodes BMP files.
+///
+/// Uncompressed and bitfield images with 1, 4, 8, 16, 24 or 32 bits per pixel are supported.  
+/// Images are always decoded to RGBA8.
+#[derive (Copy, Clone)]
+pub struct BmpCodec;
+
+/*===============================================================================================*/
+/*------BMP CODEC PUBLIC STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BmpCodec {
+
+    /// Decodes a BMP file.
+    pub fn decode (data: &[u8]) -> Result<Image, ()> {
+
+        if data.len () < 26 || !data.starts_with (b"BM") {
+
+            error! ("Invalid BMP header.");
+            return Err (());
+        }
+
+        let pixel_offset = BmpCodec::_read_u32 (data, 10) as usize;
+        let header_size  = BmpCodec::_read_u32 (data, 14) as usize;
+
+        if data.len () < 14 + header_size || (header_size != 12 && header_size < 40) {
+
+            error! ("Invalid BMP info header.");
+            return Err (());
+        }
+
+        // Read the info header. Size 12 is the old OS/2 core header
+        let (width, height, bits, compression, colours_used, palette_entry_size) = if header_size == 12 {
+
+            (BmpCodec::_read_u16 (data, 18) as i32,
+             BmpCodec::_read_u16 (data, 20) as i16 as i32,
+             BmpCodec::_read_u16 (data, 24),
+             0,
+             0,
+             3)
+        }
+
+        else {
+
+            (BmpCodec::_read_u32 (data, 18) as i32,
+             BmpCodec::_read_u32 (data, 22) as i32,
+             BmpCodec::_read_u16 (data, 28),
+             BmpCodec::_read_u32 (data, 30),
+             BmpCodec::_read_u32 (data, 46) as usize,
+             4)
+        };
+
+        if width <= 0 || height == 0 {
+
+            error! ("Invalid BMP dimensions {}x{}.", width, height);
+            return Err (());
+        }
+
+        // Get the channel masks. Bitfields follow the header, unless they're part of it
+        let mut masks = match bits {
+            16 => [0x7C00, 0x03E0, 0x001F, 0],
+            _  => [0x00FF0000, 0x0000FF00, 0x000000FF, 0]
+        };
+
+        match compression {
+
+            0 => {},
+            3 | 6 => {
+
+                let mask_offset = if header_size >= 52 {54} else {14 + header_size};
+                let mask_count  = if compression == 6 || header_size >= 56 {4} else {3};
+
+                if mask_offset + mask_count * 4 > data.len () {
+
+                    error! ("BMP bitfields exceed the length of the file.");
+                    return Err (());
+                }
+
+                for i in 0..mask_count {
+                    masks[i] = BmpCodec::_read_u32 (data, mask_offset + i * 4);
+                }
+            },
+
+            _ => {
+
+                error! ("Unsupported BMP compression type {}.", compression);
+                return Err (());
+            }
+        }
+
+        // Read the palette
+        let mut palette = Vec::new ();
+
+        if bits <= 8 {
+
+            let palette_offset = 14 + header_size;
+            let palette_count  = if colours_used > 0 {colours_used} else {1 << bits};
+
+            for i in 0..palette_count {
+
+                let entry = palette_offset + i * palette_entry_size;
+
+                if entry + 3 > data.len () {
+                    break;
+                }
+
+                palette.push ([data[entry + 2], data[entry + 1], data[entry], 255]);
+            }
+        }
+
+        match bits {
+
+            1 | 4 | 8 | 16 | 24 | 32 => {},
+            _ => {
+
+                error! ("Unsupported BMP bit depth {}.", bits);
+                return Err (());
+            }
+        }
+
+        // Read the pixels. Rows are padded to four bytes, and stored bottom to top by default
+        let width      = width as u32;
+        let height_abs = height.abs () as u32;
+        let row_size   = ((width as usize * bits as usize + 31) / 32) * 4;
+
+        if pixel_offset + row_size * height_abs as usize > data.len () {
+
+            error! ("BMP image data is too short.");
+            return Err (());
+        }
+
+        let mut image = Image::new (width, height_abs, ImageFormat::RGBA8);
+
+        for row in 0..height_abs {
+
+            let y = if height < 0 {row} else {height_abs - row - 1};
+            let row_data = &data[pixel_offset + row as usize * row_size..pixel_offset + (row as usize + 1) * row_size];
+
+            for x in 0..width as usize {
+
+                let rgba = match bits {
+
+                    1 | 4 | 8 => {
+
+                        let bit   = x * bits as usize;
+                        let shift = 8 - bits as usize - bit % 8;
+                        let index = (row_data[bit / 8] >> shift) & ((1u16 << bits) - 1) as u8;
+
+                        match palette.get (index as usize) {
+
+                            Some (entry) => *entry,
+                            None => {
+
+                                error! ("BMP palette index is out of range.");
+                                return Err (());
+                            }
+                        }
+                    },
+
+                    24 => [row_data[x * 3 + 2], row_data[x * 3 + 1], row_data[x * 3], 255],
+
+                    _ => {
+
+                        let value = if bits == 16 {BmpCodec::_read_u16 (row_data, x * 2) as u32}
+                                    else {BmpCodec::_read_u32 (row_data, x * 4)};
+
+                        [BmpCodec::_extract (value, masks[0]),
+                         BmpCodec::_extract (value, masks[1]),
+                         BmpCodec::_extract (value, masks[2]),
+                         if masks[3] != 0 {BmpCodec::_extract (value, masks[3])} else {255}]
+                    }
+                };
+
+                let offset = (y as usize * width as usize + x) * 4;
+                image.get_data_mut ()[offset..offset + 4].copy_from_slice (&rgba);
+            }
+        }
+
+        Ok (image)
+    }
+}
+
+/*===============================================================================================*/
+/*------BMP CODEC PRIVATE STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BmpCodec {
+
+    // Extracts a channel using a bit mask, scaled to 8 bits.
+    fn _extract (value: u32, mask: u32) -> u8 {
+
+        if mask == 0 {
+            return 0;
+        }
+
+        let shift = mask.trailing_zeros ();
+        let max   = mask >> shift;
+
+        (((value & mask) >> shift) as u64 * 255 / max as u64) as u8
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a little endian u16.
+    fn _read_u16 (data: &[u8], offset: usize) -> u16 {
+        data[offset] as u16 | (data[offset + 1] as u16) << 8
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a little endian u32.
+    fn _read_u32 (data: &[u8], offset: usize) -> u32 {
+
+        data[offset] as u32 | (data[offset + 1] as u32) << 8 |
+        (data[offset + 2] as u32) << 16 | (data[offset + 3] as u32) << 24
+    }
+}
@@ -0,0 +1,218 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::image::{Image, ImageFormat};
+
+// Pixel format flags.
+const DDPF_ALPHAPIXELS: u32 = 0x00001;
+const DDPF_FOURCC:      u32 = 0x00004;
+const DDPF_RGB:         u32 = 0x00040;
+const DDPF_LUMINANCE:   u32 = 0x20000;
+
+// Four character codes.
+const FOURCC_DX10:      u32 = 0x30315844;
+const FOURCC_RGBA16F:   u32 = 113;
+
+// DXGI formats of the extended header.
+const DXGI_RGBA16F:     u32 = 10;
+const DXGI_RGBA8:       u32 = 28;
+const DXGI_RGBA8_SRGB:  u32 = 29;
+const DXGI_R8:          u32 = 61;
+const DXGI_BGRA8:       u32 = 87;
+const DXGI_BGRA8_SRGB:  u32 = 91;
+
+/*===============================================================================================*/
+/*------DDS CODEC STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Decodes uncompressed DDS files.
+///
+/// Mask based RGB(A) and luminance formats are supported, along with RGBA16F,  
+/// and the matching formats of the DX10 extended header. Only the top mip level is loaded.
+#[derive (Copy, Clone)]
+pub struct DdsCodec;
+
+/*===============================================================================================*/
+/*------DDS CODEC PUBLIC STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl DdsCodec {
+
+    /// Decodes a DDS file.
+    pub fn decode (data: &[u8]) -> Result<Image, ()> {
+
+        if data.len () < 128 || !data.starts_with (b"DDS ") || DdsCodec::_read_u32 (data, 4) != 124 {
+
+            error! ("Invalid DDS header.");
+            return Err (());
+        }
+
+        let height      = DdsCodec::_read_u32 (data, 12);
+        let width       = DdsCodec::_read_u32 (data, 16);
+        let flags       = DdsCodec::_read_u32 (data, 80);
+        let fourcc      = DdsCodec::_read_u32 (data, 84);
+        let bit_count   = DdsCodec::_read_u32 (data, 88);
+        let masks       = [DdsCodec::_read_u32 (data, 92),
+                           DdsCodec::_read_u32 (data, 96),
+                           DdsCodec::_read_u32 (data, 100),
+                           if flags & DDPF_ALPHAPIXELS != 0 {DdsCodec::_read_u32 (data, 104)} else {0}];
+
+        let mut offset = 128;
+
+        // Find the layout of the pixels
+        let layout = if flags & DDPF_FOURCC != 0 {
+
+            match fourcc {
+
+                FOURCC_RGBA16F => DdsLayout::RGBA16F,
+                FOURCC_DX10 if data.len () >= 148 => {
+
+                    offset = 148;
+
+                    match DdsCodec::_read_u32 (data, 128) {
+
+                        DXGI_RGBA16F => DdsLayout::RGBA16F,
+                        DXGI_RGBA8 | DXGI_RGBA8_SRGB => DdsLayout::Masked (32, [0x000000FF, 0x0000FF00, 0x00FF0000, 0xFF000000]),
+                        DXGI_BGRA8 | DXGI_BGRA8_SRGB => DdsLayout::Masked (32, [0x00FF0000, 0x0000FF00, 0x000000FF, 0xFF000000]),
+                        DXGI_R8 => DdsLayout::Luminance,
+                        format => {
+
+                            error! ("Unsupported DXGI format {} in DDS file.", format);
+                            return Err (());
+                        }
+                    }
+                },
+
+                _ => {
+
+                    error! ("Compressed DDS files are not supported.");
+                    return Err (());
+                }
+            }
+        }
+
+        else if flags & DDPF_LUMINANCE != 0 && bit_count == 8 && masks[3] == 0 {
+            DdsLayout::Luminance
+        }
+
+        else if flags & (DDPF_RGB | DDPF_LUMINANCE) != 0 && (bit_count == 16 || bit_count == 24 || bit_count == 32) {
+            DdsLayout::Masked (bit_count, masks)
+        }
+
+        else {
+
+            error! ("Unsupported DDS pixel format.");
+            return Err (());
+        };
+
+        let pixel_size = match layout {
+
+            DdsLayout::Luminance        => 1,
+            DdsLayout::RGBA16F          => 8,
+            DdsLayout::Masked (bits, _) => bits as usize / 8,
+        };
+
+        let size = width as usize * height as usize * pixel_size;
+
+        if offset + size > data.len () {
+
+            error! ("DDS image data is too short.");
+            return Err (());
+        }
+
+        let pixels = &data[offset..offset + size];
+
+        match layout {
+
+            DdsLayout::Luminance => Image::from_data (width, height, ImageFormat::R8, pixels.to_vec ()),
+            DdsLayout::RGBA16F   => Image::from_data (width, height, ImageFormat::RGBA16F, pixels.to_vec ()),
+
+            DdsLayout::Masked (_, masks) => {
+
+                let mut image = Image::new (width, height, ImageFormat::RGBA8);
+
+                // Luminance files store grey in the red mask
+                let is_luminance = flags & DDPF_LUMINANCE != 0;
+
+                for (i, pixel) in pixels.chunks (pixel_size).enumerate () {
+
+                    let mut value = 0u32;
+
+                    for (byte_index, byte) in pixel.iter ().enumerate () {
+                        value |= (*byte as u32) << (byte_index * 8);
+                    }
+
+                    let red = DdsCodec::_extract (value, masks[0]);
+
+                    let rgba = [red,
+                                if is_luminance {red} else {DdsCodec::_extract (value, masks[1])},
+                                if is_luminance {red} else {DdsCodec::_extract (value, masks[2])},
+                                if masks[3] != 0 {DdsCodec::_extract (value, masks[3])} else {255}];
+
+                    image.get_data_mut ()[i * 4..i * 4 + 4].copy_from_slice (&rgba);
+                }
+
+                Ok (image)
+            }
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------DDS CODEC PRIVATE STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl DdsCodec {
+
+    // Extracts a channel using a bit mask, scaled to 8 bits.
+    fn _extract (value: u32, mask: u32) -> u8 {
+
+        if mask == 0 {
+            return 0;
+        }
+
+        let shift = mask.trailing_zeros ();
+        let max   = mask >> shift;
+
+        (((value & mask) >> shift) as u64 * 255 / max as u64) as u8
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a little endian u32.
+    fn _read_u32 (data: &[u8], offset: usize) -> u32 {
+
+        data[offset] as u32 | (data[offset + 1] as u32) << 8 |
+        (data[offset + 2] as u32) << 16 | (data[offset + 3] as u32) << 24
+    }
+}
+
+/*===============================================================================================*/
+/*------DDS LAYOUT ENUM--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// The layout of the pixels in a DDS file.
+#[derive (Copy, Clone)]
+enum DdsLayout {
+
+    // Single 8-bit channel.
+    Luminance,
+    // Four 16-bit floating point channels.
+    RGBA16F,
+    // Channels described by a bit count and RGBA masks.
+    Masked (u32, [u32; 4]),
+}
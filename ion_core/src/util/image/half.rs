@@ -0,0 +1,125 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use std::mem;
+
+/*===============================================================================================*/
+/*------HALF STRUCT------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Conversions between 32-bit floats and 16-bit half floats.
+///
+/// Half floats are stored as their raw `u16` bits.
+#[derive (Copy, Clone)]
+pub struct Half;
+
+/*===============================================================================================*/
+/*------HALF PUBLIC STATIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Half {
+
+    /// Converts a float to the bits of the nearest half float.
+    ///
+    /// Values outside of the half float range become infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::image::Half;
+    /// #
+    /// assert_eq! (Half::from_f32 (1.0), 0x3C00);
+    /// assert_eq! (Half::to_f32 (Half::from_f32 (-0.5)), -0.5);
+    /// ```
+    pub fn from_f32 (value: f32) -> u16 {
+
+        let bits: u32 = unsafe {mem::transmute (value)};
+
+        let sign     = ((bits >> 16) & 0x8000) as u16;
+        let exponent = ((bits >> 23) & 0xFF) as i32;
+        let mantissa = bits & 0x007FFFFF;
+
+        // NaN and infinity
+        if exponent == 0xFF {
+            return sign | 0x7C00 | if mantissa != 0 {0x0200} else {0};
+        }
+
+        let exponent = exponent - 127 + 15;
+
+        // Overflow to infinity
+        if exponent >= 0x1F {
+            return sign | 0x7C00;
+        }
+
+        // Subnormal, or underflow to zero
+        if exponent <= 0 {
+
+            if exponent < -10 {
+                return sign;
+            }
+
+            let mantissa = mantissa | 0x00800000;
+            let shift    = (14 - exponent) as u32;
+            let half     = mantissa >> shift;
+            let round    = (mantissa >> (shift - 1)) & 1;
+
+            return sign | (half + round) as u16;
+        }
+
+        // Normal, rounded to nearest
+        let half = sign as u32 | ((exponent as u32) << 10) | (mantissa >> 13);
+        (half + ((mantissa >> 12) & 1)) as u16
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Converts the bits of a half float to a float.
+    pub fn to_f32 (value: u16) -> f32 {
+
+        let sign     = ((value & 0x8000) as u32) << 16;
+        let exponent = ((value >> 10) & 0x1F) as u32;
+        let mantissa = (value & 0x03FF) as u32;
+
+        let bits = match (exponent, mantissa) {
+
+            // Zero
+            (0, 0) => sign,
+
+            // Subnormal, normalize the mantissa
+            (0, _) => {
+
+                let mut exponent = 127 - 15 + 1;
+                let mut mantissa = mantissa;
+
+                while mantissa & 0x0400 == 0 {
+
+                    mantissa <<= 1;
+                    exponent -= 1;
+                }
+
+                sign | (exponent << 23) | ((mantissa & 0x03FF) << 13)
+            },
+
+            // NaN and infinity
+            (0x1F, _) => sign | 0x7F800000 | (mantissa << 13),
+
+            // Normal
+            _ => sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)
+        };
+
+        unsafe {mem::transmute (bits)}
+    }
+}
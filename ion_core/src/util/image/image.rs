@@ -0,0 +1,451 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::image::{Half, ImageFormat};
+use ::util::image::bmp_codec::BmpCodec;
+use ::util::image::dds_codec::DdsCodec;
+use ::util::image::png_codec::PngCodec;
+use ::util::image::tga_codec::TgaCodec;
+use ::util::math::{Colour, Util};
+
+use std::cmp;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+/*===============================================================================================*/
+/*------IMAGE STRUCT-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A two dimensional image, stored in CPU memory.
+///
+/// Rows are stored top to bottom, with no padding between them.
+#[derive (Clone)]
+pub struct Image {
+
+    // Private
+    _width: u32,
+    _height: u32,
+    _format: ImageFormat,
+    _data: Vec<u8>,
+}
+
+/*===============================================================================================*/
+/*------IMAGE PUBLIC METHODS---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Image {
+
+    /// Returns the width of the image in pixels.
+    pub fn get_width (&self) -> u32 {
+        self._width
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the height of the image in pixels.
+    pub fn get_height (&self) -> u32 {
+        self._height
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the pixel format of the image.
+    pub fn get_format (&self) -> ImageFormat {
+        self._format
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the raw pixel data.
+    pub fn get_data (&self) -> &[u8] {
+        &self._data
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the raw pixel data, for modification.
+    pub fn get_data_mut (&mut self) -> &mut [u8] {
+        &mut self._data
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour of a pixel.
+    ///
+    /// Single channel images return the value in the red channel, with an alpha of one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::image::{Image, ImageFormat};
+    /// # use ion_core::util::math::Colour;
+    /// #
+    /// let mut image = Image::new (4, 4, ImageFormat::RGBA8);
+    /// image.set_pixel (1, 2, &Colour::red ());
+    ///
+    /// assert_eq! (image.get_pixel (1, 2).r, 1.0);
+    /// assert_eq! (image.get_pixel (2, 1).r, 0.0);
+    /// ```
+    pub fn get_pixel (&self, x: u32, y: u32) -> Colour {
+
+        let offset = self._get_pixel_offset (x, y);
+        let data   = &self._data[offset..];
+
+        match self._format {
+
+            ImageFormat::R8 => Colour {r: data[0] as f32 / 255.0, g: 0.0, b: 0.0, a: 1.0},
+
+            ImageFormat::RGBA8 => Colour {r: data[0] as f32 / 255.0,
+                                          g: data[1] as f32 / 255.0,
+                                          b: data[2] as f32 / 255.0,
+                                          a: data[3] as f32 / 255.0},
+
+            ImageFormat::RGBA16F => {
+
+                let channel = |i: usize| Half::to_f32 (data[i * 2] as u16 | (data[i * 2 + 1] as u16) << 8);
+                Colour {r: channel (0), g: channel (1), b: channel (2), a: channel (3)}
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets the colour of a pixel.
+    ///
+    /// 8-bit formats clamp the colour to the 0-1 range.
+    pub fn set_pixel (&mut self, x: u32, y: u32, colour: &Colour) {
+
+        let offset = self._get_pixel_offset (x, y);
+        let format = self._format;
+        let data   = &mut self._data[offset..];
+
+        let to_u8 = |value: f32| (Util::clamp (value, 0.0, 1.0) * 255.0 + 0.5) as u8;
+
+        match format {
+
+            ImageFormat::R8 => data[0] = to_u8 (colour.r),
+
+            ImageFormat::RGBA8 => {
+
+                data[0] = to_u8 (colour.r);
+                data[1] = to_u8 (colour.g);
+                data[2] = to_u8 (colour.b);
+                data[3] = to_u8 (colour.a);
+            },
+
+            ImageFormat::RGBA16F => {
+
+                for (i, value) in [colour.r, colour.g, colour.b, colour.a].iter ().enumerate () {
+
+                    let half = Half::from_f32 (*value);
+
+                    data[i * 2]     = half as u8;
+                    data[i * 2 + 1] = (half >> 8) as u8;
+                }
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a copy of the image, converted to another pixel format.
+    pub fn convert (&self, format: ImageFormat) -> Image {
+
+        if format == self._format {
+            return self.clone ();
+        }
+
+        let mut image = Image::new (self._width, self._height, format);
+
+        for y in 0..self._height {
+
+            for x in 0..self._width {
+
+                let mut colour = self.get_pixel (x, y);
+
+                // Single channel images are expanded to grey
+                if self._format == ImageFormat::R8 {
+
+                    colour.g = colour.r;
+                    colour.b = colour.r;
+                }
+
+                image.set_pixel (x, y, &colour);
+            }
+        }
+
+        image
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Flips the rows of the image.
+    ///
+    /// Used for data read back from the render backend, which is stored bottom to top.
+    pub fn flip_vertical (&mut self) {
+
+        let row_size = self._width as usize * self._format.get_pixel_size ();
+        let height   = self._height as usize;
+
+        for y in 0..height / 2 {
+
+            let top    = y * row_size;
+            let bottom = (height - y - 1) * row_size;
+
+            for i in 0..row_size {
+                self._data.swap (top + i, bottom + i);
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the full mip chain of the image, starting with the image itself.
+    ///
+    /// Each level is half the size of the previous one, down to 1x1, using a box filter.  
+    /// An image with a width or height of zero has no smaller levels.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::image::{Image, ImageFormat};
+    /// #
+    /// let image = Image::new (8, 2, ImageFormat::RGBA8);
+    /// let chain = image.get_mip_chain ();
+    ///
+    /// assert_eq! (chain.len (), 4);
+    /// assert_eq! ((chain[3].get_width (), chain[3].get_height ()), (1, 1));
+    ///
+    /// let empty = Image::new (0, 4, ImageFormat::RGBA8);
+    /// assert_eq! (empty.get_mip_chain ().len (), 1);
+    /// ```
+    pub fn get_mip_chain (&self) -> Vec<Image> {
+
+        let mut chain = vec! [self.clone ()];
+
+        // There are no pixels to average in an empty image
+        if self._width == 0 || self._height == 0 {
+            return chain;
+        }
+
+        while {let last = chain.last ().unwrap (); last._width > 1 || last._height > 1} {
+
+            let next = chain.last ().unwrap ()._downsample ();
+            chain.push (next);
+        }
+
+        chain
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Encodes the image as a PNG file.
+    ///
+    /// RGBA16F images are stored with 16 bits per channel, clamped to the 0-1 range.
+    pub fn encode_png (&self) -> Vec<u8> {
+        PngCodec::encode (self)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Saves the image as a PNG file.
+    pub fn save_png (&self, file_path: &str) -> Result<(), ()> {
+
+        info! ("Saving image \"{}\".", file_path);
+
+        match File::create (file_path).and_then (|mut file| file.write_all (&self.encode_png ())) {
+
+            Ok (_) => Ok (()),
+            Err (e) => {
+
+                error! ("Could not save image \"{}\".\n{}", file_path, e);
+                Err (())
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------IMAGE PUBLIC STATIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new image, with every pixel set to zero.
+    pub fn new (width: u32, height: u32, format: ImageFormat) -> Image {
+
+        Image {_width: width,
+               _height: height,
+               _format: format,
+               _data: vec! [0; width as usize * height as usize * format.get_pixel_size ()]}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new image using existing pixel data.
+    ///
+    /// Fails if the size of the data doesn't match the dimensions and format.
+    pub fn from_data (width: u32, height: u32, format: ImageFormat, data: Vec<u8>) -> Result<Image, ()> {
+
+        if data.len () != width as usize * height as usize * format.get_pixel_size () {
+
+            error! ("Image data size does not match a {}x{} {:?} image.", width, height, format);
+            return Err (());
+        }
+
+        Ok (Image {_width: width, _height: height, _format: format, _data: data})
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Decodes an image from the contents of a PNG, TGA, BMP or DDS file.
+    ///
+    /// The format is detected from the data. TGA files are assumed if no other format matches.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::image::{Image, ImageFormat};
+    /// # use ion_core::util::math::Colour;
+    /// #
+    /// let mut image = Image::new (3, 2, ImageFormat::RGBA8);
+    /// image.set_pixel (2, 1, &Colour::green ());
+    ///
+    /// let decoded = Image::decode (&image.encode_png ()).unwrap ();
+    ///
+    /// assert_eq! (decoded.get_format (), ImageFormat::RGBA8);
+    /// assert_eq! (decoded.get_data (), image.get_data ());
+    ///
+    /// // Images without any pixels are rejected
+    /// let empty = Image::new (0, 2, ImageFormat::RGBA8);
+    /// assert! (Image::decode (&empty.encode_png ()).is_err ());
+    ///
+    /// // Image data which doesn't match the size in the header is rejected
+    /// let small = Image::new (1, 1, ImageFormat::RGBA8).encode_png ();
+    /// let large = Image::new (64, 64, ImageFormat::RGBA8).encode_png ();
+    ///
+    /// let mut too_large = small[..33].to_vec ();
+    /// too_large.extend_from_slice (&large[33..]);
+    ///
+    /// let mut too_small = large[..33].to_vec ();
+    /// too_small.extend_from_slice (&small[33..]);
+    ///
+    /// assert! (Image::decode (&too_large).is_err ());
+    /// assert! (Image::decode (&too_small).is_err ());
+    ///
+    /// // A TGA colour map must hold true colour entries
+    /// let tga = [0, 1, 1, 0, 0, 1, 0, 8, 0, 0, 0, 0, 1, 0, 1, 0, 8, 0, 255, 0];
+    /// assert! (Image::decode (&tga).is_err ());
+    /// ```
+    pub fn decode (data: &[u8]) -> Result<Image, ()> {
+
+        if data.starts_with (b"\x89PNG") {
+            PngCodec::decode (data)
+        }
+
+        else if data.starts_with (b"BM") {
+            BmpCodec::decode (data)
+        }
+
+        else if data.starts_with (b"DDS ") {
+            DdsCodec::decode (data)
+        }
+
+        else {
+            TgaCodec::decode (data)
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Loads an image file.
+    pub fn load (file_path: &str) -> Result<Image, ()> {
+
+        info! ("Loading image \"{}\".", file_path);
+
+        let mut data = Vec::new ();
+
+        match File::open (Path::new (file_path)).and_then (|mut file| file.read_to_end (&mut data)) {
+
+            Ok (_) => {},
+            Err (e) => {
+
+                error! ("Image \"{}\" could not be read.\n{}", file_path, e);
+                return Err (());
+            }
+        }
+
+        match Image::decode (&data) {
+
+            Ok (image) => Ok (image),
+            Err (_) => {
+
+                error! ("Image \"{}\" could not be decoded.", file_path);
+                Err (())
+            }
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------IMAGE PRIVATE METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Image {
+
+    // Returns the offset of a pixel in the data.
+    fn _get_pixel_offset (&self, x: u32, y: u32) -> usize {
+
+        assert! (x < self._width && y < self._height, "Pixel ({}, {}) is outside of the image.", x, y);
+        (y as usize * self._width as usize + x as usize) * self._format.get_pixel_size ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the image at half size, averaging each 2x2 block of pixels.
+    fn _downsample (&self) -> Image {
+
+        let width  = cmp::max (self._width  / 2, 1);
+        let height = cmp::max (self._height / 2, 1);
+
+        let mut image = Image::new (width, height, self._format);
+
+        for y in 0..height {
+
+            for x in 0..width {
+
+                // Clamp the samples for dimensions of one
+                let x0 = cmp::min (x * 2,     self._width  - 1);
+                let y0 = cmp::min (y * 2,     self._height - 1);
+                let x1 = cmp::min (x * 2 + 1, self._width  - 1);
+                let y1 = cmp::min (y * 2 + 1, self._height - 1);
+
+                let samples = [self.get_pixel (x0, y0), self.get_pixel (x1, y0),
+                               self.get_pixel (x0, y1), self.get_pixel (x1, y1)];
+
+                let mut colour = Colour {r: 0.0, g: 0.0, b: 0.0, a: 0.0};
+
+                for sample in &samples {
+
+                    colour.r += sample.r * 0.25;
+                    colour.g += sample.g * 0.25;
+                    colour.b += sample.b * 0.25;
+                    colour.a += sample.a * 0.25;
+                }
+
+                image.set_pixel (x, y, &colour);
+            }
+        }
+
+        image
+    }
+}
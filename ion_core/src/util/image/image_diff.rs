@@ -0,0 +1,220 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::image::{Image, ImageFormat};
+use ::util::math::{Colour, Util};
+
+use std::cmp;
+
+// Static variables
+static SSIM_WINDOW_SIZE: u32 = 8;
+static SSIM_C1: f32 = 0.0001;
+static SSIM_C2: f32 = 0.0009;
+
+/*===============================================================================================*/
+/*------IMAGE DIFF STRUCT------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The result of comparing two images of the same size.
+///
+/// It holds both a per-pixel and a perceptual measure of the difference,
+/// and an image highlighting the pixels that differ.
+#[derive (Clone)]
+pub struct ImageDiff {
+
+    // Public
+    /// The number of pixels with a channel that differs by more than the tolerance.
+    pub mismatched_pixels: usize,
+    /// The largest difference of any channel, in the 0-1 range.
+    pub max_difference: f32,
+    /// The mean structural similarity (SSIM) of the image luminance.  
+    /// 1 means the images are perceptually identical.
+    pub similarity: f32,
+    /// The reference image dimmed to grey, with mismatched pixels drawn in red.
+    pub diff_image: Image,
+}
+
+/*===============================================================================================*/
+/*------IMAGE DIFF PUBLIC METHODS----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ImageDiff {
+
+    /// Returns whether no pixel differs by more than the tolerance.
+    pub fn is_identical (&self) -> bool {
+        self.mismatched_pixels == 0
+    }
+
+/*===============================================================================================*/
+/*------IMAGE DIFF PUBLIC STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Compares an image against a reference image.
+    ///
+    /// A pixel is mismatched when any channel differs by more than `tolerance`, in the 0-1 range.  
+    /// Both images are compared as `RGBA8`. Returns an error if the sizes differ.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::image::{Image, ImageDiff, ImageFormat};
+    /// # use ion_core::util::math::Colour;
+    /// #
+    /// let reference = Image::new (16, 16, ImageFormat::RGBA8);
+    /// let mut actual = reference.clone ();
+    ///
+    /// actual.set_pixel (3, 4, &Colour::red ());
+    ///
+    /// let diff = ImageDiff::compare (&reference, &actual, 0.01).unwrap ();
+    ///
+    /// assert_eq! (diff.mismatched_pixels, 1);
+    /// assert_eq! (diff.max_difference, 1.0);
+    /// assert! (diff.similarity < 1.0);
+    /// assert_eq! (diff.diff_image.get_pixel (3, 4).r, 1.0);
+    ///
+    /// assert! (ImageDiff::compare (&reference, &reference, 0.0).unwrap ().is_identical ());
+    /// ```
+    pub fn compare (reference: &Image, actual: &Image, tolerance: f32) -> Result<ImageDiff, ()> {
+
+        let width = reference.get_width ();
+        let height = reference.get_height ();
+
+        if actual.get_width () != width || actual.get_height () != height {
+
+            error! ("Could not compare images of size {}x{} and {}x{}.",
+                    width, height, actual.get_width (), actual.get_height ());
+
+            return Err (());
+        }
+
+        let reference = reference.convert (ImageFormat::RGBA8);
+        let actual = actual.convert (ImageFormat::RGBA8);
+
+        let mut diff_image = Image::new (width, height, ImageFormat::RGBA8);
+        let mut mismatched_pixels = 0;
+        let mut max_difference = 0.0;
+
+        for y in 0..height {
+
+            for x in 0..width {
+
+                let expected = reference.get_pixel (x, y);
+                let found = actual.get_pixel (x, y);
+
+                let difference = Util::max (Util::max ((expected.r - found.r).abs (), (expected.g - found.g).abs ()),
+                                            Util::max ((expected.b - found.b).abs (), (expected.a - found.a).abs ()));
+
+                max_difference = Util::max (max_difference, difference);
+
+                if difference > tolerance {
+
+                    mismatched_pixels += 1;
+                    diff_image.set_pixel (x, y, &Colour::red ());
+                }
+
+                else {
+
+                    let grey = ImageDiff::_get_luminance (&expected) * 0.25;
+                    diff_image.set_pixel (x, y, &Colour {r: grey, g: grey, b: grey, a: 1.0});
+                }
+            }
+        }
+
+        Ok (ImageDiff {
+
+            mismatched_pixels: mismatched_pixels,
+            max_difference: max_difference,
+            similarity: ImageDiff::_get_similarity (&reference, &actual),
+            diff_image: diff_image,
+        })
+    }
+}
+
+/*===============================================================================================*/
+/*------IMAGE DIFF PRIVATE STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ImageDiff {
+
+    // Returns the mean SSIM of two images, over non-overlapping windows.
+    fn _get_similarity (reference: &Image, actual: &Image) -> f32 {
+
+        let width = reference.get_width ();
+        let height = reference.get_height ();
+
+        let mut total = 0.0;
+        let mut window_count = 0;
+
+        for window_y in 0..(height + SSIM_WINDOW_SIZE - 1) / SSIM_WINDOW_SIZE {
+
+            for window_x in 0..(width + SSIM_WINDOW_SIZE - 1) / SSIM_WINDOW_SIZE {
+
+                let x_start = window_x * SSIM_WINDOW_SIZE;
+                let y_start = window_y * SSIM_WINDOW_SIZE;
+                let x_end = cmp::min (x_start + SSIM_WINDOW_SIZE, width);
+                let y_end = cmp::min (y_start + SSIM_WINDOW_SIZE, height);
+
+                let mut samples = Vec::new ();
+
+                for y in y_start..y_end {
+
+                    for x in x_start..x_end {
+
+                        samples.push ((ImageDiff::_get_luminance (&reference.get_pixel (x, y)),
+                                       ImageDiff::_get_luminance (&actual.get_pixel (x, y))));
+                    }
+                }
+
+                let count = samples.len () as f32;
+                let mean_a = samples.iter ().fold (0.0, |sum, &(a, _)| sum + a) / count;
+                let mean_b = samples.iter ().fold (0.0, |sum, &(_, b)| sum + b) / count;
+
+                let mut variance_a = 0.0;
+                let mut variance_b = 0.0;
+                let mut covariance = 0.0;
+
+                for &(a, b) in &samples {
+
+                    variance_a += (a - mean_a) * (a - mean_a);
+                    variance_b += (b - mean_b) * (b - mean_b);
+                    covariance += (a - mean_a) * (b - mean_b);
+                }
+
+                variance_a /= count;
+                variance_b /= count;
+                covariance /= count;
+
+                total += ((2.0 * mean_a * mean_b + SSIM_C1) * (2.0 * covariance + SSIM_C2)) /
+                         ((mean_a * mean_a + mean_b * mean_b + SSIM_C1) * (variance_a + variance_b + SSIM_C2));
+
+                window_count += 1;
+            }
+        }
+
+        if window_count == 0 {
+            return 1.0;
+        }
+
+        total / window_count as f32
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the Rec. 709 luminance of a colour.
+    fn _get_luminance (colour: &Colour) -> f32 {
+        colour.r * 0.2126 + colour.g * 0.7152 + colour.b * 0.0722
+    }
+}
@@ -0,0 +1,65 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::renderer::gpu::TextureFormat;
+
+/*===============================================================================================*/
+/*------IMAGE FORMAT ENUM------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The pixel format of an image.
+#[derive (Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ImageFormat {
+
+    /// Single 8-bit channel.
+    R8,
+    /// Four 8-bit channels.
+    RGBA8,
+    /// Four 16-bit floating point channels.
+    RGBA16F,
+}
+
+/*===============================================================================================*/
+/*------IMAGE FORMAT PUBLIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ImageFormat {
+
+    /// Returns the size of a single pixel in bytes.
+    pub fn get_pixel_size (&self) -> usize {
+
+        match *self {
+
+            ImageFormat::R8      => 1,
+            ImageFormat::RGBA8   => 4,
+            ImageFormat::RGBA16F => 8,
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the matching texture format.
+    pub fn get_texture_format (&self) -> TextureFormat {
+
+        match *self {
+
+            ImageFormat::R8      => TextureFormat::R8,
+            ImageFormat::RGBA8   => TextureFormat::RGBA8,
+            ImageFormat::RGBA16F => TextureFormat::RGBA16F,
+        }
+    }
+}
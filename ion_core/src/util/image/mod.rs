@@ -0,0 +1,37 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+/*===============================================================================================*/
+//! The image module.
+//!
+//! Contains a CPU side image type, and the codecs used to load and save it.  
+//! Images are used for textures, screenshots, and test fixtures.
+/*===============================================================================================*/
+
+// Modules
+mod bmp_codec;
+mod dds_codec;
+mod half;
+mod image;
+mod image_diff;
+mod image_format;
+mod png_codec;
+mod tga_codec;
+
+pub use self::half::Half;
+pub use self::image::Image;
+pub use self::image_diff::ImageDiff;
+pub use self::image_format::ImageFormat;
@@ -0,0 +1,585 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate flate2;
+
+use ::util::image::{Half, Image, ImageFormat};
+use ::util::math::Util;
+
+use self::flate2::Compression;
+use self::flate2::read::ZlibDecoder;
+use self::flate2::write::ZlibEncoder;
+
+use std::io::{Read, Write};
+
+// The PNG file signature.
+static PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+// The starting offset and spacing (x, y, dx, dy) of each Adam7 interlacing pass.
+static ADAM7_PASSES: [(u32, u32, u32, u32); 7] = [(0, 0, 8, 8), (4, 0, 8, 8), (0, 4, 4, 8), (2, 0, 4, 4),
+                                                   (0, 2, 2, 4), (1, 0, 2, 2), (0, 1, 1, 2)];
+
+// The largest width or height allowed by the PNG specification.
+const MAX_DIMENSION: u32 = 0x7FFFFFFF;
+
+// The largest decoded image size in bytes.
+const MAX_IMAGE_SIZE: usize = 1 << 30;
+
+// The single pass of a non-interlaced image.
+static SINGLE_PASS: [(u32, u32, u32, u32); 1] = [(0, 0, 1, 1)];
+
+/*===============================================================================================*/
+/*------PNG CODEC STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Decodes and encodes PNG files.
+///
+/// All colour types, bit depths, and interlacing are supported when decoding.  
+/// 16-bit files are decoded to RGBA16F, greyscale files without transparency to R8,  
+/// and everything else to RGBA8.
+#[derive (Copy, Clone)]
+pub struct PngCodec;
+
+/*===============================================================================================*/
+/*------PNG CODEC PUBLIC STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PngCodec {
+
+    /// Decodes a PNG file.
+    pub fn decode (data: &[u8]) -> Result<Image, ()> {
+
+        if !data.starts_with (&PNG_SIGNATURE) {
+
+            error! ("Invalid PNG signature.");
+            return Err (());
+        }
+
+        let mut header: Option<(u32, u32, u8, u8, u8)> = None;
+        let mut palette: Vec<[u8; 4]> = Vec::new ();
+        let mut transparency: Vec<u8> = Vec::new ();
+        let mut compressed: Vec<u8> = Vec::new ();
+        let mut offset = PNG_SIGNATURE.len ();
+
+        // Read the chunks
+        loop {
+
+            if offset + 12 > data.len () {
+
+                error! ("PNG file ended before the IEND chunk.");
+                return Err (());
+            }
+
+            let length     = PngCodec::_read_u32 (data, offset) as usize;
+            let chunk_type = &data[offset + 4..offset + 8];
+
+            if offset + 12 + length > data.len () {
+
+                error! ("PNG chunk exceeds the length of the file.");
+                return Err (());
+            }
+
+            let chunk = &data[offset + 8..offset + 8 + length];
+
+            if PngCodec::_crc32 (&data[offset + 4..offset + 8 + length]) != PngCodec::_read_u32 (data, offset + 8 + length) {
+
+                error! ("PNG chunk \"{}\" has an invalid CRC.", String::from_utf8_lossy (chunk_type));
+                return Err (());
+            }
+
+            match chunk_type {
+
+                b"IHDR" if length == 13 => {
+
+                    header = Some ((PngCodec::_read_u32 (chunk, 0),
+                                    PngCodec::_read_u32 (chunk, 4),
+                                    chunk[8],
+                                    chunk[9],
+                                    chunk[12]));
+                },
+
+                b"PLTE" => {
+
+                    if length % 3 != 0 {
+
+                        error! ("PNG palette length {} is not a multiple of 3.", length);
+                        return Err (());
+                    }
+
+                    palette = chunk.chunks (3).map (|c| [c[0], c[1], c[2], 255]).collect ();
+                },
+
+                b"tRNS" => transparency = chunk.to_vec (),
+                b"IDAT" => compressed.extend_from_slice (chunk),
+                b"IEND" => break,
+                _ => {}
+            }
+
+            offset += 12 + length;
+        }
+
+        let (width, height, bit_depth, colour_type, interlace) = match header {
+
+            Some (header) => header,
+            None => {
+
+                error! ("PNG file has no valid IHDR chunk.");
+                return Err (());
+            }
+        };
+
+        // Validate the colour type and bit depth
+        let channels = match (colour_type, bit_depth) {
+
+            (0, 1) | (0, 2) | (0, 4) | (0, 8) | (0, 16) => 1,
+            (2, 8) | (2, 16)                             => 3,
+            (3, 1) | (3, 2) | (3, 4) | (3, 8)            => 1,
+            (4, 8) | (4, 16)                             => 2,
+            (6, 8) | (6, 16)                             => 4,
+            _ => {
+
+                error! ("Unsupported PNG colour type {} with bit depth {}.", colour_type, bit_depth);
+                return Err (());
+            }
+        };
+
+        // Validate the dimensions before anything is allocated
+        if width == 0 || height == 0 || width > MAX_DIMENSION || height > MAX_DIMENSION {
+
+            error! ("Invalid PNG dimensions {}x{}.", width, height);
+            return Err (());
+        }
+
+        for (entry, &alpha) in palette.iter_mut ().zip (transparency.iter ()) {
+            entry[3] = alpha;
+        }
+
+        let format = if bit_depth == 16 {ImageFormat::RGBA16F}
+                     else if colour_type == 0 && transparency.is_empty () {ImageFormat::R8}
+                     else {ImageFormat::RGBA8};
+
+        let image_size = (width as usize).checked_mul (height as usize)
+                                         .and_then (|pixels| pixels.checked_mul (format.get_pixel_size ()));
+
+        match image_size {
+
+            Some (size) if size <= MAX_IMAGE_SIZE => {},
+            _ => {
+
+                error! ("PNG image of {}x{} pixels is too large to decode.", width, height);
+                return Err (());
+            }
+        }
+
+        let bits_per_pixel  = channels * bit_depth as usize;
+        let bytes_per_pixel = (bits_per_pixel + 7) / 8;
+        let max_value       = ((1u32 << bit_depth) - 1) as f32;
+
+        let passes = if interlace == 1 {&ADAM7_PASSES[..]} else {&SINGLE_PASS[..]};
+
+        // Each row of each pass is a filter byte followed by the packed pixels
+        let mut raw_size = 0u64;
+
+        for &(x0, y0, dx, dy) in passes {
+
+            if x0 >= width || y0 >= height {
+                continue;
+            }
+
+            let pass_width  = ((width  - x0 + dx - 1) / dx) as u64;
+            let pass_height = ((height - y0 + dy - 1) / dy) as u64;
+
+            raw_size += pass_height * (1 + (pass_width * bits_per_pixel as u64 + 7) / 8);
+        }
+
+        // Decompress the image data, stopping as soon as it is larger than the header allows
+        let mut raw = Vec::new ();
+
+        if let Err (e) = ZlibDecoder::new (&compressed[..]).take (raw_size + 1).read_to_end (&mut raw) {
+
+            error! ("PNG image data could not be decompressed.\n{}", e);
+            return Err (());
+        }
+
+        if raw.len () as u64 != raw_size {
+
+            error! ("PNG image data is {} bytes, but {} bytes were expected.", raw.len (), raw_size);
+            return Err (());
+        }
+
+        let mut image = Image::new (width, height, format);
+
+        let mut raw_offset = 0;
+
+        for &(x0, y0, dx, dy) in passes {
+
+            if x0 >= width || y0 >= height {
+                continue;
+            }
+
+            let pass_width  = (width  - x0 + dx - 1) / dx;
+            let pass_height = (height - y0 + dy - 1) / dy;
+            let row_size    = (pass_width as usize * bits_per_pixel + 7) / 8;
+
+            let mut previous = vec! [0u8; row_size];
+
+            for row in 0..pass_height {
+
+                if raw_offset + 1 + row_size > raw.len () {
+
+                    error! ("PNG image data is too short.");
+                    return Err (());
+                }
+
+                let filter = raw[raw_offset];
+                let mut current = raw[raw_offset + 1..raw_offset + 1 + row_size].to_vec ();
+                raw_offset += 1 + row_size;
+
+                try! (PngCodec::_unfilter (filter, &mut current, &previous, bytes_per_pixel));
+
+                for i in 0..pass_width as usize {
+
+                    let sample = |channel: usize| PngCodec::_read_sample (&current, i * channels + channel, bit_depth);
+
+                    // Expand the pixel to RGBA
+                    let (r, g, b, a) = match colour_type {
+
+                        0 => {
+
+                            let grey = sample (0);
+                            let key  = if transparency.len () >= 2 {Some (PngCodec::_read_u16 (&transparency, 0) as u32)} else {None};
+
+                            (grey, grey, grey, if Some (grey) == key {0} else {max_value as u32})
+                        },
+
+                        2 => {
+
+                            let (r, g, b) = (sample (0), sample (1), sample (2));
+                            let key = if transparency.len () >= 6 {
+
+                                Some ((PngCodec::_read_u16 (&transparency, 0) as u32,
+                                       PngCodec::_read_u16 (&transparency, 2) as u32,
+                                       PngCodec::_read_u16 (&transparency, 4) as u32))
+                            } else {None};
+
+                            (r, g, b, if Some ((r, g, b)) == key {0} else {max_value as u32})
+                        },
+
+                        3 => {
+
+                            let entry = match palette.get (sample (0) as usize) {
+
+                                Some (entry) => *entry,
+                                None => {
+
+                                    error! ("PNG palette index is out of range.");
+                                    return Err (());
+                                }
+                            };
+
+                            (entry[0] as u32, entry[1] as u32, entry[2] as u32, entry[3] as u32)
+                        },
+
+                        4 => (sample (0), sample (0), sample (0), sample (1)),
+                        _ => (sample (0), sample (1), sample (2), sample (3))
+                    };
+
+                    // The image size was checked, so these offsets can't overflow
+                    let x = (x0 + i as u32 * dx) as usize;
+                    let y = (y0 + row * dy) as usize;
+                    let width = width as usize;
+
+                    // Palette entries are always 8-bit
+                    let scale = if colour_type == 3 {255.0} else {max_value};
+
+                    match format {
+
+                        ImageFormat::R8 => {
+
+                            let offset = y * width + x;
+                            image.get_data_mut ()[offset] = (r as f32 * 255.0 / scale + 0.5) as u8;
+                        },
+
+                        ImageFormat::RGBA8 => {
+
+                            let offset = (y * width + x) * 4;
+                            let data   = image.get_data_mut ();
+
+                            data[offset]     = (r as f32 * 255.0 / scale + 0.5) as u8;
+                            data[offset + 1] = (g as f32 * 255.0 / scale + 0.5) as u8;
+                            data[offset + 2] = (b as f32 * 255.0 / scale + 0.5) as u8;
+                            data[offset + 3] = (a as f32 * 255.0 / scale + 0.5) as u8;
+                        },
+
+                        ImageFormat::RGBA16F => {
+
+                            let offset = (y * width + x) * 8;
+                            let data   = image.get_data_mut ();
+
+                            for (c, &value) in [r, g, b, a].iter ().enumerate () {
+
+                                let half = Half::from_f32 (value as f32 / scale);
+
+                                data[offset + c * 2]     = half as u8;
+                                data[offset + c * 2 + 1] = (half >> 8) as u8;
+                            }
+                        }
+                    }
+                }
+
+                previous = current;
+            }
+        }
+
+        Ok (image)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Encodes an image as a PNG file.
+    pub fn encode (image: &Image) -> Vec<u8> {
+
+        let width  = image.get_width ();
+        let height = image.get_height ();
+
+        // R8 is stored as greyscale, and RGBA16F as 16-bit RGBA
+        let (colour_type, bit_depth, bytes_per_pixel) = match image.get_format () {
+
+            ImageFormat::R8      => (0u8, 8u8, 1),
+            ImageFormat::RGBA8   => (6u8, 8u8, 4),
+            ImageFormat::RGBA16F => (6u8, 16u8, 8),
+        };
+
+        let row_size = width as usize * bytes_per_pixel;
+        let mut raw  = Vec::with_capacity ((row_size + 1) * height as usize);
+        let mut previous = vec! [0u8; row_size];
+
+        for y in 0..height as usize {
+
+            let mut row = image.get_data ()[y * row_size..(y + 1) * row_size].to_vec ();
+
+            if image.get_format () == ImageFormat::RGBA16F {
+
+                for pixel in row.chunks_mut (2) {
+
+                    let value = Half::to_f32 (pixel[0] as u16 | (pixel[1] as u16) << 8);
+                    let value = (Util::clamp (value, 0.0, 1.0) * 65535.0 + 0.5) as u16;
+
+                    pixel[0] = (value >> 8) as u8;
+                    pixel[1] = value as u8;
+                }
+            }
+
+            // Pick the filter with the smallest sum of absolute differences
+            let mut best_filter   = 0;
+            let mut best_filtered = Vec::new ();
+            let mut best_score    = u64::max_value ();
+
+            for filter in 0..5 {
+
+                let filtered = PngCodec::_filter (filter, &row, &previous, bytes_per_pixel);
+                let score: u64 = filtered.iter ().map (|&b| (b as i8 as i16).abs () as u64).sum ();
+
+                if score < best_score {
+
+                    best_filter   = filter;
+                    best_filtered = filtered;
+                    best_score    = score;
+                }
+            }
+
+            raw.push (best_filter);
+            raw.extend_from_slice (&best_filtered);
+            previous = row;
+        }
+
+        let mut encoder = ZlibEncoder::new (Vec::new (), Compression::Default);
+        let compressed  = encoder.write_all (&raw).and_then (|_| encoder.finish ()).unwrap ();
+
+        // Write the chunks
+        let mut header = Vec::with_capacity (13);
+
+        PngCodec::_write_u32 (&mut header, width);
+        PngCodec::_write_u32 (&mut header, height);
+        header.extend_from_slice (&[bit_depth, colour_type, 0, 0, 0]);
+
+        let mut output = PNG_SIGNATURE.to_vec ();
+
+        PngCodec::_write_chunk (&mut output, b"IHDR", &header);
+        PngCodec::_write_chunk (&mut output, b"IDAT", &compressed);
+        PngCodec::_write_chunk (&mut output, b"IEND", &[]);
+
+        output
+    }
+}
+
+/*===============================================================================================*/
+/*------PNG CODEC PRIVATE STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PngCodec {
+
+    // Reverses the filter of a scanline.
+    fn _unfilter (filter: u8, current: &mut [u8], previous: &[u8], bytes_per_pixel: usize) -> Result<(), ()> {
+
+        for i in 0..current.len () {
+
+            let left       = if i >= bytes_per_pixel {current[i - bytes_per_pixel]} else {0};
+            let up         = previous[i];
+            let upper_left = if i >= bytes_per_pixel {previous[i - bytes_per_pixel]} else {0};
+
+            let predictor = match filter {
+
+                0 => 0,
+                1 => left,
+                2 => up,
+                3 => ((left as u16 + up as u16) / 2) as u8,
+                4 => PngCodec::_paeth (left, up, upper_left),
+                _ => {
+
+                    error! ("Invalid PNG filter type {}.", filter);
+                    return Err (());
+                }
+            };
+
+            current[i] = current[i].wrapping_add (predictor);
+        }
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Applies a filter to a scanline.
+    fn _filter (filter: u8, current: &[u8], previous: &[u8], bytes_per_pixel: usize) -> Vec<u8> {
+
+        let mut filtered = Vec::with_capacity (current.len ());
+
+        for i in 0..current.len () {
+
+            let left       = if i >= bytes_per_pixel {current[i - bytes_per_pixel]} else {0};
+            let up         = previous[i];
+            let upper_left = if i >= bytes_per_pixel {previous[i - bytes_per_pixel]} else {0};
+
+            let predictor = match filter {
+
+                1 => left,
+                2 => up,
+                3 => ((left as u16 + up as u16) / 2) as u8,
+                4 => PngCodec::_paeth (left, up, upper_left),
+                _ => 0
+            };
+
+            filtered.push (current[i].wrapping_sub (predictor));
+        }
+
+        filtered
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // The Paeth predictor.
+    fn _paeth (left: u8, up: u8, upper_left: u8) -> u8 {
+
+        let estimate = left as i16 + up as i16 - upper_left as i16;
+
+        let distance_left       = (estimate - left as i16).abs ();
+        let distance_up         = (estimate - up as i16).abs ();
+        let distance_upper_left = (estimate - upper_left as i16).abs ();
+
+        if distance_left <= distance_up && distance_left <= distance_upper_left {left}
+        else if distance_up <= distance_upper_left {up}
+        else {upper_left}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a sample of a given bit depth from a scanline.
+    fn _read_sample (row: &[u8], index: usize, bit_depth: u8) -> u32 {
+
+        match bit_depth {
+
+            8  => row[index] as u32,
+            16 => PngCodec::_read_u16 (row, index * 2) as u32,
+            _  => {
+
+                let bit   = index * bit_depth as usize;
+                let shift = 8 - bit_depth as usize - bit % 8;
+
+                ((row[bit / 8] >> shift) & ((1 << bit_depth) - 1)) as u32
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Appends a chunk to the output.
+    fn _write_chunk (output: &mut Vec<u8>, chunk_type: &[u8], data: &[u8]) {
+
+        PngCodec::_write_u32 (output, data.len () as u32);
+
+        let start = output.len ();
+
+        output.extend_from_slice (chunk_type);
+        output.extend_from_slice (data);
+
+        let crc = PngCodec::_crc32 (&output[start..]);
+        PngCodec::_write_u32 (output, crc);
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Calculates the CRC-32 of some data.
+    fn _crc32 (data: &[u8]) -> u32 {
+
+        let mut crc = 0xFFFFFFFFu32;
+
+        for &byte in data {
+
+            crc ^= byte as u32;
+
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 {(crc >> 1) ^ 0xEDB88320} else {crc >> 1};
+            }
+        }
+
+        !crc
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a big endian u16.
+    fn _read_u16 (data: &[u8], offset: usize) -> u16 {
+        (data[offset] as u16) << 8 | data[offset + 1] as u16
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a big endian u32.
+    fn _read_u32 (data: &[u8], offset: usize) -> u32 {
+
+        (data[offset] as u32) << 24 | (data[offset + 1] as u32) << 16 |
+        (data[offset + 2] as u32) << 8 | data[offset + 3] as u32
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Writes a big endian u32.
+    fn _write_u32 (output: &mut Vec<u8>, value: u32) {
+        output.extend_from_slice (&[(value >> 24) as u8, (value >> 16) as u8, (value >> 8) as u8, value as u8]);
+    }
+}
@@ -0,0 +1,258 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::image::{Image, ImageFormat};
+
+/*===============================================================================================*/
+/*------TGA CODEC STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Decodes TGA files.
+///
+/// Colour mapped, true colour and greyscale images are supported, with or without RLE compression.  
+/// 8-bit greyscale files are decoded to R8, and everything else to RGBA8.
+#[derive (Copy, Clone)]
+pub struct TgaCodec;
+
+/*===============================================================================================*/
+/*------TGA CODEC PUBLIC STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl TgaCodec {
+
+    /// Decodes a TGA file.
+    pub fn decode (data: &[u8]) -> Result<Image, ()> {
+
+        if data.len () < 18 {
+
+            error! ("TGA file is too short.");
+            return Err (());
+        }
+
+        let id_length       = data[0] as usize;
+        let colour_map_type = data[1];
+        let image_type      = data[2];
+        let map_first       = TgaCodec::_read_u16 (data, 3) as usize;
+        let map_length      = TgaCodec::_read_u16 (data, 5) as usize;
+        let map_bits        = data[7];
+        let width           = TgaCodec::_read_u16 (data, 12) as u32;
+        let height          = TgaCodec::_read_u16 (data, 14) as u32;
+        let pixel_bits      = data[16];
+        let descriptor      = data[17];
+        let alpha_bits      = descriptor & 0x0F;
+
+        let is_rle = image_type & 0x08 != 0;
+        let base_type = image_type & 0x07;
+
+        // Validate the image type and pixel depth
+        match (base_type, pixel_bits) {
+
+            (1, 8) | (1, 16) if colour_map_type == 1 => {},
+            (2, bits) if TgaCodec::_is_colour_depth (bits) => {},
+            (3, 8) | (3, 16) => {},
+            _ => {
+
+                error! ("Unsupported TGA image type {} with {} bits per pixel.", image_type, pixel_bits);
+                return Err (());
+            }
+        }
+
+        if colour_map_type == 1 && !TgaCodec::_is_colour_depth (map_bits) {
+
+            error! ("Unsupported TGA colour map with {} bits per entry.", map_bits);
+            return Err (());
+        }
+
+        // Read the colour map
+        let mut offset = 18 + id_length;
+        let map_entry_size = (map_bits as usize + 7) / 8;
+        let mut colour_map = Vec::new ();
+
+        if colour_map_type == 1 {
+
+            if offset + map_length * map_entry_size > data.len () {
+
+                error! ("TGA colour map exceeds the length of the file.");
+                return Err (());
+            }
+
+            for i in 0..map_length {
+
+                let entry = &data[offset + i * map_entry_size..offset + (i + 1) * map_entry_size];
+                colour_map.push (TgaCodec::_to_rgba (entry, map_bits, alpha_bits));
+            }
+
+            offset += map_length * map_entry_size;
+        }
+
+        // Read the pixels, decompressing them if needed
+        let pixel_size  = (pixel_bits as usize + 7) / 8;
+        let pixel_count = width as usize * height as usize;
+        let mut pixels  = Vec::with_capacity (pixel_count * pixel_size);
+
+        if is_rle {
+
+            while pixels.len () < pixel_count * pixel_size {
+
+                if offset >= data.len () {
+
+                    error! ("TGA image data is too short.");
+                    return Err (());
+                }
+
+                let packet = data[offset];
+                let count  = (packet & 0x7F) as usize + 1;
+                offset += 1;
+
+                // Run length packets contain one pixel, raw packets contain many
+                let packet_size = if packet & 0x80 != 0 {pixel_size} else {pixel_size * count};
+
+                if offset + packet_size > data.len () {
+
+                    error! ("TGA image data is too short.");
+                    return Err (());
+                }
+
+                if packet & 0x80 != 0 {
+
+                    for _ in 0..count {
+                        pixels.extend_from_slice (&data[offset..offset + pixel_size]);
+                    }
+                }
+
+                else {
+                    pixels.extend_from_slice (&data[offset..offset + packet_size]);
+                }
+
+                offset += packet_size;
+            }
+
+            pixels.truncate (pixel_count * pixel_size);
+        }
+
+        else {
+
+            if offset + pixel_count * pixel_size > data.len () {
+
+                error! ("TGA image data is too short.");
+                return Err (());
+            }
+
+            pixels.extend_from_slice (&data[offset..offset + pixel_count * pixel_size]);
+        }
+
+        // Convert the pixels
+        let format = if base_type == 3 && pixel_bits == 8 {ImageFormat::R8} else {ImageFormat::RGBA8};
+        let mut image = Image::new (width, height, format);
+
+        let top_to_bottom = descriptor & 0x20 != 0;
+        let right_to_left = descriptor & 0x10 != 0;
+
+        for (i, pixel) in pixels.chunks (pixel_size).enumerate () {
+
+            let mut x = i as u32 % width;
+            let mut y = i as u32 / width;
+
+            if right_to_left {
+                x = width - x - 1;
+            }
+
+            if !top_to_bottom {
+                y = height - y - 1;
+            }
+
+            let rgba = match base_type {
+
+                1 => {
+
+                    let index = if pixel_size == 2 {TgaCodec::_read_u16 (pixel, 0) as usize} else {pixel[0] as usize};
+
+                    match index.checked_sub (map_first).and_then (|i| colour_map.get (i)) {
+
+                        Some (entry) => *entry,
+                        None => {
+
+                            error! ("TGA colour map index is out of range.");
+                            return Err (());
+                        }
+                    }
+                },
+
+                3 => [pixel[0], pixel[0], pixel[0], if pixel_size == 2 {pixel[1]} else {255}],
+                _ => TgaCodec::_to_rgba (pixel, pixel_bits, alpha_bits)
+            };
+
+            let pixel_offset = (y * width + x) as usize * format.get_pixel_size ();
+            let data = image.get_data_mut ();
+
+            if format == ImageFormat::R8 {
+                data[pixel_offset] = rgba[0];
+            }
+
+            else {
+                data[pixel_offset..pixel_offset + 4].copy_from_slice (&rgba);
+            }
+        }
+
+        Ok (image)
+    }
+}
+
+/*===============================================================================================*/
+/*------TGA CODEC PRIVATE STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl TgaCodec {
+
+    // Returns true if a true colour pixel or colour map entry can have this many bits.
+    fn _is_colour_depth (bits: u8) -> bool {
+
+        match bits {
+
+            15 | 16 | 24 | 32 => true,
+            _ => false
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Converts a true colour pixel to RGBA.
+    fn _to_rgba (pixel: &[u8], bits: u8, alpha_bits: u8) -> [u8; 4] {
+
+        match bits {
+
+            15 | 16 => {
+
+                let value = TgaCodec::_read_u16 (pixel, 0);
+                let scale = |channel: u16| ((channel & 0x1F) as u32 * 255 / 31) as u8;
+                let alpha = if bits == 16 && alpha_bits > 0 && value & 0x8000 == 0 {0} else {255};
+
+                [scale (value >> 10), scale (value >> 5), scale (value), alpha]
+            },
+
+            24 => [pixel[2], pixel[1], pixel[0], 255],
+            _  => [pixel[2], pixel[1], pixel[0], if alpha_bits > 0 {pixel[3]} else {255}]
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Reads a little endian u16.
+    fn _read_u16 (data: &[u8], offset: usize) -> u16 {
+        data[offset] as u16 | (data[offset + 1] as u16) << 8
+    }
+}
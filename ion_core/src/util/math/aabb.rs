@@ -0,0 +1,275 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Mat4, Util, Vec3};
+
+use std::f32;
+
+/*===============================================================================================*/
+/*------AABB STRUCT------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An axis aligned bounding box.
+#[derive (Copy, Clone, Serialize, Deserialize)]
+pub struct Aabb {
+
+    // Public
+    /// The minimum corner of the box.
+    pub min: Vec3,
+    /// The maximum corner of the box.
+    pub max: Vec3,
+}
+
+/*===============================================================================================*/
+/*------AABB PUBLIC METHODS----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Aabb {
+
+    /// Returns true if the box contains a point.
+    pub fn contains_point (&self, point: &Vec3) -> bool {
+
+        point.x >= self.min.x && point.x <= self.max.x &&
+        point.y >= self.min.y && point.y <= self.max.y &&
+        point.z >= self.min.z && point.z <= self.max.z
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the box entirely contains another box.
+    pub fn contains (&self, other: &Aabb) -> bool {
+        self.contains_point (&other.min) && self.contains_point (&other.max)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the point in the box closest to a point.
+    ///
+    /// Points inside the box are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Aabb, Vec3};
+    /// #
+    /// let aabb = Aabb::from_points (&[Vec3::new (), Vec3 {x: 2.0, y: 2.0, z: 2.0}]);
+    ///
+    /// assert! (aabb.get_closest_point (&Vec3 {x: 1.0, y: 5.0, z: -1.0}) == Vec3 {x: 1.0, y: 2.0, z: 0.0});
+    /// assert! (aabb.get_closest_point (&Vec3 {x: 1.0, y: 1.5, z: 0.5}) == Vec3 {x: 1.0, y: 1.5, z: 0.5});
+    /// assert_eq! (aabb.get_distance (&Vec3 {x: 5.0, y: 6.0, z: 1.0}), 5.0);
+    /// ```
+    pub fn get_closest_point (&self, point: &Vec3) -> Vec3 {
+
+        Vec3 {x: Util::clamp (point.x, self.min.x, self.max.x),
+              y: Util::clamp (point.y, self.min.y, self.max.y),
+              z: Util::clamp (point.z, self.min.z, self.max.z)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance from the box to a point.
+    ///
+    /// The distance is zero for points inside the box.
+    pub fn get_distance (&self, point: &Vec3) -> f32 {
+        Vec3::distance (&self.get_closest_point (point), point)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the box overlaps another box.
+    ///
+    /// Boxes that only touch are overlapping.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Aabb, Vec3};
+    /// #
+    /// let a = Aabb::from_points (&[Vec3::new (), Vec3 {x: 1.0, y: 1.0, z: 1.0}]);
+    /// let b = Aabb::from_points (&[Vec3 {x: 1.0, y: 0.5, z: 0.5}, Vec3 {x: 2.0, y: 2.0, z: 2.0}]);
+    /// let c = Aabb::from_points (&[Vec3 {x: 1.5, y: 0.0, z: 0.0}, Vec3 {x: 2.0, y: 1.0, z: 1.0}]);
+    ///
+    /// assert! (a.intersects (&b));
+    /// assert! (!a.intersects (&c));
+    /// ```
+    pub fn intersects (&self, other: &Aabb) -> bool {
+
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+        self.min.y <= other.max.y && self.max.y >= other.min.y &&
+        self.min.z <= other.max.z && self.max.z >= other.min.z
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Grows the box to contain a point.
+    pub fn expand (&mut self, point: &Vec3) {
+
+        self.min = Vec3 {x: Util::min (self.min.x, point.x),
+                         y: Util::min (self.min.y, point.y),
+                         z: Util::min (self.min.z, point.z)};
+
+        self.max = Vec3 {x: Util::max (self.max.x, point.x),
+                         y: Util::max (self.max.y, point.y),
+                         z: Util::max (self.max.z, point.z)};
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the center of the box.
+    pub fn get_center (&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the half size of the box along each axis.
+    pub fn get_extents (&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the size of the box along each axis.
+    pub fn get_size (&self) -> Vec3 {
+        self.max - self.min
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the surface area of the box.
+    pub fn get_surface_area (&self) -> f32 {
+
+        if self.is_empty () {
+            return 0.0;
+        }
+
+        let size = self.get_size ();
+        2.0 * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the box contains no points.
+    pub fn is_empty (&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the box bounding this box, transformed by a matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Aabb, Mat4, Vec3};
+    /// #
+    /// let aabb = Aabb::from_points (&[Vec3 {x: -1.0, y: -1.0, z: -1.0}, Vec3 {x: 1.0, y: 1.0, z: 1.0}]);
+    /// let moved = aabb.transform (&(Mat4::translate (&Vec3 {x: 5.0, y: 0.0, z: 0.0}) *
+    ///                               Mat4::scale (&Vec3 {x: 2.0, y: 1.0, z: 1.0})));
+    ///
+    /// assert! (moved.min == Vec3 {x: 3.0, y: -1.0, z: -1.0});
+    /// assert! (moved.max == Vec3 {x: 7.0, y: 1.0, z: 1.0});
+    /// ```
+    pub fn transform (&self, matrix: &Mat4) -> Aabb {
+
+        if self.is_empty () {
+            return *self;
+        }
+
+        // Each axis of the matrix moves the box by its smallest and largest contribution
+        let m = *matrix;
+        let mut aabb = Aabb {min: Vec3 {x: m[3][0], y: m[3][1], z: m[3][2]},
+                             max: Vec3 {x: m[3][0], y: m[3][1], z: m[3][2]}};
+
+        for col in 0..3 {
+
+            for row in 0..3 {
+
+                let a = m[col][row] * self.min[col];
+                let b = m[col][row] * self.max[col];
+
+                aabb.min[row] += Util::min (a, b);
+                aabb.max[row] += Util::max (a, b);
+            }
+        }
+
+        aabb
+    }
+
+/*===============================================================================================*/
+/*------AABB PUBLIC STATIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty box.
+    ///
+    /// Expanding an empty box by a point results in a box containing only that point.
+    pub fn new () -> Aabb {
+
+        Aabb {min: Vec3 {x: f32::MAX,  y: f32::MAX,  z: f32::MAX},
+              max: Vec3 {x: -f32::MAX, y: -f32::MAX, z: -f32::MAX}}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the smallest box containing a set of points.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Aabb, Vec3};
+    /// #
+    /// let points = [Vec3 {x: -1.0, y: 0.0, z: 2.0}, Vec3 {x: 3.0, y: -2.0, z: 0.0}];
+    /// let aabb = Aabb::from_points (&points);
+    ///
+    /// assert! (aabb.min == Vec3 {x: -1.0, y: -2.0, z: 0.0});
+    /// assert! (aabb.max == Vec3 {x: 3.0, y: 0.0, z: 2.0});
+    /// ```
+    pub fn from_points (points: &[Vec3]) -> Aabb {
+
+        let mut aabb = Aabb::new ();
+
+        for point in points {
+            aabb.expand (point);
+        }
+
+        aabb
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a box from its centre and half size along each axis.
+    pub fn from_centre_extents (centre: &Vec3, extents: &Vec3) -> Aabb {
+        Aabb {min: *centre - *extents, max: *centre + *extents}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the smallest box containing two boxes.
+    pub fn merge (lhs: &Aabb, rhs: &Aabb) -> Aabb {
+
+        let mut aabb = *lhs;
+
+        aabb.expand (&rhs.min);
+        aabb.expand (&rhs.max);
+        aabb
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for Aabb {
+
+    fn default () -> Aabb {
+        Aabb::new ()
+    }
+}
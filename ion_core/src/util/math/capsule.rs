@@ -0,0 +1,111 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Aabb, Segment, Sphere, Vec3};
+
+/*===============================================================================================*/
+/*------CAPSULE STRUCT---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A capsule, containing every point within a radius of a line segment.
+#[derive (Copy, Clone, Default, Serialize, Deserialize)]
+pub struct Capsule {
+
+    // Public
+    /// The centre of the first cap.
+    pub start: Vec3,
+    /// The centre of the second cap.
+    pub end: Vec3,
+    /// The radius of the capsule.
+    pub radius: f32,
+}
+
+/*===============================================================================================*/
+/*------CAPSULE PUBLIC METHODS-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Capsule {
+
+    /// Returns the segment between the cap centres.
+    pub fn get_segment (&self) -> Segment {
+        Segment::new (&self.start, &self.end)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the box bounding the capsule.
+    pub fn get_bounds (&self) -> Aabb {
+
+        let radius = Vec3 {x: self.radius, y: self.radius, z: self.radius};
+        Aabb::merge (&Aabb::from_centre_extents (&self.start, &radius), &Aabb::from_centre_extents (&self.end, &radius))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the capsule contains a point.
+    pub fn contains_point (&self, point: &Vec3) -> bool {
+        self.get_segment ().get_distance (point) <= self.radius
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the capsule overlaps a sphere.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Capsule, Sphere, Vec3};
+    /// #
+    /// let capsule = Capsule::new (&Vec3::new (), &Vec3 {x: 0.0, y: 4.0, z: 0.0}, 1.0);
+    ///
+    /// assert! (capsule.intersects_sphere (&Sphere::new (&Vec3 {x: 1.5, y: 2.0, z: 0.0}, 1.0)));
+    /// assert! (capsule.intersects_sphere (&Sphere::new (&Vec3 {x: 0.0, y: 6.0, z: 0.0}, 1.0)));
+    /// assert! (!capsule.intersects_sphere (&Sphere::new (&Vec3 {x: 0.0, y: -2.5, z: 0.0}, 1.0)));
+    /// ```
+    pub fn intersects_sphere (&self, sphere: &Sphere) -> bool {
+        self.get_segment ().get_distance (&sphere.centre) <= self.radius + sphere.radius
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the capsule overlaps another capsule.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Capsule, Vec3};
+    /// #
+    /// let a = Capsule::new (&Vec3::new (), &Vec3 {x: 0.0, y: 4.0, z: 0.0}, 1.0);
+    /// let crossing = Capsule::new (&Vec3 {x: -3.0, y: 2.0, z: 1.5}, &Vec3 {x: 3.0, y: 2.0, z: 1.5}, 0.5);
+    /// let parallel = Capsule::new (&Vec3 {x: 2.5, y: 1.0, z: 0.0}, &Vec3 {x: 2.5, y: 9.0, z: 0.0}, 1.0);
+    ///
+    /// assert! (a.intersects_capsule (&crossing));
+    /// assert! (!a.intersects_capsule (&parallel));
+    /// ```
+    pub fn intersects_capsule (&self, other: &Capsule) -> bool {
+
+        let (p, q) = self.get_segment ().get_closest_points (&other.get_segment ());
+        Vec3::distance (&p, &q) <= self.radius + other.radius
+    }
+
+/*===============================================================================================*/
+/*------CAPSULE PUBLIC STATIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new capsule.
+    pub fn new (start: &Vec3, end: &Vec3, radius: f32) -> Capsule {
+        Capsule {start: *start, end: *end, radius: radius}
+    }
+}
@@ -0,0 +1,181 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Mat4, Sphere, Util, Vec3, Vec4};
+
+/*===============================================================================================*/
+/*------CASCADE STRUCT---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A single cascade of a cascaded shadow map.
+///
+/// Each cascade covers a depth slice of the camera frustum, with an orthographic  
+/// projection along the light direction that is fitted around the slice.
+///
+/// # Examples
+/// ```
+/// # use ion_core::util::math::{Cascade, Mat4, Util, Vec3, Vec4};
+/// #
+/// let view = Mat4::view (&Vec3::new (), &Vec3::forward (), &Vec3::up ());
+/// let splits = Cascade::compute_splits (1.0, 100.0, 4, 0.75);
+///
+/// for i in 0..4 {
+///
+///     let slice = Mat4::perspective (Util::deg2rad (60.0), 1.5, splits[i], splits[i + 1]) * view;
+///     let cascade = Cascade::new (&slice, splits[i], splits[i + 1], &Vec3 {x: 1.0, y: -1.0, z: 0.0}, 1024, 0.0);
+///
+///     // Every corner of the slice is inside the shadow map
+///     for corner in Cascade::get_frustum_corners (&slice).iter () {
+///
+///         let p = cascade.view_projection * Vec4 {x: corner.x, y: corner.y, z: corner.z, w: 1.0};
+///         assert! (p.x.abs () <= 1.0 && p.y.abs () <= 1.0 && p.z.abs () <= 1.0);
+///     }
+/// }
+/// ```
+#[derive (Copy, Clone)]
+pub struct Cascade {
+
+    // Public
+    /// The view distance the cascade starts at.
+    pub split_near: f32,
+    /// The view distance the cascade ends at.
+    pub split_far: f32,
+    /// The world space sphere bounding the frustum slice.
+    pub bounds: Sphere,
+    /// The view-projection matrix of the light, for the cascade.
+    pub view_projection: Mat4,
+}
+
+/*===============================================================================================*/
+/*------CASCADE PUBLIC STATIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Cascade {
+
+    /// Returns a cascade fitted around a slice of the camera frustum.
+    ///
+    /// The fitted projection is stable while the camera moves and rotates: the bounds are  
+    /// a sphere, and the projection is snapped to whole shadow map texels.
+    ///
+    /// # Arguments
+    /// * `slice_view_projection` - The camera view-projection matrix, limited to the slice.
+    /// * `split_near` - The view distance the slice starts at.
+    /// * `split_far` - The view distance the slice ends at.
+    /// * `light_direction` - The direction the light shines in.
+    /// * `resolution` - The width and height of the cascade in the shadow map, in texels.
+    /// * `caster_distance` - How far behind the slice shadow casters are still included.
+    pub fn new (slice_view_projection: &Mat4,
+                split_near: f32,
+                split_far: f32,
+                light_direction: &Vec3,
+                resolution: u32,
+                caster_distance: f32) -> Cascade {
+
+        let corners = Cascade::get_frustum_corners (slice_view_projection);
+        let mut bounds = Sphere::from_points (&corners);
+
+        // Round the radius, so the projection size doesn't change as the camera rotates
+        bounds.radius = (bounds.radius * 16.0).ceil () / 16.0;
+
+        let direction = Vec3::normalize (light_direction);
+        let up = if Vec3::dot (&direction, &Vec3::up ()).abs () > 0.99 {Vec3::forward ()} else {Vec3::up ()};
+
+        let radius = bounds.radius;
+        let eye = bounds.centre - direction * radius;
+        let view = Mat4::view (&eye, &bounds.centre, &up);
+        let mut projection = Mat4::ortho (-radius, radius, radius, -radius, -caster_distance, radius * 2.0);
+
+        // Snap the projected world origin to a whole texel
+        let half_resolution = resolution as f32 / 2.0;
+        let origin = (projection * view) * Vec3::new ();
+
+        let offset_x = ((origin.x * half_resolution).round () - origin.x * half_resolution) / half_resolution;
+        let offset_y = ((origin.y * half_resolution).round () - origin.y * half_resolution) / half_resolution;
+
+        projection[3][0] += offset_x;
+        projection[3][1] += offset_y;
+
+        Cascade {split_near: split_near,
+                 split_far: split_far,
+                 bounds: bounds,
+                 view_projection: projection * view}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the view distances splitting a frustum into cascades.
+    ///
+    /// The splits blend between a uniform and a logarithmic distribution.  
+    /// The first value is the near distance, and the last the far distance.
+    ///
+    /// # Arguments
+    /// * `near` - The near distance of the frustum.
+    /// * `far` - The far distance of the frustum.
+    /// * `count` - The number of cascades.
+    /// * `lambda` - The blend factor, from 0 (uniform) to 1 (logarithmic).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::Cascade;
+    /// #
+    /// assert_eq! (Cascade::compute_splits (1.0, 100.0, 2, 0.0), vec! [1.0, 50.5, 100.0]);
+    /// assert_eq! (Cascade::compute_splits (1.0, 100.0, 2, 1.0), vec! [1.0, 10.0, 100.0]);
+    /// ```
+    pub fn compute_splits (near: f32, far: f32, count: usize, lambda: f32) -> Vec<f32> {
+
+        let mut splits = Vec::with_capacity (count + 1);
+        splits.push (near);
+
+        for i in 1..count {
+
+            let p = i as f32 / count as f32;
+            let logarithmic = near * (far / near).powf (p);
+            let uniform = near + (far - near) * p;
+
+            splits.push (Util::lerp (uniform, logarithmic, lambda));
+        }
+
+        if count > 0 {
+            splits.push (far);
+        }
+
+        splits
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the world space corners of the frustum of a view-projection matrix.
+    ///
+    /// The four near corners come first, then the four far corners.
+    pub fn get_frustum_corners (view_projection: &Mat4) -> [Vec3; 8] {
+
+        let inverse = Mat4::inverse (view_projection);
+        let mut corners = [Vec3::new (); 8];
+
+        for (i, corner) in corners.iter_mut ().enumerate () {
+
+            let x = if i % 4 == 1 || i % 4 == 2 {1.0} else {-1.0};
+            let y = if i % 4 >= 2 {1.0} else {-1.0};
+            let z = if i >= 4 {1.0} else {-1.0};
+
+            let point = inverse * Vec4 {x: x, y: y, z: z, w: 1.0};
+            *corner = Vec3 {x: point.x / point.w, y: point.y / point.w, z: point.z / point.w};
+        }
+
+        corners
+    }
+}
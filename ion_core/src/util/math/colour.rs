@@ -24,6 +24,7 @@ use ::util::math::Util;
 ///
 /// Used to represent RGBA colours.
 /// 32-bit floats are used for the values.
+#[repr (C)]
 #[derive (Copy, Clone, Default, Serialize, Deserialize)]
 pub struct Colour {
 
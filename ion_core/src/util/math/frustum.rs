@@ -0,0 +1,146 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Aabb, Mat4, Plane, Sphere, Vec3};
+
+/*===============================================================================================*/
+/*------FRUSTUM STRUCT---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A view volume, bounded by six planes facing inwards.
+///
+/// It is used to skip objects that can not be seen by a camera or a light.
+///
+/// # Examples
+/// ```
+/// # use ion_core::util::math::{Aabb, Frustum, Mat4, Sphere, Util, Vec3};
+/// #
+/// let view = Mat4::view (&Vec3::new (), &Vec3::forward (), &Vec3::up ());
+/// let projection = Mat4::perspective (Util::deg2rad (90.0), 1.0, 0.1, 100.0);
+/// let frustum = Frustum::from_matrix (&(projection * view));
+///
+/// let ahead = Vec3::forward () * 10.0;
+/// let behind = Vec3::back () * 10.0;
+///
+/// assert! (frustum.contains_point (&ahead));
+/// assert! (!frustum.contains_point (&behind));
+/// assert! (frustum.intersects_sphere (&Sphere::new (&Vec3 {x: 0.0, y: 0.0, z: 0.5}, 1.0)));
+/// assert! (!frustum.intersects_sphere (&Sphere::new (&behind, 1.0)));
+///
+/// let aabb = Aabb::from_points (&[Vec3 {x: -1.0, y: -1.0, z: 8.0}, Vec3 {x: 1.0, y: 1.0, z: 12.0}]);
+///
+/// assert! (frustum.intersects_aabb (&aabb));
+/// assert! (frustum.contains_aabb (&aabb));
+/// assert! (!frustum.intersects_aabb (&Aabb::from_points (&[Vec3 {x: 20.0, y: 0.0, z: 1.0}])));
+/// ```
+#[derive (Copy, Clone, Default)]
+pub struct Frustum {
+
+    // Public
+    /// The left, right, bottom, top, near and far planes, in that order.
+    pub planes: [Plane; 6],
+}
+
+/*===============================================================================================*/
+/*------FRUSTUM PUBLIC METHODS-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Frustum {
+
+    /// Returns true if the frustum contains a point.
+    pub fn contains_point (&self, point: &Vec3) -> bool {
+        self.planes.iter ().all (|plane| plane.get_distance (point) >= 0.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if a sphere is at least partially inside the frustum.
+    ///
+    /// Spheres near the corners of the frustum may be reported as intersecting when they are not.
+    pub fn intersects_sphere (&self, sphere: &Sphere) -> bool {
+        self.planes.iter ().all (|plane| plane.get_distance (&sphere.centre) >= -sphere.radius)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if a box is at least partially inside the frustum.
+    ///
+    /// Boxes near the corners of the frustum may be reported as intersecting when they are not.
+    pub fn intersects_aabb (&self, aabb: &Aabb) -> bool {
+
+        if aabb.is_empty () {
+            return false;
+        }
+
+        // The corner furthest along each plane normal must be in front of the plane
+        self.planes.iter ().all (|plane| plane.get_distance (&Frustum::_get_corner (aabb, &plane.normal, true)) >= 0.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if a box is entirely inside the frustum.
+    pub fn contains_aabb (&self, aabb: &Aabb) -> bool {
+
+        if aabb.is_empty () {
+            return false;
+        }
+
+        self.planes.iter ().all (|plane| plane.get_distance (&Frustum::_get_corner (aabb, &plane.normal, false)) >= 0.0)
+    }
+
+/*===============================================================================================*/
+/*------FRUSTUM PUBLIC STATIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns the frustum of a view-projection matrix.
+    ///
+    /// The planes are extracted from the matrix rows, for a clip space depth of -1 to 1.
+    pub fn from_matrix (view_projection: &Mat4) -> Frustum {
+
+        let m = *view_projection;
+        let row = |i: u8| [m[0][i], m[1][i], m[2][i], m[3][i]];
+        let plane = |a: [f32; 4], b: [f32; 4], sign: f32| {
+            Plane::from_coefficients (a[0] + b[0] * sign, a[1] + b[1] * sign, a[2] + b[2] * sign, a[3] + b[3] * sign)
+        };
+
+        let (x, y, z, w) = (row (0), row (1), row (2), row (3));
+
+        Frustum {planes: [plane (w, x, 1.0),
+                          plane (w, x, -1.0),
+                          plane (w, y, 1.0),
+                          plane (w, y, -1.0),
+                          plane (w, z, 1.0),
+                          plane (w, z, -1.0)]}
+    }
+}
+
+/*===============================================================================================*/
+/*------FRUSTUM PRIVATE STATIC METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Frustum {
+
+    // Returns the corner of a box furthest along a direction, or against it.
+    fn _get_corner (aabb: &Aabb, direction: &Vec3, positive: bool) -> Vec3 {
+
+        let pick = |d: f32, min: f32, max: f32| if (d >= 0.0) == positive {max} else {min};
+
+        Vec3 {x: pick (direction.x, aabb.min.x, aabb.max.x),
+              y: pick (direction.y, aabb.min.y, aabb.max.y),
+              z: pick (direction.z, aabb.min.z, aabb.max.z)}
+    }
+}
@@ -27,6 +27,23 @@ use std::cmp::PartialEq;
 ///
 /// This struct represents a 3x3 matrix. It is normally used for 2D graphics transformations,
 /// such as translation, rotation, and scaling.
+///
+/// The matrix is stored by row and transforms column vectors, so the translation lives in the
+/// last column and transformations apply from right to left.
+///
+/// # Examples
+/// ```
+/// # use ion_core::util::math::{Mat3, Vec2};
+/// #
+/// let point = Vec2 {x: 1.0, y: 0.0};
+///
+/// // Rotate by 90 degrees, then move 10 units along the x axis
+/// let mat = Mat3::translate (&Vec2 {x: 10.0, y: 0.0}) * Mat3::rotate (90.0);
+/// let moved = mat * point;
+///
+/// assert! ((moved.x - 10.0).abs () < 1e-3);
+/// assert! ((moved.y - 1.0).abs () < 1e-3);
+/// ```
 #[derive (Copy, Clone, Default, Serialize, Deserialize)]
 pub struct Mat3 {
 
@@ -381,8 +398,8 @@ impl Mul <Vec2> for Mat3 {
     // Multiplication operator (Vec2)
     fn mul (self, rhs: Vec2) -> Vec2 {
 
-        Vec2 {x : (self[0][0] * rhs.x) + (self[1][0] * rhs.y) + self[2][0],
-              y : (self[0][1] * rhs.x) + (self[1][1] * rhs.y) + self[2][1]}
+        Vec2 {x : (self[0][0] * rhs.x) + (self[0][1] * rhs.y) + self[0][2],
+              y : (self[1][0] * rhs.x) + (self[1][1] * rhs.y) + self[1][2]}
     }
 }
 
@@ -427,6 +427,22 @@ impl Mul <Vec3> for Mat4 {
 
 /*-----------------------------------------------------------------------------------------------*/
 
+impl Mul <Vec4> for Mat4 {
+
+    type Output = Vec4;
+
+    // Multiplication operator (Vec4)
+    fn mul (self, rhs: Vec4) -> Vec4 {
+
+        Vec4 {x: (self[0][0] * rhs.x) + (self[1][0] * rhs.y) + (self[2][0] * rhs.z) + (self[3][0] * rhs.w),
+              y: (self[0][1] * rhs.x) + (self[1][1] * rhs.y) + (self[2][1] * rhs.z) + (self[3][1] * rhs.w),
+              z: (self[0][2] * rhs.x) + (self[1][2] * rhs.y) + (self[2][2] * rhs.z) + (self[3][2] * rhs.w),
+              w: (self[0][3] * rhs.x) + (self[1][3] * rhs.y) + (self[2][3] * rhs.z) + (self[3][3] * rhs.w)}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
 impl MulAssign for Mat4 {
 
     // Multiplication assignment operator (matrix)
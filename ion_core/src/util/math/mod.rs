@@ -23,6 +23,7 @@
 //! Quaternions,
 //! Colours,
 //! Bounding volumes,
+//! Intersection and distance queries,
 //! Random numbers,
 //! Utilities, etc.
 /*===============================================================================================*/
@@ -36,22 +37,44 @@ pub static TWO_PI  : f32 = consts::PI;
 pub static PI      : f32 = consts::PI * 2.0;
 /// Half of PI
 pub static HALF_PI : f32 = consts::PI / 2.0;
+/// A small value, below which lengths and angles are treated as zero by geometric queries
+pub static EPSILON : f32 = 0.000001;
 
 // Modules
+mod aabb;
+mod capsule;
+mod cascade;
 mod colour;
+mod frustum;
 mod mat3;
 mod mat4;
+mod obb;
+mod plane;
 mod quat;
+mod ray;
+mod segment;
+mod sphere;
+mod triangle;
 mod util;
 mod vec2;
 mod vec3;
 mod vec4;
 
+pub use self::aabb::Aabb;
+pub use self::capsule::Capsule;
+pub use self::cascade::Cascade;
 pub use self::colour::Colour;
+pub use self::frustum::Frustum;
 pub use self::util::Util;
 pub use self::mat3::Mat3;
 pub use self::mat4::Mat4;
+pub use self::obb::Obb;
+pub use self::plane::Plane;
 pub use self::quat::Quat;
+pub use self::ray::Ray;
+pub use self::segment::Segment;
+pub use self::sphere::Sphere;
+pub use self::triangle::Triangle;
 pub use self::vec2::Vec2;
 pub use self::vec3::Vec3;
 pub use self::vec4::Vec4;
@@ -0,0 +1,217 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Aabb, EPSILON, Mat4, Sphere, Util, Vec3};
+
+/*===============================================================================================*/
+/*------OBB STRUCT-------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An oriented bounding box.
+///
+/// The box is centred on a point, with a half size along each of three perpendicular axes.
+#[derive (Copy, Clone, Serialize, Deserialize)]
+pub struct Obb {
+
+    // Public
+    /// The centre of the box.
+    pub centre: Vec3,
+    /// The local x, y and z axes of the box. They should be normalized and perpendicular.
+    pub axes: [Vec3; 3],
+    /// The half size of the box along each of its axes.
+    pub extents: Vec3,
+}
+
+/*===============================================================================================*/
+/*------OBB PUBLIC METHODS-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Obb {
+
+    /// Returns the point in the box closest to a point.
+    ///
+    /// Points inside the box are returned unchanged.
+    pub fn get_closest_point (&self, point: &Vec3) -> Vec3 {
+
+        let offset = *point - self.centre;
+        let mut closest = self.centre;
+
+        for i in 0..3 {
+
+            let distance = Vec3::dot (&offset, &self.axes[i]);
+            let extent = self.extents[i as u8];
+
+            closest += self.axes[i] * Util::clamp (distance, -extent, extent);
+        }
+
+        closest
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the box contains a point.
+    pub fn contains_point (&self, point: &Vec3) -> bool {
+
+        let offset = *point - self.centre;
+        (0..3).all (|i| Vec3::dot (&offset, &self.axes[i]).abs () <= self.extents[i as u8])
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the axis aligned box bounding this box.
+    pub fn get_bounds (&self) -> Aabb {
+
+        let mut extents = Vec3::new ();
+
+        for i in 0..3 {
+
+            let axis = self.axes[i] * self.extents[i as u8];
+            extents += Vec3 {x: axis.x.abs (), y: axis.y.abs (), z: axis.z.abs ()};
+        }
+
+        Aabb::from_centre_extents (&self.centre, &extents)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the box overlaps a sphere.
+    pub fn intersects_sphere (&self, sphere: &Sphere) -> bool {
+        Vec3::distance (&self.get_closest_point (&sphere.centre), &sphere.centre) <= sphere.radius
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the box overlaps another box.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Aabb, Mat4, Obb, Vec3};
+    /// #
+    /// let unit = Aabb::from_points (&[Vec3 {x: -1.0, y: -1.0, z: -1.0}, Vec3 {x: 1.0, y: 1.0, z: 1.0}]);
+    /// let a = Obb::from_aabb (&unit, &Mat4::identity ());
+    ///
+    /// // A box turned 45 degrees about z reaches further along x
+    /// let turned = Mat4::translate (&Vec3 {x: 2.3, y: 0.0, z: 0.0}) * Mat4::rotate (&Vec3 {x: 0.0, y: 0.0, z: 45.0});
+    /// assert! (a.intersects_obb (&Obb::from_aabb (&unit, &turned)));
+    ///
+    /// // Separated along the diagonal, although their bounds overlap
+    /// let diagonal = Mat4::translate (&Vec3 {x: 2.2, y: 2.2, z: 0.0}) * Mat4::rotate (&Vec3 {x: 0.0, y: 0.0, z: 45.0});
+    /// let b = Obb::from_aabb (&unit, &diagonal);
+    ///
+    /// assert! (a.get_bounds ().intersects (&b.get_bounds ()));
+    /// assert! (!a.intersects_obb (&b));
+    ///
+    /// // Parallel edges, and touching faces
+    /// assert! (a.intersects_obb (&Obb::from_aabb (&unit, &Mat4::translate (&Vec3 {x: 2.0, y: 0.0, z: 0.0}))));
+    /// ```
+    pub fn intersects_obb (&self, other: &Obb) -> bool {
+
+        // Rotation of the other box in the space of this box, padded for parallel edges
+        let mut rotation = [[0.0; 3]; 3];
+        let mut abs_rotation = [[0.0; 3]; 3];
+
+        for i in 0..3 {
+
+            for j in 0..3 {
+
+                rotation[i][j] = Vec3::dot (&self.axes[i], &other.axes[j]);
+                abs_rotation[i][j] = rotation[i][j].abs () + EPSILON;
+            }
+        }
+
+        let offset = other.centre - self.centre;
+        let t = [Vec3::dot (&offset, &self.axes[0]), Vec3::dot (&offset, &self.axes[1]), Vec3::dot (&offset, &self.axes[2])];
+        let a = [self.extents.x, self.extents.y, self.extents.z];
+        let b = [other.extents.x, other.extents.y, other.extents.z];
+
+        // Axes of this box
+        for i in 0..3 {
+
+            let rb = b[0] * abs_rotation[i][0] + b[1] * abs_rotation[i][1] + b[2] * abs_rotation[i][2];
+
+            if t[i].abs () > a[i] + rb {
+                return false;
+            }
+        }
+
+        // Axes of the other box
+        for j in 0..3 {
+
+            let ra = a[0] * abs_rotation[0][j] + a[1] * abs_rotation[1][j] + a[2] * abs_rotation[2][j];
+            let distance = t[0] * rotation[0][j] + t[1] * rotation[1][j] + t[2] * rotation[2][j];
+
+            if distance.abs () > ra + b[j] {
+                return false;
+            }
+        }
+
+        // Cross products of an axis of each box
+        for i in 0..3 {
+
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+
+            for j in 0..3 {
+
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+
+                let ra = a[i1] * abs_rotation[i2][j] + a[i2] * abs_rotation[i1][j];
+                let rb = b[j1] * abs_rotation[i][j2] + b[j2] * abs_rotation[i][j1];
+                let distance = t[i2] * rotation[i1][j] - t[i1] * rotation[i2][j];
+
+                if distance.abs () > ra + rb {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+/*===============================================================================================*/
+/*------OBB PUBLIC STATIC METHODS----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new box, aligned to the world axes.
+    pub fn new (centre: &Vec3, extents: &Vec3) -> Obb {
+
+        Obb {centre: *centre,
+             axes: [Vec3::right (), Vec3::up (), Vec3::forward ()],
+             extents: *extents}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns an axis aligned box, transformed by a matrix.
+    ///
+    /// The matrix must not be sheared.
+    pub fn from_aabb (aabb: &Aabb, matrix: &Mat4) -> Obb {
+
+        let m = *matrix;
+        let mut obb = Obb::new (&(m * aabb.get_center ()), &aabb.get_extents ());
+
+        for i in 0..3 {
+
+            let column = Vec3 {x: m[i][0], y: m[i][1], z: m[i][2]};
+            let scale = Vec3::length (&column);
+
+            obb.axes[i as usize] = Vec3::normalize (&column);
+            obb.extents[i] *= scale;
+        }
+
+        obb
+    }
+}
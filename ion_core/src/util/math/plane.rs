@@ -0,0 +1,146 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Aabb, Sphere, Vec3};
+
+/*===============================================================================================*/
+/*------PLANE STRUCT-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An infinite plane, dividing space into a front and a back half.
+///
+/// Points on the plane satisfy `dot (normal, point) == distance`.
+#[derive (Copy, Clone, Default, Serialize, Deserialize)]
+pub struct Plane {
+
+    // Public
+    /// The normal of the plane, pointing to the front half. It should be normalized.
+    pub normal: Vec3,
+    /// The distance of the plane from the origin, along the normal.
+    pub distance: f32,
+}
+
+/*===============================================================================================*/
+/*------PLANE PUBLIC METHODS---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Plane {
+
+    /// Returns the signed distance from the plane to a point.
+    ///
+    /// Points in front of the plane have a positive distance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Plane, Vec3};
+    /// #
+    /// let plane = Plane::from_point_normal (&Vec3 {x: 0.0, y: 2.0, z: 0.0}, &Vec3::up ());
+    ///
+    /// assert_eq! (plane.get_distance (&Vec3 {x: 5.0, y: 3.0, z: -1.0}), 1.0);
+    /// assert_eq! (plane.get_distance (&Vec3::new ()), -2.0);
+    /// ```
+    pub fn get_distance (&self, point: &Vec3) -> f32 {
+        Vec3::dot (&self.normal, point) - self.distance
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the point on the plane closest to a point.
+    pub fn get_closest_point (&self, point: &Vec3) -> Vec3 {
+        *point - self.normal * self.get_distance (point)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if a sphere touches or crosses the plane.
+    pub fn intersects_sphere (&self, sphere: &Sphere) -> bool {
+        self.get_distance (&sphere.centre).abs () <= sphere.radius
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if a box touches or crosses the plane.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Aabb, Plane, Vec3};
+    /// #
+    /// let plane = Plane::new (&Vec3 {x: 1.0, y: 1.0, z: 0.0}, 0.0);
+    ///
+    /// assert! (plane.intersects_aabb (&Aabb::from_points (&[Vec3 {x: -1.0, y: 0.5, z: 0.0}, Vec3 {x: 0.0, y: 1.0, z: 1.0}])));
+    /// assert! (!plane.intersects_aabb (&Aabb::from_points (&[Vec3 {x: 0.5, y: 0.5, z: 0.0}, Vec3 {x: 1.0, y: 1.0, z: 1.0}])));
+    /// ```
+    pub fn intersects_aabb (&self, aabb: &Aabb) -> bool {
+
+        if aabb.is_empty () {
+            return false;
+        }
+
+        let extents = aabb.get_extents ();
+        let radius = extents.x * self.normal.x.abs () + extents.y * self.normal.y.abs () + extents.z * self.normal.z.abs ();
+
+        self.get_distance (&aabb.get_center ()).abs () <= radius
+    }
+
+/*===============================================================================================*/
+/*------PLANE PUBLIC STATIC METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new plane.
+    ///
+    /// The normal is normalized.
+    pub fn new (normal: &Vec3, distance: f32) -> Plane {
+        Plane {normal: Vec3::normalize (normal), distance: distance}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the plane through a point, with a normal.
+    ///
+    /// The normal is normalized.
+    pub fn from_point_normal (point: &Vec3, normal: &Vec3) -> Plane {
+
+        let normal = Vec3::normalize (normal);
+        Plane {normal: normal, distance: Vec3::dot (&normal, point)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the plane through three points.
+    ///
+    /// The front of the plane faces the side the points wind counter-clockwise on.
+    pub fn from_points (a: &Vec3, b: &Vec3, c: &Vec3) -> Plane {
+        Plane::from_point_normal (a, &Vec3::cross (&(*b - *a), &(*c - *a)))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the plane `a * x + b * y + c * z + d = 0`, normalized.
+    ///
+    /// The front of the plane is where the equation is positive.
+    pub fn from_coefficients (a: f32, b: f32, c: f32, d: f32) -> Plane {
+
+        let normal = Vec3 {x: a, y: b, z: c};
+        let length = Vec3::length (&normal);
+
+        if length == 0.0 {
+            return Plane::default ();
+        }
+
+        Plane {normal: normal / length, distance: -d / length}
+    }
+}
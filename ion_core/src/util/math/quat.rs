@@ -14,6 +14,12 @@
 // limitations under the License.
 /*===============================================================================================*/
 
+
+use ::util::math::{Mat4, Util, Vec3, Vec4};
+
+use std::ops::*;
+use std::cmp::PartialEq;
+
 /*===============================================================================================*/
 /*------QUAT STRUCT------------------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -21,6 +27,24 @@
 /// Quaternion implementation.
 ///
 /// Allows for representing rotations without gimbal lock.
+///
+/// # Examples
+/// ```
+/// # use ion_core::util::math::{Mat4, Quat, Vec3};
+/// #
+/// let rotation = Quat::from_axis_angle (&Vec3::up (), 90.0);
+/// let point = rotation * Vec3::right ();
+///
+/// assert! (Vec3::distance (&point, &Vec3::back ()) < 0.0001);
+///
+/// // Rotations are combined by multiplying, and undone by the conjugate
+/// let twice = rotation * rotation;
+/// assert! (Vec3::distance (&(twice * Vec3::right ()), &Vec3::left ()) < 0.0001);
+/// assert! (Vec3::distance (&(Quat::conjugate (&rotation) * point), &Vec3::right ()) < 0.0001);
+///
+/// // The matrix rotates points the same way
+/// assert! (Vec3::distance (&(Quat::to_mat4 (&rotation) * Vec3::right ()), &point) < 0.0001);
+/// ```
 #[derive (Copy, Clone, Default, Serialize, Deserialize)]
 pub struct Quat {
 
@@ -34,3 +58,256 @@ pub struct Quat {
     /// W-axis coordinate.
     pub w: f32,
 }
+
+/*===============================================================================================*/
+/*------QUAT PUBLIC METHODS----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Quat {
+
+    /// Formats the quaternion as a string.
+    pub fn to_string (&self) -> String {
+        format! ("{}, {}, {}, {}", self.x, self.y, self.z, self.w)
+    }
+
+/*===============================================================================================*/
+/*------QUAT PUBLIC STATIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Creates a quaternion representing no rotation.
+    pub fn identity () -> Quat {
+
+        Quat {x: 0.0,
+              y: 0.0,
+              z: 0.0,
+              w: 1.0}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates a rotation around an axis.
+    ///
+    /// # Arguments
+    /// * `axis` - The axis to rotate around. It does not need to be normalized.
+    /// * `angle` - The counter-clockwise rotation in degrees, looking down the axis.
+    pub fn from_axis_angle (axis: &Vec3, angle: f32) -> Quat {
+
+        let axis = Vec3::normalize (axis);
+        let half_angle = Util::deg2rad (angle) * 0.5;
+        let sin = half_angle.sin ();
+
+        Quat {x: axis.x * sin,
+              y: axis.y * sin,
+              z: axis.z * sin,
+              w: half_angle.cos ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Creates a rotation which turns `Vec3::forward ()` to face a direction.
+    ///
+    /// `Vec3::up ()` is turned as close to the up vector as possible. Neither vector needs to be
+    /// normalized. If they are parallel, a different up vector is picked.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Quat, Vec3};
+    /// #
+    /// let rotation = Quat::look_rotation (&Vec3::right (), &Vec3::up ());
+    ///
+    /// assert! (Vec3::distance (&(rotation * Vec3::forward ()), &Vec3::right ()) < 0.0001);
+    /// assert! (Vec3::distance (&(rotation * Vec3::up ()), &Vec3::up ()) < 0.0001);
+    ///
+    /// // The up vector only needs to be roughly perpendicular
+    /// let rotation = Quat::look_rotation (&Vec3::back (), &Vec3 {x: 0.0, y: 1.0, z: 1.0});
+    ///
+    /// assert! (Vec3::distance (&(rotation * Vec3::forward ()), &Vec3::back ()) < 0.0001);
+    /// assert! (Vec3::distance (&(rotation * Vec3::up ()), &Vec3::up ()) < 0.0001);
+    /// ```
+    pub fn look_rotation (forward: &Vec3, up: &Vec3) -> Quat {
+
+        let forward = Vec3::normalize (forward);
+        let mut right = Vec3::cross (up, &forward);
+
+        if Vec3::length (&right) < 0.000001 {
+
+            let fallback = if forward.x.abs () < 0.9 {Vec3::right ()} else {Vec3::up ()};
+            right = Vec3::cross (&fallback, &forward);
+        }
+
+        let right = Vec3::normalize (&right);
+        let up = Vec3::cross (&forward, &right);
+
+        // Convert the rotation matrix with the basis vectors as columns
+        let trace = right.x + up.y + forward.z;
+
+        let quat = if trace > 0.0 {
+
+            let s = (trace + 1.0).sqrt () * 2.0;
+
+            Quat {x: (up.z - forward.y) / s,
+                  y: (forward.x - right.z) / s,
+                  z: (right.y - up.x) / s,
+                  w: 0.25 * s}
+        }
+
+        else if right.x > up.y && right.x > forward.z {
+
+            let s = (1.0 + right.x - up.y - forward.z).sqrt () * 2.0;
+
+            Quat {x: 0.25 * s,
+                  y: (up.x + right.y) / s,
+                  z: (forward.x + right.z) / s,
+                  w: (up.z - forward.y) / s}
+        }
+
+        else if up.y > forward.z {
+
+            let s = (1.0 + up.y - right.x - forward.z).sqrt () * 2.0;
+
+            Quat {x: (up.x + right.y) / s,
+                  y: 0.25 * s,
+                  z: (forward.y + up.z) / s,
+                  w: (forward.x - right.z) / s}
+        }
+
+        else {
+
+            let s = (1.0 + forward.z - right.x - up.y).sqrt () * 2.0;
+
+            Quat {x: (forward.x + right.z) / s,
+                  y: (forward.y + up.z) / s,
+                  z: 0.25 * s,
+                  w: (right.y - up.x) / s}
+        };
+
+        Quat::normalize (&quat)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the conjugate of a quaternion.
+    ///
+    /// For a normalized quaternion, this is the opposite rotation.
+    pub fn conjugate (quat: &Quat) -> Quat {
+
+        Quat {x: -quat.x,
+              y: -quat.y,
+              z: -quat.z,
+              w:  quat.w}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the dot product of two quaternions.
+    pub fn dot (lhs: &Quat, rhs: &Quat) -> f32 {
+
+        (lhs.x * rhs.x) +
+        (lhs.y * rhs.y) +
+        (lhs.z * rhs.z) +
+        (lhs.w * rhs.w)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the length of a quaternion.
+    pub fn length (quat: &Quat) -> f32 {
+        Quat::dot (quat, quat).sqrt ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a normalized quaternion.
+    ///
+    /// A zero length quaternion returns the identity.
+    pub fn normalize (quat: &Quat) -> Quat {
+
+        let length = Quat::length (quat);
+
+        if length != 0.0 {
+
+            return Quat {x: quat.x / length,
+                         y: quat.y / length,
+                         z: quat.z / length,
+                         w: quat.w / length}
+        }
+
+        Quat::identity ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the rotation matrix of a normalized quaternion.
+    pub fn to_mat4 (quat: &Quat) -> Mat4 {
+
+        let (x, y, z, w) = (quat.x, quat.y, quat.z, quat.w);
+        let mut matrix = Mat4::identity ();
+
+        matrix[0] = Vec4 {x: 1.0 - 2.0 * (y * y + z * z), y: 2.0 * (x * y + w * z),       z: 2.0 * (x * z - w * y),       w: 0.0};
+        matrix[1] = Vec4 {x: 2.0 * (x * y - w * z),       y: 1.0 - 2.0 * (x * x + z * z), z: 2.0 * (y * z + w * x),       w: 0.0};
+        matrix[2] = Vec4 {x: 2.0 * (x * z + w * y),       y: 2.0 * (y * z - w * x),       z: 1.0 - 2.0 * (x * x + y * y), w: 0.0};
+
+        matrix
+    }
+}
+
+/*===============================================================================================*/
+/*------QUAT OPERATOR OVERLOADS------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Mul for Quat {
+
+    type Output = Quat;
+
+    // Multiplication operator (quaternion)
+    // The result applies the right hand rotation first.
+    fn mul (self, rhs: Quat) -> Quat {
+
+        Quat {x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+              y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+              z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+              w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Mul <Vec3> for Quat {
+
+    type Output = Vec3;
+
+    // Multiplication operator (Vec3)
+    // Rotates the vector by a normalized quaternion.
+    fn mul (self, rhs: Vec3) -> Vec3 {
+
+        let axis = Vec3 {x: self.x, y: self.y, z: self.z};
+        let t = Vec3::cross (&axis, &rhs) * 2.0;
+
+        rhs + t * self.w + Vec3::cross (&axis, &t)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl PartialEq for Quat {
+
+    // Equal to operator
+    fn eq (&self, rhs: &Quat) -> bool {
+
+        self.x == rhs.x &&
+        self.y == rhs.y &&
+        self.z == rhs.z &&
+        self.w == rhs.w
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Not equal to operator
+    fn ne (&self, rhs: &Quat) -> bool {
+
+        self.x != rhs.x ||
+        self.y != rhs.y ||
+        self.z != rhs.z ||
+        self.w != rhs.w
+    }
+}
@@ -0,0 +1,377 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Aabb, Capsule, EPSILON, Obb, Plane, Sphere, Triangle, Util, Vec3};
+
+use std::f32;
+
+/*===============================================================================================*/
+/*------RAY STRUCT-------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A half-line, starting at an origin and extending infinitely in one direction.
+///
+/// It is used for picking, and other intersection queries.
+#[derive (Copy, Clone, Default, Serialize, Deserialize)]
+pub struct Ray {
+
+    // Public
+    /// The start point of the ray.
+    pub origin: Vec3,
+    /// The direction of the ray. It should be normalized.
+    pub direction: Vec3,
+}
+
+/*===============================================================================================*/
+/*------RAY PUBLIC METHODS-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Ray {
+
+    /// Returns the point at a distance along the ray.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Ray, Vec3};
+    /// #
+    /// let ray = Ray::new (&Vec3::new (), &Vec3 {x: 0.0, y: 0.0, z: -2.0});
+    /// let point = ray.get_point (3.0);
+    ///
+    /// assert_eq! (point.z, -3.0);
+    /// ```
+    pub fn get_point (&self, distance: f32) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance along the ray to the first point inside a box.
+    ///
+    /// The distance is zero if the ray starts inside the box, and None if the ray misses it.  
+    /// Empty boxes are never hit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Aabb, Ray, Vec3};
+    /// #
+    /// let aabb = Aabb::from_points (&[Vec3 {x: -1.0, y: -1.0, z: 4.0}, Vec3 {x: 1.0, y: 1.0, z: 6.0}]);
+    ///
+    /// assert_eq! (Ray::new (&Vec3::new (), &Vec3::forward ()).intersect_aabb (&aabb), Some (4.0));
+    /// assert_eq! (Ray::new (&Vec3::new (), &Vec3::back ()).intersect_aabb (&aabb), None);
+    /// assert_eq! (Ray::new (&Vec3 {x: 0.0, y: 0.0, z: 5.0}, &Vec3::up ()).intersect_aabb (&aabb), Some (0.0));
+    /// assert_eq! (Ray::new (&Vec3::new (), &Vec3 {x: 1.0, y: 1.0, z: 1.0}).intersect_aabb (&Aabb::new ()), None);
+    /// ```
+    pub fn intersect_aabb (&self, aabb: &Aabb) -> Option<f32> {
+
+        if aabb.is_empty () {
+            return None;
+        }
+
+        let mut near = 0.0;
+        let mut far = f32::MAX;
+
+        for axis in 0..3 {
+
+            let origin = self.origin[axis];
+            let direction = self.direction[axis];
+            let (min, max) = (aabb.min[axis], aabb.max[axis]);
+
+            // Parallel to the slab, so the origin has to be between its planes
+            if direction == 0.0 {
+
+                if origin < min || origin > max {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let t0 = (min - origin) / direction;
+            let t1 = (max - origin) / direction;
+
+            near = Util::max (near, Util::min (t0, t1));
+            far = Util::min (far, Util::max (t0, t1));
+
+            if near > far {
+                return None;
+            }
+        }
+
+        Some (near)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the point on the ray closest to a point.
+    pub fn get_closest_point (&self, point: &Vec3) -> Vec3 {
+        self.get_point (Util::max (Vec3::dot (&(*point - self.origin), &self.direction), 0.0))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance from the ray to a point.
+    pub fn get_distance (&self, point: &Vec3) -> f32 {
+        Vec3::distance (&self.get_closest_point (point), point)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance along the ray to a plane.
+    ///
+    /// Rays parallel to the plane never hit it, even if they start on it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Plane, Ray, Vec3};
+    /// #
+    /// let ground = Plane::new (&Vec3::up (), 0.0);
+    ///
+    /// assert_eq! (Ray::new (&Vec3 {x: 3.0, y: 2.0, z: 0.0}, &Vec3::down ()).intersect_plane (&ground), Some (2.0));
+    ///
+    /// // From below, pointing away, and parallel
+    /// assert_eq! (Ray::new (&Vec3 {x: 0.0, y: -2.0, z: 0.0}, &Vec3::up ()).intersect_plane (&ground), Some (2.0));
+    /// assert_eq! (Ray::new (&Vec3 {x: 0.0, y: 2.0, z: 0.0}, &Vec3::up ()).intersect_plane (&ground), None);
+    /// assert_eq! (Ray::new (&Vec3::new (), &Vec3::right ()).intersect_plane (&ground), None);
+    /// ```
+    pub fn intersect_plane (&self, plane: &Plane) -> Option<f32> {
+
+        let denominator = Vec3::dot (&plane.normal, &self.direction);
+
+        if denominator.abs () <= EPSILON {
+            return None;
+        }
+
+        let distance = -plane.get_distance (&self.origin) / denominator;
+
+        if distance >= 0.0 {Some (distance)} else {None}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance along the ray to the first point inside a sphere.
+    ///
+    /// The distance is zero if the ray starts inside the sphere.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Ray, Sphere, Vec3};
+    /// #
+    /// let sphere = Sphere::new (&Vec3 {x: 0.0, y: 0.0, z: 5.0}, 1.0);
+    ///
+    /// assert_eq! (Ray::new (&Vec3::new (), &Vec3::forward ()).intersect_sphere (&sphere), Some (4.0));
+    /// assert_eq! (Ray::new (&sphere.centre, &Vec3::up ()).intersect_sphere (&sphere), Some (0.0));
+    /// assert_eq! (Ray::new (&Vec3::new (), &Vec3::back ()).intersect_sphere (&sphere), None);
+    ///
+    /// // Grazing the surface
+    /// assert_eq! (Ray::new (&Vec3 {x: 1.0, y: 0.0, z: 0.0}, &Vec3::forward ()).intersect_sphere (&sphere), Some (5.0));
+    /// assert_eq! (Ray::new (&Vec3 {x: 1.5, y: 0.0, z: 0.0}, &Vec3::forward ()).intersect_sphere (&sphere), None);
+    /// ```
+    pub fn intersect_sphere (&self, sphere: &Sphere) -> Option<f32> {
+
+        let offset = self.origin - sphere.centre;
+        let b = Vec3::dot (&offset, &self.direction);
+        let c = Vec3::dot (&offset, &offset) - sphere.radius * sphere.radius;
+
+        // Outside the sphere, and pointing away from it
+        if c > 0.0 && b > 0.0 {
+            return None;
+        }
+
+        let discriminant = b * b - c;
+
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        Some (Util::max (-b - discriminant.sqrt (), 0.0))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance along the ray to a triangle.
+    ///
+    /// Both faces of the triangle are hit. Rays in the plane of the triangle never hit it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Ray, Triangle, Vec3};
+    /// #
+    /// let triangle = Triangle::new (&Vec3 {x: -1.0, y: -1.0, z: 3.0},
+    ///                               &Vec3 {x: 1.0, y: -1.0, z: 3.0},
+    ///                               &Vec3 {x: 0.0, y: 1.0, z: 3.0});
+    ///
+    /// assert_eq! (Ray::new (&Vec3::new (), &Vec3::forward ()).intersect_triangle (&triangle), Some (3.0));
+    ///
+    /// // From behind, pointing away, outside the edges, and in the plane
+    /// let behind = Vec3 {x: 0.0, y: 0.0, z: 5.0};
+    ///
+    /// assert_eq! (Ray::new (&behind, &Vec3::back ()).intersect_triangle (&triangle), Some (2.0));
+    /// assert_eq! (Ray::new (&behind, &Vec3::forward ()).intersect_triangle (&triangle), None);
+    /// assert_eq! (Ray::new (&Vec3 {x: 0.9, y: 0.9, z: 0.0}, &Vec3::forward ()).intersect_triangle (&triangle), None);
+    /// assert_eq! (Ray::new (&Vec3 {x: -5.0, y: 0.0, z: 3.0}, &Vec3::right ()).intersect_triangle (&triangle), None);
+    /// ```
+    pub fn intersect_triangle (&self, triangle: &Triangle) -> Option<f32> {
+
+        let edge1 = triangle.b - triangle.a;
+        let edge2 = triangle.c - triangle.a;
+
+        let p = Vec3::cross (&self.direction, &edge2);
+        let determinant = Vec3::dot (&edge1, &p);
+
+        if determinant.abs () <= EPSILON {
+            return None;
+        }
+
+        let inverse = 1.0 / determinant;
+        let offset = self.origin - triangle.a;
+
+        // Barycentric coordinates of the hit point
+        let u = Vec3::dot (&offset, &p) * inverse;
+
+        if u < 0.0 || u > 1.0 {
+            return None;
+        }
+
+        let q = Vec3::cross (&offset, &edge1);
+        let v = Vec3::dot (&self.direction, &q) * inverse;
+
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = Vec3::dot (&edge2, &q) * inverse;
+
+        if distance >= 0.0 {Some (distance)} else {None}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance along the ray to the first point inside a capsule.
+    ///
+    /// The distance is zero if the ray starts inside the capsule.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Capsule, Ray, Vec3};
+    /// #
+    /// let capsule = Capsule::new (&Vec3::new (), &Vec3 {x: 0.0, y: 4.0, z: 0.0}, 1.0);
+    ///
+    /// // The side, a cap, and along the axis
+    /// assert_eq! (Ray::new (&Vec3 {x: -5.0, y: 2.0, z: 0.0}, &Vec3::right ()).intersect_capsule (&capsule), Some (4.0));
+    /// assert_eq! (Ray::new (&Vec3 {x: 0.0, y: 10.0, z: 0.0}, &Vec3::down ()).intersect_capsule (&capsule), Some (5.0));
+    /// assert_eq! (Ray::new (&Vec3 {x: 0.0, y: -3.0, z: 0.0}, &Vec3::up ()).intersect_capsule (&capsule), Some (2.0));
+    ///
+    /// // Inside, and passing beside it
+    /// assert_eq! (Ray::new (&Vec3 {x: 0.0, y: 1.0, z: 0.0}, &Vec3::right ()).intersect_capsule (&capsule), Some (0.0));
+    /// assert_eq! (Ray::new (&Vec3 {x: -5.0, y: 2.0, z: 1.5}, &Vec3::right ()).intersect_capsule (&capsule), None);
+    /// ```
+    pub fn intersect_capsule (&self, capsule: &Capsule) -> Option<f32> {
+
+        if capsule.contains_point (&self.origin) {
+            return Some (0.0);
+        }
+
+        // The cylinder between the caps
+        let axis = capsule.end - capsule.start;
+        let offset = self.origin - capsule.start;
+
+        let axis_length_squared = Vec3::dot (&axis, &axis);
+        let axis_direction = Vec3::dot (&axis, &self.direction);
+        let axis_offset = Vec3::dot (&axis, &offset);
+
+        let a = axis_length_squared - axis_direction * axis_direction;
+        let mut body = None;
+
+        if a > EPSILON {
+
+            let b = axis_length_squared * Vec3::dot (&offset, &self.direction) - axis_offset * axis_direction;
+            let c = axis_length_squared * (Vec3::dot (&offset, &offset) - capsule.radius * capsule.radius) -
+                    axis_offset * axis_offset;
+
+            let discriminant = b * b - a * c;
+
+            if discriminant >= 0.0 {
+
+                let distance = (-b - discriminant.sqrt ()) / a;
+                let along = axis_offset + distance * axis_direction;
+
+                if distance >= 0.0 && along >= 0.0 && along <= axis_length_squared {
+                    body = Some (distance);
+                }
+            }
+        }
+
+        // The nearest hit of the cylinder and both caps
+        let hits = [body,
+                    self.intersect_sphere (&Sphere::new (&capsule.start, capsule.radius)),
+                    self.intersect_sphere (&Sphere::new (&capsule.end, capsule.radius))];
+
+        hits.iter ().filter_map (|hit| *hit).fold (None, |nearest, distance| {
+
+            match nearest {
+
+                Some (n) if n <= distance => Some (n),
+                _ => Some (distance)
+            }
+        })
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance along the ray to the first point inside an oriented box.
+    ///
+    /// The distance is zero if the ray starts inside the box.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Aabb, Mat4, Obb, Ray, Vec3};
+    /// #
+    /// let unit = Aabb::from_points (&[Vec3 {x: -1.0, y: -1.0, z: -1.0}, Vec3 {x: 1.0, y: 1.0, z: 1.0}]);
+    /// let obb = Obb::from_aabb (&unit, &(Mat4::translate (&Vec3 {x: 5.0, y: 0.0, z: 0.0}) *
+    ///                                    Mat4::rotate (&Vec3 {x: 0.0, y: 0.0, z: 45.0})));
+    ///
+    /// // The corner of the turned box points at the ray origin
+    /// let distance = Ray::new (&Vec3::new (), &Vec3::right ()).intersect_obb (&obb).unwrap ();
+    /// assert! ((distance - (5.0 - 2.0f32.sqrt ())).abs () < 0.0001);
+    ///
+    /// assert_eq! (Ray::new (&Vec3::new (), &Vec3::left ()).intersect_obb (&obb), None);
+    /// assert_eq! (Ray::new (&obb.centre, &Vec3::up ()).intersect_obb (&obb), Some (0.0));
+    /// ```
+    pub fn intersect_obb (&self, obb: &Obb) -> Option<f32> {
+
+        let offset = self.origin - obb.centre;
+        let local = Ray {origin: Vec3 {x: Vec3::dot (&offset, &obb.axes[0]),
+                                       y: Vec3::dot (&offset, &obb.axes[1]),
+                                       z: Vec3::dot (&offset, &obb.axes[2])},
+                         direction: Vec3 {x: Vec3::dot (&self.direction, &obb.axes[0]),
+                                          y: Vec3::dot (&self.direction, &obb.axes[1]),
+                                          z: Vec3::dot (&self.direction, &obb.axes[2])}};
+
+        local.intersect_aabb (&Aabb::from_centre_extents (&Vec3::new (), &obb.extents))
+    }
+
+/*===============================================================================================*/
+/*------RAY PUBLIC STATIC METHODS----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new ray.
+    ///
+    /// The direction is normalized.
+    pub fn new (origin: &Vec3, direction: &Vec3) -> Ray {
+        Ray {origin: *origin, direction: Vec3::normalize (direction)}
+    }
+}
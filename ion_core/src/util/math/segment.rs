@@ -0,0 +1,182 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{EPSILON, Util, Vec3};
+
+/*===============================================================================================*/
+/*------SEGMENT STRUCT---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A line segment between two points.
+#[derive (Copy, Clone, Default, Serialize, Deserialize)]
+pub struct Segment {
+
+    // Public
+    /// The start point of the segment.
+    pub start: Vec3,
+    /// The end point of the segment.
+    pub end: Vec3,
+}
+
+/*===============================================================================================*/
+/*------SEGMENT PUBLIC METHODS-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Segment {
+
+    /// Returns the length of the segment.
+    pub fn get_length (&self) -> f32 {
+        Vec3::distance (&self.start, &self.end)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the point on the segment closest to a point.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Segment, Vec3};
+    /// #
+    /// let segment = Segment::new (&Vec3::new (), &Vec3 {x: 4.0, y: 0.0, z: 0.0});
+    ///
+    /// // Beside, before and after the segment
+    /// assert! (segment.get_closest_point (&Vec3 {x: 1.0, y: 3.0, z: 0.0}) == Vec3 {x: 1.0, y: 0.0, z: 0.0});
+    /// assert! (segment.get_closest_point (&Vec3 {x: -2.0, y: 1.0, z: 0.0}) == Vec3::new ());
+    /// assert! (segment.get_closest_point (&Vec3 {x: 9.0, y: 0.0, z: 1.0}) == Vec3 {x: 4.0, y: 0.0, z: 0.0});
+    ///
+    /// // A segment with no length is a point
+    /// let point = Segment::new (&Vec3::up (), &Vec3::up ());
+    /// assert! (point.get_closest_point (&Vec3::new ()) == Vec3::up ());
+    /// ```
+    pub fn get_closest_point (&self, point: &Vec3) -> Vec3 {
+
+        let direction = self.end - self.start;
+        let length_squared = Vec3::dot (&direction, &direction);
+
+        if length_squared <= EPSILON {
+            return self.start;
+        }
+
+        let t = Util::clamp (Vec3::dot (&(*point - self.start), &direction) / length_squared, 0.0, 1.0);
+        self.start + direction * t
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the distance from the segment to a point.
+    pub fn get_distance (&self, point: &Vec3) -> f32 {
+        Vec3::distance (&self.get_closest_point (point), point)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the closest pair of points on two segments, the first on this segment.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Segment, Vec3};
+    /// #
+    /// let a = Segment::new (&Vec3 {x: -1.0, y: 0.0, z: 0.0}, &Vec3 {x: 1.0, y: 0.0, z: 0.0});
+    ///
+    /// // Crossing above
+    /// let b = Segment::new (&Vec3 {x: 0.5, y: 2.0, z: -1.0}, &Vec3 {x: 0.5, y: 2.0, z: 1.0});
+    /// let (p, q) = a.get_closest_points (&b);
+    ///
+    /// assert! (p == Vec3 {x: 0.5, y: 0.0, z: 0.0});
+    /// assert! (q == Vec3 {x: 0.5, y: 2.0, z: 0.0});
+    ///
+    /// // Parallel and overlapping
+    /// let c = Segment::new (&Vec3 {x: 0.0, y: 1.0, z: 0.0}, &Vec3 {x: 3.0, y: 1.0, z: 0.0});
+    /// let (p, q) = a.get_closest_points (&c);
+    ///
+    /// assert_eq! (Vec3::distance (&p, &q), 1.0);
+    ///
+    /// // Past the end of both segments
+    /// let d = Segment::new (&Vec3 {x: 3.0, y: 0.0, z: 1.0}, &Vec3 {x: 5.0, y: 0.0, z: 1.0});
+    /// let (p, q) = a.get_closest_points (&d);
+    ///
+    /// assert! (p == Vec3 {x: 1.0, y: 0.0, z: 0.0});
+    /// assert! (q == Vec3 {x: 3.0, y: 0.0, z: 1.0});
+    ///
+    /// // Against a point
+    /// let e = Segment::new (&Vec3 {x: 0.0, y: 0.0, z: 2.0}, &Vec3 {x: 0.0, y: 0.0, z: 2.0});
+    /// let (p, q) = a.get_closest_points (&e);
+    ///
+    /// assert! (p == Vec3::new () && q == e.start);
+    /// ```
+    pub fn get_closest_points (&self, other: &Segment) -> (Vec3, Vec3) {
+
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let r = self.start - other.start;
+
+        let a = Vec3::dot (&d1, &d1);
+        let e = Vec3::dot (&d2, &d2);
+        let f = Vec3::dot (&d2, &r);
+
+        let (s, t) = if a <= EPSILON && e <= EPSILON {
+            (0.0, 0.0)
+        }
+
+        else if a <= EPSILON {
+            (0.0, Util::clamp (f / e, 0.0, 1.0))
+        }
+
+        else {
+
+            let c = Vec3::dot (&d1, &r);
+
+            if e <= EPSILON {
+                (Util::clamp (-c / a, 0.0, 1.0), 0.0)
+            }
+
+            else {
+
+                // Closest points of the infinite lines, clamped to the first segment
+                let b = Vec3::dot (&d1, &d2);
+                let denominator = a * e - b * b;
+
+                let s = if denominator > EPSILON {Util::clamp ((b * f - c * e) / denominator, 0.0, 1.0)} else {0.0};
+                let t = (b * s + f) / e;
+
+                // Clamp to the second segment, and recompute the point on the first
+                if t < 0.0 {
+                    (Util::clamp (-c / a, 0.0, 1.0), 0.0)
+                }
+
+                else if t > 1.0 {
+                    (Util::clamp ((b - c) / a, 0.0, 1.0), 1.0)
+                }
+
+                else {
+                    (s, t)
+                }
+            }
+        };
+
+        (self.start + d1 * s, other.start + d2 * t)
+    }
+
+/*===============================================================================================*/
+/*------SEGMENT PUBLIC STATIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new segment.
+    pub fn new (start: &Vec3, end: &Vec3) -> Segment {
+        Segment {start: *start, end: *end}
+    }
+}
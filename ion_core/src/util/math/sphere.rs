@@ -0,0 +1,174 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Aabb, Mat4, Util, Vec3};
+
+/*===============================================================================================*/
+/*------SPHERE STRUCT----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A bounding sphere.
+#[derive (Copy, Clone, Default, Serialize, Deserialize)]
+pub struct Sphere {
+
+    // Public
+    /// The centre of the sphere.
+    pub centre: Vec3,
+    /// The radius of the sphere.
+    pub radius: f32,
+}
+
+/*===============================================================================================*/
+/*------SPHERE PUBLIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Sphere {
+
+    /// Returns true if the sphere contains a point.
+    pub fn contains_point (&self, point: &Vec3) -> bool {
+        Vec3::distance (&self.centre, point) <= self.radius
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the sphere overlaps another sphere.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Sphere, Vec3};
+    /// #
+    /// let a = Sphere::new (&Vec3::new (), 1.0);
+    ///
+    /// assert! (a.intersects_sphere (&Sphere::new (&Vec3 {x: 1.5, y: 0.0, z: 0.0}, 1.0)));
+    /// assert! (a.intersects_sphere (&Sphere::new (&Vec3 {x: 2.0, y: 0.0, z: 0.0}, 1.0)));
+    /// assert! (!a.intersects_sphere (&Sphere::new (&Vec3 {x: 2.0, y: 0.1, z: 0.0}, 1.0)));
+    ///
+    /// // Contained entirely
+    /// assert! (a.intersects_sphere (&Sphere::new (&Vec3::new (), 0.1)));
+    /// ```
+    pub fn intersects_sphere (&self, other: &Sphere) -> bool {
+
+        let offset = self.centre - other.centre;
+        let radius = self.radius + other.radius;
+
+        Vec3::dot (&offset, &offset) <= radius * radius
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the sphere overlaps a box.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Aabb, Sphere, Vec3};
+    /// #
+    /// let aabb = Aabb::from_points (&[Vec3::new (), Vec3 {x: 2.0, y: 2.0, z: 2.0}]);
+    ///
+    /// assert! (Sphere::new (&Vec3 {x: 1.0, y: 1.0, z: 1.0}, 0.5).intersects_aabb (&aabb));
+    /// assert! (Sphere::new (&Vec3 {x: 3.0, y: 1.0, z: 1.0}, 1.0).intersects_aabb (&aabb));
+    ///
+    /// // Near a corner, but outside the box
+    /// assert! (!Sphere::new (&Vec3 {x: 2.8, y: 2.8, z: 2.8}, 1.0).intersects_aabb (&aabb));
+    /// ```
+    pub fn intersects_aabb (&self, aabb: &Aabb) -> bool {
+
+        if aabb.is_empty () {
+            return false;
+        }
+
+        let offset = aabb.get_closest_point (&self.centre) - self.centre;
+        Vec3::dot (&offset, &offset) <= self.radius * self.radius
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the sphere transformed by a matrix.
+    ///
+    /// The radius is scaled by the largest scale of the matrix axes,  
+    /// so the result still bounds the transformed contents.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Mat4, Sphere, Vec3};
+    /// #
+    /// let sphere = Sphere::new (&Vec3::new (), 1.0);
+    /// let transform = Mat4::translate (&Vec3 {x: 5.0, y: 0.0, z: 0.0}) *
+    ///                 Mat4::scale (&Vec3 {x: 1.0, y: 3.0, z: 2.0});
+    ///
+    /// let moved = sphere.transform (&transform);
+    ///
+    /// assert_eq! (moved.centre.x, 5.0);
+    /// assert_eq! (moved.radius, 3.0);
+    /// ```
+    pub fn transform (&self, matrix: &Mat4) -> Sphere {
+
+        let m = *matrix;
+        let scale_x = Vec3::length (&Vec3 {x: m[0][0], y: m[0][1], z: m[0][2]});
+        let scale_y = Vec3::length (&Vec3 {x: m[1][0], y: m[1][1], z: m[1][2]});
+        let scale_z = Vec3::length (&Vec3 {x: m[2][0], y: m[2][1], z: m[2][2]});
+
+        Sphere {centre: m * self.centre,
+                radius: self.radius * Util::max (scale_x, Util::max (scale_y, scale_z))}
+    }
+
+/*===============================================================================================*/
+/*------SPHERE PUBLIC STATIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new sphere.
+    pub fn new (centre: &Vec3, radius: f32) -> Sphere {
+        Sphere {centre: *centre, radius: radius}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the smallest sphere containing a box.
+    pub fn from_aabb (aabb: &Aabb) -> Sphere {
+
+        if aabb.is_empty () {
+            return Sphere::default ();
+        }
+
+        Sphere {centre: aabb.get_center (),
+                radius: Vec3::length (&aabb.get_extents ())}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a sphere containing a set of points.
+    ///
+    /// The sphere is centred on the average of the points, so it is not always the smallest  
+    /// bounding sphere, but it is stable for points that move together.
+    pub fn from_points (points: &[Vec3]) -> Sphere {
+
+        if points.is_empty () {
+            return Sphere::default ();
+        }
+
+        let mut centre = Vec3::new ();
+
+        for point in points {
+            centre += *point;
+        }
+
+        centre /= points.len () as f32;
+
+        let radius = points.iter ().fold (0.0, |radius, point| Util::max (radius, Vec3::distance (&centre, point)));
+
+        Sphere {centre: centre, radius: radius}
+    }
+}
@@ -0,0 +1,229 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Aabb, Sphere, Util, Vec3};
+
+/*===============================================================================================*/
+/*------TRIANGLE STRUCT--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A triangle, defined by three corners.
+///
+/// The front face is the side the corners wind counter-clockwise on.
+#[derive (Copy, Clone, Default, Serialize, Deserialize)]
+pub struct Triangle {
+
+    // Public
+    /// The first corner.
+    pub a: Vec3,
+    /// The second corner.
+    pub b: Vec3,
+    /// The third corner.
+    pub c: Vec3,
+}
+
+/*===============================================================================================*/
+/*------TRIANGLE PUBLIC METHODS------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl Triangle {
+
+    /// Returns the normal of the front face.
+    pub fn get_normal (&self) -> Vec3 {
+        Vec3::normalize (&Vec3::cross (&(self.b - self.a), &(self.c - self.a)))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the area of the triangle.
+    pub fn get_area (&self) -> f32 {
+        Vec3::length (&Vec3::cross (&(self.b - self.a), &(self.c - self.a))) * 0.5
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the point on the triangle closest to a point.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Triangle, Vec3};
+    /// #
+    /// let triangle = Triangle::new (&Vec3::new (), &Vec3 {x: 4.0, y: 0.0, z: 0.0}, &Vec3 {x: 0.0, y: 4.0, z: 0.0});
+    ///
+    /// // Above the face
+    /// assert! (triangle.get_closest_point (&Vec3 {x: 1.0, y: 1.0, z: 5.0}) == Vec3 {x: 1.0, y: 1.0, z: 0.0});
+    ///
+    /// // Past a corner
+    /// assert! (triangle.get_closest_point (&Vec3 {x: -1.0, y: -1.0, z: 0.0}) == Vec3::new ());
+    /// assert! (triangle.get_closest_point (&Vec3 {x: 6.0, y: -1.0, z: 2.0}) == Vec3 {x: 4.0, y: 0.0, z: 0.0});
+    ///
+    /// // Past an edge
+    /// assert! (triangle.get_closest_point (&Vec3 {x: 2.0, y: -3.0, z: 0.0}) == Vec3 {x: 2.0, y: 0.0, z: 0.0});
+    /// assert! (triangle.get_closest_point (&Vec3 {x: 3.0, y: 3.0, z: 0.0}) == Vec3 {x: 2.0, y: 2.0, z: 0.0});
+    /// ```
+    pub fn get_closest_point (&self, point: &Vec3) -> Vec3 {
+
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+
+        // Corner a
+        let ap = *point - self.a;
+        let d1 = Vec3::dot (&ab, &ap);
+        let d2 = Vec3::dot (&ac, &ap);
+
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return self.a;
+        }
+
+        // Corner b
+        let bp = *point - self.b;
+        let d3 = Vec3::dot (&ab, &bp);
+        let d4 = Vec3::dot (&ac, &bp);
+
+        if d3 >= 0.0 && d4 <= d3 {
+            return self.b;
+        }
+
+        // Edge ab
+        let vc = d1 * d4 - d3 * d2;
+
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return self.a + ab * (d1 / (d1 - d3));
+        }
+
+        // Corner c
+        let cp = *point - self.c;
+        let d5 = Vec3::dot (&ab, &cp);
+        let d6 = Vec3::dot (&ac, &cp);
+
+        if d6 >= 0.0 && d5 <= d6 {
+            return self.c;
+        }
+
+        // Edge ac
+        let vb = d5 * d2 - d1 * d6;
+
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return self.a + ac * (d2 / (d2 - d6));
+        }
+
+        // Edge bc
+        let va = d3 * d6 - d5 * d4;
+
+        if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+            return self.b + (self.c - self.b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        // Inside the face
+        let denominator = 1.0 / (va + vb + vc);
+        self.a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the triangle overlaps a sphere.
+    pub fn intersects_sphere (&self, sphere: &Sphere) -> bool {
+        Vec3::distance (&self.get_closest_point (&sphere.centre), &sphere.centre) <= sphere.radius
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if the triangle overlaps a box.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_core::util::math::{Aabb, Triangle, Vec3};
+    /// #
+    /// let aabb = Aabb::from_points (&[Vec3 {x: -1.0, y: -1.0, z: -1.0}, Vec3 {x: 1.0, y: 1.0, z: 1.0}]);
+    ///
+    /// // Cutting through the box, with every corner outside it
+    /// let through = Triangle::new (&Vec3 {x: -5.0, y: -5.0, z: 0.0},
+    ///                              &Vec3 {x: 5.0, y: -5.0, z: 0.0},
+    ///                              &Vec3 {x: 0.0, y: 5.0, z: 0.0});
+    /// assert! (through.intersects_aabb (&aabb));
+    ///
+    /// // Inside the box
+    /// let inside = Triangle::new (&Vec3::new (), &Vec3 {x: 0.5, y: 0.0, z: 0.0}, &Vec3 {x: 0.0, y: 0.5, z: 0.0});
+    /// assert! (inside.intersects_aabb (&aabb));
+    ///
+    /// // Near a corner of the box, but only its bounds overlap
+    /// let corner = Triangle::new (&Vec3 {x: 3.5, y: 0.0, z: 0.0},
+    ///                             &Vec3 {x: 0.0, y: 3.5, z: 0.0},
+    ///                             &Vec3 {x: 0.0, y: 0.0, z: 3.5});
+    /// assert! (!corner.intersects_aabb (&aabb));
+    ///
+    /// // Touching a face
+    /// let touching = Triangle::new (&Vec3 {x: 1.0, y: 0.0, z: 0.0},
+    ///                               &Vec3 {x: 3.0, y: 0.0, z: 0.0},
+    ///                               &Vec3 {x: 3.0, y: 1.0, z: 0.0});
+    /// assert! (touching.intersects_aabb (&aabb));
+    /// ```
+    pub fn intersects_aabb (&self, aabb: &Aabb) -> bool {
+
+        if aabb.is_empty () {
+            return false;
+        }
+
+        // Separating axis test, with the box moved to the origin
+        let centre = aabb.get_center ();
+        let extents = aabb.get_extents ();
+
+        let v = [self.a - centre, self.b - centre, self.c - centre];
+        let edges = [v[1] - v[0], v[2] - v[1], v[0] - v[2]];
+        let box_axes = [Vec3::right (), Vec3::up (), Vec3::forward ()];
+
+        let is_separating = |axis: &Vec3| {
+
+            let p0 = Vec3::dot (&v[0], axis);
+            let p1 = Vec3::dot (&v[1], axis);
+            let p2 = Vec3::dot (&v[2], axis);
+            let r = extents.x * axis.x.abs () + extents.y * axis.y.abs () + extents.z * axis.z.abs ();
+
+            Util::min (p0, Util::min (p1, p2)) > r || Util::max (p0, Util::max (p1, p2)) < -r
+        };
+
+        // Box face normals, then the triangle normal, then the edge cross products
+        if box_axes.iter ().any (|axis| is_separating (axis)) {
+            return false;
+        }
+
+        if is_separating (&Vec3::cross (&edges[0], &edges[1])) {
+            return false;
+        }
+
+        for box_axis in &box_axes {
+
+            for edge in &edges {
+
+                if is_separating (&Vec3::cross (box_axis, edge)) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+/*===============================================================================================*/
+/*------TRIANGLE PUBLIC STATIC METHODS-----------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new triangle.
+    pub fn new (a: &Vec3, b: &Vec3, c: &Vec3) -> Triangle {
+        Triangle {a: *a, b: *b, c: *c}
+    }
+}
@@ -27,6 +27,7 @@ use std::cmp::PartialEq;
 ///
 /// It is used mainly for 2D releated mathematics (e.g. texture and UV coordinates).
 /// 32-bit floats are used for the values.
+#[repr (C)]
 #[derive (Copy, Clone, Default, Serialize, Deserialize)]
 pub struct Vec2 {
 
@@ -112,6 +113,15 @@ impl Vec2 {
               y: 0.0}
     }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the cross product of two vectors.
+    ///
+    /// This is the z component of the 3D cross product, with the vectors in the xy plane.
+    pub fn cross (lhs: &Vec2, rhs: &Vec2) -> f32 {
+        (lhs.x * rhs.y) - (lhs.y * rhs.x)
+    }
+
 /*-----------------------------------------------------------------------------------------------*/
 
     /// Returns the dot product of two vectors.
@@ -21,13 +21,17 @@
 /*===============================================================================================*/
 
 // Modules
+pub mod image;
 pub mod math;
 pub mod serialization;
+pub mod spatial;
 pub mod traits;
+mod bytes;
 mod directory;
 mod logger;
 mod version;
 
+pub use self::bytes::Bytes;
 pub use self::directory::Directory;
 pub use self::logger::Logger;
 pub use self::version::Version;
@@ -0,0 +1,335 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Aabb, Frustum, Ray};
+
+use std::cmp::Ordering;
+
+// Static variables
+static MAX_LEAF_SIZE: usize = 4;
+
+/*===============================================================================================*/
+/*------BVH NODE STRUCT--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// A node of the hierarchy, covering a contiguous range of the items.
+// Leaf nodes have no children, and inner nodes store their children next to each other.
+#[derive (Copy, Clone)]
+struct BvhNode {
+
+    bounds: Aabb,
+    first_item: usize,
+    item_count: usize,
+    first_child: Option<usize>,
+}
+
+/*===============================================================================================*/
+/*------BVH STRUCT-------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A bounding volume hierarchy over a set of objects.
+///
+/// The hierarchy is built once from the object bounds, splitting nodes in the middle of their
+/// longest axis, and has to be rebuilt when the objects move.
+/// It suits scenes where most objects are static; see `Octree` for moving objects.
+///
+/// # Examples
+/// ```
+/// # use ion_core::util::math::{Aabb, Ray, Vec3};
+/// # use ion_core::util::spatial::Bvh;
+/// #
+/// let items = (0..100).map (|i| {
+///
+///     let centre = Vec3 {x: i as f32 * 3.0, y: 0.0, z: 0.0};
+///     (Aabb::from_centre_extents (&centre, &Vec3 {x: 1.0, y: 1.0, z: 1.0}), i)
+///
+/// }).collect ();
+///
+/// let bvh = Bvh::build (items);
+/// let query = Aabb::from_points (&[Vec3 {x: 5.5, y: 0.0, z: 0.0}, Vec3 {x: 9.5, y: 0.0, z: 0.0}]);
+///
+/// let mut found = bvh.query_aabb (&query);
+/// found.sort ();
+///
+/// assert_eq! (found, vec! [&2, &3]);
+///
+/// let hits = bvh.query_ray (&Ray::new (&Vec3 {x: -5.0, y: 0.0, z: 0.0}, &Vec3::right ()), 9.0);
+///
+/// assert_eq! (hits.len (), 2);
+/// assert_eq! (*hits[0].0, 0);
+/// assert_eq! (hits[0].1, 4.0);
+/// ```
+pub struct Bvh<T> {
+
+    // Private
+    _nodes: Vec<BvhNode>,
+    _items: Vec<(Aabb, T)>,
+}
+
+/*===============================================================================================*/
+/*------BVH PUBLIC METHODS-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Bvh<T> {
+
+    /// Returns the objects with bounds at least partially inside a frustum.
+    pub fn query_frustum (&self, frustum: &Frustum) -> Vec<&T> {
+
+        let mut found = Vec::new ();
+
+        if self._nodes.is_empty () {
+            return found;
+        }
+
+        let mut stack = vec! [0];
+
+        while let Some (index) = stack.pop () {
+
+            let node = self._nodes[index];
+
+            if !frustum.intersects_aabb (&node.bounds) {
+                continue;
+            }
+
+            // Everything below a node inside the frustum is visible
+            if frustum.contains_aabb (&node.bounds) {
+
+                found.extend (self._get_node_items (&node).iter ().map (|item| &item.1));
+                continue;
+            }
+
+            match node.first_child {
+
+                Some (child) => stack.extend_from_slice (&[child, child + 1]),
+                None => {
+
+                    found.extend (self._get_node_items (&node).iter ()
+                                                            .filter (|item| frustum.intersects_aabb (&item.0))
+                                                            .map (|item| &item.1));
+                }
+            }
+        }
+
+        found
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the objects with bounds overlapping a box.
+    pub fn query_aabb (&self, aabb: &Aabb) -> Vec<&T> {
+
+        let mut found = Vec::new ();
+
+        if self._nodes.is_empty () {
+            return found;
+        }
+
+        let mut stack = vec! [0];
+
+        while let Some (index) = stack.pop () {
+
+            let node = self._nodes[index];
+
+            if !aabb.intersects (&node.bounds) {
+                continue;
+            }
+
+            match node.first_child {
+
+                Some (child) => stack.extend_from_slice (&[child, child + 1]),
+                None => {
+
+                    found.extend (self._get_node_items (&node).iter ()
+                                                            .filter (|item| aabb.intersects (&item.0))
+                                                            .map (|item| &item.1));
+                }
+            }
+        }
+
+        found
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the objects with bounds hit by a ray, within a distance.
+    ///
+    /// Each object is returned with the distance to its bounds, ordered nearest first.
+    pub fn query_ray (&self, ray: &Ray, max_distance: f32) -> Vec<(&T, f32)> {
+
+        let mut found = Vec::new ();
+
+        if self._nodes.is_empty () {
+            return found;
+        }
+
+        let mut stack = vec! [0];
+
+        while let Some (index) = stack.pop () {
+
+            let node = self._nodes[index];
+
+            match ray.intersect_aabb (&node.bounds) {
+
+                Some (distance) if distance <= max_distance => {},
+                _ => continue
+            }
+
+            match node.first_child {
+
+                Some (child) => stack.extend_from_slice (&[child, child + 1]),
+                None => {
+
+                    for item in self._get_node_items (&node) {
+
+                        if let Some (distance) = ray.intersect_aabb (&item.0) {
+
+                            if distance <= max_distance {
+                                found.push ((&item.1, distance));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        found.sort_by (|a, b| a.1.partial_cmp (&b.1).unwrap_or (Ordering::Equal));
+        found
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the bounds of every object.
+    pub fn get_bounds (&self) -> Aabb {
+
+        match self._nodes.first () {
+
+            Some (node) => node.bounds,
+            None => Aabb::new ()
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of nodes in the hierarchy.
+    pub fn get_node_count (&self) -> usize {
+        self._nodes.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of objects.
+    pub fn len (&self) -> usize {
+        self._items.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if there are no objects.
+    pub fn is_empty (&self) -> bool {
+        self._items.is_empty ()
+    }
+
+/*===============================================================================================*/
+/*------BVH PUBLIC STATIC METHODS----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Builds a new hierarchy over a set of objects and their bounds.
+    pub fn build (items: Vec<(Aabb, T)>) -> Bvh<T> {
+
+        let mut bvh = Bvh {_nodes: Vec::new (), _items: items};
+
+        if !bvh._items.is_empty () {
+
+            let count = bvh._items.len ();
+
+            bvh._nodes.push (BvhNode {bounds: Aabb::new (), first_item: 0, item_count: count, first_child: None});
+            bvh._split (0);
+        }
+
+        bvh
+    }
+}
+
+/*===============================================================================================*/
+/*------BVH PRIVATE METHODS----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Bvh<T> {
+
+    // Fits a node around its items, and splits it into two children until the leaves are small.
+    fn _split (&mut self, index: usize) {
+
+        let mut stack = vec! [index];
+
+        while let Some (index) = stack.pop () {
+
+            let (first, count) = (self._nodes[index].first_item, self._nodes[index].item_count);
+            let items = &mut self._items[first..first + count];
+
+            let mut centres = Aabb::new ();
+
+            for item in items.iter () {
+
+                self._nodes[index].bounds = Aabb::merge (&self._nodes[index].bounds, &item.0);
+                centres.expand (&item.0.get_center ());
+            }
+
+            if count <= MAX_LEAF_SIZE {
+                continue;
+            }
+
+            // Split at the middle of the centres along the longest axis
+            let size = centres.get_size ();
+            let axis = if size.x >= size.y && size.x >= size.z {0}
+                       else if size.y >= size.z {1}
+                       else {2};
+
+            let middle = centres.get_center ()[axis];
+            let mut half = 0;
+
+            for i in 0..count {
+
+                if items[i].0.get_center ()[axis] < middle {
+
+                    items.swap (i, half);
+                    half += 1;
+                }
+            }
+
+            // Objects with the same centre are split evenly
+            if half == 0 || half == count {
+                half = count / 2;
+            }
+
+            let child = self._nodes.len ();
+
+            self._nodes.push (BvhNode {bounds: Aabb::new (), first_item: first, item_count: half, first_child: None});
+            self._nodes.push (BvhNode {bounds: Aabb::new (), first_item: first + half, item_count: count - half, first_child: None});
+            self._nodes[index].first_child = Some (child);
+
+            stack.push (child);
+            stack.push (child + 1);
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the items below a node.
+    fn _get_node_items (&self, node: &BvhNode) -> &[(Aabb, T)] {
+        &self._items[node.first_item..node.first_item + node.item_count]
+    }
+}
@@ -0,0 +1,460 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+use ::util::math::{Aabb, Frustum, Ray, Util, Vec3};
+use ::util::spatial::OctreeHandle;
+
+use std::cmp::Ordering;
+
+// Static variables
+static LOOSENESS: f32 = 2.0;
+
+/*===============================================================================================*/
+/*------OCTREE NODE STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// A cube of the octree. Its loose bounds are twice the size of the cube.
+struct OctreeNode {
+
+    centre: Vec3,
+    half_size: f32,
+    depth: u32,
+    children: [Option<usize>; 8],
+    items: Vec<usize>,
+}
+
+/*===============================================================================================*/
+/*------OCTREE ITEM STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// An object stored in the octree, with the node it is in.
+struct OctreeItem<T> {
+
+    bounds: Aabb,
+    node: usize,
+    value: T,
+}
+
+/*===============================================================================================*/
+/*------OCTREE STRUCT----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A loose octree over a set of moving objects.
+///
+/// Every node bounds a cube twice the size of its cell, so an object is stored in the
+/// deepest cell containing its centre that it fits in, and never spans several nodes.  
+/// Objects can be inserted, moved and removed cheaply. Objects outside the root cube
+/// are kept in the root node.
+///
+/// # Examples
+/// ```
+/// # use ion_core::util::math::{Aabb, Vec3};
+/// # use ion_core::util::spatial::Octree;
+/// #
+/// let mut octree = Octree::new (&Vec3::new (), 64.0, 6);
+/// let extents = Vec3 {x: 0.5, y: 0.5, z: 0.5};
+///
+/// let a = octree.insert (&Aabb::from_centre_extents (&Vec3 {x: 10.0, y: 0.0, z: 0.0}, &extents), "a");
+/// let b = octree.insert (&Aabb::from_centre_extents (&Vec3 {x: -10.0, y: 0.0, z: 0.0}, &extents), "b");
+///
+/// let query = Aabb::from_centre_extents (&Vec3 {x: 10.0, y: 0.0, z: 0.0}, &Vec3 {x: 2.0, y: 2.0, z: 2.0});
+/// assert_eq! (octree.query_aabb (&query), vec! [&"a"]);
+///
+/// // Move b next to a, and remove a
+/// octree.update (b, &Aabb::from_centre_extents (&Vec3 {x: 11.0, y: 0.0, z: 0.0}, &extents));
+/// assert_eq! (octree.remove (a), Some ("a"));
+///
+/// assert_eq! (octree.query_aabb (&query), vec! [&"b"]);
+/// assert_eq! (octree.len (), 1);
+/// ```
+pub struct Octree<T> {
+
+    // Private
+    _nodes: Vec<OctreeNode>,
+    _items: Vec<Option<OctreeItem<T>>>,
+    _free_items: Vec<usize>,
+    _max_depth: u32,
+    _count: usize,
+}
+
+/*===============================================================================================*/
+/*------OCTREE PUBLIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Octree<T> {
+
+    /// Inserts an object with its bounds, and returns its handle.
+    pub fn insert (&mut self, bounds: &Aabb, value: T) -> OctreeHandle {
+
+        let node = self._find_node (bounds);
+        let item = OctreeItem {bounds: *bounds, node: node, value: value};
+
+        let index = match self._free_items.pop () {
+
+            Some (index) => {
+
+                self._items[index] = Some (item);
+                index
+            },
+
+            None => {
+
+                self._items.push (Some (item));
+                self._items.len () - 1
+            }
+        };
+
+        self._nodes[node].items.push (index);
+        self._count += 1;
+
+        OctreeHandle::new (index)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Removes an object, and returns it.
+    ///
+    /// Returns None if the handle does not refer to an object.
+    pub fn remove (&mut self, handle: OctreeHandle) -> Option<T> {
+
+        let index = handle.get_index ();
+        let item = match self._items.get_mut (index) {
+
+            Some (slot) => slot.take (),
+            None => None
+        };
+
+        item.map (|item| {
+
+            self._remove_from_node (item.node, index);
+            self._free_items.push (index);
+            self._count -= 1;
+
+            item.value
+        })
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Updates the bounds of an object, moving it to another node if needed.
+    pub fn update (&mut self, handle: OctreeHandle, bounds: &Aabb) {
+
+        let index = handle.get_index ();
+        let old_node = match self._items.get (index) {
+
+            Some (&Some (ref item)) => item.node,
+            _ => return
+        };
+
+        let node = self._find_node (bounds);
+
+        if node != old_node {
+
+            self._remove_from_node (old_node, index);
+            self._nodes[node].items.push (index);
+        }
+
+        if let Some (item) = self._items[index].as_mut () {
+
+            item.bounds = *bounds;
+            item.node = node;
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns an object.
+    pub fn get (&self, handle: OctreeHandle) -> Option<&T> {
+
+        match self._items.get (handle.get_index ()) {
+
+            Some (&Some (ref item)) => Some (&item.value),
+            _ => None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns an object, for modification.
+    pub fn get_mut (&mut self, handle: OctreeHandle) -> Option<&mut T> {
+
+        match self._items.get_mut (handle.get_index ()) {
+
+            Some (&mut Some (ref mut item)) => Some (&mut item.value),
+            _ => None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the bounds of an object.
+    pub fn get_bounds (&self, handle: OctreeHandle) -> Option<Aabb> {
+
+        match self._items.get (handle.get_index ()) {
+
+            Some (&Some (ref item)) => Some (item.bounds),
+            _ => None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the objects with bounds at least partially inside a frustum.
+    pub fn query_frustum (&self, frustum: &Frustum) -> Vec<&T> {
+
+        let mut found = Vec::new ();
+        let mut stack = vec! [(0, false)];
+
+        while let Some ((index, inside)) = stack.pop () {
+
+            let node = &self._nodes[index];
+            let mut inside = inside;
+
+            // Objects outside the root cube are kept in the root, so it is always visited
+            if !inside && index != 0 {
+
+                let bounds = Octree::<T>::_get_loose_bounds (node);
+
+                if !frustum.intersects_aabb (&bounds) {
+                    continue;
+                }
+
+                inside = frustum.contains_aabb (&bounds);
+            }
+
+            for &item_index in &node.items {
+
+                if let Some (ref item) = self._items[item_index] {
+
+                    if inside || frustum.intersects_aabb (&item.bounds) {
+                        found.push (&item.value);
+                    }
+                }
+            }
+
+            for child in node.children.iter () {
+
+                if let Some (child) = *child {
+                    stack.push ((child, inside));
+                }
+            }
+        }
+
+        found
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the objects with bounds overlapping a box.
+    pub fn query_aabb (&self, aabb: &Aabb) -> Vec<&T> {
+
+        let mut found = Vec::new ();
+        let mut stack = vec! [0];
+
+        while let Some (index) = stack.pop () {
+
+            let node = &self._nodes[index];
+
+            if index != 0 && !aabb.intersects (&Octree::<T>::_get_loose_bounds (node)) {
+                continue;
+            }
+
+            for &item_index in &node.items {
+
+                if let Some (ref item) = self._items[item_index] {
+
+                    if aabb.intersects (&item.bounds) {
+                        found.push (&item.value);
+                    }
+                }
+            }
+
+            stack.extend (node.children.iter ().filter_map (|child| *child));
+        }
+
+        found
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the objects with bounds hit by a ray, within a distance.
+    ///
+    /// Each object is returned with the distance to its bounds, ordered nearest first.
+    pub fn query_ray (&self, ray: &Ray, max_distance: f32) -> Vec<(&T, f32)> {
+
+        let mut found = Vec::new ();
+        let mut stack = vec! [0];
+
+        while let Some (index) = stack.pop () {
+
+            let node = &self._nodes[index];
+
+            if index != 0 {
+
+                match ray.intersect_aabb (&Octree::<T>::_get_loose_bounds (node)) {
+
+                    Some (distance) if distance <= max_distance => {},
+                    _ => continue
+                }
+            }
+
+            for &item_index in &node.items {
+
+                if let Some (ref item) = self._items[item_index] {
+
+                    if let Some (distance) = ray.intersect_aabb (&item.bounds) {
+
+                        if distance <= max_distance {
+                            found.push ((&item.value, distance));
+                        }
+                    }
+                }
+            }
+
+            stack.extend (node.children.iter ().filter_map (|child| *child));
+        }
+
+        found.sort_by (|a, b| a.1.partial_cmp (&b.1).unwrap_or (Ordering::Equal));
+        found
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of nodes in the octree.
+    ///
+    /// Nodes are created on demand, and are kept when they become empty.
+    pub fn get_node_count (&self) -> usize {
+        self._nodes.len ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the number of objects.
+    pub fn len (&self) -> usize {
+        self._count
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns true if there are no objects.
+    pub fn is_empty (&self) -> bool {
+        self._count == 0
+    }
+
+/*===============================================================================================*/
+/*------OCTREE PUBLIC STATIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new, empty octree.
+    ///
+    /// # Arguments
+    /// * `centre` - The centre of the root cube.
+    /// * `half_size` - Half the size of the root cube.
+    /// * `max_depth` - The deepest level of nodes, below the root.
+    pub fn new (centre: &Vec3, half_size: f32, max_depth: u32) -> Octree<T> {
+
+        Octree {
+
+            _nodes: vec! [OctreeNode {centre: *centre, half_size: half_size, depth: 0, children: [None; 8], items: Vec::new ()}],
+            _items: Vec::new (),
+            _free_items: Vec::new (),
+            _max_depth: max_depth,
+            _count: 0,
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------OCTREE PRIVATE METHODS-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Octree<T> {
+
+    // Returns the deepest node an object fits in, creating the nodes on the way.
+    fn _find_node (&mut self, bounds: &Aabb) -> usize {
+
+        let centre = bounds.get_center ();
+        let extents = bounds.get_extents ();
+        let extent = Util::max (extents.x, Util::max (extents.y, extents.z));
+
+        let mut index = 0;
+
+        loop {
+
+            let (node_centre, half_size, depth) = {
+
+                let node = &self._nodes[index];
+                (node.centre, node.half_size, node.depth)
+            };
+
+            let child_half_size = half_size * 0.5;
+            let offset = centre - node_centre;
+
+            if depth >= self._max_depth ||
+               extent > child_half_size * (LOOSENESS - 1.0) ||
+               offset.x.abs () > half_size || offset.y.abs () > half_size || offset.z.abs () > half_size {
+
+                return index;
+            }
+
+            let octant = (offset.x >= 0.0) as usize | ((offset.y >= 0.0) as usize) << 1 | ((offset.z >= 0.0) as usize) << 2;
+
+            index = match self._nodes[index].children[octant] {
+
+                Some (child) => child,
+                None => {
+
+                    let sign = |bit: usize| if octant & bit != 0 {child_half_size} else {-child_half_size};
+                    let child_centre = node_centre + Vec3 {x: sign (1), y: sign (2), z: sign (4)};
+                    let child = self._nodes.len ();
+
+                    self._nodes.push (OctreeNode {centre: child_centre,
+                                                  half_size: child_half_size,
+                                                  depth: depth + 1,
+                                                  children: [None; 8],
+                                                  items: Vec::new ()});
+
+                    self._nodes[index].children[octant] = Some (child);
+                    child
+                }
+            };
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Removes an item index from the list of a node.
+    fn _remove_from_node (&mut self, node: usize, item_index: usize) {
+
+        let items = &mut self._nodes[node].items;
+
+        if let Some (position) = items.iter ().position (|&i| i == item_index) {
+            items.swap_remove (position);
+        }
+    }
+
+/*===============================================================================================*/
+/*------OCTREE PRIVATE STATIC METHODS------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Returns the loose bounds of a node.
+    fn _get_loose_bounds (node: &OctreeNode) -> Aabb {
+
+        let extent = node.half_size * LOOSENESS;
+        Aabb::from_centre_extents (&node.centre, &Vec3 {x: extent, y: extent, z: extent})
+    }
+}
@@ -0,0 +1,51 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+/*------OCTREE HANDLE STRUCT---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Identifies an object inserted into an octree.
+///
+/// Handles of removed objects may be reused by objects inserted later.
+#[derive (Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OctreeHandle {
+
+    // Private
+    _index: usize,
+}
+
+/*===============================================================================================*/
+/*------OCTREE HANDLE PUBLIC METHODS-------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl OctreeHandle {
+
+    /// Returns the index of the object slot in the octree.
+    pub fn get_index (&self) -> usize {
+        self._index
+    }
+
+/*===============================================================================================*/
+/*------OCTREE HANDLE PUBLIC STATIC METHODS------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new handle to an object slot.
+    pub fn new (index: usize) -> OctreeHandle {
+        OctreeHandle {_index: index}
+    }
+}
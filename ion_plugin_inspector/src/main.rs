@@ -0,0 +1,150 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! Lists every plugin in the plugin directory, along with any reason it cannot be loaded.
+//!
+//! Usage: `ion_plugin_inspector [plugin directory]`  
+//! If no directory is given, the engine plugin directory (`bin/plugins/`) is used.
+/*===============================================================================================*/
+
+extern crate ion_core;
+
+use self::ion_core::resource::{PluginLoader, PluginReport};
+use self::ion_core::util::Directory;
+
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process;
+
+/*===============================================================================================*/
+/*------MAIN-------------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+fn main () {
+
+    let plugin_dir    = env::args ().nth (1).unwrap_or (Directory::get_plugin_directory ());
+    let plugin_loader = PluginLoader::new ();
+
+    // Find all of the libraries in the plugin directory
+    let mut plugin_list = match find_plugins (&plugin_dir, plugin_loader.get_plugin_extension ()) {
+
+        Ok (list) => list,
+        Err (e) => {
+
+            writeln! (io::stderr (), "Could not read plugin directory \"{}\".\n{}", plugin_dir, e).unwrap ();
+            process::exit (2);
+        }
+    };
+
+    plugin_list.sort ();
+
+    if plugin_list.is_empty () {
+
+        println! ("No plugins found in \"{}\".", plugin_dir);
+        return;
+    }
+
+    // Inspect and print each plugin
+    let mut error_count = 0;
+
+    for plugin_path in &plugin_list {
+
+        let report = plugin_loader.inspect_plugin (plugin_path);
+
+        if !report.is_valid () {
+            error_count += 1;
+        }
+
+        print_report (&report);
+    }
+
+    println! ("{} plugin(s) found, {} with errors.", plugin_list.len (), error_count);
+
+    if error_count > 0 {
+        process::exit (1);
+    }
+}
+
+/*===============================================================================================*/
+/*------FUNCTIONS--------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Returns the path of every file in the directory with the plugin extension.
+fn find_plugins (plugin_dir: &str, plugin_ext: &str) -> Result<Vec<String>, io::Error> {
+
+    let mut plugin_list = Vec::new ();
+
+    for entry in try! (fs::read_dir (plugin_dir)) {
+
+        let path = try! (entry).path ();
+
+        // Skip anything that isn't a library
+        let is_plugin = match path.extension () {
+
+            Some (ext) => format! (".{}", ext.to_string_lossy ()) == plugin_ext,
+            None => false
+        };
+
+        if is_plugin && path.is_file () {
+            plugin_list.push (path.to_string_lossy ().into_owned ());
+        }
+    }
+
+    Ok (plugin_list)
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Prints the plugin report.
+fn print_report (report: &PluginReport) {
+
+    let file_name = match Path::new (&report.plug_path).file_name () {
+
+        Some (name) => name.to_string_lossy ().into_owned (),
+        None => report.plug_path.clone ()
+    };
+
+    println! ("{}", file_name);
+
+    if let Some (ref info) = report.plug_info {
+
+        println! ("    Name:        {}", info.plug_name);
+        println! ("    Developer:   {}", info.plug_developer);
+        println! ("    Description: {}", info.plug_description);
+        println! ("    Version:     {}", info.plug_version.to_string ());
+        println! ("    Type:        {:?}", info.plug_type);
+    }
+
+    println! ("    Symbols:     {}", if report.plug_symbols.is_empty () {"none".to_string ()}
+                                     else {report.plug_symbols.join (", ")});
+
+    if report.plug_errors.is_empty () {
+        println! ("    Status:      OK");
+    }
+
+    else {
+
+        for error in &report.plug_errors {
+            println! ("    Error:       {}", error.replace ("\n", " "));
+        }
+    }
+
+    println! ("");
+}
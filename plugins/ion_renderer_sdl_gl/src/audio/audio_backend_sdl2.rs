@@ -0,0 +1,313 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate ion_core;
+extern crate sdl2;
+
+use ::SdlContext;
+
+use self::ion_core::audio::{AudioBackend, AudioBackendNull, AudioConfig, AudioMixer};
+use self::ion_core::util::traits::AsAny;
+use self::sdl2::audio::{AudioCallback, AudioDevice, AudioSpecDesired, AudioStatus};
+
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+// The time in seconds between attempts to reopen a lost device.
+static REOPEN_INTERVAL: f32 = 1.0;
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND SDL2 STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Plays the mixer through the default SDL2 audio device.
+///
+/// The device callback locks the mixer and mixes straight into the device buffer.
+/// Streams are decoded ahead on their own threads, so the callback never reads or decodes files.
+/// If the device is lost, or can't be opened to begin with, the mixer keeps being pulled in
+/// real time while the backend tries to reopen the device.
+pub struct AudioBackendSDL2 {
+
+    // Private
+    _config: AudioConfig,
+    _mixer: Option<Arc<Mutex<AudioMixer>>>,
+    _device: Option<AudioDevice<MixerCallback>>,
+    _fallback: AudioBackendNull,
+    _reopen_timer: f32,
+}
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND SDL2 PUBLIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioBackendSDL2 {
+
+    /// Returns whether the device is open and playing.
+    pub fn is_device_open (&self) -> bool {
+        self._device.is_some ()
+    }
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND SDL2 PUBLIC STATIC METHODS-------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new SDL2 audio backend.
+    ///
+    /// The device isn't opened until the audio manager opens the backend.
+    pub fn new () -> AudioBackendSDL2 {
+
+        AudioBackendSDL2 {
+
+            _config:       AudioConfig::new (),
+            _mixer:        None,
+            _device:       None,
+            _fallback:     AudioBackendNull::new (),
+            _reopen_timer: 0.0,
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for AudioBackendSDL2 {
+
+    fn default () -> AudioBackendSDL2 {
+        AudioBackendSDL2::new ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AudioBackend for AudioBackendSDL2 {
+
+    fn open (&mut self, mixer: Arc<Mutex<AudioMixer>>, config: &AudioConfig) -> Result<(), ()> {
+
+        self.close ();
+
+        // Without the audio subsystem there is no device to wait for
+        if let Err (e) = SdlContext::get ().and_then (|context| context.audio ()) {
+
+            error! ("Could not initialize the SDL2 audio subsystem.\n{}", e);
+            return Err (());
+        }
+
+        // SDL takes narrower types than the config, so out of range values must not reach it
+        if config.is_valid () {
+            self._config = *config;
+        }
+
+        else {
+
+            warn! ("The audio config can't be used, so the default config is used instead.");
+            self._config = AudioConfig::new ();
+        }
+
+        self._mixer = Some (mixer);
+
+        // The device is reopened by update if it can't be opened now
+        if self._open_device ().is_err () {
+            warn! ("Audio will not be heard until a device can be opened.");
+        }
+
+        Ok (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn update (&mut self, delta_time: f32) {
+
+        if self._mixer.is_none () {
+            return;
+        }
+
+        // SDL stops a device once it has been disconnected
+        let is_lost = match self._device {
+
+            Some (ref device) => device.status () == AudioStatus::Stopped,
+            None => false
+        };
+
+        if is_lost {
+
+            warn! ("The audio device was lost. Trying to reopen it.");
+            self._close_device ();
+        }
+
+        if self._device.is_some () {
+            return;
+        }
+
+        self._fallback.update (delta_time);
+        self._reopen_timer += delta_time;
+
+        if self._reopen_timer >= REOPEN_INTERVAL {
+
+            self._reopen_timer = 0.0;
+
+            if self._open_device ().is_ok () {
+                info! ("Reopened the audio device.");
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn close (&mut self) {
+
+        self._close_device ();
+        self._fallback.close ();
+        self._mixer = None;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for AudioBackendSDL2 {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
+
+/*===============================================================================================*/
+/*------AUDIO BACKEND SDL2 PRIVATE METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl AudioBackendSDL2 {
+
+    // Opens the device, and starts playing.
+    fn _open_device (&mut self) -> Result<(), ()> {
+
+        let mixer = match self._mixer {
+
+            Some (ref mixer) => mixer.clone (),
+            None => return Err (())
+        };
+
+        let audio = match SdlContext::get ().and_then (|context| context.audio ()) {
+
+            Ok (audio) => audio,
+            Err (e) => {
+
+                error! ("Could not initialize the SDL2 audio subsystem.\n{}", e);
+                return Err (());
+            }
+        };
+
+        let desired = AudioSpecDesired {
+
+            freq:     Some (self._config.audio_sample_rate as i32),
+            channels: Some (self._config.audio_channels as u8),
+            samples:  Some (self._config.audio_buffer_size),
+        };
+
+        // SDL converts to the device format, but the mixer is set to what SDL reports anyway
+        let device = audio.open_playback (None, &desired, |spec| {
+
+            match mixer.lock () {
+
+                Ok (mut mixer) => mixer.set_format (spec.freq as u32, spec.channels as u16),
+                Err (poisoned) => poisoned.into_inner ().set_format (spec.freq as u32, spec.channels as u16)
+            }
+
+            MixerCallback {mixer: mixer.clone ()}
+        });
+
+        match device {
+
+            Ok (device) => {
+
+                info! ("Opened audio device at {}Hz, with {} channels and {} frame buffer.",
+                       device.spec ().freq, device.spec ().channels, device.spec ().samples);
+
+                device.resume ();
+
+                // Stop pulling the mixer from the main thread
+                self._fallback.close ();
+                self._device = Some (device);
+                self._reopen_timer = 0.0;
+
+                Ok (())
+            },
+
+            Err (e) => {
+
+                warn! ("Could not open the audio device.\n{}", e);
+
+                // Keep the mixer running in real time until the device comes back
+                if let Some (ref mixer) = self._mixer {
+
+                    match self._fallback.open (mixer.clone (), &self._config) {
+                        Ok (_) | Err (_) => {}
+                    }
+                }
+
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Closes the device, and keeps the mixer running without it.
+    fn _close_device (&mut self) {
+
+        if self._device.take ().is_some () {
+
+            if let Some (ref mixer) = self._mixer {
+
+                match self._fallback.open (mixer.clone (), &self._config) {
+                    Ok (_) | Err (_) => {}
+                }
+            }
+
+            self._reopen_timer = 0.0;
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------MIXER CALLBACK STRUCT--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Mixes into the device buffer from the audio thread. It only mixes, as stream voices
+// read from buffers which are filled by their decoder threads.
+struct MixerCallback {
+
+    mixer: Arc<Mutex<AudioMixer>>,
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AudioCallback for MixerCallback {
+
+    type Channel = f32;
+
+    fn callback (&mut self, output: &mut [f32]) {
+
+        match self.mixer.lock () {
+
+            Ok (mut mixer) => mixer.mix (output),
+            Err (_) => {
+
+                for sample in output.iter_mut () {
+                    *sample = 0.0;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate gl;
+extern crate ion_core;
+
+use self::gl::types::*;
+use self::ion_core::renderer::gpu::{BufferDesc, BufferType, BufferUsage};
+use self::ion_core::renderer::traits::Buffer;
+use self::ion_core::util::traits::AsAny;
+
+use std::any::Any;
+use std::cmp;
+use std::os::raw::c_void;
+use std::ptr;
+
+/*===============================================================================================*/
+/*------BUFFER GL STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An `OpenGL` buffer object.
+pub struct BufferGL {
+
+    // Public
+    /// The buffer object name.
+    pub gl_id: GLuint,
+
+    // Private
+    _desc: BufferDesc,
+}
+
+/*===============================================================================================*/
+/*------BUFFER GL PUBLIC METHODS-----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BufferGL {
+
+    /// Returns the `OpenGL` binding target of the buffer.
+    pub fn get_gl_target (&self) -> GLenum {
+
+        match self._desc.buffer_type {
+
+            BufferType::Vertex => gl::ARRAY_BUFFER,
+            BufferType::Index  => gl::ELEMENT_ARRAY_BUFFER,
+        }
+    }
+
+/*===============================================================================================*/
+/*------BUFFER GL PUBLIC STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Creates a new buffer object, filled with `data`.
+    pub fn new (desc: &BufferDesc, data: &[u8]) -> BufferGL {
+
+        let mut buffer = BufferGL {gl_id: 0, _desc: *desc};
+
+        let usage = match desc.buffer_usage {
+
+            BufferUsage::Static  => gl::STATIC_DRAW,
+            BufferUsage::Dynamic => gl::DYNAMIC_DRAW,
+            BufferUsage::Stream  => gl::STREAM_DRAW,
+        };
+
+        unsafe {
+
+            gl::GenBuffers (1, &mut buffer.gl_id);
+            gl::BindBuffer (buffer.get_gl_target (), buffer.gl_id);
+            gl::BufferData (buffer.get_gl_target (), desc.buffer_size as GLsizeiptr, ptr::null (), usage);
+        }
+
+        buffer.set_data (0, data);
+        buffer
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Buffer for BufferGL {
+
+    fn get_buffer_type (&self) -> BufferType {
+        self._desc.buffer_type
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_buffer_usage (&self) -> BufferUsage {
+        self._desc.buffer_usage
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_size (&self) -> usize {
+        self._desc.buffer_size
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn set_data (&self, offset: usize, data: &[u8]) {
+
+        // Ignore anything past the end of the buffer
+        if offset >= self._desc.buffer_size || data.is_empty () {
+            return;
+        }
+
+        let size = cmp::min (data.len (), self._desc.buffer_size - offset);
+
+        unsafe {
+
+            gl::BindBuffer    (self.get_gl_target (), self.gl_id);
+            gl::BufferSubData (self.get_gl_target (), offset as GLintptr, size as GLsizeiptr, data.as_ptr () as *const c_void);
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for BufferGL {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Drop for BufferGL {
+
+    fn drop (&mut self) {
+        unsafe {gl::DeleteBuffers (1, &self.gl_id)};
+    }
+}
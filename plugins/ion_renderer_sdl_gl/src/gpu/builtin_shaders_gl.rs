@@ -0,0 +1,595 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate ion_core;
+
+use self::ion_core::renderer::gpu::{BuiltinShader, ShaderStage};
+use self::ion_core::renderer::post_process::PostEffectType;
+
+use std::cmp;
+
+/*===============================================================================================*/
+/*------SHADER SOURCES---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Sprite vertex shader.
+static SPRITE_VERT: &'static str = "#version 330 core
+
+in vec2 a_position;
+in vec2 a_uv;
+in vec4 a_colour;
+
+uniform mat4 u_view_projection;
+
+out vec2 v_uv;
+out vec4 v_colour;
+
+void main () {
+
+    v_uv        = a_uv;
+    v_colour    = a_colour;
+    gl_Position = u_view_projection * vec4 (a_position, 0.0, 1.0);
+}
+";
+
+// Sprite fragment shader.
+static SPRITE_FRAG: &'static str = "#version 330 core
+
+in vec2 v_uv;
+in vec4 v_colour;
+
+uniform sampler2D u_texture;
+
+out vec4 o_colour;
+
+void main () {
+    o_colour = texture (u_texture, v_uv) * v_colour;
+}
+";
+
+// Debug line vertex shader.
+static DEBUG_LINE_VERT: &'static str = "#version 330 core
+
+in vec3 a_position;
+in vec4 a_colour;
+
+uniform mat4 u_view_projection;
+
+out vec4 v_colour;
+
+void main () {
+
+    v_colour    = a_colour;
+    gl_Position = u_view_projection * vec4 (a_position, 1.0);
+}
+";
+
+// Debug line fragment shader.
+static DEBUG_LINE_FRAG: &'static str = "#version 330 core
+
+in vec4 v_colour;
+
+out vec4 o_colour;
+
+void main () {
+    o_colour = v_colour;
+}
+";
+
+// Forward lit vertex shader.
+static FORWARD_LIT_VERT: &'static str = "#version 330 core
+
+in vec3 a_position;
+in vec3 a_normal;
+in vec2 a_uv;
+in vec4 a_colour;
+
+uniform mat4 u_model;
+uniform mat4 u_view_projection;
+
+out vec3 v_position;
+out vec3 v_normal;
+out vec2 v_uv;
+out vec4 v_colour;
+
+void main () {
+
+    vec4 world_position = u_model * vec4 (a_position, 1.0);
+
+    v_position  = world_position.xyz;
+    v_normal    = mat3 (transpose (inverse (u_model))) * a_normal;
+    v_uv        = a_uv;
+    v_colour    = a_colour;
+    gl_Position = u_view_projection * world_position;
+}
+";
+
+// Forward lit fragment shader, without the version and MAX_LIGHTS header.
+//
+// The attenuation and cone falloff must match Light::get_attenuation in ion_core,
+// and the array sizes must match MAX_SHADOW_CASCADES and MAX_SPOT_SHADOWS.
+static FORWARD_LIT_FRAG: &'static str = "
+#define MAX_CASCADES 4
+#define MAX_SPOT_SHADOWS 4
+
+in vec3 v_position;
+in vec3 v_normal;
+in vec2 v_uv;
+in vec4 v_colour;
+
+uniform sampler2D u_texture;
+uniform vec4 u_colour;
+uniform vec4 u_ambient;
+
+uniform int  u_light_count;
+uniform vec4 u_light_position[MAX_LIGHTS];
+uniform vec4 u_light_direction[MAX_LIGHTS];
+uniform vec4 u_light_colour[MAX_LIGHTS];
+uniform vec2 u_light_cone[MAX_LIGHTS];
+uniform int  u_light_shadow[MAX_LIGHTS];
+
+uniform mat4      u_view;
+uniform sampler2D u_shadow_map;
+uniform sampler2D u_spot_shadow_map;
+uniform int       u_shadow_cascade_count;
+uniform mat4      u_shadow_matrices[MAX_CASCADES];
+uniform mat4      u_spot_shadow_matrices[MAX_SPOT_SHADOWS];
+uniform vec4      u_shadow_splits;
+uniform vec2      u_shadow_bias;
+uniform int       u_shadow_pcf_radius;
+uniform vec4      u_shadow_texel_size;
+uniform vec2      u_shadow_tile_size;
+
+out vec4 o_colour;
+
+// Returns the lit fraction of a position, from a tile of a shadow map atlas.
+float sample_shadow (sampler2D shadow_map, mat4 shadow_matrix, vec3 position, int tile, float tile_width, vec2 texel_size) {
+
+    vec4 coord = shadow_matrix * vec4 (position, 1.0);
+    coord.xyz /= coord.w;
+
+    if (coord.z >= 1.0) {
+        return 1.0;
+    }
+
+    // Keep the filter inside the tile
+    float tile_min = float (tile)     * tile_width + texel_size.x * 0.5;
+    float tile_max = float (tile + 1) * tile_width - texel_size.x * 0.5;
+
+    float lit = 0.0;
+    float samples = 0.0;
+
+    for (int y = -u_shadow_pcf_radius; y <= u_shadow_pcf_radius; y++) {
+        for (int x = -u_shadow_pcf_radius; x <= u_shadow_pcf_radius; x++) {
+
+            vec2 uv = coord.xy + vec2 (x, y) * texel_size;
+            uv.x = clamp (uv.x, tile_min, tile_max);
+
+            lit += (coord.z - u_shadow_bias.x <= texture (shadow_map, uv).r) ? 1.0 : 0.0;
+            samples += 1.0;
+        }
+    }
+
+    return lit / samples;
+}
+
+// Returns the lit fraction of a position, for a light slot.
+float get_shadow (int light, vec3 position, vec3 normal) {
+
+    int slot = u_light_shadow[light];
+    vec3 biased = position + normal * u_shadow_bias.y;
+
+    if (slot == 0) {
+
+        float depth = -(u_view * vec4 (position, 1.0)).z;
+
+        for (int c = 0; c < u_shadow_cascade_count && c < MAX_CASCADES; c++) {
+
+            if (depth <= u_shadow_splits[c]) {
+                return sample_shadow (u_shadow_map, u_shadow_matrices[c], biased, c, u_shadow_tile_size.x, u_shadow_texel_size.xy);
+            }
+        }
+    }
+
+    else if (slot > 0 && slot <= MAX_SPOT_SHADOWS) {
+        return sample_shadow (u_spot_shadow_map, u_spot_shadow_matrices[slot - 1], biased, slot - 1, u_shadow_tile_size.y, u_shadow_texel_size.zw);
+    }
+
+    return 1.0;
+}
+
+void main () {
+
+    vec4 albedo = texture (u_texture, v_uv) * v_colour * u_colour;
+    vec3 normal = normalize (v_normal);
+    vec3 light  = u_ambient.rgb;
+
+    for (int i = 0; i < u_light_count && i < MAX_LIGHTS; i++) {
+
+        vec3 to_light;
+        float attenuation = 1.0;
+
+        if (u_light_position[i].w < 0.5) {
+            to_light = -normalize (u_light_direction[i].xyz);
+        }
+
+        else {
+
+            vec3 offset = u_light_position[i].xyz - v_position;
+            float dist = length (offset);
+            float falloff = clamp (1.0 - pow (dist / u_light_direction[i].w, 2.0), 0.0, 1.0);
+
+            to_light = offset / max (dist, 0.0001);
+            attenuation = falloff * falloff;
+
+            if (u_light_position[i].w > 1.5) {
+
+                float cos_angle = dot (-to_light, normalize (u_light_direction[i].xyz));
+                attenuation *= smoothstep (u_light_cone[i].y, u_light_cone[i].x, cos_angle);
+            }
+        }
+
+        float diffuse = max (dot (normal, to_light), 0.0) * attenuation;
+
+        if (diffuse > 0.0) {
+            light += u_light_colour[i].rgb * diffuse * get_shadow (i, v_position, normal);
+        }
+    }
+
+    o_colour = vec4 (albedo.rgb * light, albedo.a);
+}
+";
+
+// Shadow depth vertex shader.
+static SHADOW_DEPTH_VERT: &'static str = "#version 330 core
+
+in vec3 a_position;
+
+uniform mat4 u_model;
+uniform mat4 u_view_projection;
+
+void main () {
+    gl_Position = u_view_projection * u_model * vec4 (a_position, 1.0);
+}
+";
+
+// Shadow depth fragment shader.
+static SHADOW_DEPTH_FRAG: &'static str = "#version 330 core
+
+out float o_depth;
+
+void main () {
+    o_depth = gl_FragCoord.z;
+}
+";
+
+// Full-screen vertex shader, shared by the post-processing passes.
+static POST_VERT: &'static str = "#version 330 core
+
+in vec2 a_position;
+
+out vec2 v_uv;
+
+void main () {
+
+    v_uv        = a_position * 0.5 + 0.5;
+    gl_Position = vec4 (a_position, 0.0, 1.0);
+}
+";
+
+// Copy fragment shader.
+static BLIT_FRAG: &'static str = "#version 330 core
+
+in vec2 v_uv;
+
+uniform sampler2D u_texture;
+
+out vec4 o_colour;
+
+void main () {
+    o_colour = texture (u_texture, v_uv);
+}
+";
+
+// ACES filmic tonemapping fragment shader.
+static TONEMAP_FRAG: &'static str = "#version 330 core
+
+in vec2 v_uv;
+
+uniform sampler2D u_texture;
+uniform float u_exposure;
+
+out vec4 o_colour;
+
+// Fitted ACES curve, by Krzysztof Narkowicz.
+vec3 aces (vec3 x) {
+    return clamp ((x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14), 0.0, 1.0);
+}
+
+void main () {
+
+    vec4 colour = texture (u_texture, v_uv);
+    o_colour = vec4 (aces (colour.rgb * u_exposure), colour.a);
+}
+";
+
+// Gamma correction fragment shader.
+static GAMMA_FRAG: &'static str = "#version 330 core
+
+in vec2 v_uv;
+
+uniform sampler2D u_texture;
+uniform float u_gamma;
+
+out vec4 o_colour;
+
+vec3 linear_to_srgb (vec3 c) {
+    return mix (c * 12.92, 1.055 * pow (c, vec3 (1.0 / 2.4)) - 0.055, step (0.0031308, c));
+}
+
+void main () {
+
+    vec4 colour = texture (u_texture, v_uv);
+    vec3 rgb = max (colour.rgb, 0.0);
+
+    o_colour = vec4 (u_gamma > 0.0 ? pow (rgb, vec3 (1.0 / u_gamma)) : linear_to_srgb (rgb), colour.a);
+}
+";
+
+// Bloom bright pass fragment shader.
+static BLOOM_EXTRACT_FRAG: &'static str = "#version 330 core
+
+in vec2 v_uv;
+
+uniform sampler2D u_texture;
+uniform float u_bloom_threshold;
+
+out vec4 o_colour;
+
+void main () {
+
+    vec3 colour = texture (u_texture, v_uv).rgb;
+    float brightness = max (colour.r, max (colour.g, colour.b));
+    float contribution = max (brightness - u_bloom_threshold, 0.0) / max (brightness, 0.0001);
+
+    o_colour = vec4 (colour * contribution, 1.0);
+}
+";
+
+// Separable gaussian blur fragment shader.
+static BLOOM_BLUR_FRAG: &'static str = "#version 330 core
+
+in vec2 v_uv;
+
+uniform sampler2D u_texture;
+uniform vec2 u_texel_size;
+uniform vec2 u_blur_direction;
+
+out vec4 o_colour;
+
+const float weights[5] = float[] (0.227027, 0.1945946, 0.1216216, 0.054054, 0.016216);
+
+void main () {
+
+    vec3 result = texture (u_texture, v_uv).rgb * weights[0];
+
+    for (int i = 1; i < 5; i++) {
+
+        vec2 offset = u_blur_direction * u_texel_size * float (i);
+
+        result += texture (u_texture, v_uv + offset).rgb * weights[i];
+        result += texture (u_texture, v_uv - offset).rgb * weights[i];
+    }
+
+    o_colour = vec4 (result, 1.0);
+}
+";
+
+// Bloom composite fragment shader.
+static BLOOM_FRAG: &'static str = "#version 330 core
+
+in vec2 v_uv;
+
+uniform sampler2D u_texture;
+uniform sampler2D u_bloom_texture;
+uniform float u_bloom_intensity;
+
+out vec4 o_colour;
+
+void main () {
+
+    vec4 colour = texture (u_texture, v_uv);
+    o_colour = vec4 (colour.rgb + texture (u_bloom_texture, v_uv).rgb * u_bloom_intensity, colour.a);
+}
+";
+
+// FXAA fragment shader, based on the FXAA 3.11 console algorithm.
+static FXAA_FRAG: &'static str = "#version 330 core
+
+in vec2 v_uv;
+
+uniform sampler2D u_texture;
+uniform vec2 u_texel_size;
+uniform float u_fxaa_edge_threshold;
+
+out vec4 o_colour;
+
+float luma (vec3 c) {
+    return dot (c, vec3 (0.299, 0.587, 0.114));
+}
+
+void main () {
+
+    vec4 centre = texture (u_texture, v_uv);
+
+    float luma_nw = luma (texture (u_texture, v_uv + vec2 (-1.0, -1.0) * u_texel_size).rgb);
+    float luma_ne = luma (texture (u_texture, v_uv + vec2 ( 1.0, -1.0) * u_texel_size).rgb);
+    float luma_sw = luma (texture (u_texture, v_uv + vec2 (-1.0,  1.0) * u_texel_size).rgb);
+    float luma_se = luma (texture (u_texture, v_uv + vec2 ( 1.0,  1.0) * u_texel_size).rgb);
+    float luma_m  = luma (centre.rgb);
+
+    float luma_min = min (luma_m, min (min (luma_nw, luma_ne), min (luma_sw, luma_se)));
+    float luma_max = max (luma_m, max (max (luma_nw, luma_ne), max (luma_sw, luma_se)));
+
+    // Skip pixels without enough contrast to be an edge
+    if (luma_max - luma_min < max (0.0312, luma_max * u_fxaa_edge_threshold)) {
+
+        o_colour = centre;
+        return;
+    }
+
+    vec2 dir = vec2 (-((luma_nw + luma_ne) - (luma_sw + luma_se)),
+                      ((luma_nw + luma_sw) - (luma_ne + luma_se)));
+
+    float dir_reduce  = max ((luma_nw + luma_ne + luma_sw + luma_se) * 0.03125, 0.0078125);
+    float rcp_dir_min = 1.0 / (min (abs (dir.x), abs (dir.y)) + dir_reduce);
+
+    dir = clamp (dir * rcp_dir_min, vec2 (-8.0), vec2 (8.0)) * u_texel_size;
+
+    vec3 rgb_a = 0.5 * (texture (u_texture, v_uv + dir * (1.0 / 3.0 - 0.5)).rgb +
+                        texture (u_texture, v_uv + dir * (2.0 / 3.0 - 0.5)).rgb);
+
+    vec3 rgb_b = rgb_a * 0.5 + 0.25 * (texture (u_texture, v_uv - dir * 0.5).rgb +
+                                       texture (u_texture, v_uv + dir * 0.5).rgb);
+
+    float luma_b = luma (rgb_b);
+
+    o_colour = vec4 ((luma_b < luma_min || luma_b > luma_max) ? rgb_a : rgb_b, centre.a);
+}
+";
+
+// Vignette fragment shader.
+static VIGNETTE_FRAG: &'static str = "#version 330 core
+
+in vec2 v_uv;
+
+uniform sampler2D u_texture;
+uniform float u_vignette_intensity;
+uniform float u_vignette_radius;
+uniform float u_vignette_softness;
+
+out vec4 o_colour;
+
+void main () {
+
+    vec4 colour = texture (u_texture, v_uv);
+
+    // 0 at the centre, and 1 in the corners
+    float dist = length (v_uv - 0.5) * 1.41421356;
+    float falloff = smoothstep (u_vignette_radius - u_vignette_softness, u_vignette_radius, dist);
+
+    o_colour = vec4 (colour.rgb * (1.0 - falloff * u_vignette_intensity), colour.a);
+}
+";
+
+// Colour grading fragment shader.
+//
+// The lookup table is a strip of square slices, one per blue value. Within a slice,
+// red increases along the width, and green along the height.
+static COLOUR_GRADING_FRAG: &'static str = "#version 330 core
+
+in vec2 v_uv;
+
+uniform sampler2D u_texture;
+uniform sampler2D u_lut;
+uniform float u_lut_strength;
+
+out vec4 o_colour;
+
+void main () {
+
+    vec4 colour = texture (u_texture, v_uv);
+    vec3 rgb = clamp (colour.rgb, 0.0, 1.0);
+
+    vec2 lut_size = vec2 (textureSize (u_lut, 0));
+    float size = lut_size.y;
+
+    float blue = rgb.b * (size - 1.0);
+    float slice_0 = floor (blue);
+    float slice_1 = min (slice_0 + 1.0, size - 1.0);
+
+    vec2 uv = vec2 ((rgb.r * (size - 1.0) + 0.5) / lut_size.x, (rgb.g * (size - 1.0) + 0.5) / size);
+
+    vec3 graded_0 = textureLod (u_lut, uv + vec2 (slice_0 * size / lut_size.x, 0.0), 0.0).rgb;
+    vec3 graded_1 = textureLod (u_lut, uv + vec2 (slice_1 * size / lut_size.x, 0.0), 0.0).rgb;
+    vec3 graded = mix (graded_0, graded_1, blue - slice_0);
+
+    o_colour = vec4 (mix (colour.rgb, graded, u_lut_strength), colour.a);
+}
+";
+
+/*===============================================================================================*/
+/*------BUILTIN SHADERS GL STRUCT----------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Holds the GLSL source of the built in shaders.
+#[derive (Copy, Clone)]
+pub struct BuiltinShadersGL;
+
+/*===============================================================================================*/
+/*------BUILTIN SHADERS GL PUBLIC STATIC METHODS-------------------------------------------------*/
+/*===============================================================================================*/
+
+impl BuiltinShadersGL {
+
+    /// Returns the source of one stage of a built in shader.
+    pub fn get_source (shader: BuiltinShader, stage: ShaderStage) -> String {
+
+        match (shader, stage) {
+
+            (BuiltinShader::Sprite, ShaderStage::Vertex)   => SPRITE_VERT.to_string (),
+            (BuiltinShader::Sprite, ShaderStage::Fragment) => SPRITE_FRAG.to_string (),
+
+            (BuiltinShader::DebugLine, ShaderStage::Vertex)   => DEBUG_LINE_VERT.to_string (),
+            (BuiltinShader::DebugLine, ShaderStage::Fragment) => DEBUG_LINE_FRAG.to_string (),
+
+            (BuiltinShader::ForwardLit (_), ShaderStage::Vertex) => FORWARD_LIT_VERT.to_string (),
+            (BuiltinShader::ForwardLit (max_lights), ShaderStage::Fragment) => {
+
+                // GLSL arrays can't be empty
+                format! ("#version 330 core\n\n#define MAX_LIGHTS {}\n{}", cmp::max (max_lights, 1), FORWARD_LIT_FRAG)
+            },
+
+            (BuiltinShader::ShadowDepth, ShaderStage::Vertex)   => SHADOW_DEPTH_VERT.to_string (),
+            (BuiltinShader::ShadowDepth, ShaderStage::Fragment) => SHADOW_DEPTH_FRAG.to_string (),
+
+            (BuiltinShader::PostEffect (_), ShaderStage::Vertex) |
+            (BuiltinShader::BloomExtract, ShaderStage::Vertex)   |
+            (BuiltinShader::BloomBlur, ShaderStage::Vertex)      |
+            (BuiltinShader::Blit, ShaderStage::Vertex)           => POST_VERT.to_string (),
+
+            (BuiltinShader::PostEffect (effect), ShaderStage::Fragment) => {
+
+                let source = match effect {
+
+                    PostEffectType::Tonemap       => TONEMAP_FRAG,
+                    PostEffectType::Gamma         => GAMMA_FRAG,
+                    PostEffectType::Bloom         => BLOOM_FRAG,
+                    PostEffectType::Fxaa          => FXAA_FRAG,
+                    PostEffectType::Vignette      => VIGNETTE_FRAG,
+                    PostEffectType::ColourGrading => COLOUR_GRADING_FRAG,
+                };
+
+                source.to_string ()
+            },
+
+            (BuiltinShader::BloomExtract, ShaderStage::Fragment) => BLOOM_EXTRACT_FRAG.to_string (),
+            (BuiltinShader::BloomBlur, ShaderStage::Fragment)    => BLOOM_BLUR_FRAG.to_string (),
+            (BuiltinShader::Blit, ShaderStage::Fragment)         => BLIT_FRAG.to_string (),
+        }
+    }
+}
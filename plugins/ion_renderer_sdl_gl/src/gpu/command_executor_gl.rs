@@ -0,0 +1,305 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate gl;
+extern crate ion_core;
+
+use ::gpu::{BufferGL, PipelineGL, RenderTargetGL, TextureGL};
+
+use self::gl::types::*;
+use self::ion_core::renderer::command::{CommandBuffer, RenderCommand};
+use self::ion_core::renderer::gpu::{BlendMode, CullMode, DepthFunc, IndexFormat, RenderState};
+use self::ion_core::renderer::traits::Pipeline;
+use self::ion_core::util::traits::AsAny;
+
+use std::os::raw::c_void;
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------COMMAND EXECUTOR GL STRUCT---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Translates recorded render commands into `OpenGL` calls.
+///
+/// The render state lasts between command buffers, so the executor remembers the parts of it
+/// which other commands have to change temporarily.
+#[derive (Copy, Clone)]
+pub struct CommandExecutorGL {
+
+    // Private
+    _depth_write: bool,
+}
+
+/*===============================================================================================*/
+/*------COMMAND EXECUTOR GL PUBLIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl CommandExecutorGL {
+
+    /// Executes the commands in a command buffer, in order.
+    ///
+    /// Resources which were not created by the `OpenGL` render factory are skipped.
+    pub fn execute (&mut self, command_buffer: &CommandBuffer) {
+
+        let mut pipeline: Option<Rc<Pipeline>> = None;
+        let mut index_format = IndexFormat::U16;
+
+        for command in command_buffer.get_commands () {
+
+            match *command {
+
+                RenderCommand::SetRenderTarget (ref target) => {
+
+                    let target_id = match *target {
+
+                        Some (ref t) => match t.as_any ().downcast_ref::<RenderTargetGL> () {
+
+                            Some (t) => t.gl_id,
+                            None => {warn! ("Render target was not created by the OpenGL render factory."); continue;}
+                        },
+
+                        None => 0
+                    };
+
+                    unsafe {gl::BindFramebuffer (gl::FRAMEBUFFER, target_id)};
+                }
+
+                RenderCommand::SetViewport (x, y, width, height) => unsafe {
+                    gl::Viewport (x, y, width as GLsizei, height as GLsizei);
+                },
+
+                RenderCommand::SetScissor (rect) => unsafe {
+
+                    match rect {
+
+                        Some ((x, y, width, height)) => {
+
+                            gl::Enable  (gl::SCISSOR_TEST);
+                            gl::Scissor (x, y, width as GLsizei, height as GLsizei);
+                        },
+
+                        None => gl::Disable (gl::SCISSOR_TEST)
+                    }
+                },
+
+                RenderCommand::Clear {colour, depth, stencil} => unsafe {
+
+                    let mut mask = 0;
+
+                    if let Some (c) = colour {
+
+                        gl::ClearColor (c.r, c.g, c.b, c.a);
+                        mask |= gl::COLOR_BUFFER_BIT;
+                    }
+
+                    if let Some (d) = depth {
+
+                        gl::DepthMask  (gl::TRUE);
+                        gl::ClearDepth (d as GLdouble);
+                        mask |= gl::DEPTH_BUFFER_BIT;
+                    }
+
+                    if let Some (s) = stencil {
+
+                        gl::ClearStencil (s);
+                        mask |= gl::STENCIL_BUFFER_BIT;
+                    }
+
+                    if mask != 0 {
+                        gl::Clear (mask);
+                    }
+
+                    // Depth can only be cleared while it is writable, so restore the bound state
+                    if depth.is_some () {
+                        gl::DepthMask (if self._depth_write {gl::TRUE} else {gl::FALSE});
+                    }
+                },
+
+                RenderCommand::SetRenderState (ref state) => {
+
+                    CommandExecutorGL::_apply_render_state (state);
+                    self._depth_write = state.depth_write;
+                },
+
+                RenderCommand::BindPipeline (ref p) => {
+
+                    // Only OpenGL pipelines are kept for the following draw calls
+                    pipeline = match p.as_any ().downcast_ref::<PipelineGL> () {
+
+                        Some (p_gl) => {p_gl.bind (); Some (p.clone ())},
+                        None => {warn! ("Pipeline was not created by the OpenGL render factory."); None}
+                    };
+                }
+
+                RenderCommand::BindVertexBuffer (ref buffer) | RenderCommand::BindIndexBuffer (ref buffer, _) => {
+
+                    if let RenderCommand::BindIndexBuffer (_, format) = *command {
+                        index_format = format;
+                    }
+
+                    match buffer.as_any ().downcast_ref::<BufferGL> () {
+
+                        Some (b) => unsafe {gl::BindBuffer (b.get_gl_target (), b.gl_id)},
+                        None => warn! ("Buffer was not created by the OpenGL render factory.")
+                    }
+                }
+
+                RenderCommand::BindTexture (slot, ref texture) => {
+
+                    match texture.as_any ().downcast_ref::<TextureGL> () {
+
+                        Some (t) => unsafe {
+
+                            gl::ActiveTexture (gl::TEXTURE0 + slot);
+                            gl::BindTexture   (gl::TEXTURE_2D, t.gl_id);
+                        },
+
+                        None => warn! ("Texture was not created by the OpenGL render factory.")
+                    }
+                }
+
+                RenderCommand::SetUniform (ref name, ref value) => {
+
+                    if let Some (p) = CommandExecutorGL::_get_pipeline (&pipeline) {
+                        p.set_uniform (name, value);
+                    }
+                }
+
+                RenderCommand::Draw (first, count) => {
+
+                    if let Some (p) = CommandExecutorGL::_get_pipeline (&pipeline) {
+
+                        p.apply_vertex_layout ();
+
+                        unsafe {
+                            gl::DrawArrays (PipelineGL::get_gl_primitive (p.get_primitive_type ()), first as GLint, count as GLsizei);
+                        }
+                    }
+                }
+
+                RenderCommand::DrawIndexed (first, count) => {
+
+                    if let Some (p) = CommandExecutorGL::_get_pipeline (&pipeline) {
+
+                        let (index_type, index_size) = match index_format {
+
+                            IndexFormat::U16 => (gl::UNSIGNED_SHORT, 2),
+                            IndexFormat::U32 => (gl::UNSIGNED_INT,   4),
+                        };
+
+                        p.apply_vertex_layout ();
+
+                        unsafe {
+
+                            gl::DrawElements (PipelineGL::get_gl_primitive (p.get_primitive_type ()),
+                                              count as GLsizei,
+                                              index_type,
+                                              (first as usize * index_size) as *const c_void);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------COMMAND EXECUTOR GL PUBLIC STATIC METHODS------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a new command executor, for a context in its default state.
+    pub fn new () -> CommandExecutorGL {
+        CommandExecutorGL {_depth_write: true}
+    }
+
+/*===============================================================================================*/
+/*------COMMAND EXECUTOR GL PRIVATE STATIC METHODS-----------------------------------------------*/
+/*===============================================================================================*/
+
+    // Applies the blend, cull and depth state.
+    fn _apply_render_state (state: &RenderState) {
+
+        unsafe {
+
+            match state.blend_mode {
+
+                BlendMode::Opaque => gl::Disable (gl::BLEND),
+                mode => {
+
+                    let (src, dst) = match mode {
+
+                        BlendMode::Premultiplied => (gl::ONE,       gl::ONE_MINUS_SRC_ALPHA),
+                        BlendMode::Additive      => (gl::SRC_ALPHA, gl::ONE),
+                        BlendMode::Multiply      => (gl::DST_COLOR, gl::ZERO),
+                        _                        => (gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA),
+                    };
+
+                    gl::Enable (gl::BLEND);
+                    gl::BlendFuncSeparate (src, dst, gl::ONE, gl::ONE_MINUS_SRC_ALPHA);
+                }
+            }
+
+            match state.cull_mode {
+
+                CullMode::None  => gl::Disable (gl::CULL_FACE),
+                CullMode::Back  => {gl::Enable (gl::CULL_FACE); gl::CullFace (gl::BACK)},
+                CullMode::Front => {gl::Enable (gl::CULL_FACE); gl::CullFace (gl::FRONT)},
+            }
+
+            if state.depth_test {
+
+                gl::Enable (gl::DEPTH_TEST);
+                gl::DepthFunc (match state.depth_func {
+
+                    DepthFunc::Never        => gl::NEVER,
+                    DepthFunc::Less         => gl::LESS,
+                    DepthFunc::LessEqual    => gl::LEQUAL,
+                    DepthFunc::Equal        => gl::EQUAL,
+                    DepthFunc::GreaterEqual => gl::GEQUAL,
+                    DepthFunc::Greater      => gl::GREATER,
+                    DepthFunc::Always       => gl::ALWAYS,
+                });
+            }
+
+            else {
+                gl::Disable (gl::DEPTH_TEST);
+            }
+
+            gl::DepthMask (if state.depth_write {gl::TRUE} else {gl::FALSE});
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Returns the bound pipeline as an OpenGL pipeline.
+    fn _get_pipeline (pipeline: &Option<Rc<Pipeline>>) -> Option<&PipelineGL> {
+
+        match *pipeline {
+
+            Some (ref p) => p.as_any ().downcast_ref::<PipelineGL> (),
+            None => None
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for CommandExecutorGL {
+
+    fn default () -> CommandExecutorGL {
+        CommandExecutorGL::new ()
+    }
+}
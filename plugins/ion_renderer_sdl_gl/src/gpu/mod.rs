@@ -0,0 +1,36 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+/*===============================================================================================*/
+//! Contains the `OpenGL` implementations of the GPU resources.
+/*===============================================================================================*/
+
+mod buffer_gl;
+mod builtin_shaders_gl;
+mod command_executor_gl;
+mod pipeline_gl;
+mod render_target_gl;
+mod shader_gl;
+mod texture_gl;
+
+pub use self::buffer_gl::BufferGL;
+pub use self::builtin_shaders_gl::BuiltinShadersGL;
+pub use self::command_executor_gl::CommandExecutorGL;
+pub use self::pipeline_gl::PipelineGL;
+pub use self::render_target_gl::RenderTargetGL;
+pub use self::shader_gl::ShaderGL;
+pub use self::texture_gl::TextureGL;
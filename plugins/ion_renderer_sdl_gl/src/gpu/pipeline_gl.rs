@@ -0,0 +1,295 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate gl;
+extern crate ion_core;
+
+use ::gpu::ShaderGL;
+
+use self::gl::types::*;
+use self::ion_core::renderer::gpu::{PipelineDesc, PrimitiveType, UniformValue, VertexFormat, VertexLayout};
+use self::ion_core::renderer::traits::{Pipeline, Shader};
+use self::ion_core::util::traits::AsAny;
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::os::raw::c_void;
+use std::ptr;
+
+// The vertex attribute locations enabled by the last vertex layout applied, as a bit mask.
+// Attribute arrays are context state, so they outlive the pipeline which enabled them.
+thread_local! (static ENABLED_ATTRIBUTES: Cell<u32> = Cell::new (0));
+
+/*===============================================================================================*/
+/*------PIPELINE GL STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An `OpenGL` program object, along with its vertex layout.
+pub struct PipelineGL {
+
+    // Public
+    /// The program object name.
+    pub gl_id: GLuint,
+
+    // Private
+    _desc: PipelineDesc,
+    _attrib_locations: Vec<GLint>,
+    _uniform_locations: RefCell<HashMap<String, GLint>>,
+}
+
+/*===============================================================================================*/
+/*------PIPELINE GL PUBLIC METHODS---------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PipelineGL {
+
+    /// Makes the program current.
+    pub fn bind (&self) {
+        unsafe {gl::UseProgram (self.gl_id)};
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets up the vertex attributes for the currently bound vertex buffer.
+    ///
+    /// Attribute locations left enabled by a previous pipeline, which this one doesn't use,
+    /// are disabled.
+    pub fn apply_vertex_layout (&self) {
+
+        let layout = &self._desc.vertex_layout;
+        let mut enabled = 0u32;
+
+        for (attribute, location) in layout.attributes.iter ().zip (self._attrib_locations.iter ()) {
+
+            // The attribute is not used by the shader
+            if *location < 0 {
+                continue;
+            }
+
+            let (data_type, normalized) = match attribute.format {
+
+                VertexFormat::UByte4Norm => (gl::UNSIGNED_BYTE, gl::TRUE),
+                _ => (gl::FLOAT, gl::FALSE),
+            };
+
+            unsafe {
+
+                gl::EnableVertexAttribArray (*location as GLuint);
+                gl::VertexAttribPointer     (*location as GLuint,
+                                             attribute.format.get_component_count () as GLint,
+                                             data_type,
+                                             normalized,
+                                             layout.stride as GLsizei,
+                                             attribute.offset as *const c_void);
+            }
+
+            if *location < 32 {
+                enabled |= 1 << *location;
+            }
+        }
+
+        ENABLED_ATTRIBUTES.with (|previous| {
+
+            let stale = previous.get () & !enabled;
+
+            for location in 0..32 {
+
+                if stale & (1 << location) != 0 {
+                    unsafe {gl::DisableVertexAttribArray (location as GLuint)};
+                }
+            }
+
+            previous.set (enabled);
+        });
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Sets a uniform on the program.
+    ///
+    /// The program must be bound. Uniforms which don't exist are ignored.
+    pub fn set_uniform (&self, name: &str, value: &UniformValue) {
+
+        let location = self._get_uniform_location (name);
+
+        if location < 0 {
+            return;
+        }
+
+        unsafe {
+
+            match *value {
+
+                UniformValue::Int    (v) => gl::Uniform1i (location, v),
+                UniformValue::Float  (v) => gl::Uniform1f (location, v),
+                UniformValue::Vec2   (v) => gl::Uniform2f (location, v.x, v.y),
+                UniformValue::Vec3   (v) => gl::Uniform3f (location, v.x, v.y, v.z),
+                UniformValue::Vec4   (v) => gl::Uniform4f (location, v.x, v.y, v.z, v.w),
+                UniformValue::Colour (v) => gl::Uniform4f (location, v.r, v.g, v.b, v.a),
+                UniformValue::Mat4   (m) => {
+
+                    let mut values = [0.0f32; 16];
+
+                    for col in 0..4u8 {
+
+                        for row in 0..4u8 {
+                            values[(col * 4 + row) as usize] = m[col][row];
+                        }
+                    }
+
+                    gl::UniformMatrix4fv (location, 1, gl::FALSE, values.as_ptr ());
+                }
+            }
+        }
+    }
+
+/*===============================================================================================*/
+/*------PIPELINE GL PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Links a new program object from the shaders in the description.
+    ///
+    /// On failure, the program info log is returned.
+    pub fn new (desc: &PipelineDesc) -> Result<PipelineGL, String> {
+
+        let vertex_shader   = try! (PipelineGL::_get_shader (&*desc.vertex_shader));
+        let fragment_shader = try! (PipelineGL::_get_shader (&*desc.fragment_shader));
+
+        unsafe {
+
+            let mut pipeline = PipelineGL {gl_id: gl::CreateProgram (),
+                                           _desc: desc.clone (),
+                                           _attrib_locations: Vec::new (),
+                                           _uniform_locations: RefCell::new (HashMap::new ())};
+
+            gl::AttachShader (pipeline.gl_id, vertex_shader.gl_id);
+            gl::AttachShader (pipeline.gl_id, fragment_shader.gl_id);
+            gl::LinkProgram  (pipeline.gl_id);
+
+            // Check the link status
+            let mut status = gl::FALSE as GLint;
+            gl::GetProgramiv (pipeline.gl_id, gl::LINK_STATUS, &mut status);
+
+            if status != gl::TRUE as GLint {
+
+                let mut log_length = 0;
+                gl::GetProgramiv (pipeline.gl_id, gl::INFO_LOG_LENGTH, &mut log_length);
+
+                let mut log = vec! [0u8; log_length as usize];
+                gl::GetProgramInfoLog (pipeline.gl_id, log_length, ptr::null_mut (), log.as_mut_ptr () as *mut GLchar);
+
+                return Err (String::from_utf8_lossy (&log).trim_right_matches ('\0').to_string ());
+            }
+
+            // Look up the attribute locations
+            for attribute in &desc.vertex_layout.attributes {
+
+                let name = CString::new (attribute.name.clone ()).unwrap ();
+                pipeline._attrib_locations.push (gl::GetAttribLocation (pipeline.gl_id, name.as_ptr ()));
+            }
+
+            Ok (pipeline)
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the `OpenGL` primitive mode of a primitive type.
+    pub fn get_gl_primitive (primitive_type: PrimitiveType) -> GLenum {
+
+        match primitive_type {
+
+            PrimitiveType::Triangles     => gl::TRIANGLES,
+            PrimitiveType::TriangleStrip => gl::TRIANGLE_STRIP,
+            PrimitiveType::Lines         => gl::LINES,
+            PrimitiveType::Points        => gl::POINTS,
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Pipeline for PipelineGL {
+
+    fn get_vertex_layout (&self) -> &VertexLayout {
+        &self._desc.vertex_layout
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_primitive_type (&self) -> PrimitiveType {
+        self._desc.primitive_type
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for PipelineGL {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Drop for PipelineGL {
+
+    fn drop (&mut self) {
+        unsafe {gl::DeleteProgram (self.gl_id)};
+    }
+}
+
+/*===============================================================================================*/
+/*------PIPELINE GL PRIVATE METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl PipelineGL {
+
+    // Returns the location of a uniform, caching the result.
+    fn _get_uniform_location (&self, name: &str) -> GLint {
+
+        if let Some (location) = self._uniform_locations.borrow ().get (name) {
+            return *location;
+        }
+
+        let location = match CString::new (name) {
+
+            Ok (name_c) => unsafe {gl::GetUniformLocation (self.gl_id, name_c.as_ptr ())},
+            Err (_) => -1
+        };
+
+        self._uniform_locations.borrow_mut ().insert (name.to_string (), location);
+        location
+    }
+
+/*===============================================================================================*/
+/*------PIPELINE GL PRIVATE STATIC METHODS-------------------------------------------------------*/
+/*===============================================================================================*/
+
+    // Casts a shader back to an OpenGL shader.
+    fn _get_shader (shader: &Shader) -> Result<&ShaderGL, String> {
+
+        match shader.as_any ().downcast_ref::<ShaderGL> () {
+
+            Some (s) => Ok (s),
+            None => Err ("Shader was not created by the OpenGL render factory.".to_string ())
+        }
+    }
+}
@@ -0,0 +1,166 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate gl;
+extern crate ion_core;
+
+use ::gpu::TextureGL;
+
+use self::gl::types::*;
+use self::ion_core::renderer::gpu::{RenderTargetDesc, TextureDesc, TextureWrap};
+use self::ion_core::renderer::traits::{RenderTarget, Texture};
+use self::ion_core::util::image::{Image, ImageFormat};
+use self::ion_core::util::traits::AsAny;
+
+use std::any::Any;
+use std::os::raw::c_void;
+use std::rc::Rc;
+
+/*===============================================================================================*/
+/*------RENDER TARGET GL STRUCT------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An `OpenGL` framebuffer object, with a colour texture and optional depth / stencil buffer.
+pub struct RenderTargetGL {
+
+    // Public
+    /// The framebuffer object name.
+    pub gl_id: GLuint,
+
+    // Private
+    _desc: RenderTargetDesc,
+    _colour_texture: Rc<TextureGL>,
+    _depth_buffer: GLuint,
+}
+
+/*===============================================================================================*/
+/*------RENDER TARGET GL PUBLIC STATIC METHODS---------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderTargetGL {
+
+    /// Creates a new framebuffer object.
+    pub fn new (desc: &RenderTargetDesc) -> Result<RenderTargetGL, String> {
+
+        let mut texture_desc = TextureDesc::new (desc.width, desc.height, desc.colour_format);
+        texture_desc.wrap = TextureWrap::Clamp;
+
+        let mut target = RenderTargetGL {gl_id: 0,
+                                         _desc: *desc,
+                                         _colour_texture: Rc::new (TextureGL::new (&texture_desc, &[])),
+                                         _depth_buffer: 0};
+
+        unsafe {
+
+            gl::GenFramebuffers      (1, &mut target.gl_id);
+            gl::BindFramebuffer      (gl::FRAMEBUFFER, target.gl_id);
+            gl::FramebufferTexture2D (gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT0, gl::TEXTURE_2D, target._colour_texture.gl_id, 0);
+
+            // Create the depth / stencil buffer
+            if desc.has_depth {
+
+                gl::GenRenderbuffers        (1, &mut target._depth_buffer);
+                gl::BindRenderbuffer        (gl::RENDERBUFFER, target._depth_buffer);
+                gl::RenderbufferStorage     (gl::RENDERBUFFER, gl::DEPTH24_STENCIL8, desc.width as GLsizei, desc.height as GLsizei);
+                gl::FramebufferRenderbuffer (gl::FRAMEBUFFER, gl::DEPTH_STENCIL_ATTACHMENT, gl::RENDERBUFFER, target._depth_buffer);
+            }
+
+            let status = gl::CheckFramebufferStatus (gl::FRAMEBUFFER);
+            gl::BindFramebuffer (gl::FRAMEBUFFER, 0);
+
+            if status != gl::FRAMEBUFFER_COMPLETE {
+                return Err (format! ("Framebuffer is incomplete (status 0x{:x}).", status));
+            }
+        }
+
+        Ok (target)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Reads back a region of a framebuffer as an `RGBA8` image, with the rows ordered top to bottom.
+    ///
+    /// The framebuffer name 0 reads the default framebuffer.
+    pub fn read_pixels (framebuffer: GLuint, x: i32, y: i32, width: u32, height: u32) -> Image {
+
+        let mut image = Image::new (width, height, ImageFormat::RGBA8);
+
+        unsafe {
+
+            let mut previous = 0;
+            gl::GetIntegerv (gl::READ_FRAMEBUFFER_BINDING, &mut previous);
+
+            gl::BindFramebuffer (gl::READ_FRAMEBUFFER, framebuffer);
+            gl::PixelStorei     (gl::PACK_ALIGNMENT, 1);
+            gl::ReadPixels      (x, y, width as GLsizei, height as GLsizei, gl::RGBA, gl::UNSIGNED_BYTE,
+                                 image.get_data_mut ().as_mut_ptr () as *mut c_void);
+
+            gl::BindFramebuffer (gl::READ_FRAMEBUFFER, previous as GLuint);
+        }
+
+        // OpenGL rows start at the bottom
+        image.flip_vertical ();
+        image
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl RenderTarget for RenderTargetGL {
+
+    fn get_width (&self) -> u32 {
+        self._desc.width
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_height (&self) -> u32 {
+        self._desc.height
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_colour_texture (&self) -> Rc<Texture> {
+        self._colour_texture.clone ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for RenderTargetGL {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Drop for RenderTargetGL {
+
+    fn drop (&mut self) {
+
+        unsafe {
+
+            gl::DeleteFramebuffers (1, &self.gl_id);
+
+            if self._depth_buffer != 0 {
+                gl::DeleteRenderbuffers (1, &self._depth_buffer);
+            }
+        }
+    }
+}
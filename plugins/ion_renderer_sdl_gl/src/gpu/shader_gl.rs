@@ -0,0 +1,120 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate gl;
+extern crate ion_core;
+
+use self::gl::types::*;
+use self::ion_core::renderer::gpu::ShaderStage;
+use self::ion_core::renderer::traits::Shader;
+use self::ion_core::util::traits::AsAny;
+
+use std::any::Any;
+use std::ffi::CString;
+use std::ptr;
+
+/*===============================================================================================*/
+/*------SHADER GL STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An `OpenGL` shader object.
+pub struct ShaderGL {
+
+    // Public
+    /// The shader object name.
+    pub gl_id: GLuint,
+
+    // Private
+    _stage: ShaderStage,
+}
+
+/*===============================================================================================*/
+/*------SHADER GL PUBLIC STATIC METHODS----------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ShaderGL {
+
+    /// Compiles a new shader object from GLSL source.
+    ///
+    /// On failure, the shader info log is returned.
+    pub fn new (stage: ShaderStage, source: &str) -> Result<ShaderGL, String> {
+
+        let gl_stage = match stage {
+
+            ShaderStage::Vertex   => gl::VERTEX_SHADER,
+            ShaderStage::Fragment => gl::FRAGMENT_SHADER,
+        };
+
+        let source_c = match CString::new (source) {
+
+            Ok (s) => s,
+            Err (_) => return Err ("Shader source contains a null character.".to_string ())
+        };
+
+        unsafe {
+
+            let shader = ShaderGL {gl_id: gl::CreateShader (gl_stage), _stage: stage};
+
+            gl::ShaderSource  (shader.gl_id, 1, &source_c.as_ptr (), ptr::null ());
+            gl::CompileShader (shader.gl_id);
+
+            // Check the compile status
+            let mut status = gl::FALSE as GLint;
+            gl::GetShaderiv (shader.gl_id, gl::COMPILE_STATUS, &mut status);
+
+            if status != gl::TRUE as GLint {
+
+                let mut log_length = 0;
+                gl::GetShaderiv (shader.gl_id, gl::INFO_LOG_LENGTH, &mut log_length);
+
+                let mut log = vec! [0u8; log_length as usize];
+                gl::GetShaderInfoLog (shader.gl_id, log_length, ptr::null_mut (), log.as_mut_ptr () as *mut GLchar);
+
+                return Err (String::from_utf8_lossy (&log).trim_right_matches ('\0').to_string ());
+            }
+
+            Ok (shader)
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Shader for ShaderGL {
+
+    fn get_shader_stage (&self) -> ShaderStage {
+        self._stage
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for ShaderGL {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Drop for ShaderGL {
+
+    fn drop (&mut self) {
+        unsafe {gl::DeleteShader (self.gl_id)};
+    }
+}
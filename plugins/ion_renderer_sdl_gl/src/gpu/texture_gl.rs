@@ -0,0 +1,188 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate gl;
+extern crate ion_core;
+
+use self::gl::types::*;
+use self::ion_core::renderer::gpu::{TextureDesc, TextureFilter, TextureFormat, TextureWrap};
+use self::ion_core::renderer::traits::Texture;
+use self::ion_core::util::traits::AsAny;
+
+use std::any::Any;
+use std::os::raw::c_void;
+use std::ptr;
+
+/*===============================================================================================*/
+/*------TEXTURE GL STRUCT------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// An `OpenGL` 2D texture object.
+pub struct TextureGL {
+
+    // Public
+    /// The texture object name.
+    pub gl_id: GLuint,
+
+    // Private
+    _desc: TextureDesc,
+}
+
+/*===============================================================================================*/
+/*------TEXTURE GL PUBLIC STATIC METHODS---------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl TextureGL {
+
+    /// Creates a new texture object, filled with `data`.
+    ///
+    /// The texture is left uninitialized if `data` is empty, or too small to fill it.
+    pub fn new (desc: &TextureDesc, data: &[u8]) -> TextureGL {
+
+        let mut texture = TextureGL {gl_id: 0, _desc: *desc};
+        let (internal, format, data_type) = TextureGL::get_gl_format (desc.format);
+
+        let expected_size = (desc.width * desc.height) as usize * desc.format.get_pixel_size ();
+
+        let data_ptr = if data.is_empty () {
+            ptr::null ()
+        }
+
+        else if data.len () < expected_size {
+
+            warn! ("Texture data is too small ({} bytes, expected {}), so it is not uploaded.", data.len (), expected_size);
+            ptr::null ()
+        }
+
+        else {
+            data.as_ptr () as *const c_void
+        };
+
+        let (min_filter, mag_filter) = match desc.filter {
+
+            TextureFilter::Nearest => (if desc.has_mipmaps {gl::NEAREST_MIPMAP_NEAREST} else {gl::NEAREST}, gl::NEAREST),
+            TextureFilter::Linear  => (if desc.has_mipmaps {gl::LINEAR_MIPMAP_LINEAR}   else {gl::LINEAR},  gl::LINEAR),
+        };
+
+        let wrap = match desc.wrap {
+
+            TextureWrap::Repeat => gl::REPEAT,
+            TextureWrap::Clamp  => gl::CLAMP_TO_EDGE,
+        };
+
+        unsafe {
+
+            gl::GenTextures   (1, &mut texture.gl_id);
+            gl::BindTexture   (gl::TEXTURE_2D, texture.gl_id);
+            gl::PixelStorei   (gl::UNPACK_ALIGNMENT, 1);
+            gl::TexImage2D    (gl::TEXTURE_2D, 0, internal as GLint, desc.width as GLsizei, desc.height as GLsizei,
+                               0, format, data_type, data_ptr);
+
+            gl::TexParameteri (gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, min_filter as GLint);
+            gl::TexParameteri (gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, mag_filter as GLint);
+            gl::TexParameteri (gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, wrap as GLint);
+            gl::TexParameteri (gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, wrap as GLint);
+
+            if desc.has_mipmaps {
+                gl::GenerateMipmap (gl::TEXTURE_2D);
+            }
+        }
+
+        texture
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the `OpenGL` internal format, format and data type of a texture format.
+    pub fn get_gl_format (format: TextureFormat) -> (GLenum, GLenum, GLenum) {
+
+        match format {
+
+            TextureFormat::R8              => (gl::R8,               gl::RED,           gl::UNSIGNED_BYTE),
+            TextureFormat::RGBA8           => (gl::RGBA8,            gl::RGBA,          gl::UNSIGNED_BYTE),
+            TextureFormat::RGBA16F         => (gl::RGBA16F,          gl::RGBA,          gl::HALF_FLOAT),
+            TextureFormat::R32F            => (gl::R32F,             gl::RED,           gl::FLOAT),
+            TextureFormat::Depth24Stencil8 => (gl::DEPTH24_STENCIL8, gl::DEPTH_STENCIL, gl::UNSIGNED_INT_24_8),
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Texture for TextureGL {
+
+    fn get_width (&self) -> u32 {
+        self._desc.width
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_height (&self) -> u32 {
+        self._desc.height
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_format (&self) -> TextureFormat {
+        self._desc.format
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn set_data (&self, data: &[u8]) {
+
+        let expected_size = (self._desc.width * self._desc.height) as usize * self._desc.format.get_pixel_size ();
+
+        if data.len () < expected_size {
+
+            warn! ("Texture data is too small ({} bytes, expected {}).", data.len (), expected_size);
+            return;
+        }
+
+        let (_, format, data_type) = TextureGL::get_gl_format (self._desc.format);
+
+        unsafe {
+
+            gl::BindTexture     (gl::TEXTURE_2D, self.gl_id);
+            gl::PixelStorei     (gl::UNPACK_ALIGNMENT, 1);
+            gl::TexSubImage2D   (gl::TEXTURE_2D, 0, 0, 0, self._desc.width as GLsizei, self._desc.height as GLsizei,
+                                 format, data_type, data.as_ptr () as *const c_void);
+
+            if self._desc.has_mipmaps {
+                gl::GenerateMipmap (gl::TEXTURE_2D);
+            }
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl AsAny for TextureGL {
+
+    fn as_any (&self) -> &Any {
+        self
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Drop for TextureGL {
+
+    fn drop (&mut self) {
+        unsafe {gl::DeleteTextures (1, &self.gl_id)};
+    }
+}
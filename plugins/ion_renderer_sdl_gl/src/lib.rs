@@ -26,13 +26,20 @@
 #![feature (plugin)]
 
 // Modules
+#[macro_use]
+extern crate log;
 extern crate ion_core;
 
+pub mod audio;
+pub mod gpu;
 pub mod window;
 mod render_factory;
+mod sdl_context;
 
 pub use self::render_factory::RenderFactorySDLGL;
+pub use self::sdl_context::SdlContext;
 
+use ion_core::audio::AudioBackend;
 use ion_core::renderer::traits::RenderFactory;
 use ion_core::resource::{PluginInfo, PluginType};
 use ion_core::util::Version;
@@ -66,5 +73,13 @@ pub fn get_plugin_info () -> PluginInfo {
 /// Returns an instance of the render factory.
 #[no_mangle]
 pub fn get_factory () -> Box<RenderFactory> {
-    Box::new (RenderFactorySDLGL)
+    Box::new (RenderFactorySDLGL::new ())
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Returns an instance of the audio backend.
+#[no_mangle]
+pub fn get_audio_backend () -> Box<AudioBackend> {
+    Box::new (audio::AudioBackendSDL2::new ())
 }
@@ -16,17 +16,32 @@
 
 extern crate ion_core;
 
+use ::gpu::{BufferGL, BuiltinShadersGL, CommandExecutorGL, PipelineGL, RenderTargetGL, ShaderGL, TextureGL};
 use ::window::WindowBackendSDL2;
 
+use self::ion_core::renderer::command::CommandBuffer;
+use self::ion_core::renderer::gpu::{BufferDesc, BuiltinShader, PipelineDesc, RenderTargetDesc, ShaderStage, TextureDesc};
+use self::ion_core::renderer::traits::{Buffer, Pipeline, RenderTarget, Shader, Texture};
 use self::ion_core::renderer::traits::{WindowBackend, RenderFactory};
+use self::ion_core::util::image::Image;
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 
 /*===============================================================================================*/
 /*------RENDER FACTORY STRUCT--------------------------------------------------------------------*/
 /*===============================================================================================*/
 
 /// The render factory.
-#[derive (Copy, Clone)]
-pub struct RenderFactorySDLGL;
+///
+/// Nothing can be created until the window backend it returns has created an OpenGL context.
+/// If the context could not be created, every resource fails to create and commands are dropped.
+pub struct RenderFactorySDLGL {
+
+    // Private
+    _is_context_ready: Rc<Cell<bool>>,
+    _executor: RefCell<CommandExecutorGL>,
+}
 
 /*===============================================================================================*/
 /*------RENDER FACTORY STRUCT PUBLIC METHODS-----------------------------------------------------*/
@@ -35,6 +50,157 @@ pub struct RenderFactorySDLGL;
 impl RenderFactory for RenderFactorySDLGL {
 
     fn get_window_backend (&self) -> Box<WindowBackend> {
-        Box::new (WindowBackendSDL2::new ())
+
+        let mut window_backend = WindowBackendSDL2::new ();
+        window_backend.set_context_state (self._is_context_ready.clone ());
+
+        Box::new (window_backend)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_buffer (&self, desc: &BufferDesc, data: &[u8]) -> Result<Rc<Buffer>, ()> {
+
+        try! (self._check_context ());
+        Ok (Rc::new (BufferGL::new (desc, data)))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_texture (&self, desc: &TextureDesc, data: &[u8]) -> Result<Rc<Texture>, ()> {
+
+        try! (self._check_context ());
+        Ok (Rc::new (TextureGL::new (desc, data)))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_shader (&self, stage: ShaderStage, source: &str) -> Result<Rc<Shader>, ()> {
+
+        try! (self._check_context ());
+
+        match ShaderGL::new (stage, source) {
+
+            Ok (shader) => Ok (Rc::new (shader)),
+            Err (e) => {
+
+                error! ("Could not compile {:?} shader.\n{}", stage, e);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_builtin_shader (&self, shader: BuiltinShader, stage: ShaderStage) -> Result<Rc<Shader>, ()> {
+        self.create_shader (stage, &BuiltinShadersGL::get_source (shader, stage))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_pipeline (&self, desc: &PipelineDesc) -> Result<Rc<Pipeline>, ()> {
+
+        try! (self._check_context ());
+
+        match PipelineGL::new (desc) {
+
+            Ok (pipeline) => Ok (Rc::new (pipeline)),
+            Err (e) => {
+
+                error! ("Could not link pipeline.\n{}", e);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn create_render_target (&self, desc: &RenderTargetDesc) -> Result<Rc<RenderTarget>, ()> {
+
+        try! (self._check_context ());
+
+        match RenderTargetGL::new (desc) {
+
+            Ok (target) => Ok (Rc::new (target)),
+            Err (e) => {
+
+                error! ("Could not create render target.\n{}", e);
+                Err (())
+            }
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn read_pixels (&self, target: Option<Rc<RenderTarget>>, x: i32, y: i32, width: u32, height: u32) -> Result<Image, ()> {
+
+        try! (self._check_context ());
+
+        let framebuffer = match target {
+
+            Some (ref t) => match t.as_any ().downcast_ref::<RenderTargetGL> () {
+
+                Some (t) => t.gl_id,
+                None => {
+
+                    error! ("Render target was not created by the OpenGL render factory.");
+                    return Err (());
+                }
+            },
+
+            None => 0
+        };
+
+        Ok (RenderTargetGL::read_pixels (framebuffer, x, y, width, height))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn execute_command_buffer (&self, command_buffer: &CommandBuffer) {
+
+        if self._is_context_ready.get () {
+            self._executor.borrow_mut ().execute (command_buffer);
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------RENDER FACTORY STRUCT PUBLIC STATIC METHODS----------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderFactorySDLGL {
+
+    /// Returns a new instance of the render factory.
+    pub fn new () -> RenderFactorySDLGL {
+        RenderFactorySDLGL {_is_context_ready: Rc::new (Cell::new (false)),
+                            _executor: RefCell::new (CommandExecutorGL::new ())}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Default for RenderFactorySDLGL {
+
+    fn default () -> RenderFactorySDLGL {
+        RenderFactorySDLGL::new ()
+    }
+}
+
+/*===============================================================================================*/
+/*------RENDER FACTORY STRUCT PRIVATE METHODS----------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RenderFactorySDLGL {
+
+    // Returns an error if there is no OpenGL context to create resources with.
+    fn _check_context (&self) -> Result<(), ()> {
+
+        if self._is_context_ready.get () {
+            Ok (())
+        }
+
+        else {
+            Err (())
+        }
     }
 }
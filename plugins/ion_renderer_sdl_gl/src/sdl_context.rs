@@ -0,0 +1,56 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+
+extern crate sdl2;
+
+use std::cell::RefCell;
+
+// The SDL context shared by the backends.
+thread_local! (static SDL2_CONTEXT: RefCell<Option<sdl2::Sdl>> = RefCell::new (None));
+
+/*===============================================================================================*/
+/*------SDL CONTEXT STRUCT-----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Gives the backends access to a single SDL context.
+///
+/// SDL can only be initialized once at a time, so the window and audio backends
+/// share the context instead of each creating their own.
+#[derive (Copy, Clone)]
+pub struct SdlContext;
+
+/*===============================================================================================*/
+/*------SDL CONTEXT PUBLIC STATIC METHODS--------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl SdlContext {
+
+    /// Returns the SDL context, initializing SDL on first use.
+    pub fn get () -> Result<sdl2::Sdl, String> {
+
+        SDL2_CONTEXT.with (|context| {
+
+            let mut context = context.borrow_mut ();
+
+            if context.is_none () {
+                *context = Some (try! (sdl2::init ()));
+            }
+
+            Ok (context.as_ref ().unwrap ().clone ())
+        })
+    }
+}
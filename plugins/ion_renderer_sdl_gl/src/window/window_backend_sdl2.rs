@@ -14,16 +14,24 @@
 // limitations under the License.
 /*===============================================================================================*/
 
+extern crate gl;
 extern crate ion_core;
 extern crate sdl2;
 
+use ::SdlContext;
+
+use self::gl::types::*;
+use self::ion_core::renderer::{ContextConfig, ContextProfile};
 use self::ion_core::renderer::traits::WindowBackend;
 use self::ion_core::renderer::window::WindowConfig;
 use self::ion_core::util::traits::AsAny;
 use self::ion_core::util::math::Vec2;
 use self::sdl2::event::{Event, WindowEventId};
+use self::sdl2::video::GLProfile;
 
 use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
 
 /*===============================================================================================*/
 /*------WINDOW BACKEND SDL2 STRUCT---------------------------------------------------------------*/
@@ -39,6 +47,8 @@ pub struct WindowBackendSDL2 {
     pub sdl2_video:      Option<sdl2::VideoSubsystem>,
     /// Stores the sdl2 window.
     pub sdl2_window:     Option<sdl2::video::Window>,
+    /// Stores the OpenGL context.
+    pub sdl2_gl_context: Option<sdl2::video::GLContext>,
     /// Stores the sdl2 event pump.
     pub sdl2_event_pump: Option<sdl2::EventPump>,
 
@@ -47,6 +57,8 @@ pub struct WindowBackendSDL2 {
     _move_callback:   Box<Fn (Vec2)>,
     _resize_callback: Box<Fn (Vec2)>,
     _close_callback:  Box<Fn ()>,
+    _gl_vao:          GLuint,
+    _is_context_ready: Rc<Cell<bool>>,
 }
 
 /*===============================================================================================*/
@@ -64,12 +76,15 @@ impl AsAny for WindowBackendSDL2 {
 
 impl WindowBackend for WindowBackendSDL2 {
 
-    fn init (&mut self, config: &WindowConfig) {
+    fn init (&mut self, config: &WindowConfig, context_config: &ContextConfig) {
 
         // Initialize sdl and video subsystem
-        let sdl2_context = sdl2::init ().unwrap ();
+        let sdl2_context = SdlContext::get ().unwrap ();
         let sdl2_video   = sdl2_context.video ().unwrap ();
 
+        // The context attributes must be set before the window is created
+        WindowBackendSDL2::_set_gl_attributes (&sdl2_video, context_config);
+
         // Create new sdl2 window builder
         let mut sdl2_window_builder = sdl2_video.window (&config.window_title,
             config.window_size.x as u32,
@@ -87,11 +102,58 @@ impl WindowBackend for WindowBackendSDL2 {
         let sdl2_window     = sdl2_window_builder.build ().unwrap ();
         let sdl2_event_pump = sdl2_context.event_pump   ().unwrap ();
 
+        // Create the OpenGL context, falling back to lower versions if needed
+        let sdl2_gl_context = match WindowBackendSDL2::_create_gl_context (&sdl2_video, &sdl2_window, context_config) {
+
+            Ok  (gl_context) => gl_context,
+            Err (_) => {
+
+                error! ("Could not create an OpenGL context, so the renderer will not be created.");
+
+                self.sdl2_context    = Some (sdl2_context);
+                self.sdl2_video      = Some (sdl2_video);
+                self.sdl2_event_pump = Some (sdl2_event_pump);
+                self.sdl2_window     = Some (sdl2_window);
+                return;
+            }
+        };
+
+        // Load the function pointers, and log the version which was actually created
+        gl::load_with (|name| sdl2_video.gl_get_proc_address (name) as *const _);
+        self._is_context_ready.set (true);
+
+        let mut major_version = 0;
+        let mut minor_version = 0;
+
+        unsafe {
+
+            gl::GetIntegerv (gl::MAJOR_VERSION, &mut major_version);
+            gl::GetIntegerv (gl::MINOR_VERSION, &mut minor_version);
+        }
+
+        info! ("Created OpenGL {}.{} context.", major_version, minor_version);
+
+        if !sdl2_video.gl_set_swap_interval (if context_config.context_vsync {1} else {0}) {
+            warn! ("Could not set the swap interval.");
+        }
+
+        unsafe {
+
+            // Core profiles require a vertex array object to be bound
+            gl::GenVertexArrays (1, &mut self._gl_vao);
+            gl::BindVertexArray (self._gl_vao);
+
+            if context_config.context_msaa_samples > 0 {
+                gl::Enable (gl::MULTISAMPLE);
+            }
+        }
+
         // Set window backend variables
         self.sdl2_context    = Some (sdl2_context);
         self.sdl2_video      = Some (sdl2_video);
         self.sdl2_event_pump = Some (sdl2_event_pump);
         self.sdl2_window     = Some (sdl2_window);
+        self.sdl2_gl_context = Some (sdl2_gl_context);
 
         // Call the window creation callback
         (self._create_callback) ();
@@ -154,14 +216,57 @@ impl WindowBackend for WindowBackendSDL2 {
             }
         }
     }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn present (&mut self) {
+
+        if let Some (ref window) = self.sdl2_window {
+            window.gl_swap_window ();
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn get_drawable_size (&self) -> Result<Vec2, ()> {
+
+        match self.sdl2_window {
+
+            Some (ref window) => {
+
+                let (width, height) = window.drawable_size ();
+                Ok (Vec2 {x: width as f32, y: height as f32})
+            },
+
+            None => Err (())
+        }
+    }
 }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+impl WindowBackendSDL2 {
+
+    /// Returns whether the OpenGL context was created, and its functions loaded.
+    ///
+    /// No OpenGL functions can be called until it is.
+    pub fn is_context_ready (&self) -> bool {
+        self._is_context_ready.get ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Shares the context state with another owner, such as the render factory.
+    ///
+    /// The state is set once the OpenGL context is ready, and cleared when the window is dropped.
+    pub fn set_context_state (&mut self, is_context_ready: Rc<Cell<bool>>) {
+        self._is_context_ready = is_context_ready;
+    }
+
 /*===============================================================================================*/
 /*------WINDOW BACKEND SDL2 PUBLIC STATIC METHODS------------------------------------------------*/
 /*===============================================================================================*/
 
-impl WindowBackendSDL2 {
-
     /// Returns a new `WindowBackendSDL2` instance.
     pub fn new () -> WindowBackendSDL2 {
 
@@ -171,10 +276,13 @@ impl WindowBackendSDL2 {
             sdl2_video:       None,
             sdl2_event_pump:  None,
             sdl2_window:      None,
+            sdl2_gl_context:  None,
             _create_callback: Box::new (||  {}),
             _move_callback:   Box::new (|_| {}),
             _resize_callback: Box::new (|_| {}),
             _close_callback:  Box::new (||  {}),
+            _gl_vao:          0,
+            _is_context_ready: Rc::new (Cell::new (false)),
         }
     }
 }
@@ -187,3 +295,86 @@ impl Default for WindowBackendSDL2 {
         WindowBackendSDL2::new ()
     }
 }
+
+/*===============================================================================================*/
+/*------WINDOW BACKEND SDL2 PRIVATE STATIC METHODS-----------------------------------------------*/
+/*===============================================================================================*/
+
+impl WindowBackendSDL2 {
+
+    // Creates an OpenGL context for the window. If the requested version is not supported,
+    // each lower version for the profile is tried in turn.
+    fn _create_gl_context (video:  &sdl2::VideoSubsystem,
+                           window: &sdl2::video::Window,
+                           config: &ContextConfig) -> Result<sdl2::video::GLContext, ()> {
+
+        let requested = (config.context_version_major, config.context_version_minor);
+
+        match window.gl_create_context () {
+
+            Ok  (gl_context) => return Ok (gl_context),
+            Err (e) => warn! ("Could not create an OpenGL {}.{} context ({}).", requested.0, requested.1, e),
+        }
+
+        let fallback_versions: &[(u8, u8)] = match config.context_profile {
+
+            ContextProfile::ES => &[(3, 2), (3, 1), (3, 0), (2, 0)],
+            _ => &[(4, 5), (4, 4), (4, 3), (4, 2), (4, 1), (4, 0), (3, 3), (3, 2)],
+        };
+
+        for &(major, minor) in fallback_versions.iter ().filter (|version| **version < requested) {
+
+            video.gl_attr ().set_context_version (major, minor);
+
+            match window.gl_create_context () {
+
+                Ok  (gl_context) => return Ok (gl_context),
+                Err (e) => warn! ("Could not create an OpenGL {}.{} context ({}).", major, minor, e),
+            }
+        }
+
+        Err (())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    // Sets the OpenGL context attributes from the context config.
+    fn _set_gl_attributes (video: &sdl2::VideoSubsystem, config: &ContextConfig) {
+
+        let gl_attr = video.gl_attr ();
+
+        gl_attr.set_context_version (config.context_version_major, config.context_version_minor);
+        gl_attr.set_context_profile (match config.context_profile {
+
+            ContextProfile::Core          => GLProfile::Core,
+            ContextProfile::Compatibility => GLProfile::Compatibility,
+            ContextProfile::ES            => GLProfile::GLES,
+        });
+
+        gl_attr.set_double_buffer (true);
+        gl_attr.set_depth_size    (config.context_depth_bits);
+        gl_attr.set_stencil_size  (config.context_stencil_bits);
+
+        // Multisampling
+        if config.context_msaa_samples > 0 {
+
+            gl_attr.set_multisample_buffers (1);
+            gl_attr.set_multisample_samples (config.context_msaa_samples);
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Drop for WindowBackendSDL2 {
+
+    fn drop (&mut self) {
+
+        // Release the vertex array while the context is still alive
+        if self.sdl2_gl_context.is_some () && self._gl_vao != 0 {
+            unsafe {gl::DeleteVertexArrays (1, &self._gl_vao)};
+        }
+
+        self._is_context_ready.set (false);
+    }
+}